@@ -0,0 +1,127 @@
+//! `include_schema!` expands a Tech Paws Buffers schema in place, so crates
+//! can use the generated models, buffers and `register_rpc` without a build
+//! script:
+//!
+//! ```ignore
+//! mod buffers {
+//!     tech_paws_buffers_macros::include_schema!("schema/editor.tpb");
+//! }
+//! ```
+//!
+//! The expansion never refers to this crate, so the runtime can re-export
+//! the macro as `tech_paws_buffers::include_schema!`.
+
+use std::{env, fs, path::PathBuf};
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, LitStr};
+use tech_paws_buffers_generator::{parser, rust_generator};
+
+/// Generates the Rust code for a schema, the path is relative to the
+/// directory containing the crate manifest.
+#[proc_macro]
+pub fn include_schema(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+
+    match expand(&path) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(path: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+    let full_path = match manifest_dir {
+        Some(manifest_dir) => manifest_dir.join(path.value()),
+        None => PathBuf::from(path.value()),
+    };
+
+    let src = fs::read_to_string(&full_path).map_err(|err| {
+        syn::Error::new(
+            path.span(),
+            format!("failed to read {}: {}", full_path.display(), err),
+        )
+    })?;
+
+    let ast = parser::parse_source(&src).map_err(|err| {
+        syn::Error::new(path.span(), format_parse_error(&path.value(), &src, &err))
+    })?;
+
+    // The generator panics on schemas it can't generate, e.g. without `#[id]`.
+    let code = parser::catch_panic(|| rust_generator::generate_items(&ast)).map_err(|err| {
+        syn::Error::new(
+            path.span(),
+            format!("failed to generate {}: {}", path.value(), err),
+        )
+    })?;
+
+    let code: proc_macro2::TokenStream = code.parse().map_err(|err| {
+        syn::Error::new(
+            path.span(),
+            format!("generated code for {} is invalid: {}", path.value(), err),
+        )
+    })?;
+
+    // Makes cargo rebuild the crate when the schema changes.
+    let full_path = full_path.to_string_lossy();
+
+    Ok(quote! {
+        const _: &[u8] = include_bytes!(#full_path);
+
+        #code
+    })
+}
+
+/// Formats a parse error the way rustc shows errors, with the schema line
+/// and a caret under the position.
+fn format_parse_error(path: &str, src: &str, err: &parser::ParseError) -> String {
+    let mut message = format!("{}\n --> {}:{}:{}", err.message, path, err.line, err.pos);
+
+    if let Some(line) = err.line.checked_sub(1).and_then(|i| src.lines().nth(i)) {
+        let gutter = err.line.to_string();
+        let padding = " ".repeat(gutter.len());
+
+        message.push_str(&format!(
+            "\n{} |\n{} | {}\n{} | {}^",
+            padding,
+            gutter,
+            line,
+            padding,
+            " ".repeat(err.pos),
+        ));
+    }
+
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+
+    #[test]
+    fn expand_reports_generator_errors() {
+        let path = LitStr::new("../test_resources/rpc_missing_id.tpb", Span::call_site());
+        let err = expand(&path).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "failed to generate ../test_resources/rpc_missing_id.tpb: id is required"
+        );
+    }
+
+    #[test]
+    fn format_parse_error_test() {
+        let src = "struct Point {\n    x f32,\n}\n";
+        let err = parser::parse_source(src).unwrap_err();
+
+        assert_eq!(
+            format_parse_error("schema/point.tpb", src, &err),
+            format!(
+                "{}\n --> schema/point.tpb:2:6\n  |\n2 |     x f32,\n  |       ^",
+                err.message,
+            )
+        );
+    }
+}
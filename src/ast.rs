@@ -1,6 +1,9 @@
-use crate::lexer::Literal;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+use crate::{lexer::Literal, writer::Writer};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ASTNode {
     Enum(EnumASTNode),
     Struct(StructASTNode),
@@ -10,13 +13,14 @@ pub enum ASTNode {
     DocComments { comments: Vec<String> },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConstBlockASTNode {
     pub id: String,
     pub items: Vec<ConstItemASTNode>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ConstItemASTNode {
     Value {
         id: String,
@@ -28,7 +32,8 @@ pub enum ConstItemASTNode {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "directive", rename_all = "snake_case")]
 pub enum DirectiveASTNode {
     Value {
         id: String,
@@ -52,7 +57,7 @@ impl DirectiveASTNode {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct EnumASTNode {
     pub doc_comments: Vec<String>,
     pub directives: Vec<DirectiveASTNode>,
@@ -60,7 +65,7 @@ pub struct EnumASTNode {
     pub items: Vec<EnumItemASTNode>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct StructASTNode {
     pub doc_comments: Vec<String>,
     pub directives: Vec<DirectiveASTNode>,
@@ -70,7 +75,7 @@ pub struct StructASTNode {
     pub into_buffers: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FnASTNode {
     pub doc_comments: Vec<String>,
     pub directives: Vec<DirectiveASTNode>,
@@ -82,7 +87,8 @@ pub struct FnASTNode {
     pub is_async: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum EnumItemASTNode {
     Empty {
         doc_comments: Vec<String>,
@@ -103,7 +109,8 @@ pub enum EnumItemASTNode {
     },
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ConstValueASTNode {
     Literal {
         literal: Literal,
@@ -111,13 +118,14 @@ pub enum ConstValueASTNode {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IdValuePair {
     pub id: String,
     pub value: Option<ConstValueASTNode>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum TypeIDASTNode {
     Integer {
         id: String,
@@ -221,14 +229,14 @@ impl TypeIDASTNode {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TupleFieldASTNode {
     pub doc_comments: Vec<String>,
     pub position: u32,
     pub type_id: TypeIDASTNode,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StructFieldASTNode {
     pub doc_comments: Vec<String>,
     pub position: u32,
@@ -236,7 +244,7 @@ pub struct StructFieldASTNode {
     pub type_id: TypeIDASTNode,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FnArgASTNode {
     pub id: String,
     pub type_id: TypeIDASTNode,
@@ -286,6 +294,30 @@ pub fn find_fn_nodes(ast: &[ASTNode]) -> Vec<&FnASTNode> {
     res
 }
 
+pub fn find_struct_node<'a>(ast: &'a [ASTNode], target_id: &str) -> Option<&'a StructASTNode> {
+    for node in ast {
+        if let ASTNode::Struct(node) = node {
+            if node.id == target_id {
+                return Some(node);
+            }
+        }
+    }
+
+    None
+}
+
+pub fn find_enum_node<'a>(ast: &'a [ASTNode], target_id: &str) -> Option<&'a EnumASTNode> {
+    for node in ast {
+        if let ASTNode::Enum(node) = node {
+            if node.id == target_id {
+                return Some(node);
+            }
+        }
+    }
+
+    None
+}
+
 pub fn find_directive_value(ast: &[ASTNode], target_id: &str) -> Option<ConstValueASTNode> {
     for node in ast {
         if let ASTNode::Directive(DirectiveASTNode::Value { id, value }) = node {
@@ -343,3 +375,272 @@ pub fn find_directive_group_values(
 
     res
 }
+
+pub fn contains_directive_group_flag(
+    ast: &[ASTNode],
+    target_group_id: &str,
+    target_id: &str,
+) -> bool {
+    for node in ast {
+        if let ASTNode::Directive(DirectiveASTNode::Group { group_id, values }) = node {
+            if target_group_id == group_id && values.iter().any(|value| value.id == target_id) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Prints the AST in the format of the `.ast` test fixtures.
+pub fn stringify_ast(ast: &[ASTNode]) -> String {
+    stringify_ast_impl(0, ast)
+}
+
+fn stringify_ast_impl(tab: usize, ast: &[ASTNode]) -> String {
+    let mut writer = Writer::new(2);
+
+    for node in ast {
+        match node {
+            ASTNode::DocComments { comments } => {
+                writer.writeln_tab(tab, "DocComment {");
+                writer.writeln_tab(tab + 1, "comments: [");
+
+                for comment in comments {
+                    writer.writeln_tab(tab + 2, &format!("\"{}\"", comment));
+                }
+
+                writer.writeln_tab(tab + 1, "]");
+                writer.writeln_tab(tab, "}");
+            }
+            ASTNode::Directive(DirectiveASTNode::Group {
+                group_id,
+                values: args,
+            }) => {
+                writer.writeln_tab(tab, "DirectiveASTNode::Group {");
+                writer.writeln_tab(tab + 1, &format!("group_id: \"{}\",", group_id));
+                writer.writeln_tab(tab + 1, "args: [");
+
+                for arg in args {
+                    writer.writeln_tab(tab + 2, "IdValuePair {");
+                    writer.writeln_tab(tab + 3, &format!("id: \"{}\"", arg.id));
+                    writer.writeln_tab(tab + 3, &format!("value: {:?}", arg.value));
+                    writer.writeln_tab(tab + 2, "}");
+                }
+
+                writer.writeln_tab(tab + 1, "]");
+                writer.writeln_tab(tab, "}");
+            }
+            ASTNode::Directive(DirectiveASTNode::Value { id, value }) => {
+                writer.writeln_tab(tab, "DirectiveASTNode::Value {");
+                writer.writeln_tab(tab + 1, &format!("id: \"{}\"", id));
+                writer.writeln_tab(tab + 1, &format!("value: {:?}", value));
+                writer.writeln_tab(tab, "}");
+            }
+            ASTNode::Enum(EnumASTNode {
+                doc_comments,
+                id,
+                items,
+                directives,
+            }) => {
+                writer.writeln_tab(tab, "Enum {");
+                writer.writeln_tab(tab + 1, "doc_comments: [");
+
+                for comment in doc_comments {
+                    writer.writeln_tab(tab + 2, &format!("\"{}\"", comment));
+                }
+
+                writer.writeln_tab(tab + 1, "],");
+
+                writer.writeln_tab(tab + 1, "directives: [");
+
+                let nodes: Vec<ASTNode> = directives
+                    .iter()
+                    .map(|directive| ASTNode::Directive(directive.clone()))
+                    .collect();
+
+                writer.write(&stringify_ast_impl(tab + 2, &nodes));
+
+                writer.writeln_tab(tab + 1, "],");
+
+                writer.writeln_tab(tab + 1, &format!("id: \"{}\",", id));
+                writer.writeln_tab(tab + 1, "items: [");
+
+                for item in items {
+                    match &item {
+                        EnumItemASTNode::Tuple {
+                            doc_comments,
+                            position,
+                            id,
+                            values,
+                        } => {
+                            writer.writeln_tab(tab + 2, "TupleFieldASTNode {");
+
+                            writer.writeln_tab(tab + 3, "doc_comments: [");
+
+                            for comment in doc_comments {
+                                writer.writeln_tab(tab + 4, &format!("\"{}\"", comment));
+                            }
+
+                            writer.writeln_tab(tab + 3, "],");
+
+                            writer.writeln_tab(tab + 3, &format!("position: {},", position));
+                            writer.writeln_tab(tab + 3, &format!("id: \"{}\",", id));
+                            writer.writeln_tab(tab + 3, "items: [");
+
+                            for value in values {
+                                writer.writeln_tab(tab + 4, &format!("{:?}", value));
+                            }
+
+                            writer.writeln_tab(tab + 3, "]");
+                            writer.writeln_tab(tab + 2, "}");
+                        }
+                        EnumItemASTNode::Struct {
+                            doc_comments,
+                            position,
+                            id,
+                            fields,
+                        } => {
+                            writer.writeln_tab(tab + 2, "EnumItemASTNode {");
+
+                            writer.writeln_tab(tab + 3, "doc_comments: [");
+
+                            for comment in doc_comments {
+                                writer.writeln_tab(tab + 4, &format!("\"{}\"", comment));
+                            }
+
+                            writer.writeln_tab(tab + 3, "],");
+
+                            writer.writeln_tab(tab + 3, &format!("position: {},", position));
+                            writer.writeln_tab(tab + 3, &format!("id: \"{}\",", id));
+                            writer.writeln_tab(tab + 3, "fields: [");
+
+                            for field in fields {
+                                writer.writeln_tab(tab + 4, &format!("{:?}", field));
+                            }
+
+                            writer.writeln_tab(tab + 3, "]");
+                            writer.writeln_tab(tab + 2, "}");
+                        }
+                        _ => writer.writeln_tab(tab + 2, &format!("{:?}", item)),
+                    }
+                }
+
+                writer.writeln_tab(tab + 1, "]");
+                writer.writeln_tab(tab, "}");
+            }
+            ASTNode::Struct(StructASTNode {
+                doc_comments,
+                id,
+                fields,
+                emplace_buffers: _,
+                into_buffers: _,
+                directives,
+            }) => {
+                writer.writeln_tab(tab, "Struct {");
+
+                writer.writeln_tab(tab + 1, "doc_comments: [");
+
+                for comment in doc_comments {
+                    writer.writeln_tab(tab + 2, &format!("\"{}\"", comment));
+                }
+
+                writer.writeln_tab(tab + 1, "],");
+
+                writer.writeln_tab(tab + 1, "directives: [");
+
+                let nodes: Vec<ASTNode> = directives
+                    .iter()
+                    .map(|directive| ASTNode::Directive(directive.clone()))
+                    .collect();
+
+                writer.write(&stringify_ast_impl(tab + 2, &nodes));
+
+                writer.writeln_tab(tab + 1, "],");
+
+                writer.writeln_tab(tab + 1, &format!("id: \"{}\",", id));
+                writer.writeln_tab(tab + 1, "fields: [");
+
+                for field in fields {
+                    writer.writeln_tab(tab + 2, &format!("{:?}", field));
+                }
+
+                writer.writeln_tab(tab + 1, "]");
+                writer.writeln_tab(tab, "}");
+            }
+            ASTNode::Fn(FnASTNode {
+                id,
+                position,
+                args,
+                return_type_id,
+                is_signal,
+                is_async,
+                doc_comments,
+                directives,
+            }) => {
+                writer.writeln_tab(tab, "Fn {");
+
+                writer.writeln_tab(tab + 1, "doc_comments: [");
+
+                for comment in doc_comments {
+                    writer.writeln_tab(tab + 2, &format!("\"{}\"", comment));
+                }
+
+                writer.writeln_tab(tab + 1, "],");
+
+                writer.writeln_tab(tab + 1, "directives: [");
+
+                let nodes: Vec<ASTNode> = directives
+                    .iter()
+                    .map(|directive| ASTNode::Directive(directive.clone()))
+                    .collect();
+
+                writer.write(&stringify_ast_impl(tab + 2, &nodes));
+
+                writer.writeln_tab(tab + 1, "],");
+
+                writer.writeln_tab(tab + 1, &format!("id: \"{}\",", id));
+                writer.writeln_tab(tab + 1, &format!("position: {:?},", position));
+                writer.writeln_tab(tab + 1, &format!("return_type_id: {:?},", return_type_id));
+                writer.writeln_tab(tab + 1, &format!("is_signal: {:?},", is_signal));
+                writer.writeln_tab(tab + 1, &format!("is_async: {:?},", is_async));
+                writer.writeln_tab(tab + 1, "args: [");
+
+                for arg in args {
+                    writer.writeln_tab(tab + 2, &format!("{:?}", arg));
+                }
+
+                writer.writeln_tab(tab + 1, "]");
+                writer.writeln_tab(tab, "}");
+            }
+            ASTNode::Const(ConstBlockASTNode { id, items }) => {
+                writer.writeln_tab(tab, "Const {");
+                writer.writeln_tab(tab + 1, &format!("id: \"{}\",", id));
+                writer.writeln_tab(tab + 1, "items: [");
+
+                for item in items {
+                    match &item {
+                        ConstItemASTNode::Value { id, type_id, value } => {
+                            writer.writeln_tab(tab + 2, "Value {");
+                            writer.writeln_tab(tab + 3, &format!("id: {}", id));
+                            writer.writeln_tab(tab + 3, &format!("type_id: {:?}", type_id));
+                            writer.writeln_tab(tab + 3, &format!("value: {:?}", value));
+                            writer.writeln_tab(tab + 2, "}");
+                        }
+                        ConstItemASTNode::ConstsBlock { node } => {
+                            writer.write(&stringify_ast_impl(
+                                tab + 2,
+                                &[ASTNode::Const(node.clone())],
+                            ));
+                        }
+                    }
+                }
+
+                writer.writeln_tab(tab + 1, "]");
+                writer.writeln_tab(tab, "}");
+            }
+        }
+    }
+
+    writer.show().to_string()
+}
@@ -0,0 +1,989 @@
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{
+        self, ASTNode, ConstBlockASTNode, ConstItemASTNode, ConstValueASTNode, EnumASTNode,
+        EnumItemASTNode, FnASTNode, StructASTNode, StructFieldASTNode, TypeIDASTNode,
+    },
+    lexer::Literal,
+    writer::Writer,
+};
+
+const C_KEYWORDS: &[&str] = &[
+    "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else",
+    "enum", "extern", "float", "for", "goto", "if", "inline", "int", "long", "register",
+    "restrict", "return", "short", "signed", "sizeof", "static", "struct", "switch", "typedef",
+    "union", "unsigned", "void", "volatile", "while", "bool", "true", "false",
+];
+
+/// Buffer arguments of every generated function.
+const READER_ARGS: &str = "const uint8_t* data, size_t len, size_t* cursor";
+const WRITER_ARGS: &str = "uint8_t* data, size_t len, size_t* cursor";
+
+pub fn generate_consts(ast: &[ASTNode]) -> String {
+    let mut writer = Writer::default();
+
+    for node in ast {
+        if let ASTNode::Const(node) = node {
+            generate_const_block(&mut writer, &node.id.to_uppercase(), node);
+        }
+    }
+
+    writer.show().to_string()
+}
+
+fn generate_const_block(writer: &mut Writer, prefix: &str, node: &ConstBlockASTNode) {
+    for item in &node.items {
+        match item {
+            ConstItemASTNode::Value { id, type_id, value } => {
+                writer.writeln(&format!(
+                    "#define {}_{} {}",
+                    prefix,
+                    id.to_uppercase(),
+                    generate_const_value(value, type_id)
+                ));
+            }
+            ConstItemASTNode::ConstsBlock { node } => {
+                let prefix = format!("{}_{}", prefix, node.id.to_uppercase());
+                generate_const_block(writer, &prefix, node);
+            }
+        }
+    }
+}
+
+/// Models come after the models they contain, C needs complete types for
+/// fields and declarations before calls.
+pub fn generate_models(ast: &[ASTNode]) -> String {
+    let mut ids = vec![];
+
+    for node in ast {
+        match node {
+            ASTNode::Struct(node) => sort_models(ast, &node.id, &mut vec![], &mut ids),
+            ASTNode::Enum(node) => sort_models(ast, &node.id, &mut vec![], &mut ids),
+            _ => (),
+        }
+    }
+
+    let mut blocks = vec![];
+
+    for id in ids {
+        if let Some(node) = ast::find_struct_node(ast, &id) {
+            blocks.push(generate_struct_model(ast, node));
+        } else if let Some(node) = ast::find_enum_node(ast, &id) {
+            blocks.push(generate_enum_model(ast, node));
+        }
+    }
+
+    blocks.join("\n")
+}
+
+fn sort_models(ast: &[ASTNode], id: &str, visiting: &mut Vec<String>, ids: &mut Vec<String>) {
+    if ids
+        .iter()
+        .chain(visiting.iter())
+        .any(|visited| visited == id)
+    {
+        return;
+    }
+
+    visiting.push(id.to_string());
+
+    for type_id in model_type_ids(ast, id) {
+        let mut dependencies = vec![];
+        collect_model_ids(type_id, &mut dependencies);
+
+        for dependency in dependencies {
+            sort_models(ast, &dependency, visiting, ids);
+        }
+    }
+
+    visiting.pop();
+    ids.push(id.to_string());
+}
+
+fn model_type_ids<'a>(ast: &'a [ASTNode], id: &str) -> Vec<&'a TypeIDASTNode> {
+    if let Some(node) = ast::find_struct_node(ast, id) {
+        node.fields.iter().map(|field| &field.type_id).collect()
+    } else if let Some(node) = ast::find_enum_node(ast, id) {
+        let mut res = vec![];
+
+        for item in &node.items {
+            match item {
+                EnumItemASTNode::Empty { .. } => (),
+                EnumItemASTNode::Tuple { values, .. } => {
+                    res.extend(values.iter().map(|value| &value.type_id))
+                }
+                EnumItemASTNode::Struct { fields, .. } => {
+                    res.extend(fields.iter().map(|field| &field.type_id))
+                }
+            }
+        }
+
+        res
+    } else {
+        vec![]
+    }
+}
+
+fn collect_model_ids(type_id: &TypeIDASTNode, ids: &mut Vec<String>) {
+    match type_id {
+        TypeIDASTNode::Other { id } if id != "String" && !is_address(id) => ids.push(id.clone()),
+        TypeIDASTNode::Generic { generics, .. } => {
+            for generic in generics {
+                collect_model_ids(generic, ids);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Whether the type has a C representation, `visiting` holds the models
+/// being checked to reject models containing themselves.
+fn is_representable(ast: &[ASTNode], type_id: &TypeIDASTNode, visiting: &mut Vec<String>) -> bool {
+    match type_id {
+        TypeIDASTNode::Generic { id, generics } if id == "Option" => match &generics[0] {
+            TypeIDASTNode::Generic { id, .. } if id == "Option" => false,
+            generic => is_representable(ast, generic, visiting),
+        },
+        TypeIDASTNode::Generic { generics, .. } if is_vec(type_id) => {
+            generate_skip_fn(&generics[0]).is_some()
+                && is_representable(ast, &generics[0], visiting)
+        }
+        TypeIDASTNode::Generic { .. } => is_bytes(type_id),
+        TypeIDASTNode::Other { id } if id != "String" && !is_address(id) => {
+            if visiting.contains(id) {
+                return false;
+            }
+
+            if ast::find_struct_node(ast, id).is_none() && ast::find_enum_node(ast, id).is_none() {
+                return false;
+            }
+
+            visiting.push(id.clone());
+            let res = model_type_ids(ast, id)
+                .into_iter()
+                .all(|type_id| is_representable(ast, type_id, visiting));
+            visiting.pop();
+
+            res
+        }
+        _ => true,
+    }
+}
+
+/// Whether the type can be skipped, recursive models can.
+fn is_skippable(ast: &[ASTNode], type_id: &TypeIDASTNode, visiting: &mut Vec<String>) -> bool {
+    match type_id {
+        TypeIDASTNode::Generic { id, generics } if id == "Option" || id == "Vec" => {
+            is_skippable(ast, &generics[0], visiting)
+        }
+        TypeIDASTNode::Generic { .. } => false,
+        TypeIDASTNode::Other { id } if id != "String" && !is_address(id) => {
+            if visiting.contains(id) {
+                return true;
+            }
+
+            if ast::find_struct_node(ast, id).is_none() && ast::find_enum_node(ast, id).is_none() {
+                return false;
+            }
+
+            visiting.push(id.clone());
+            let res = model_type_ids(ast, id)
+                .into_iter()
+                .all(|type_id| is_skippable(ast, type_id, visiting));
+            visiting.pop();
+
+            res
+        }
+        _ => true,
+    }
+}
+
+fn is_model_representable(ast: &[ASTNode], id: &str) -> bool {
+    is_representable(
+        ast,
+        &TypeIDASTNode::Other { id: id.to_string() },
+        &mut vec![],
+    )
+}
+
+fn is_model_skippable(ast: &[ASTNode], id: &str) -> bool {
+    is_skippable(
+        ast,
+        &TypeIDASTNode::Other { id: id.to_string() },
+        &mut vec![],
+    )
+}
+
+fn generate_struct_model(ast: &[ASTNode], node: &StructASTNode) -> String {
+    let mut writer = Writer::default();
+    let prefix = node.id.to_case(Case::Snake);
+
+    if !is_model_representable(ast, &node.id) {
+        return generate_skip_only_model(ast, &node.id, |writer| {
+            for field in &node.fields {
+                generate_skip(writer, &field.type_id, 0);
+            }
+        });
+    }
+
+    writer.writeln(&format!("typedef struct {} {{", node.id));
+    writer.push_tab();
+    generate_fields(&mut writer, &node.fields);
+    writer.pop_tab();
+    writer.writeln(&format!("}} {};", node.id));
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "static inline bool {}_decode({}, {}* out) {{",
+        prefix, READER_ARGS, node.id
+    ));
+    writer.push_tab();
+
+    for field in &node.fields {
+        generate_decode(
+            &mut writer,
+            &field.type_id,
+            &format!("out->{}", field_name(&field.name)),
+        );
+    }
+
+    writer.writeln("return true;");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "static inline bool {}_encode({}, const {}* value) {{",
+        prefix, WRITER_ARGS, node.id
+    ));
+    writer.push_tab();
+
+    for field in &node.fields {
+        generate_encode(
+            &mut writer,
+            &field.type_id,
+            &format!("value->{}", field_name(&field.name)),
+        );
+    }
+
+    writer.writeln("return true;");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "static inline bool {}_skip({}) {{",
+        prefix, READER_ARGS
+    ));
+    writer.push_tab();
+
+    for field in &node.fields {
+        generate_skip(&mut writer, &field.type_id, 0);
+    }
+
+    writer.writeln("return true;");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+fn generate_enum_model(ast: &[ASTNode], node: &EnumASTNode) -> String {
+    let mut writer = Writer::default();
+    let prefix = node.id.to_case(Case::Snake);
+
+    if !is_model_representable(ast, &node.id) {
+        return generate_skip_only_model(ast, &node.id, |writer| {
+            generate_enum_switch(writer, node, "kind", false, |writer, item| {
+                for type_id in enum_item_type_ids(item) {
+                    generate_skip(writer, type_id, 0);
+                }
+            });
+        });
+    }
+
+    writer.writeln(&format!("typedef enum {}Kind {{", node.id));
+    writer.push_tab();
+
+    for item in &node.items {
+        writer.writeln(&format!(
+            "{} = {},",
+            enum_kind_id(node, item),
+            item.position()
+        ));
+    }
+
+    writer.pop_tab();
+    writer.writeln(&format!("}} {}Kind;", node.id));
+
+    writer.new_line();
+    writer.writeln(&format!("typedef struct {} {{", node.id));
+    writer.push_tab();
+    writer.writeln(&format!("{}Kind kind;", node.id));
+
+    if !node
+        .items
+        .iter()
+        .all(|item| matches!(item, EnumItemASTNode::Empty { .. }))
+    {
+        writer.writeln("union {");
+        writer.push_tab();
+
+        for item in &node.items {
+            let fields = enum_item_fields(item);
+
+            if !fields.is_empty() {
+                writer.writeln("struct {");
+                writer.push_tab();
+                generate_fields(&mut writer, &fields);
+                writer.pop_tab();
+                writer.writeln(&format!("}} {};", enum_item_name(item)));
+            }
+        }
+
+        writer.pop_tab();
+        writer.writeln("} value;");
+    }
+
+    writer.pop_tab();
+    writer.writeln(&format!("}} {};", node.id));
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "static inline bool {}_decode({}, {}* out) {{",
+        prefix, READER_ARGS, node.id
+    ));
+    writer.push_tab();
+    writer.writeln("uint32_t kind;");
+    writer.writeln("if (!tpb_read_u32(data, len, cursor, &kind)) return false;");
+    writer.writeln(&format!("out->kind = ({}Kind)kind;", node.id));
+    writer.new_line();
+    generate_enum_switch(&mut writer, node, "out->kind", true, |writer, item| {
+        for field in enum_item_fields(item) {
+            let accessor = format!(
+                "out->value.{}.{}",
+                enum_item_name(item),
+                field_name(&field.name)
+            );
+            generate_decode(writer, &field.type_id, &accessor);
+        }
+    });
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "static inline bool {}_encode({}, const {}* value) {{",
+        prefix, WRITER_ARGS, node.id
+    ));
+    writer.push_tab();
+    writer.writeln("if (!tpb_write_u32(data, len, cursor, (uint32_t)value->kind)) return false;");
+    writer.new_line();
+    generate_enum_switch(&mut writer, node, "value->kind", true, |writer, item| {
+        for field in enum_item_fields(item) {
+            let accessor = format!(
+                "value->value.{}.{}",
+                enum_item_name(item),
+                field_name(&field.name)
+            );
+            generate_encode(writer, &field.type_id, &accessor);
+        }
+    });
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "static inline bool {}_skip({}) {{",
+        prefix, READER_ARGS
+    ));
+    writer.push_tab();
+    writer.writeln("uint32_t kind;");
+    writer.writeln("if (!tpb_read_u32(data, len, cursor, &kind)) return false;");
+    writer.new_line();
+    generate_enum_switch(&mut writer, node, "kind", false, |writer, item| {
+        for type_id in enum_item_type_ids(item) {
+            generate_skip(writer, type_id, 0);
+        }
+    });
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+/// Models without a C representation still get a skip function when
+/// possible, so C code can step over them.
+fn generate_skip_only_model(
+    ast: &[ASTNode],
+    id: &str,
+    generate_body: impl Fn(&mut Writer),
+) -> String {
+    let mut writer = Writer::default();
+
+    if !is_model_skippable(ast, id) {
+        writer.writeln(&format!("/* {} has no C representation */", id));
+        return writer.show().to_string();
+    }
+
+    writer.writeln(&format!(
+        "/* {} has no C representation, it can only be skipped */",
+        id
+    ));
+    writer.writeln(&format!(
+        "static inline bool {}_skip({}) {{",
+        id.to_case(Case::Snake),
+        READER_ARGS
+    ));
+    writer.push_tab();
+
+    if ast::find_enum_node(ast, id).is_some() {
+        writer.writeln("uint32_t kind;");
+        writer.writeln("if (!tpb_read_u32(data, len, cursor, &kind)) return false;");
+        writer.new_line();
+        generate_body(&mut writer);
+    } else {
+        generate_body(&mut writer);
+        writer.writeln("return true;");
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+/// Writes a switch over the enum items returning `true` after the body of
+/// every item and `false` for unknown kinds.
+fn generate_enum_switch(
+    writer: &mut Writer,
+    node: &EnumASTNode,
+    value: &str,
+    use_kind_ids: bool,
+    generate_item: impl Fn(&mut Writer, &EnumItemASTNode),
+) {
+    writer.writeln(&format!("switch ({}) {{", value));
+
+    for item in &node.items {
+        if use_kind_ids {
+            writer.writeln(&format!("case {}:", enum_kind_id(node, item)));
+        } else {
+            writer.writeln(&format!("case {}:", item.position()));
+        }
+
+        writer.push_tab();
+        generate_item(writer, item);
+        writer.writeln("return true;");
+        writer.pop_tab();
+    }
+
+    writer.writeln("default:");
+    writer.push_tab();
+    writer.writeln("return false;");
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+fn enum_kind_id(node: &EnumASTNode, item: &EnumItemASTNode) -> String {
+    format!(
+        "{}_{}",
+        node.id.to_case(Case::UpperSnake),
+        item.id().to_case(Case::UpperSnake)
+    )
+}
+
+fn enum_item_name(item: &EnumItemASTNode) -> String {
+    field_name(&item.id().to_case(Case::Snake))
+}
+
+/// Fields of the item, tuple values are named `p` with the position like in
+/// the Swift models.
+fn enum_item_fields(item: &EnumItemASTNode) -> Vec<StructFieldASTNode> {
+    match item {
+        EnumItemASTNode::Empty { .. } => vec![],
+        EnumItemASTNode::Tuple { values, .. } => values
+            .iter()
+            .map(|value| StructFieldASTNode {
+                doc_comments: vec![],
+                position: value.position,
+                name: format!("p{}", value.position),
+                type_id: value.type_id.clone(),
+            })
+            .collect(),
+        EnumItemASTNode::Struct { fields, .. } => fields.clone(),
+    }
+}
+
+fn enum_item_type_ids(item: &EnumItemASTNode) -> Vec<&TypeIDASTNode> {
+    match item {
+        EnumItemASTNode::Empty { .. } => vec![],
+        EnumItemASTNode::Tuple { values, .. } => {
+            values.iter().map(|value| &value.type_id).collect()
+        }
+        EnumItemASTNode::Struct { fields, .. } => {
+            fields.iter().map(|field| &field.type_id).collect()
+        }
+    }
+}
+
+fn generate_fields(writer: &mut Writer, fields: &[StructFieldASTNode]) {
+    for field in fields {
+        let name = field_name(&field.name);
+
+        match &field.type_id {
+            TypeIDASTNode::Generic { id, generics } if id == "Option" => {
+                writer.writeln(&format!("bool has_{};", field.name));
+                writer.writeln(&format!("{} {};", generate_type_id(&generics[0]), name));
+            }
+            type_id => writer.writeln(&format!("{} {};", generate_type_id(type_id), name)),
+        }
+    }
+}
+
+fn field_name(name: &str) -> String {
+    if C_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// `has_` flag of an optional value at `accessor`.
+fn option_flag(accessor: &str) -> String {
+    match accessor.rfind(['.', '>']) {
+        Some(index) => format!(
+            "{}has_{}",
+            &accessor[..index + 1],
+            accessor[index + 1..].trim_end_matches('_')
+        ),
+        None => format!("has_{}", accessor.trim_end_matches('_')),
+    }
+}
+
+fn generate_decode(writer: &mut Writer, type_id: &TypeIDASTNode, accessor: &str) {
+    match type_id {
+        TypeIDASTNode::Generic { id, generics } if id == "Option" => {
+            let flag = option_flag(accessor);
+            writer.writeln(&format!(
+                "if (!tpb_read_bool(data, len, cursor, &{})) return false;",
+                flag
+            ));
+            writer.writeln(&format!(
+                "if ({} && !{}) return false;",
+                flag,
+                generate_decode_call(&generics[0], accessor)
+            ));
+        }
+        _ => writer.writeln(&format!(
+            "if (!{}) return false;",
+            generate_decode_call(type_id, accessor)
+        )),
+    }
+}
+
+fn generate_decode_call(type_id: &TypeIDASTNode, accessor: &str) -> String {
+    if let TypeIDASTNode::Generic { generics, .. } = type_id {
+        if is_vec(type_id) {
+            return format!(
+                "tpb_read_vec(data, len, cursor, {}, &{})",
+                generate_skip_fn(&generics[0]).unwrap(),
+                accessor
+            );
+        }
+    }
+
+    match generate_primitive_id(type_id) {
+        Some(id) => format!("tpb_read_{}(data, len, cursor, &{})", id, accessor),
+        None => format!(
+            "{}_decode(data, len, cursor, &{})",
+            model_id(type_id).to_case(Case::Snake),
+            accessor
+        ),
+    }
+}
+
+fn generate_encode(writer: &mut Writer, type_id: &TypeIDASTNode, accessor: &str) {
+    match type_id {
+        TypeIDASTNode::Generic { id, generics } if id == "Option" => {
+            let flag = option_flag(accessor);
+            writer.writeln(&format!(
+                "if (!tpb_write_bool(data, len, cursor, {})) return false;",
+                flag
+            ));
+            writer.writeln(&format!(
+                "if ({} && !{}) return false;",
+                flag,
+                generate_encode_call(&generics[0], accessor)
+            ));
+        }
+        _ => writer.writeln(&format!(
+            "if (!{}) return false;",
+            generate_encode_call(type_id, accessor)
+        )),
+    }
+}
+
+fn generate_encode_call(type_id: &TypeIDASTNode, accessor: &str) -> String {
+    match generate_primitive_id(type_id) {
+        Some(id) => format!("tpb_write_{}(data, len, cursor, {})", id, accessor),
+        None if is_vec(type_id) => format!("tpb_write_vec(data, len, cursor, {})", accessor),
+        None => format!(
+            "{}_encode(data, len, cursor, &{})",
+            model_id(type_id).to_case(Case::Snake),
+            accessor
+        ),
+    }
+}
+
+/// Writes statements skipping a value, `depth` keeps the names of nested
+/// loop variables apart.
+fn generate_skip(writer: &mut Writer, type_id: &TypeIDASTNode, depth: usize) {
+    match type_id {
+        TypeIDASTNode::Generic { generics, .. } if is_vec(type_id) => {
+            writer.writeln("{");
+            writer.push_tab();
+            writer.writeln(&format!("uint64_t count{};", depth));
+            writer.writeln(&format!(
+                "if (!tpb_read_u64(data, len, cursor, &count{})) return false;",
+                depth
+            ));
+            writer.writeln(&format!(
+                "for (uint64_t i{} = 0; i{} < count{}; i{}++) {{",
+                depth, depth, depth, depth
+            ));
+            writer.push_tab();
+            generate_skip(writer, &generics[0], depth + 1);
+            writer.pop_tab();
+            writer.writeln("}");
+            writer.pop_tab();
+            writer.writeln("}");
+        }
+        TypeIDASTNode::Generic { id, generics } if id == "Option" => {
+            writer.writeln("{");
+            writer.push_tab();
+            writer.writeln(&format!("bool flag{};", depth));
+            writer.writeln(&format!(
+                "if (!tpb_read_bool(data, len, cursor, &flag{})) return false;",
+                depth
+            ));
+            writer.writeln(&format!("if (flag{}) {{", depth));
+            writer.push_tab();
+            generate_skip(writer, &generics[0], depth + 1);
+            writer.pop_tab();
+            writer.writeln("}");
+            writer.pop_tab();
+            writer.writeln("}");
+        }
+        _ => writer.writeln(&format!(
+            "if (!{}) return false;",
+            generate_skip_call(type_id)
+        )),
+    }
+}
+
+fn generate_skip_call(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { size, .. } | TypeIDASTNode::Number { size, .. } => {
+            format!("tpb_skip(data, len, cursor, {})", size)
+        }
+        TypeIDASTNode::Bool { .. } => String::from("tpb_skip(data, len, cursor, 1)"),
+        TypeIDASTNode::Char { .. } => String::from("tpb_skip(data, len, cursor, 4)"),
+        TypeIDASTNode::Other { id } if is_address(id) => {
+            String::from("tpb_skip(data, len, cursor, 8)")
+        }
+        _ if is_bytes(type_id) => String::from("tpb_skip_bytes(data, len, cursor)"),
+        _ => format!(
+            "{}_skip(data, len, cursor)",
+            model_id(type_id).to_case(Case::Snake)
+        ),
+    }
+}
+
+/// Skip function of vector items, passed to `tpb_read_vec`.
+fn generate_skip_fn(type_id: &TypeIDASTNode) -> Option<String> {
+    match type_id {
+        TypeIDASTNode::Generic { .. } if !is_bytes(type_id) => None,
+        TypeIDASTNode::Other { id } if id != "String" && !is_address(id) => {
+            Some(format!("{}_skip", id.to_case(Case::Snake)))
+        }
+        _ => generate_primitive_id(type_id).map(|id| format!("tpb_skip_{}", id)),
+    }
+}
+
+pub fn generate_rpc(ast: &[ASTNode]) -> String {
+    let namespace = ast::find_directive_value(ast, "namespace").expect("namespace is required");
+    let namespace = match namespace {
+        ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("namespace should be a string literal"),
+        },
+    };
+
+    let scope_id = ast::find_directive_value(ast, "id").expect("id is required");
+    let scope_id = match scope_id {
+        ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("id should be a string literal"),
+        },
+    };
+
+    let prefix = namespace.to_uppercase();
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!("#define {}_SCOPE_ID \"{}\"", prefix, scope_id));
+    writer.new_line();
+
+    for node in ast::find_fn_nodes(ast) {
+        writer.writeln(&format!(
+            "#define {}_{}_METHOD_ID {}",
+            prefix,
+            node.id.to_uppercase(),
+            node.position
+        ));
+    }
+
+    for node in ast::find_fn_nodes(ast) {
+        if !node.args.is_empty() {
+            writer.new_line();
+            writer.write(&generate_rpc_args(ast, &namespace, node));
+        }
+    }
+
+    writer.show().to_string()
+}
+
+/// Arguments struct of the method, encoded into the args buffer of the
+/// call.
+fn generate_rpc_args(ast: &[ASTNode], namespace: &str, node: &FnASTNode) -> String {
+    let mut writer = Writer::default();
+    let id = format!(
+        "{}{}Args",
+        namespace.to_case(Case::Pascal),
+        node.id.to_case(Case::Pascal)
+    );
+    let fields = node
+        .args
+        .iter()
+        .map(|arg| StructFieldASTNode {
+            doc_comments: vec![],
+            position: 0,
+            name: arg.id.clone(),
+            type_id: arg.type_id.clone(),
+        })
+        .collect::<Vec<StructFieldASTNode>>();
+
+    if !fields
+        .iter()
+        .all(|field| is_representable(ast, &field.type_id, &mut vec![]))
+    {
+        writer.writeln(&format!("/* {} has no C representation */", id));
+        return writer.show().to_string();
+    }
+
+    writer.writeln(&format!("typedef struct {} {{", id));
+    writer.push_tab();
+    generate_fields(&mut writer, &fields);
+    writer.pop_tab();
+    writer.writeln(&format!("}} {};", id));
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "static inline bool {}_encode({}, const {}* value) {{",
+        id.to_case(Case::Snake),
+        WRITER_ARGS,
+        id
+    ));
+    writer.push_tab();
+
+    for field in &fields {
+        generate_encode(
+            &mut writer,
+            &field.type_id,
+            &format!("value->{}", field_name(&field.name)),
+        );
+    }
+
+    writer.writeln("return true;");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+fn is_address(id: &str) -> bool {
+    id == "GroupAddress" || id == "CommandsBufferAddress"
+}
+
+/// `String` and `Vec<u8>`, both decoded as `tpb_bytes`.
+fn is_bytes(type_id: &TypeIDASTNode) -> bool {
+    match type_id {
+        TypeIDASTNode::Other { id } => id == "String",
+        TypeIDASTNode::Generic { id, generics } => {
+            id == "Vec"
+                && matches!(
+                    generics.first(),
+                    Some(TypeIDASTNode::Integer {
+                        size: 1,
+                        signed: false,
+                        ..
+                    })
+                )
+        }
+        _ => false,
+    }
+}
+
+/// Vectors other than `Vec<u8>`, decoded as `tpb_vec`.
+fn is_vec(type_id: &TypeIDASTNode) -> bool {
+    matches!(type_id, TypeIDASTNode::Generic { id, .. } if id == "Vec") && !is_bytes(type_id)
+}
+
+fn model_id(type_id: &TypeIDASTNode) -> &str {
+    match type_id {
+        TypeIDASTNode::Other { id } | TypeIDASTNode::Generic { id, .. } => id,
+        _ => panic!("{:?} is not a model", type_id),
+    }
+}
+
+/// Suffix of the `tpb_read_`/`tpb_write_` functions of the type, `None`
+/// for models.
+fn generate_primitive_id(type_id: &TypeIDASTNode) -> Option<String> {
+    match type_id {
+        TypeIDASTNode::Integer { size, signed, .. } => {
+            Some(format!("{}{}", if *signed { "i" } else { "u" }, size * 8))
+        }
+        TypeIDASTNode::Number { size, .. } => Some(format!("f{}", size * 8)),
+        TypeIDASTNode::Bool { .. } => Some(String::from("bool")),
+        TypeIDASTNode::Char { .. } => Some(String::from("u32")),
+        TypeIDASTNode::Other { id } if is_address(id) => Some(String::from("u64")),
+        _ if is_bytes(type_id) => Some(String::from("bytes")),
+        _ => None,
+    }
+}
+
+pub fn generate_type_id(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { size, signed, .. } => {
+            format!("{}int{}_t", if *signed { "" } else { "u" }, size * 8)
+        }
+        TypeIDASTNode::Number { size: 4, .. } => String::from("float"),
+        TypeIDASTNode::Number { .. } => String::from("double"),
+        TypeIDASTNode::Bool { .. } => String::from("bool"),
+        TypeIDASTNode::Char { .. } => String::from("uint32_t"),
+        TypeIDASTNode::Other { id } if is_address(id) => String::from("uint64_t"),
+        _ if is_bytes(type_id) => String::from("tpb_bytes"),
+        _ if is_vec(type_id) => String::from("tpb_vec"),
+        _ => model_id(type_id).to_string(),
+    }
+}
+
+pub fn generate_const_value(node: &ConstValueASTNode, type_id: &TypeIDASTNode) -> String {
+    match node {
+        ConstValueASTNode::Literal { literal, .. } => match literal {
+            Literal::StringLiteral(value) => format!("\"{}\"", value),
+            Literal::IntLiteral(value) => match type_id {
+                TypeIDASTNode::Integer {
+                    size: 8, signed, ..
+                } => {
+                    format!("{}{}", value, if *signed { "LL" } else { "ULL" })
+                }
+                TypeIDASTNode::Integer { signed: false, .. } => format!("{}U", value),
+                TypeIDASTNode::Integer { .. } => format!("{}", value),
+                TypeIDASTNode::Other { id } if is_address(id) => format!("{}ULL", value),
+                _ => panic!(
+                    "Integer literal cannot have non integer type: {:?}",
+                    type_id
+                ),
+            },
+            Literal::NumberLiteral(value) => match type_id {
+                TypeIDASTNode::Number { size: 4, .. } if value.floor() == *value => {
+                    format!("{}.0f", value)
+                }
+                TypeIDASTNode::Number { size: 4, .. } => format!("{}f", value),
+                _ if value.floor() == *value => format!("{}.0", value),
+                _ => format!("{}", value),
+            },
+            Literal::BoolLiteral(value) => format!("{}", value),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    fn parse_resource(name: &str) -> Vec<ASTNode> {
+        let src = fs::read_to_string(format!("test_resources/{}.tpb", name)).unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        parse(&mut lexer)
+    }
+
+    fn read_target(name: &str) -> String {
+        fs::read_to_string(format!("test_resources/c/{}.h", name)).unwrap()
+    }
+
+    #[test]
+    fn generate_consts_test() {
+        let ast = parse_resource("consts");
+        let actual = generate_consts(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("consts").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_basic() {
+        let ast = parse_resource("struct_basic");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_basic").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_types() {
+        let ast = parse_resource("struct_types");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_types").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_mixed() {
+        let ast = parse_resource("struct_mixed");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_mixed").trim());
+    }
+
+    #[test]
+    fn generate_models_test_nested() {
+        let ast = parse_resource("struct_nested");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_nested").trim());
+    }
+
+    #[test]
+    fn generate_enum_model_extended_test() {
+        let ast = parse_resource("enum_extended");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("enum_extended").trim());
+    }
+
+    #[test]
+    fn generate_rpc_sync_methods_test() {
+        let ast = parse_resource("rpc_sync_methods");
+        let actual = generate_rpc(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_sync_methods").trim());
+    }
+}
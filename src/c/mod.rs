@@ -0,0 +1,79 @@
+use crate::{
+    ast::{self, ASTNode},
+    options::GeneratorOptions,
+    writer::reindent,
+};
+
+use self::generator::{generate_consts, generate_models, generate_rpc};
+
+pub mod generator;
+
+/// Readers and writers of the primitive types, guarded so headers of
+/// several schemas can be included together.
+const PRIMITIVES: &str = include_str!("primitives.h");
+
+pub fn generate(ast: &[ASTNode]) -> String {
+    generate_with_options(ast, &GeneratorOptions::default())
+}
+
+pub fn generate_with_options(ast: &[ASTNode], options: &GeneratorOptions) -> String {
+    let mut writer = options.writer();
+
+    if !options.header().is_empty() {
+        writer.writeln(options.header());
+        writer.new_line();
+    }
+
+    writer.writeln("#pragma once");
+    writer.new_line();
+    writer.writeln("#include <stdbool.h>");
+    writer.writeln("#include <stddef.h>");
+    writer.writeln("#include <stdint.h>");
+    writer.writeln("#include <string.h>");
+
+    for import in options.imports.iter() {
+        if import.starts_with('<') || import.starts_with('"') {
+            writer.writeln(&format!("#include {}", import));
+        } else {
+            writer.writeln(&format!("#include \"{}\"", import));
+        }
+    }
+
+    writer.new_line();
+    writer.write(PRIMITIVES);
+
+    let mut sections = vec![generate_consts(ast), generate_models(ast)];
+
+    if ast::contains_fn_nodes(ast) {
+        sections.push(generate_rpc(ast));
+    }
+
+    for section in sections.iter().filter(|section| !section.is_empty()) {
+        writer.new_line();
+        writer.write(section);
+    }
+
+    reindent(writer.show(), options.indent())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    #[test]
+    fn generate_with_custom_options() {
+        let src = fs::read_to_string("test_resources/struct_basic.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/c/struct_basic_options.h").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let options = GeneratorOptions {
+            indent: Some(2),
+            imports: vec![String::from("math.h"), String::from("<assert.h>")],
+            ..GeneratorOptions::default()
+        };
+        let actual = generate_with_options(&ast, &options);
+        assert_eq!(actual.trim(), target.trim());
+    }
+}
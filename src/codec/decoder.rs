@@ -0,0 +1,597 @@
+use serde_json::{Map, Number, Value};
+
+use crate::ast::{
+    self, ASTNode, EnumItemASTNode, StructFieldASTNode, TupleFieldASTNode, TypeIDASTNode,
+};
+use crate::json_schema::{ENUM_CONTENT, ENUM_TAG};
+use crate::rust_generator::generate_type_id;
+
+use super::{primitive_size, Annotation, DecodeError, JsonForm, Root};
+
+/// Zero sized items take no bytes, so the remaining bytes can't bound their
+/// count, a corrupted length would otherwise decode billions of items.
+const MAX_ZERO_SIZED_LENGTH: u64 = 65536;
+
+pub struct Decoder<'a> {
+    ast: &'a [ASTNode],
+    form: JsonForm,
+    data: &'a [u8],
+    cursor: usize,
+    annotations: Vec<Annotation>,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(ast: &'a [ASTNode], data: &'a [u8]) -> Self {
+        Decoder {
+            ast,
+            form: JsonForm::Schema,
+            data,
+            cursor: 0,
+            annotations: vec![],
+        }
+    }
+
+    pub fn with_form(mut self, form: JsonForm) -> Self {
+        self.form = form;
+        self
+    }
+
+    pub fn annotations(&self) -> &[Annotation] {
+        &self.annotations
+    }
+
+    /// Number of bytes read so far.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn decode(&mut self, root: &Root) -> Result<Value, DecodeError> {
+        match root {
+            Root::Type(type_id) => self.decode_type(type_id, "$"),
+            Root::Args(args) => {
+                let mut values = Map::new();
+
+                for arg in args {
+                    let name = self.form.field_name(&arg.id);
+                    let path = format!("$.{}", name);
+                    values.insert(name, self.decode_type(&arg.type_id, &path)?);
+                }
+
+                Ok(Value::Object(values))
+            }
+            Root::SignalResult(type_id) => {
+                let offset = self.cursor;
+                let marker = self.read_bytes(1, "$")?[0];
+
+                if marker != 0xFF {
+                    return Err(DecodeError {
+                        offset,
+                        path: String::from("$"),
+                        message: format!("Expected new data marker 0xFF, but got {:#04x}", marker),
+                    });
+                }
+
+                self.annotate(offset, "$", "marker", String::from("0xFF"));
+
+                match type_id {
+                    Some(type_id) => self.decode_type(type_id, "$"),
+                    None => Ok(Value::Null),
+                }
+            }
+        }
+    }
+
+    fn annotate(&mut self, offset: usize, path: &str, type_id: &str, value: String) {
+        self.annotations.push(Annotation {
+            offset,
+            size: self.cursor - offset,
+            path: path.to_string(),
+            type_id: type_id.to_string(),
+            value,
+        });
+    }
+
+    fn read_bytes(&mut self, size: usize, path: &str) -> Result<&'a [u8], DecodeError> {
+        let remaining = self.data.len() - self.cursor;
+
+        if size > remaining {
+            return Err(DecodeError {
+                offset: self.cursor,
+                path: path.to_string(),
+                message: format!(
+                    "Unexpected end of buffer, need {} bytes, but only {} left",
+                    size, remaining
+                ),
+            });
+        }
+
+        let bytes = &self.data[self.cursor..self.cursor + size];
+        self.cursor += size;
+
+        Ok(bytes)
+    }
+
+    fn read_u64(&mut self, path: &str) -> Result<u64, DecodeError> {
+        let bytes = self.read_bytes(8, path)?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn read_length(
+        &mut self,
+        path: &str,
+        item_type_id: Option<&TypeIDASTNode>,
+    ) -> Result<u64, DecodeError> {
+        let offset = self.cursor;
+        let length = self.read_u64(path)?;
+        let remaining = (self.data.len() - self.cursor) as u64;
+        let is_zero_sized = item_type_id.is_some_and(|type_id| self.is_zero_sized(type_id));
+
+        if is_zero_sized && length > MAX_ZERO_SIZED_LENGTH {
+            return Err(DecodeError {
+                offset,
+                path: path.to_string(),
+                message: format!(
+                    "Length {} of zero sized items exceeds the limit of {}",
+                    length, MAX_ZERO_SIZED_LENGTH
+                ),
+            });
+        }
+
+        if length > remaining && !is_zero_sized {
+            return Err(DecodeError {
+                offset,
+                path: path.to_string(),
+                message: format!(
+                    "Length {} exceeds the {} remaining bytes",
+                    length, remaining
+                ),
+            });
+        }
+
+        self.annotate(offset, path, "length", length.to_string());
+
+        Ok(length)
+    }
+
+    fn is_zero_sized(&self, type_id: &TypeIDASTNode) -> bool {
+        match type_id {
+            TypeIDASTNode::Other { id } => {
+                ast::find_struct_node(self.ast, id).is_some_and(|node| node.fields.is_empty())
+            }
+            _ => false,
+        }
+    }
+
+    fn decode_type(&mut self, type_id: &TypeIDASTNode, path: &str) -> Result<Value, DecodeError> {
+        if let Some(size) = primitive_size(type_id) {
+            return self.decode_primitive(type_id, size, path);
+        }
+
+        match type_id {
+            TypeIDASTNode::Other { id } if id == "String" => {
+                let length = self.read_length(path, None)? as usize;
+                let offset = self.cursor;
+                let bytes = self.read_bytes(length, path)?;
+                let value = String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError {
+                    offset,
+                    path: path.to_string(),
+                    message: String::from("Invalid UTF-8 string"),
+                })?;
+
+                self.annotate(offset, path, "String", format!("{:?}", value));
+
+                Ok(Value::String(value))
+            }
+            TypeIDASTNode::Other { id } => {
+                if let Some(node) = ast::find_struct_node(self.ast, id) {
+                    let fields = self.decode_struct_fields(&node.fields, path)?;
+                    Ok(Value::Object(fields))
+                } else if let Some(node) = ast::find_enum_node(self.ast, id) {
+                    self.decode_enum(id, &node.items, path)
+                } else {
+                    Err(DecodeError {
+                        offset: self.cursor,
+                        path: path.to_string(),
+                        message: format!("Unknown type: {}", id),
+                    })
+                }
+            }
+            TypeIDASTNode::Generic { id, generics } if id == "Vec" && generics.len() == 1 => {
+                let length = self.read_length(path, Some(&generics[0]))?;
+                let mut items = vec![];
+
+                for i in 0..length {
+                    let item_path = format!("{}[{}]", path, i);
+                    items.push(self.decode_type(&generics[0], &item_path)?);
+                }
+
+                Ok(Value::Array(items))
+            }
+            TypeIDASTNode::Generic { id, generics } if id == "Option" && generics.len() == 1 => {
+                let offset = self.cursor;
+                let tag = self.read_bytes(1, path)?[0];
+
+                match tag {
+                    0 => {
+                        self.annotate(offset, path, "Option", String::from("None"));
+                        Ok(Value::Null)
+                    }
+                    1 => {
+                        self.annotate(offset, path, "Option", String::from("Some"));
+                        self.decode_type(&generics[0], path)
+                    }
+                    _ => Err(DecodeError {
+                        offset,
+                        path: path.to_string(),
+                        message: format!("Invalid Option tag: {}", tag),
+                    }),
+                }
+            }
+            _ => Err(DecodeError {
+                offset: self.cursor,
+                path: path.to_string(),
+                message: format!("Unsupported type: {}", generate_type_id(type_id)),
+            }),
+        }
+    }
+
+    fn decode_primitive(
+        &mut self,
+        type_id: &TypeIDASTNode,
+        size: usize,
+        path: &str,
+    ) -> Result<Value, DecodeError> {
+        let offset = self.cursor;
+        let bytes = self.read_bytes(size, path)?;
+        let type_name = generate_type_id(type_id);
+
+        let value = match type_id {
+            TypeIDASTNode::Integer { signed: true, .. } => {
+                let mut buffer = if bytes[size - 1] & 0x80 != 0 {
+                    [0xFF; 8]
+                } else {
+                    [0; 8]
+                };
+                buffer[..size].copy_from_slice(bytes);
+                Value::Number(i64::from_le_bytes(buffer).into())
+            }
+            TypeIDASTNode::Integer { signed: false, .. } | TypeIDASTNode::Other { .. } => {
+                let mut buffer = [0; 8];
+                buffer[..size].copy_from_slice(bytes);
+                Value::Number(u64::from_le_bytes(buffer).into())
+            }
+            TypeIDASTNode::Number { .. } => {
+                let value = if size == 4 {
+                    f32::from_le_bytes(bytes.try_into().unwrap()) as f64
+                } else {
+                    f64::from_le_bytes(bytes.try_into().unwrap())
+                };
+
+                Number::from_f64(value).map_or(Value::Null, Value::Number)
+            }
+            TypeIDASTNode::Bool { .. } => match bytes[0] {
+                0 => Value::Bool(false),
+                1 => Value::Bool(true),
+                value => {
+                    return Err(DecodeError {
+                        offset,
+                        path: path.to_string(),
+                        message: format!("Invalid bool value: {}", value),
+                    })
+                }
+            },
+            TypeIDASTNode::Char { .. } => {
+                let code = u32::from_le_bytes(bytes.try_into().unwrap());
+
+                match char::from_u32(code) {
+                    Some(value) => Value::String(value.to_string()),
+                    None => {
+                        return Err(DecodeError {
+                            offset,
+                            path: path.to_string(),
+                            message: format!("Invalid char code point: {:#x}", code),
+                        })
+                    }
+                }
+            }
+            TypeIDASTNode::Generic { .. } => unreachable!(),
+        };
+
+        self.annotate(offset, path, &type_name, value.to_string());
+
+        Ok(value)
+    }
+
+    fn decode_struct_fields(
+        &mut self,
+        fields: &[StructFieldASTNode],
+        path: &str,
+    ) -> Result<Map<String, Value>, DecodeError> {
+        let mut values = Map::new();
+
+        for field in fields {
+            let name = self.form.field_name(&field.name);
+            let field_path = format!("{}.{}", path, name);
+            values.insert(name, self.decode_type(&field.type_id, &field_path)?);
+        }
+
+        Ok(values)
+    }
+
+    fn decode_tuple_values(
+        &mut self,
+        values: &[TupleFieldASTNode],
+        path: &str,
+    ) -> Result<Vec<Value>, DecodeError> {
+        let mut items = vec![];
+
+        for (i, value) in values.iter().enumerate() {
+            let value_path = format!("{}.{}", path, i);
+            items.push(self.decode_type(&value.type_id, &value_path)?);
+        }
+
+        Ok(items)
+    }
+
+    fn decode_enum(
+        &mut self,
+        id: &str,
+        items: &[EnumItemASTNode],
+        path: &str,
+    ) -> Result<Value, DecodeError> {
+        let offset = self.cursor;
+        let bytes = self.read_bytes(4, path)?;
+        let tag = u32::from_le_bytes(bytes.try_into().unwrap());
+
+        let item = items
+            .iter()
+            .find(|item| item.position() == tag)
+            .ok_or_else(|| DecodeError {
+                offset,
+                path: path.to_string(),
+                message: format!("Unknown {} enum value: {}", id, tag),
+            })?;
+
+        self.annotate(offset, path, "enum tag", format!("{} ({})", tag, item.id()));
+
+        let item_path = format!("{}.{}", path, item.id());
+
+        let value = match item {
+            EnumItemASTNode::Empty { .. } => None,
+            EnumItemASTNode::Tuple { values, .. } => {
+                let mut values = self.decode_tuple_values(values, &item_path)?;
+
+                if self.form == JsonForm::Models && values.len() == 1 {
+                    values.pop()
+                } else {
+                    Some(Value::Array(values))
+                }
+            }
+            EnumItemASTNode::Struct { fields, .. } => Some(Value::Object(
+                self.decode_struct_fields(fields, &item_path)?,
+            )),
+        };
+
+        let mut result = Map::new();
+
+        match (self.form, value) {
+            (JsonForm::Schema, None) => return Ok(Value::String(item.id().to_string())),
+            (JsonForm::Schema, Some(value)) => {
+                result.insert(item.id().to_string(), value);
+            }
+            (JsonForm::Models, value) => {
+                result.insert(String::from(ENUM_TAG), Value::String(item.id().to_string()));
+
+                if let Some(value) = value {
+                    result.insert(String::from(ENUM_CONTENT), value);
+                }
+            }
+        }
+
+        Ok(Value::Object(result))
+    }
+}
+
+/// Formats the annotations as a hexdump, one row per value.
+pub fn format_hexdump(data: &[u8], annotations: &[Annotation]) -> String {
+    let mut res = String::new();
+
+    for annotation in annotations {
+        let bytes = &data[annotation.offset..annotation.offset + annotation.size];
+        let mut chunks = bytes.chunks(8);
+        let first_chunk = chunks.next().unwrap_or(&[]);
+
+        res += &format!(
+            "{:08x}  {:<24} {:<32} {:<12} {}\n",
+            annotation.offset,
+            format_bytes(first_chunk),
+            annotation.path,
+            annotation.type_id,
+            annotation.value
+        );
+
+        for (i, chunk) in chunks.enumerate() {
+            res += &format!(
+                "{:08x}  {}\n",
+                annotation.offset + (i + 1) * 8,
+                format_bytes(chunk)
+            );
+        }
+    }
+
+    res
+}
+
+fn format_bytes(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        codec::{parse_hex, resolve_root},
+        lexer::Lexer,
+        parser::parse,
+    };
+
+    const SCHEMA: &str = "
+        struct Point { x: f32, y: f32 }
+
+        struct Nothing;
+
+        enum Shape {
+            Empty,
+            #[3] Circle { center: Point, radius: f64 },
+            Polygon(Vec<Point>, Option<String>),
+        }
+
+        fn say_hello(name: String) -> String;
+
+        signal counter -> i32;
+    ";
+
+    fn decode(root_id: &str, data: &[u8]) -> (Result<Value, DecodeError>, Vec<Annotation>) {
+        let mut lexer = Lexer::tokenize(SCHEMA);
+        let ast = parse(&mut lexer);
+        let root = resolve_root(&ast, root_id).unwrap();
+        let mut decoder = Decoder::new(&ast, data);
+        let result = decoder.decode(&root);
+
+        (result, decoder.annotations().to_vec())
+    }
+
+    #[test]
+    fn decode_enum_struct_item() {
+        let (result, annotations) = decode(
+            "Shape",
+            &[
+                3, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 248, 63,
+            ],
+        );
+
+        assert_eq!(
+            result.unwrap(),
+            serde_json::json!({
+                "Circle": {
+                    "center": { "x": 1.0, "y": 2.0 },
+                    "radius": 1.5,
+                }
+            })
+        );
+        assert_eq!(
+            annotations
+                .iter()
+                .map(|annotation| annotation.path.as_str())
+                .collect::<Vec<&str>>(),
+            vec![
+                "$",
+                "$.Circle.center.x",
+                "$.Circle.center.y",
+                "$.Circle.radius"
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_enum_tuple_item() {
+        let (result, _) = decode(
+            "Shape",
+            &[
+                2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 64, 1, 2, 0, 0, 0, 0,
+                0, 0, 0, 104, 105,
+            ],
+        );
+
+        assert_eq!(
+            result.unwrap(),
+            serde_json::json!({ "Polygon": [[{ "x": 1.0, "y": 2.0 }], "hi"] })
+        );
+    }
+
+    #[test]
+    fn decode_rpc_args_and_signal_result() {
+        let (result, _) = decode("say_hello.args", &[2, 0, 0, 0, 0, 0, 0, 0, 104, 105]);
+        assert_eq!(result.unwrap(), serde_json::json!({ "name": "hi" }));
+
+        let (result, _) = decode("counter.result", &[0xFF, 0xFE, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(result.unwrap(), serde_json::json!(-2));
+    }
+
+    #[test]
+    fn decode_reports_error_location() {
+        let (result, annotations) = decode("Vec<Shape>", &[1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0]);
+
+        assert_eq!(
+            result.unwrap_err(),
+            DecodeError {
+                offset: 8,
+                path: String::from("$[0]"),
+                message: String::from("Unknown Shape enum value: 7"),
+            }
+        );
+        assert_eq!(annotations.len(), 1);
+
+        let (result, _) = decode("Point", &[0, 0, 128, 63, 0, 0]);
+
+        assert_eq!(
+            result.unwrap_err(),
+            DecodeError {
+                offset: 4,
+                path: String::from("$.y"),
+                message: String::from("Unexpected end of buffer, need 4 bytes, but only 2 left"),
+            }
+        );
+    }
+
+    #[test]
+    fn decode_limits_zero_sized_items() {
+        let (result, _) = decode("Vec<Nothing>", &[2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(result.unwrap(), serde_json::json!([{}, {}]));
+
+        let (result, _) = decode(
+            "Vec<Nothing>",
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F],
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            DecodeError {
+                offset: 0,
+                path: String::from("$"),
+                message: String::from(
+                    "Length 9223372036854775807 of zero sized items exceeds the limit of 65536"
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn resolve_root_rejects_malformed_types() {
+        let mut lexer = Lexer::tokenize(SCHEMA);
+        let ast = parse(&mut lexer);
+
+        for root_id in ["Vec<", "Vec<>", "Option<Point", "Point Shape", "<Point>"] {
+            assert_eq!(
+                resolve_root(&ast, root_id).unwrap_err(),
+                format!("Invalid type: {}", root_id)
+            );
+        }
+
+        assert!(resolve_root(&ast, "Vec<Option<Point>>").is_ok());
+    }
+
+    #[test]
+    fn parse_hex_rejects_non_hex_digits() {
+        assert_eq!(parse_hex("0x0a0B 0c:0d").unwrap(), vec![10, 11, 12, 13]);
+        assert_eq!(
+            parse_hex("a\u{e9}0").unwrap_err(),
+            "Invalid hex digit: \u{e9}"
+        );
+        assert_eq!(parse_hex("0g").unwrap_err(), "Invalid hex digit: g");
+    }
+}
@@ -0,0 +1,484 @@
+use serde_json::{Map, Value};
+
+use crate::ast::{
+    self, ASTNode, EnumItemASTNode, StructFieldASTNode, TupleFieldASTNode, TypeIDASTNode,
+};
+use crate::json_schema::{ENUM_CONTENT, ENUM_TAG};
+use crate::rust_generator::generate_type_id;
+
+use super::{primitive_size, EncodeError, JsonForm, Root};
+
+pub struct Encoder<'a> {
+    ast: &'a [ASTNode],
+    form: JsonForm,
+    data: Vec<u8>,
+}
+
+impl<'a> Encoder<'a> {
+    pub fn new(ast: &'a [ASTNode]) -> Self {
+        Encoder {
+            ast,
+            form: JsonForm::Schema,
+            data: vec![],
+        }
+    }
+
+    pub fn with_form(mut self, form: JsonForm) -> Self {
+        self.form = form;
+        self
+    }
+
+    pub fn encode(mut self, root: &Root, value: &Value) -> Result<Vec<u8>, EncodeError> {
+        match root {
+            Root::Type(type_id) => self.encode_type(type_id, value, "$")?,
+            Root::Args(args) => {
+                let values = expect_object(value, "$")?;
+
+                for arg in args {
+                    let name = self.form.field_name(&arg.id);
+                    let path = format!("$.{}", name);
+                    let value = values.get(&name).ok_or_else(|| EncodeError {
+                        path: path.clone(),
+                        message: String::from("Missing argument"),
+                    })?;
+
+                    self.encode_type(&arg.type_id, value, &path)?;
+                }
+            }
+            Root::SignalResult(type_id) => {
+                self.data.push(0xFF);
+
+                if let Some(type_id) = type_id {
+                    self.encode_type(type_id, value, "$")?;
+                }
+            }
+        }
+
+        Ok(self.data)
+    }
+
+    fn write_length(&mut self, length: usize) {
+        self.data.extend_from_slice(&(length as u64).to_le_bytes());
+    }
+
+    fn encode_type(
+        &mut self,
+        type_id: &TypeIDASTNode,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), EncodeError> {
+        if let Some(size) = primitive_size(type_id) {
+            return self.encode_primitive(type_id, size, value, path);
+        }
+
+        match type_id {
+            TypeIDASTNode::Other { id } if id == "String" => {
+                let value = value
+                    .as_str()
+                    .ok_or_else(|| unexpected(value, "string", path))?;
+
+                self.write_length(value.len());
+                self.data.extend_from_slice(value.as_bytes());
+
+                Ok(())
+            }
+            TypeIDASTNode::Other { id } => {
+                if let Some(node) = ast::find_struct_node(self.ast, id) {
+                    self.encode_struct_fields(&node.fields, value, path)
+                } else if let Some(node) = ast::find_enum_node(self.ast, id) {
+                    self.encode_enum(id, &node.items, value, path)
+                } else {
+                    Err(EncodeError {
+                        path: path.to_string(),
+                        message: format!("Unknown type: {}", id),
+                    })
+                }
+            }
+            TypeIDASTNode::Generic { id, generics } if id == "Vec" && generics.len() == 1 => {
+                let items = value
+                    .as_array()
+                    .ok_or_else(|| unexpected(value, "array", path))?;
+
+                self.write_length(items.len());
+
+                for (i, item) in items.iter().enumerate() {
+                    self.encode_type(&generics[0], item, &format!("{}[{}]", path, i))?;
+                }
+
+                Ok(())
+            }
+            TypeIDASTNode::Generic { id, generics } if id == "Option" && generics.len() == 1 => {
+                if value.is_null() {
+                    self.data.push(0);
+                    Ok(())
+                } else {
+                    self.data.push(1);
+                    self.encode_type(&generics[0], value, path)
+                }
+            }
+            _ => Err(EncodeError {
+                path: path.to_string(),
+                message: format!("Unsupported type: {}", generate_type_id(type_id)),
+            }),
+        }
+    }
+
+    fn encode_primitive(
+        &mut self,
+        type_id: &TypeIDASTNode,
+        size: usize,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), EncodeError> {
+        let type_name = generate_type_id(type_id);
+        let out_of_range = || EncodeError {
+            path: path.to_string(),
+            message: format!("{} is out of range of {}", value, type_name),
+        };
+
+        match type_id {
+            TypeIDASTNode::Integer { signed: true, .. } => {
+                let value = value
+                    .as_i64()
+                    .ok_or_else(|| unexpected(value, "integer", path))?;
+                let bits = size as u32 * 8;
+
+                if bits < 64 && (value < -(1 << (bits - 1)) || value >= 1 << (bits - 1)) {
+                    return Err(out_of_range());
+                }
+
+                self.data.extend_from_slice(&value.to_le_bytes()[..size]);
+            }
+            TypeIDASTNode::Integer { signed: false, .. } | TypeIDASTNode::Other { .. } => {
+                let value = value
+                    .as_u64()
+                    .ok_or_else(|| unexpected(value, "unsigned integer", path))?;
+                let bits = size as u32 * 8;
+
+                if bits < 64 && value >= 1 << bits {
+                    return Err(out_of_range());
+                }
+
+                self.data.extend_from_slice(&value.to_le_bytes()[..size]);
+            }
+            TypeIDASTNode::Number { .. } => {
+                let value = value
+                    .as_f64()
+                    .ok_or_else(|| unexpected(value, "number", path))?;
+
+                if size == 4 {
+                    self.data.extend_from_slice(&(value as f32).to_le_bytes());
+                } else {
+                    self.data.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            TypeIDASTNode::Bool { .. } => {
+                let value = value
+                    .as_bool()
+                    .ok_or_else(|| unexpected(value, "bool", path))?;
+
+                self.data.push(value as u8);
+            }
+            TypeIDASTNode::Char { .. } => {
+                let mut chars = value
+                    .as_str()
+                    .ok_or_else(|| unexpected(value, "char", path))?
+                    .chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(value), None) => {
+                        self.data.extend_from_slice(&(value as u32).to_le_bytes())
+                    }
+                    _ => return Err(unexpected(value, "char", path)),
+                }
+            }
+            TypeIDASTNode::Generic { .. } => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    fn encode_struct_fields(
+        &mut self,
+        fields: &[StructFieldASTNode],
+        value: &Value,
+        path: &str,
+    ) -> Result<(), EncodeError> {
+        let values = expect_object(value, path)?;
+        let names = fields
+            .iter()
+            .map(|field| self.form.field_name(&field.name))
+            .collect::<Vec<String>>();
+
+        for key in values.keys() {
+            if !names.contains(key) {
+                return Err(EncodeError {
+                    path: format!("{}.{}", path, key),
+                    message: String::from("Unknown field"),
+                });
+            }
+        }
+
+        for (field, name) in fields.iter().zip(&names) {
+            let field_path = format!("{}.{}", path, name);
+            let value = values.get(name).ok_or_else(|| EncodeError {
+                path: field_path.clone(),
+                message: String::from("Missing field"),
+            })?;
+
+            self.encode_type(&field.type_id, value, &field_path)?;
+        }
+
+        Ok(())
+    }
+
+    fn encode_tuple_values(
+        &mut self,
+        values: &[TupleFieldASTNode],
+        value: &Value,
+        path: &str,
+    ) -> Result<(), EncodeError> {
+        let items = value
+            .as_array()
+            .ok_or_else(|| unexpected(value, "array", path))?;
+
+        if items.len() != values.len() {
+            return Err(EncodeError {
+                path: path.to_string(),
+                message: format!("Expected {} values, but got {}", values.len(), items.len()),
+            });
+        }
+
+        for (i, (tuple_value, item)) in values.iter().zip(items).enumerate() {
+            self.encode_type(&tuple_value.type_id, item, &format!("{}.{}", path, i))?;
+        }
+
+        Ok(())
+    }
+
+    fn encode_enum(
+        &mut self,
+        id: &str,
+        items: &[EnumItemASTNode],
+        value: &Value,
+        path: &str,
+    ) -> Result<(), EncodeError> {
+        let (item_id, item_value) = match (self.form, value) {
+            (JsonForm::Schema, Value::String(item_id)) => (item_id.as_str(), None),
+            (JsonForm::Schema, Value::Object(values)) if values.len() == 1 => {
+                let (item_id, item_value) = values.iter().next().unwrap();
+                (item_id.as_str(), Some(item_value))
+            }
+            (JsonForm::Models, Value::Object(values))
+                if values
+                    .keys()
+                    .all(|key| key == ENUM_TAG || key == ENUM_CONTENT) =>
+            {
+                match values.get(ENUM_TAG) {
+                    Some(Value::String(item_id)) => (item_id.as_str(), values.get(ENUM_CONTENT)),
+                    _ => return Err(unexpected(value, "enum item", path)),
+                }
+            }
+            _ => return Err(unexpected(value, "enum item", path)),
+        };
+
+        let item = items
+            .iter()
+            .find(|item| item.id() == item_id)
+            .ok_or_else(|| EncodeError {
+                path: path.to_string(),
+                message: format!("Unknown {} enum item: {}", id, item_id),
+            })?;
+
+        self.data.extend_from_slice(&item.position().to_le_bytes());
+
+        let item_path = format!("{}.{}", path, item_id);
+
+        match (item, item_value) {
+            (EnumItemASTNode::Empty { .. }, None) => Ok(()),
+            (EnumItemASTNode::Tuple { values, .. }, Some(value))
+                if self.form == JsonForm::Models && values.len() == 1 =>
+            {
+                self.encode_type(&values[0].type_id, value, &format!("{}.0", item_path))
+            }
+            (EnumItemASTNode::Tuple { values, .. }, Some(value)) => {
+                self.encode_tuple_values(values, value, &item_path)
+            }
+            (EnumItemASTNode::Struct { fields, .. }, Some(value)) => {
+                self.encode_struct_fields(fields, value, &item_path)
+            }
+            _ => Err(EncodeError {
+                path: item_path,
+                message: String::from("Enum item payload doesn't match the schema"),
+            }),
+        }
+    }
+}
+
+fn expect_object<'a>(value: &'a Value, path: &str) -> Result<&'a Map<String, Value>, EncodeError> {
+    value
+        .as_object()
+        .ok_or_else(|| unexpected(value, "object", path))
+}
+
+fn unexpected(value: &Value, expected: &str, path: &str) -> EncodeError {
+    EncodeError {
+        path: path.to_string(),
+        message: format!("Expected {}, but got {}", expected, value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        codec::{decoder::Decoder, resolve_root},
+        lexer::Lexer,
+        parser::parse,
+    };
+    use serde_json::json;
+
+    const SCHEMA: &str = "
+        struct Point { x: f32, y: f32 }
+
+        enum Shape {
+            Empty,
+            #[3] Circle { center: Point, radius: f64 },
+            Polygon(Vec<Point>, Option<String>),
+        }
+
+        fn say_hello(name: String, times: u8) -> String;
+
+        signal counter -> i32;
+    ";
+
+    fn encode(root_id: &str, value: &Value) -> Result<Vec<u8>, EncodeError> {
+        let mut lexer = Lexer::tokenize(SCHEMA);
+        let ast = parse(&mut lexer);
+        let root = resolve_root(&ast, root_id).unwrap();
+
+        Encoder::new(&ast).encode(&root, value)
+    }
+
+    #[test]
+    fn encode_enum_items() {
+        assert_eq!(encode("Shape", &json!("Empty")).unwrap(), vec![0, 0, 0, 0]);
+        assert_eq!(
+            encode(
+                "Shape",
+                &json!({ "Circle": { "center": { "x": 1.0, "y": 2.0 }, "radius": 1.5 } })
+            )
+            .unwrap(),
+            vec![3, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 248, 63]
+        );
+        assert_eq!(
+            encode("Shape", &json!({ "Polygon": [[], null] })).unwrap(),
+            vec![2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn encode_rpc_args_and_signal_result() {
+        assert_eq!(
+            encode("say_hello.args", &json!({ "name": "hi", "times": 3 })).unwrap(),
+            vec![2, 0, 0, 0, 0, 0, 0, 0, 104, 105, 3]
+        );
+        assert_eq!(
+            encode("counter.result", &json!(-2)).unwrap(),
+            vec![0xFF, 0xFE, 0xFF, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let mut lexer = Lexer::tokenize(SCHEMA);
+        let ast = parse(&mut lexer);
+        let root = resolve_root(&ast, "Vec<Shape>").unwrap();
+        let value = json!([
+            "Empty",
+            { "Polygon": [[{ "x": 1.0, "y": -2.5 }], "name"] },
+            { "Circle": { "center": { "x": 0.0, "y": 0.0 }, "radius": 10.0 } },
+        ]);
+
+        let data = Encoder::new(&ast).encode(&root, &value).unwrap();
+        let decoded = Decoder::new(&ast, &data).decode(&root).unwrap();
+
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn encode_decode_models_form() {
+        let mut lexer = Lexer::tokenize(SCHEMA);
+        let ast = parse(&mut lexer);
+        let root = resolve_root(&ast, "Vec<Shape>").unwrap();
+        let value = json!([
+            { "type": "Empty" },
+            { "type": "Polygon", "value": [[{ "x": 1.0, "y": -2.5 }], null] },
+            { "type": "Circle", "value": { "center": { "x": 0.0, "y": 0.0 }, "radius": 10.0 } },
+        ]);
+
+        let data = Encoder::new(&ast)
+            .with_form(JsonForm::Models)
+            .encode(&root, &value)
+            .unwrap();
+        let decoded = Decoder::new(&ast, &data)
+            .with_form(JsonForm::Models)
+            .decode(&root)
+            .unwrap();
+
+        assert_eq!(decoded, value);
+
+        let schema_form = Encoder::new(&ast)
+            .encode(
+                &root,
+                &json!([
+                    "Empty",
+                    { "Polygon": [[{ "x": 1.0, "y": -2.5 }], null] },
+                    { "Circle": { "center": { "x": 0.0, "y": 0.0 }, "radius": 10.0 } },
+                ]),
+            )
+            .unwrap();
+
+        assert_eq!(data, schema_form);
+
+        let lexer = &mut Lexer::tokenize("enum Id { Uuid(String) }\nfn find(user_id: Id);");
+        let ast = parse(lexer);
+        let root = resolve_root(&ast, "find.args").unwrap();
+        let value = json!({ "userId": { "type": "Uuid", "value": "a" } });
+        let data = Encoder::new(&ast)
+            .with_form(JsonForm::Models)
+            .encode(&root, &value)
+            .unwrap();
+
+        assert_eq!(data, vec![0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97]);
+        assert_eq!(
+            Decoder::new(&ast, &data)
+                .with_form(JsonForm::Models)
+                .decode(&root)
+                .unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn encode_reports_error_location() {
+        assert_eq!(
+            encode("say_hello.args", &json!({ "name": "hi", "times": 256 })).unwrap_err(),
+            EncodeError {
+                path: String::from("$.times"),
+                message: String::from("256 is out of range of u8"),
+            }
+        );
+        assert_eq!(
+            encode(
+                "Shape",
+                &json!({ "Circle": { "center": { "x": 1.0 }, "radius": 1.0 } })
+            )
+            .unwrap_err(),
+            EncodeError {
+                path: String::from("$.Circle.center.y"),
+                message: String::from("Missing field"),
+            }
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Binary encoding used by `tech_paws_buffers`, driven by the parsed AST.
+//!
+//! - integers and floats are little endian with the size of the type;
+//! - `bool` is a single byte, `char` is a `u32` code point;
+//! - `String` is a `u64` byte length followed by UTF-8 bytes;
+//! - `Vec<T>` is a `u64` item count followed by the items;
+//! - `Option<T>` is a `u8` tag, `0` for `None` and `1` for `Some`, followed by the value;
+//! - `GroupAddress` and `CommandsBufferAddress` are `u64`;
+//! - struct fields and enum payloads follow in position order;
+//! - enums start with the `u32` position of the item.
+
+use std::fmt;
+
+use clap::ArgEnum;
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{self, ASTNode, FnArgASTNode, TypeIDASTNode},
+    lexer::{Lexer, Token},
+    parser,
+};
+
+pub mod decoder;
+pub mod encoder;
+pub mod samples;
+
+/// Root value of a buffer.
+#[derive(Debug, Clone)]
+pub enum Root {
+    Type(TypeIDASTNode),
+    /// Arguments of an RPC method, written one after another.
+    Args(Vec<FnArgASTNode>),
+    /// Result of a signal, prefixed with the `0xFF` new data marker.
+    SignalResult(Option<TypeIDASTNode>),
+}
+
+/// Shape of the JSON values read by the encoder and written by the decoder.
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonForm {
+    /// Field names as they are in the schema and externally tagged enums:
+    /// `"Idle"`, `{"Move": {"x": 1.0}}` or `{"Update": [1.0, "text"]}`.
+    Schema,
+    /// The form described by [`crate::json_schema`]: camelCase field names
+    /// and enums tagged with `type` and `value`, a tuple item with a single
+    /// value stores it without the array.
+    Models,
+}
+
+impl JsonForm {
+    /// Name of the property holding a struct field or an RPC argument.
+    pub fn field_name(&self, name: &str) -> String {
+        match self {
+            JsonForm::Schema => name.to_string(),
+            JsonForm::Models => name.to_case(Case::Camel),
+        }
+    }
+}
+
+/// Resolves the root type from a type expression like `Vec<Point>`,
+/// or from `<method>.args` and `<method>.result` for RPC methods.
+pub fn resolve_root(ast: &[ASTNode], root_id: &str) -> Result<Root, String> {
+    if let Some((fn_id, part)) = root_id.split_once('.') {
+        let node = ast::find_fn_nodes(ast)
+            .into_iter()
+            .find(|node| node.id == fn_id)
+            .ok_or_else(|| format!("Unknown RPC method: {}", fn_id))?;
+
+        return match part {
+            "args" => Ok(Root::Args(node.args.clone())),
+            "result" if node.is_signal => Ok(Root::SignalResult(node.return_type_id.clone())),
+            "result" => node
+                .return_type_id
+                .clone()
+                .map(Root::Type)
+                .ok_or_else(|| format!("RPC method {} has no result", fn_id)),
+            _ => Err(format!("Expected 'args' or 'result', but got '{}'", part)),
+        };
+    }
+
+    // The parser panics on malformed types, e.g. `Vec<`.
+    let type_id = parser::catch_panic(|| {
+        let mut lexer = Lexer::tokenize(root_id);
+
+        if !matches!(lexer.current_token(), Token::ID { .. }) {
+            return None;
+        }
+
+        let type_id = parser::parse_type_id(&mut lexer);
+
+        if *lexer.current_token() != Token::EOF {
+            return None;
+        }
+
+        Some(type_id)
+    });
+
+    match type_id {
+        Ok(Some(type_id)) => Ok(Root::Type(type_id)),
+        _ => Err(format!("Invalid type: {}", root_id)),
+    }
+}
+
+/// Describes a range of bytes read for a value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    pub offset: usize,
+    pub size: usize,
+    pub path: String,
+    pub type_id: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodeError {
+    pub offset: usize,
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:08x}: {}: {}", self.offset, self.path, self.message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodeError {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Size in bytes of a fixed size primitive type.
+pub fn primitive_size(type_id: &TypeIDASTNode) -> Option<usize> {
+    match type_id {
+        TypeIDASTNode::Integer { id, .. } => match id.as_str() {
+            "i8" | "u8" => Some(1),
+            "i32" | "u32" => Some(4),
+            "i64" | "u64" => Some(8),
+            _ => None,
+        },
+        TypeIDASTNode::Number { size, .. } => Some(*size),
+        TypeIDASTNode::Bool { .. } => Some(1),
+        TypeIDASTNode::Char { .. } => Some(4),
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "GroupAddress" | "CommandsBufferAddress" => Some(8),
+            _ => None,
+        },
+        TypeIDASTNode::Generic { .. } => None,
+    }
+}
+
+/// Parses bytes written as hex, e.g. `0a 0b`, `0x0a0b` or `0A:0B`.
+pub fn parse_hex(data: &str) -> Result<Vec<u8>, String> {
+    let digits = data
+        .split_whitespace()
+        .map(|chunk| chunk.trim_start_matches("0x"))
+        .collect::<String>()
+        .replace([':', ','], "");
+
+    if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid hex digit: {}", c));
+    }
+
+    if digits.len() % 2 != 0 {
+        return Err(String::from(
+            "Hex string should have an even number of digits",
+        ));
+    }
+
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&digits[i..i + 2], 16)
+                .map_err(|_| format!("Invalid hex byte: {}", &digits[i..i + 2]))
+        })
+        .collect()
+}
@@ -0,0 +1,480 @@
+use convert_case::{Case, Casing};
+use serde_json::{Map, Number, Value};
+
+use crate::ast::{
+    self, ASTNode, EnumItemASTNode, StructFieldASTNode, TupleFieldASTNode, TypeIDASTNode,
+};
+use crate::rust_generator::generate_type_id;
+
+use super::{encoder::Encoder, Root};
+
+/// Nested structs and enums deeper than this are filled with the
+/// shortest value available: empty vectors, `None` or empty enum items.
+const MAX_DEPTH: usize = 4;
+
+const SAMPLE_CHARS: &[char] = &[
+    'a', 'b', 'c', 'X', 'Y', 'Z', '0', '1', '9', ' ', '_', '-', '"', '\\', '\n', 'ü', 'ж', 'λ',
+    '中', '✓',
+];
+
+const MAX_STRING: &str = "Ünïcødé ✓ \"quoted\" \\ \n中文";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sample {
+    Default,
+    Min,
+    Max,
+    Random,
+}
+
+/// A sample value of a root struct or enum with the bytes it is encoded to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestVector {
+    pub name: String,
+    pub type_id: String,
+    pub value: Value,
+    pub data: Vec<u8>,
+}
+
+/// Generates test vectors for every struct and enum of the schema:
+/// defaults, boundaries and `count` random values, per enum item for enums.
+/// Types that can't be sampled, e.g. referring to unknown generic types,
+/// are returned separately with the reason.
+pub fn generate_test_vectors(
+    ast: &[ASTNode],
+    seed: u64,
+    count: usize,
+) -> (Vec<TestVector>, Vec<(String, String)>) {
+    let mut vectors = vec![];
+    let mut skipped = vec![];
+
+    for node in ast {
+        let (id, samples) = match node {
+            ASTNode::Struct(node) => (
+                &node.id,
+                generate_struct_samples(ast, &node.id, seed, count),
+            ),
+            ASTNode::Enum(node) => (
+                &node.id,
+                generate_enum_samples(ast, &node.items, seed, count),
+            ),
+            _ => continue,
+        };
+
+        let root = Root::Type(TypeIDASTNode::Other { id: id.clone() });
+        let type_vectors = samples.and_then(|samples| {
+            samples
+                .into_iter()
+                .map(|(suffix, value)| {
+                    let data = Encoder::new(ast)
+                        .encode(&root, &value)
+                        .map_err(|err| err.to_string())?;
+
+                    Ok(TestVector {
+                        name: format!("{}_{}", id.to_case(Case::Snake), suffix),
+                        type_id: id.clone(),
+                        value,
+                        data,
+                    })
+                })
+                .collect::<Result<Vec<TestVector>, String>>()
+        });
+
+        match type_vectors {
+            Ok(mut type_vectors) => vectors.append(&mut type_vectors),
+            Err(err) => skipped.push((id.clone(), err)),
+        }
+    }
+
+    (vectors, skipped)
+}
+
+fn generate_struct_samples(
+    ast: &[ASTNode],
+    id: &str,
+    seed: u64,
+    count: usize,
+) -> Result<Vec<(String, Value)>, String> {
+    let type_id = TypeIDASTNode::Other { id: id.to_string() };
+    let mut sampler = Sampler::new(ast, seed);
+    let mut samples = vec![
+        (
+            String::from("default"),
+            sampler.sample(&type_id, Sample::Default)?,
+        ),
+        (String::from("min"), sampler.sample(&type_id, Sample::Min)?),
+        (String::from("max"), sampler.sample(&type_id, Sample::Max)?),
+    ];
+
+    for i in 0..count {
+        samples.push((
+            format!("random_{}", i),
+            sampler.sample(&type_id, Sample::Random)?,
+        ));
+    }
+
+    Ok(samples)
+}
+
+fn generate_enum_samples(
+    ast: &[ASTNode],
+    items: &[EnumItemASTNode],
+    seed: u64,
+    count: usize,
+) -> Result<Vec<(String, Value)>, String> {
+    let mut sampler = Sampler::new(ast, seed);
+    let mut samples = vec![];
+
+    if let Some(item) = items.first() {
+        samples.push((
+            String::from("default"),
+            sampler.sample_enum_item(item, Sample::Default, 0)?,
+        ));
+    }
+
+    for item in items {
+        let item_id = item.id().to_case(Case::Snake);
+
+        if let EnumItemASTNode::Empty { .. } = item {
+            samples.push((item_id, sampler.sample_enum_item(item, Sample::Default, 0)?));
+            continue;
+        }
+
+        samples.push((
+            format!("{}_min", item_id),
+            sampler.sample_enum_item(item, Sample::Min, 0)?,
+        ));
+        samples.push((
+            format!("{}_max", item_id),
+            sampler.sample_enum_item(item, Sample::Max, 0)?,
+        ));
+    }
+
+    for i in 0..count {
+        if items.is_empty() {
+            break;
+        }
+
+        let item = &items[sampler.next_below(items.len() as u64) as usize];
+        samples.push((
+            format!("random_{}", i),
+            sampler.sample_enum_item(item, Sample::Random, 0)?,
+        ));
+    }
+
+    Ok(samples)
+}
+
+/// Generates sample JSON values in the shape accepted by the encoder.
+pub struct Sampler<'a> {
+    ast: &'a [ASTNode],
+    state: u64,
+}
+
+impl<'a> Sampler<'a> {
+    pub fn new(ast: &'a [ASTNode], seed: u64) -> Self {
+        Sampler { ast, state: seed }
+    }
+
+    pub fn sample(&mut self, type_id: &TypeIDASTNode, sample: Sample) -> Result<Value, String> {
+        self.sample_type(type_id, sample, 0)
+    }
+
+    /// SplitMix64, so the same seed gives the same vectors on every platform.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn next_below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+
+    fn sample_type(
+        &mut self,
+        type_id: &TypeIDASTNode,
+        sample: Sample,
+        depth: usize,
+    ) -> Result<Value, String> {
+        let value = match type_id {
+            TypeIDASTNode::Integer { size, signed, .. } => {
+                self.sample_integer(*size, *signed, sample)
+            }
+            TypeIDASTNode::Number { size, .. } => self.sample_number(*size, sample),
+            TypeIDASTNode::Bool { .. } => Value::Bool(match sample {
+                Sample::Default | Sample::Min => false,
+                Sample::Max => true,
+                Sample::Random => self.next_u64() & 1 == 1,
+            }),
+            TypeIDASTNode::Char { .. } => Value::String(
+                match sample {
+                    Sample::Default | Sample::Min => '\0',
+                    Sample::Max => '\u{FFFF}',
+                    Sample::Random => self.sample_char(),
+                }
+                .to_string(),
+            ),
+            TypeIDASTNode::Other { id } => match id.as_str() {
+                "GroupAddress" | "CommandsBufferAddress" => self.sample_integer(8, false, sample),
+                "String" => Value::String(match sample {
+                    Sample::Default | Sample::Min => String::new(),
+                    Sample::Max => String::from(MAX_STRING),
+                    Sample::Random => {
+                        let len = self.next_below(9);
+                        (0..len).map(|_| self.sample_char()).collect()
+                    }
+                }),
+                _ => self.sample_node(id, sample, depth)?,
+            },
+            TypeIDASTNode::Generic { id, generics } if id == "Vec" && generics.len() == 1 => {
+                let len = match sample {
+                    _ if depth >= MAX_DEPTH => 0,
+                    Sample::Default | Sample::Min => 0,
+                    Sample::Max => 2,
+                    Sample::Random => self.next_below(4),
+                };
+
+                Value::Array(
+                    (0..len)
+                        .map(|_| self.sample_type(&generics[0], sample, depth + 1))
+                        .collect::<Result<Vec<Value>, String>>()?,
+                )
+            }
+            TypeIDASTNode::Generic { id, generics } if id == "Option" && generics.len() == 1 => {
+                let is_some = match sample {
+                    _ if depth >= MAX_DEPTH => false,
+                    Sample::Default | Sample::Min => false,
+                    Sample::Max => true,
+                    Sample::Random => self.next_u64() & 1 == 1,
+                };
+
+                if is_some {
+                    self.sample_type(&generics[0], sample, depth + 1)?
+                } else {
+                    Value::Null
+                }
+            }
+            TypeIDASTNode::Generic { .. } => {
+                return Err(format!("Unsupported type: {}", generate_type_id(type_id)))
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn sample_integer(&mut self, size: usize, signed: bool, sample: Sample) -> Value {
+        let bits = size as u32 * 8;
+
+        if signed {
+            let min = if bits == 64 {
+                i64::MIN
+            } else {
+                -(1 << (bits - 1))
+            };
+            let max = if bits == 64 {
+                i64::MAX
+            } else {
+                (1 << (bits - 1)) - 1
+            };
+
+            Value::from(match sample {
+                Sample::Default => 0,
+                Sample::Min => min,
+                Sample::Max => max,
+                Sample::Random => (self.next_u64() << (64 - bits)) as i64 >> (64 - bits),
+            })
+        } else {
+            let max = if bits == 64 {
+                u64::MAX
+            } else {
+                (1 << bits) - 1
+            };
+
+            Value::from(match sample {
+                Sample::Default | Sample::Min => 0,
+                Sample::Max => max,
+                Sample::Random => self.next_u64() & max,
+            })
+        }
+    }
+
+    /// Random numbers are kept exactly representable, so the JSON value,
+    /// the literals in the generated tests and the bytes all agree.
+    fn sample_number(&mut self, size: usize, sample: Sample) -> Value {
+        let value = match (sample, size) {
+            (Sample::Default, _) => 0.0,
+            (Sample::Min, 4) => f32::MIN as f64,
+            (Sample::Max, 4) => f32::MAX as f64,
+            (Sample::Min, _) => f64::MIN,
+            (Sample::Max, _) => f64::MAX,
+            (Sample::Random, 4) => ((self.next_below(1 << 24) as i64) - (1 << 23)) as f64 / 256.0,
+            (Sample::Random, _) => {
+                ((self.next_below(1 << 53) as i64) - (1 << 52)) as f64 / 1048576.0
+            }
+        };
+
+        Value::Number(Number::from_f64(value).unwrap())
+    }
+
+    fn sample_char(&mut self) -> char {
+        SAMPLE_CHARS[self.next_below(SAMPLE_CHARS.len() as u64) as usize]
+    }
+
+    fn sample_node(&mut self, id: &str, sample: Sample, depth: usize) -> Result<Value, String> {
+        if depth > MAX_DEPTH * 2 {
+            return Err(format!("{} is recursive", id));
+        }
+
+        if let Some(node) = ast::find_struct_node(self.ast, id) {
+            self.sample_fields(&node.fields, sample, depth + 1)
+        } else if let Some(node) = ast::find_enum_node(self.ast, id) {
+            let item = match sample {
+                _ if depth >= MAX_DEPTH => node
+                    .items
+                    .iter()
+                    .find(|item| matches!(item, EnumItemASTNode::Empty { .. }))
+                    .or_else(|| node.items.first()),
+                Sample::Default | Sample::Min => node.items.first(),
+                Sample::Max => node.items.last(),
+                Sample::Random if node.items.is_empty() => None,
+                Sample::Random => {
+                    Some(&node.items[self.next_below(node.items.len() as u64) as usize])
+                }
+            }
+            .ok_or_else(|| format!("{} has no items", id))?;
+
+            self.sample_enum_item(item, sample, depth + 1)
+        } else {
+            Err(format!("Unknown type: {}", id))
+        }
+    }
+
+    fn sample_fields(
+        &mut self,
+        fields: &[StructFieldASTNode],
+        sample: Sample,
+        depth: usize,
+    ) -> Result<Value, String> {
+        let mut values = Map::new();
+
+        for field in fields {
+            values.insert(
+                field.name.clone(),
+                self.sample_type(&field.type_id, sample, depth)?,
+            );
+        }
+
+        Ok(Value::Object(values))
+    }
+
+    fn sample_tuple_values(
+        &mut self,
+        values: &[TupleFieldASTNode],
+        sample: Sample,
+        depth: usize,
+    ) -> Result<Value, String> {
+        Ok(Value::Array(
+            values
+                .iter()
+                .map(|value| self.sample_type(&value.type_id, sample, depth))
+                .collect::<Result<Vec<Value>, String>>()?,
+        ))
+    }
+
+    pub fn sample_enum_item(
+        &mut self,
+        item: &EnumItemASTNode,
+        sample: Sample,
+        depth: usize,
+    ) -> Result<Value, String> {
+        let value = match item {
+            EnumItemASTNode::Empty { id, .. } => return Ok(Value::String(id.clone())),
+            EnumItemASTNode::Tuple { values, .. } => {
+                self.sample_tuple_values(values, sample, depth)?
+            }
+            EnumItemASTNode::Struct { fields, .. } => self.sample_fields(fields, sample, depth)?,
+        };
+
+        let mut values = Map::new();
+        values.insert(item.id().to_string(), value);
+
+        Ok(Value::Object(values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{codec::decoder::Decoder, lexer::Lexer, parser::parse};
+    use serde_json::json;
+
+    const SCHEMA: &str = "
+        struct Point { x: f32, y: i8 }
+
+        enum Shape {
+            Empty,
+            Polygon(Vec<Point>, Option<String>),
+        }
+
+        struct Unsupported { table: LinearTable<f32, Point> }
+    ";
+
+    #[test]
+    fn generate_boundary_vectors() {
+        let mut lexer = Lexer::tokenize(SCHEMA);
+        let ast = parse(&mut lexer);
+        let (vectors, skipped) = generate_test_vectors(&ast, 0, 1);
+
+        let names = vectors
+            .iter()
+            .map(|vector| vector.name.as_str())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            names,
+            vec![
+                "point_default",
+                "point_min",
+                "point_max",
+                "point_random_0",
+                "shape_default",
+                "shape_empty",
+                "shape_polygon_min",
+                "shape_polygon_max",
+                "shape_random_0",
+            ]
+        );
+        assert_eq!(vectors[1].value, json!({ "x": f32::MIN, "y": -128 }));
+        assert_eq!(vectors[2].data, vec![0xFF, 0xFF, 0x7F, 0x7F, 0x7F]);
+        assert_eq!(
+            skipped,
+            vec![(
+                String::from("Unsupported"),
+                String::from("Unsupported type: LinearTable<f32, Point>")
+            )]
+        );
+    }
+
+    #[test]
+    fn generate_deterministic_random_vectors() {
+        let mut lexer = Lexer::tokenize(SCHEMA);
+        let ast = parse(&mut lexer);
+        let (first, _) = generate_test_vectors(&ast, 42, 8);
+        let (second, _) = generate_test_vectors(&ast, 42, 8);
+        let (other, _) = generate_test_vectors(&ast, 43, 8);
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+
+        for vector in first {
+            let root = Root::Type(TypeIDASTNode::Other { id: vector.type_id });
+            let decoded = Decoder::new(&ast, &vector.data).decode(&root).unwrap();
+
+            assert_eq!(decoded, vector.value);
+        }
+    }
+}
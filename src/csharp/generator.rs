@@ -0,0 +1,928 @@
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{
+        self, ASTNode, ConstBlockASTNode, ConstItemASTNode, ConstValueASTNode, EnumASTNode,
+        EnumItemASTNode, FnASTNode, StructASTNode, StructFieldASTNode, TypeIDASTNode,
+    },
+    lexer::Literal,
+    options::GeneratorOptions,
+    writer::Writer,
+};
+
+/// Nested static classes, blank lines are placed like between the `pub mod`
+/// blocks of `rust::consts::generate_const_block`.
+pub fn generate_consts(ast: &[ASTNode], options: &GeneratorOptions) -> String {
+    let mut blocks = vec![];
+
+    for node in ast {
+        if let ASTNode::Const(node) = node {
+            let mut writer = Writer::default();
+            generate_const_block(&mut writer, &access_level(options), node);
+            blocks.push(writer.show().to_string());
+        }
+    }
+
+    blocks.join("\n")
+}
+
+fn generate_const_block(writer: &mut Writer, access: &str, node: &ConstBlockASTNode) {
+    writer.writeln(&format!(
+        "{} static class {}",
+        access,
+        node.id.to_case(Case::Pascal)
+    ));
+    writer.writeln("{");
+    writer.push_tab();
+
+    let mut is_first = true;
+    let mut is_value = false;
+
+    for item in &node.items {
+        match item {
+            ConstItemASTNode::Value { id, type_id, value } => {
+                if !is_value && !is_first {
+                    writer.new_line();
+                }
+
+                is_value = true;
+
+                let id = id.to_case(Case::Pascal);
+                let type_name = generate_type_id(type_id);
+                let value = generate_const_value(value, type_id);
+
+                match type_id {
+                    TypeIDASTNode::Other { id: type_id } if is_address(type_id) => {
+                        writer.writeln(&format!(
+                            "{} static readonly {} {} = new {}({});",
+                            access, type_name, id, type_name, value
+                        ));
+                    }
+                    _ => writer.writeln(&format!(
+                        "{} const {} {} = {};",
+                        access, type_name, id, value
+                    )),
+                }
+            }
+            ConstItemASTNode::ConstsBlock { node } => {
+                if is_value && !is_first {
+                    writer.new_line();
+                }
+
+                generate_const_block(writer, access, node);
+                is_value = false;
+            }
+        }
+
+        is_first = false;
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+pub fn generate_models(ast: &[ASTNode], options: &GeneratorOptions) -> String {
+    let mut blocks = vec![];
+
+    for node in ast {
+        match node {
+            ASTNode::Struct(node) => blocks.push(generate_struct_model(node, options)),
+            ASTNode::Enum(node) => blocks.push(generate_enum_model(node, options)),
+            _ => (),
+        }
+    }
+
+    blocks.join("\n")
+}
+
+fn generate_struct_model(node: &StructASTNode, options: &GeneratorOptions) -> String {
+    let mut writer = Writer::default();
+    let access = access_level(options);
+
+    if node.fields.is_empty() {
+        writer.writeln(&format!("{} sealed record {}()", access, node.id));
+    } else {
+        writer.writeln(&format!("{} sealed record {}(", access, node.id));
+        writer.push_tab();
+        generate_parameters(&mut writer, &node.fields);
+        writer.pop_tab();
+        writer.writeln(")");
+    }
+
+    writer.writeln("{");
+    writer.push_tab();
+
+    writer.writeln(&format!("public static {} CreateBuffersDefault()", node.id));
+    writer.writeln("{");
+    writer.push_tab();
+    generate_constructor(&mut writer, &node.id, &node.fields, generate_default_value);
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "public static {} ReadFromBuffers(BinaryReader reader)",
+        node.id
+    ));
+    writer.writeln("{");
+    writer.push_tab();
+    generate_constructor(&mut writer, &node.id, &node.fields, generate_read);
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    generate_skip_method(&mut writer);
+
+    writer.new_line();
+    writer.writeln("public void WriteToBuffers(BinaryWriter writer)");
+    writer.writeln("{");
+    writer.push_tab();
+
+    for field in &node.fields {
+        writer.writeln(&format!(
+            "{};",
+            generate_write(&field.type_id, &field.name.to_case(Case::Pascal))
+        ));
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+/// An abstract record with a nested sealed record per item, the private
+/// constructor keeps the hierarchy closed.
+fn generate_enum_model(node: &EnumASTNode, options: &GeneratorOptions) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!(
+        "{} abstract record {}",
+        access_level(options),
+        node.id
+    ));
+    writer.writeln("{");
+    writer.push_tab();
+    writer.writeln(&format!("private {}() {{}}", node.id));
+    writer.new_line();
+
+    let fields = |item: &EnumItemASTNode| enum_item_fields(item, options);
+
+    for item in &node.items {
+        let fields = fields(item);
+
+        if fields.is_empty() {
+            writer.writeln(&format!(
+                "public sealed record {}() : {};",
+                item.id(),
+                node.id
+            ));
+        } else {
+            writer.writeln(&format!("public sealed record {}(", item.id()));
+            writer.push_tab();
+            generate_parameters(&mut writer, &fields);
+            writer.pop_tab();
+            writer.writeln(&format!(") : {};", node.id));
+        }
+    }
+
+    writer.new_line();
+    writer.writeln(&format!("public static {} CreateBuffersDefault()", node.id));
+    writer.writeln("{");
+    writer.push_tab();
+    let item = node
+        .items
+        .first()
+        .expect("enum should have at least one item");
+    generate_constructor(
+        &mut writer,
+        item.id(),
+        &fields(item),
+        generate_default_value,
+    );
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "public static {} ReadFromBuffers(BinaryReader reader)",
+        node.id
+    ));
+    writer.writeln("{");
+    writer.push_tab();
+    writer.writeln("var kind = reader.ReadUInt32();");
+    writer.new_line();
+    writer.writeln("switch (kind)");
+    writer.writeln("{");
+    writer.push_tab();
+
+    for item in &node.items {
+        writer.writeln(&format!("case {}:", item.position()));
+        writer.push_tab();
+        generate_constructor(&mut writer, item.id(), &fields(item), generate_read);
+        writer.pop_tab();
+    }
+
+    writer.writeln("default:");
+    writer.push_tab();
+    writer.writeln(&format!(
+        "throw new InvalidDataException($\"Invalid {} kind: {{kind}}\");",
+        node.id
+    ));
+    writer.pop_tab();
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    generate_skip_method(&mut writer);
+
+    writer.new_line();
+    writer.writeln("public void WriteToBuffers(BinaryWriter writer)");
+    writer.writeln("{");
+    writer.push_tab();
+    writer.writeln("switch (this)");
+    writer.writeln("{");
+    writer.push_tab();
+
+    for item in &node.items {
+        let fields = fields(item);
+
+        if fields.is_empty() {
+            writer.writeln(&format!("case {}:", item.id()));
+        } else {
+            writer.writeln(&format!("case {} model:", item.id()));
+        }
+
+        writer.push_tab();
+        writer.writeln(&format!("writer.Write({}u);", item.position()));
+
+        for field in &fields {
+            let accessor = format!("model.{}", field.name.to_case(Case::Pascal));
+            writer.writeln(&format!("{};", generate_write(&field.type_id, &accessor)));
+        }
+
+        writer.writeln("break;");
+        writer.pop_tab();
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+/// Fields of the item, tuple values are named `P` with the position like
+/// in the Swift models. The item records are nested in the enum record, so
+/// model types are referred to by their full name, otherwise `Circle(Circle)`
+/// would refer to the item itself, and fields named like the item get a
+/// `Value` suffix since a member can't be named like its record.
+fn enum_item_fields(item: &EnumItemASTNode, options: &GeneratorOptions) -> Vec<StructFieldASTNode> {
+    let fields = match item {
+        EnumItemASTNode::Empty { .. } => vec![],
+        EnumItemASTNode::Tuple { values, .. } => values
+            .iter()
+            .map(|value| StructFieldASTNode {
+                doc_comments: vec![],
+                position: value.position,
+                name: format!("p{}", value.position),
+                type_id: value.type_id.clone(),
+            })
+            .collect(),
+        EnumItemASTNode::Struct { fields, .. } => fields.clone(),
+    };
+
+    let prefix = match &options.module {
+        Some(module) => format!("global::{}.", module),
+        None => String::from("global::"),
+    };
+
+    fields
+        .into_iter()
+        .map(|field| {
+            let name = if field.name.to_case(Case::Pascal) == item.id() {
+                format!("{}_value", field.name)
+            } else {
+                field.name
+            };
+
+            StructFieldASTNode {
+                name,
+                type_id: qualify_type_id(&field.type_id, &prefix),
+                ..field
+            }
+        })
+        .collect()
+}
+
+fn qualify_type_id(type_id: &TypeIDASTNode, prefix: &str) -> TypeIDASTNode {
+    match type_id {
+        TypeIDASTNode::Other { id } if id != "String" && !is_address(id) => TypeIDASTNode::Other {
+            id: format!("{}{}", prefix, id),
+        },
+        TypeIDASTNode::Generic { id, generics } => TypeIDASTNode::Generic {
+            id: id.clone(),
+            generics: generics
+                .iter()
+                .map(|generic| qualify_type_id(generic, prefix))
+                .collect(),
+        },
+        _ => type_id.clone(),
+    }
+}
+
+fn generate_parameters(writer: &mut Writer, fields: &[StructFieldASTNode]) {
+    for (i, field) in fields.iter().enumerate() {
+        let separator = if i == fields.len() - 1 { "" } else { "," };
+
+        writer.writeln(&format!(
+            "{} {}{}",
+            generate_type_id(&field.type_id),
+            field.name.to_case(Case::Pascal),
+            separator
+        ));
+    }
+}
+
+/// Writes `return new Id(...);`, arguments are evaluated in order, so reads
+/// happen in the buffer order.
+fn generate_constructor(
+    writer: &mut Writer,
+    id: &str,
+    fields: &[StructFieldASTNode],
+    value: fn(&TypeIDASTNode) -> String,
+) {
+    if fields.is_empty() {
+        writer.writeln(&format!("return new {}();", id));
+        return;
+    }
+
+    writer.writeln(&format!("return new {}(", id));
+    writer.push_tab();
+
+    for (i, field) in fields.iter().enumerate() {
+        let separator = if i == fields.len() - 1 { "" } else { "," };
+        writer.writeln(&format!("{}{}", value(&field.type_id), separator));
+    }
+
+    writer.pop_tab();
+    writer.writeln(");");
+}
+
+fn generate_skip_method(writer: &mut Writer) {
+    writer.writeln("public static void SkipInBuffers(BinaryReader reader, ulong count)");
+    writer.writeln("{");
+    writer.push_tab();
+    writer.writeln("for (ulong i = 0; i < count; i++)");
+    writer.writeln("{");
+    writer.push_tab();
+    writer.writeln("ReadFromBuffers(reader);");
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+pub fn generate_rpc(ast: &[ASTNode], options: &GeneratorOptions) -> String {
+    let namespace = ast::find_directive_value(ast, "namespace").expect("namespace is required");
+    let namespace = match namespace {
+        ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("namespace should be a string literal"),
+        },
+    };
+
+    let scope_id = ast::find_directive_value(ast, "id").expect("id is required");
+    let scope_id = match scope_id {
+        ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("id should be a string literal"),
+        },
+    };
+
+    let class_id = format!("{}Rpc", namespace.to_case(Case::Pascal));
+    let fn_nodes = ast::find_fn_nodes(ast);
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!(
+        "{} sealed class {}",
+        access_level(options),
+        class_id
+    ));
+    writer.writeln("{");
+    writer.push_tab();
+    writer.writeln(&format!("private const string ScopeId = \"{}\";", scope_id));
+    writer.new_line();
+    writer.writeln("private readonly ITechPawsBuffersRuntime runtime;");
+
+    if fn_nodes.iter().any(|node| node.is_signal) {
+        writer.new_line();
+
+        for node in fn_nodes.iter().filter(|node| node.is_signal) {
+            match &node.return_type_id {
+                Some(type_id) => writer.writeln(&format!(
+                    "public event Action<{}>? {};",
+                    generate_type_id(type_id),
+                    node.id.to_case(Case::Pascal)
+                )),
+                None => writer.writeln(&format!(
+                    "public event Action? {};",
+                    node.id.to_case(Case::Pascal)
+                )),
+            }
+        }
+    }
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "public {}(ITechPawsBuffersRuntime runtime)",
+        class_id
+    ));
+    writer.writeln("{");
+    writer.push_tab();
+    writer.writeln("this.runtime = runtime;");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    for node in fn_nodes.iter().filter(|node| !node.is_signal) {
+        writer.new_line();
+        generate_call_method(&mut writer, node);
+    }
+
+    if fn_nodes.iter().any(|node| node.is_signal) {
+        writer.new_line();
+        generate_consume_signals_method(&mut writer, &fn_nodes);
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+fn generate_call_method(writer: &mut Writer, node: &FnASTNode) {
+    let args = node
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{} {}",
+                generate_type_id(&arg.type_id),
+                generate_arg_id(&arg.id)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let return_type_id = match (&node.return_type_id, node.is_async) {
+        (Some(type_id), false) => generate_type_id(type_id),
+        (Some(type_id), true) => format!("Task<{}>", generate_type_id(type_id)),
+        (None, false) => String::from("void"),
+        (None, true) => String::from("Task"),
+    };
+
+    let call = if node.is_async { "CallAsync" } else { "Call" };
+    let statement = if node.return_type_id.is_some() || node.is_async {
+        "return "
+    } else {
+        ""
+    };
+
+    writer.writeln(&format!(
+        "public {} {}({})",
+        return_type_id,
+        node.id.to_case(Case::Pascal),
+        args
+    ));
+    writer.writeln("{");
+    writer.push_tab();
+    writer.writeln(&format!("{}runtime.{}(", statement, call));
+    writer.push_tab();
+    writer.writeln("ScopeId,");
+
+    let mut arguments = vec![];
+
+    if node.args.is_empty() {
+        arguments.push(vec![String::from("null")]);
+    } else {
+        let mut lines = vec![String::from("writer =>"), String::from("{")];
+
+        for arg in &node.args {
+            lines.push(format!(
+                "    {};",
+                generate_write(&arg.type_id, &generate_arg_id(&arg.id))
+            ));
+        }
+
+        lines.push(String::from("}"));
+        arguments.push(lines);
+    }
+
+    if let Some(type_id) = &node.return_type_id {
+        arguments.push(vec![format!("reader => {}", generate_read(type_id))]);
+    }
+
+    writer.writeln(&format!("{},", node.position));
+
+    for (i, lines) in arguments.iter().enumerate() {
+        let separator = if i == arguments.len() - 1 { "" } else { "," };
+
+        for (j, line) in lines.iter().enumerate() {
+            if j == lines.len() - 1 {
+                writer.writeln(&format!("{}{}", line, separator));
+            } else {
+                writer.writeln(line);
+            }
+        }
+    }
+
+    writer.pop_tab();
+    writer.writeln(");");
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+/// Raises the events of the signals emitted since the last call, like
+/// `consumeStreams` of the Swift client.
+fn generate_consume_signals_method(writer: &mut Writer, fn_nodes: &[&FnASTNode]) {
+    writer.writeln("public void ConsumeSignals()");
+    writer.writeln("{");
+    writer.push_tab();
+
+    for node in fn_nodes.iter().filter(|node| node.is_signal) {
+        let value = node
+            .return_type_id
+            .as_ref()
+            .map_or(String::new(), generate_read);
+
+        writer.writeln(&format!(
+            "runtime.ConsumeSignal(ScopeId, {}, reader => {}?.Invoke({}));",
+            node.position,
+            node.id.to_case(Case::Pascal),
+            value
+        ));
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+fn access_level(options: &GeneratorOptions) -> String {
+    match &options.access_level {
+        Some(access_level) if !access_level.is_empty() => access_level.clone(),
+        _ => String::from("public"),
+    }
+}
+
+/// Arguments are camel case, so they can collide with C# keywords.
+fn generate_arg_id(id: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "base",
+        "bool",
+        "break",
+        "byte",
+        "case",
+        "catch",
+        "char",
+        "checked",
+        "class",
+        "const",
+        "continue",
+        "decimal",
+        "default",
+        "delegate",
+        "do",
+        "double",
+        "else",
+        "enum",
+        "event",
+        "explicit",
+        "extern",
+        "false",
+        "finally",
+        "fixed",
+        "float",
+        "for",
+        "foreach",
+        "goto",
+        "if",
+        "implicit",
+        "in",
+        "int",
+        "interface",
+        "internal",
+        "is",
+        "lock",
+        "long",
+        "namespace",
+        "new",
+        "null",
+        "object",
+        "operator",
+        "out",
+        "override",
+        "params",
+        "private",
+        "protected",
+        "public",
+        "readonly",
+        "ref",
+        "return",
+        "sbyte",
+        "sealed",
+        "short",
+        "sizeof",
+        "stackalloc",
+        "static",
+        "string",
+        "struct",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typeof",
+        "uint",
+        "ulong",
+        "unchecked",
+        "unsafe",
+        "ushort",
+        "using",
+        "virtual",
+        "void",
+        "volatile",
+        "while",
+    ];
+
+    let id = id.to_case(Case::Camel);
+
+    if KEYWORDS.contains(&id.as_str()) {
+        format!("@{}", id)
+    } else {
+        id
+    }
+}
+
+fn is_address(id: &str) -> bool {
+    id == "GroupAddress" || id == "CommandsBufferAddress"
+}
+
+pub fn generate_type_id(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { size, signed, .. } => match (size, signed) {
+            (1, true) => String::from("sbyte"),
+            (4, true) => String::from("int"),
+            (8, true) => String::from("long"),
+            (1, false) => String::from("byte"),
+            (4, false) => String::from("uint"),
+            (8, false) => String::from("ulong"),
+            _ => panic!("Unsupported integer size, {}", size),
+        },
+        TypeIDASTNode::Number { size: 4, .. } => String::from("float"),
+        TypeIDASTNode::Number { .. } => String::from("double"),
+        TypeIDASTNode::Bool { .. } => String::from("bool"),
+        TypeIDASTNode::Char { .. } => String::from("uint"),
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => String::from("string"),
+            _ => id.clone(),
+        },
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Option" => format!(
+                "{}?",
+                generate_type_id(generics.first().expect("Optional type cannot be empty"))
+            ),
+            "Vec" => format!(
+                "List<{}>",
+                generate_type_id(generics.first().expect("Vec type cannot be empty"))
+            ),
+            _ => format!(
+                "{}<{}>",
+                id,
+                generics
+                    .iter()
+                    .map(generate_type_id)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        },
+    }
+}
+
+pub fn generate_read(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { size, signed, .. } => match (size, signed) {
+            (1, true) => String::from("reader.ReadSByte()"),
+            (4, true) => String::from("reader.ReadInt32()"),
+            (8, true) => String::from("reader.ReadInt64()"),
+            (1, false) => String::from("reader.ReadByte()"),
+            (4, false) => String::from("reader.ReadUInt32()"),
+            (8, false) => String::from("reader.ReadUInt64()"),
+            _ => panic!("Unsupported integer size, {}", size),
+        },
+        TypeIDASTNode::Number { size: 4, .. } => String::from("reader.ReadSingle()"),
+        TypeIDASTNode::Number { .. } => String::from("reader.ReadDouble()"),
+        TypeIDASTNode::Bool { .. } => String::from("reader.ReadBoolean()"),
+        TypeIDASTNode::Char { .. } => String::from("reader.ReadUInt32()"),
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => String::from("reader.ReadBuffersString()"),
+            id if is_address(id) => format!("new {}(reader.ReadUInt64())", id),
+            _ => format!("{}.ReadFromBuffers(reader)", id),
+        },
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Option" => format!(
+                "reader.ReadByte() != 0 ? ({}){} : null",
+                generate_type_id(type_id),
+                generate_read(generics.first().expect("Optional type cannot be empty"))
+            ),
+            "Vec" => format!(
+                "reader.ReadList(() => {})",
+                generate_read(generics.first().expect("Vec type cannot be empty"))
+            ),
+            _ => format!("{}.ReadFromBuffers(reader)", generate_type_id(type_id)),
+        },
+    }
+}
+
+/// `BinaryWriter.Write` overloads write the primitives in the buffers
+/// format, except strings, those have a varint length.
+pub fn generate_write(type_id: &TypeIDASTNode, accessor: &str) -> String {
+    generate_nested_write(type_id, accessor, 0)
+}
+
+/// Lambda parameters of nested options and vectors get the depth as a
+/// suffix, C# doesn't allow them to shadow the outer ones.
+fn generate_nested_write(type_id: &TypeIDASTNode, accessor: &str, depth: usize) -> String {
+    let suffix = if depth == 0 {
+        String::new()
+    } else {
+        depth.to_string()
+    };
+
+    match type_id {
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => format!("writer.WriteBuffersString({})", accessor),
+            id if is_address(id) => format!("writer.Write({}.Value)", accessor),
+            _ => format!("{}.WriteToBuffers(writer)", accessor),
+        },
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Option" => {
+                let value = format!("value{}", suffix);
+
+                format!(
+                    "writer.WriteOption({}, {} => {})",
+                    accessor,
+                    value,
+                    generate_nested_write(
+                        generics.first().expect("Optional type cannot be empty"),
+                        &value,
+                        depth + 1
+                    )
+                )
+            }
+            "Vec" => {
+                let item = format!("item{}", suffix);
+
+                format!(
+                    "writer.WriteList({}, {} => {})",
+                    accessor,
+                    item,
+                    generate_nested_write(
+                        generics.first().expect("Vec type cannot be empty"),
+                        &item,
+                        depth + 1
+                    )
+                )
+            }
+            _ => format!("{}.WriteToBuffers(writer)", accessor),
+        },
+        _ => format!("writer.Write({})", accessor),
+    }
+}
+
+pub fn generate_default_value(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { .. } | TypeIDASTNode::Number { .. } => String::from("0"),
+        TypeIDASTNode::Bool { .. } => String::from("false"),
+        TypeIDASTNode::Char { .. } => String::from("0"),
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => String::from("\"\""),
+            id if is_address(id) => format!("new {}(0)", id),
+            _ => format!("{}.CreateBuffersDefault()", id),
+        },
+        TypeIDASTNode::Generic { id, .. } => match id.as_str() {
+            "Option" => String::from("null"),
+            "Vec" => format!("new {}()", generate_type_id(type_id)),
+            _ => format!("{}.CreateBuffersDefault()", generate_type_id(type_id)),
+        },
+    }
+}
+
+pub fn generate_const_value(node: &ConstValueASTNode, type_id: &TypeIDASTNode) -> String {
+    match node {
+        ConstValueASTNode::Literal { literal, .. } => match literal {
+            Literal::StringLiteral(value) => format!("\"{}\"", value),
+            Literal::IntLiteral(value) => format!("{}", value),
+            Literal::NumberLiteral(value) => {
+                let suffix = match type_id {
+                    TypeIDASTNode::Number { size: 4, .. } => "f",
+                    _ => "",
+                };
+
+                if value.floor() == *value {
+                    format!("{}.0{}", value, suffix)
+                } else {
+                    format!("{}{}", value, suffix)
+                }
+            }
+            Literal::BoolLiteral(value) => format!("{}", value),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    fn parse_resource(name: &str) -> Vec<ASTNode> {
+        let src = fs::read_to_string(format!("test_resources/{}.tpb", name)).unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        parse(&mut lexer)
+    }
+
+    fn read_target(name: &str) -> String {
+        fs::read_to_string(format!("test_resources/csharp/{}.cs", name)).unwrap()
+    }
+
+    #[test]
+    fn generate_consts_test() {
+        let ast = parse_resource("consts");
+        let actual = generate_consts(&ast, &GeneratorOptions::default());
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("consts").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_basic() {
+        let ast = parse_resource("struct_basic");
+        let actual = generate_models(&ast, &GeneratorOptions::default());
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_basic").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_types() {
+        let ast = parse_resource("struct_types");
+        let actual = generate_models(&ast, &GeneratorOptions::default());
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_types").trim());
+    }
+
+    #[test]
+    fn generate_enum_model_extended_test() {
+        let ast = parse_resource("enum_extended");
+        let actual = generate_models(&ast, &GeneratorOptions::default());
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("enum_extended").trim());
+    }
+
+    #[test]
+    fn generate_enum_model_item_names_test() {
+        let ast = parse_resource("enum_item_names");
+        let actual = generate_models(&ast, &GeneratorOptions::default());
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("enum_item_names").trim());
+    }
+
+    #[test]
+    fn generate_rpc_sync_methods_test() {
+        let ast = parse_resource("rpc_sync_methods");
+        let actual = generate_rpc(&ast, &GeneratorOptions::default());
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_sync_methods").trim());
+    }
+
+    #[test]
+    fn generate_rpc_stream_methods_test() {
+        let ast = parse_resource("rpc_stream_methods");
+        let actual = generate_rpc(&ast, &GeneratorOptions::default());
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_stream_methods").trim());
+    }
+}
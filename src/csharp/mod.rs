@@ -0,0 +1,106 @@
+use crate::{
+    ast::{self, ASTNode},
+    options::GeneratorOptions,
+    writer::reindent,
+};
+
+use self::generator::{generate_consts, generate_models, generate_rpc};
+
+pub mod generator;
+
+/// Namespace of the runtime with the `BinaryReader`/`BinaryWriter`
+/// extensions, the address types and `ITechPawsBuffersRuntime`.
+const RUNTIME_NAMESPACE: &str = "TechPaws.Buffers";
+
+pub fn generate(ast: &[ASTNode]) -> String {
+    generate_with_options(ast, &GeneratorOptions::default())
+}
+
+pub fn generate_with_options(ast: &[ASTNode], options: &GeneratorOptions) -> String {
+    let mut writer = options.writer();
+
+    if !options.header().is_empty() {
+        writer.writeln(options.header());
+        writer.new_line();
+    }
+
+    writer.writeln("#nullable enable");
+    writer.new_line();
+    writer.writeln("using System;");
+    writer.writeln("using System.Collections.Generic;");
+    writer.writeln("using System.IO;");
+
+    if ast::find_fn_nodes(ast).iter().any(|node| node.is_async) {
+        writer.writeln("using System.Threading.Tasks;");
+    }
+
+    writer.writeln(&format!("using {};", RUNTIME_NAMESPACE));
+
+    for import in options.imports.iter() {
+        writer.writeln(&format!("using {};", import));
+    }
+
+    let mut sections = vec![generate_consts(ast, options), generate_models(ast, options)];
+
+    if ast::contains_fn_nodes(ast) {
+        sections.push(generate_rpc(ast, options));
+    }
+
+    let sections = sections
+        .into_iter()
+        .filter(|section| !section.is_empty())
+        .collect::<Vec<String>>();
+
+    writer.new_line();
+
+    match &options.module {
+        Some(namespace) => {
+            writer.writeln(&format!("namespace {}", namespace));
+            writer.writeln("{");
+
+            for (i, section) in sections.iter().enumerate() {
+                if i > 0 {
+                    writer.new_line();
+                }
+
+                for line in section.lines() {
+                    if line.is_empty() {
+                        writer.new_line();
+                    } else {
+                        writer.writeln(&format!("    {}", line));
+                    }
+                }
+            }
+
+            writer.writeln("}");
+        }
+        None => writer.write(&sections.join("\n")),
+    }
+
+    reindent(writer.show(), options.indent())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    #[test]
+    fn generate_with_custom_options() {
+        let src = fs::read_to_string("test_resources/struct_basic.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/csharp/struct_basic_options.cs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let options = GeneratorOptions {
+            indent: Some(2),
+            header: Some(String::from("// Copyright (c) Tech Paws")),
+            access_level: Some(String::from("internal")),
+            imports: vec![String::from("UnityEngine")],
+            module: Some(String::from("Game.Buffers")),
+            ..GeneratorOptions::default()
+        };
+        let actual = generate_with_options(&ast, &options);
+        assert_eq!(actual.trim(), target.trim());
+    }
+}
@@ -7,6 +7,7 @@ use super::ir::{
     NamedConstructorIR, ShortFuncIR, VarDeclarationIR,
 };
 
+#[allow(clippy::single_match)]
 pub fn generate_models(ast: &[ASTNode]) -> Vec<DartIR> {
     let mut ir = vec![];
 
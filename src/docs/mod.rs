@@ -0,0 +1,547 @@
+//! Documentation pages of schemas, one page per namespace and an index.
+//!
+//! Types are linked across all pages by name, so the schemas of a config
+//! are documented together.
+
+use std::{collections::HashMap, path::Path};
+
+use clap::clap_derive::ArgEnum;
+
+use crate::{
+    ast::{
+        self, ASTNode, ConstBlockASTNode, ConstItemASTNode, ConstValueASTNode, EnumASTNode,
+        EnumItemASTNode, FnASTNode, StructASTNode, StructFieldASTNode, TypeIDASTNode,
+    },
+    codec,
+    lexer::Literal,
+};
+
+use self::page::Page;
+
+mod page;
+
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+pub enum DocsFormat {
+    Markdown,
+    Html,
+}
+
+impl DocsFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DocsFormat::Markdown => "md",
+            DocsFormat::Html => "html",
+        }
+    }
+}
+
+/// A parsed schema and the path it was read from.
+pub struct DocsSchema {
+    pub source: String,
+    pub ast: Vec<ASTNode>,
+}
+
+#[derive(Debug)]
+pub struct DocsFile {
+    pub name: String,
+    pub content: String,
+}
+
+/// Renders the pages of all namespaces and the `index` page. Schemas with
+/// the same namespace share a page, schemas without one are named after
+/// the file.
+pub fn generate_docs(schemas: &[DocsSchema], format: DocsFormat) -> Vec<DocsFile> {
+    let mut namespaces: Vec<(String, Vec<&DocsSchema>)> = vec![];
+
+    for schema in schemas {
+        let namespace = namespace(schema);
+
+        match namespaces.iter_mut().find(|(id, _)| id == &namespace) {
+            Some((_, schemas)) => schemas.push(schema),
+            None => namespaces.push((namespace, vec![schema])),
+        }
+    }
+
+    let mut types = HashMap::new();
+
+    for (namespace, schemas) in &namespaces {
+        for schema in schemas {
+            for node in &schema.ast {
+                match node {
+                    ASTNode::Struct(StructASTNode { id, .. })
+                    | ASTNode::Enum(EnumASTNode { id, .. }) => {
+                        types.entry(id.clone()).or_insert_with(|| namespace.clone());
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    let mut files = vec![DocsFile {
+        name: format!("index.{}", format.extension()),
+        content: generate_index(&namespaces, format),
+    }];
+
+    for (namespace, schemas) in &namespaces {
+        let generator = PageGenerator {
+            page: Page::new(format, namespace),
+            format,
+            namespace,
+            types: &types,
+        };
+
+        files.push(DocsFile {
+            name: format!("{}.{}", namespace, format.extension()),
+            content: generator.generate(schemas),
+        });
+    }
+
+    files
+}
+
+fn namespace(schema: &DocsSchema) -> String {
+    match ast::find_directive_value(&schema.ast, "namespace") {
+        Some(ConstValueASTNode::Literal {
+            literal: Literal::StringLiteral(namespace),
+            ..
+        }) => namespace,
+        _ => Path::new(&schema.source).file_stem().map_or_else(
+            || schema.source.clone(),
+            |stem| stem.to_string_lossy().to_string(),
+        ),
+    }
+}
+
+fn generate_index(namespaces: &[(String, Vec<&DocsSchema>)], format: DocsFormat) -> String {
+    let mut page = Page::new(format, "Schemas");
+    page.heading(1, "Schemas", None);
+
+    let items = namespaces
+        .iter()
+        .map(|(namespace, schemas)| {
+            let href = format!("{}.{}", namespace, format.extension());
+            let link = page.link(&page.text(namespace), &href);
+            let sources = schemas
+                .iter()
+                .map(|schema| page.code(&schema.source))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            format!("{}: {}", link, sources)
+        })
+        .collect::<Vec<String>>();
+
+    page.list(&items);
+    page.finish()
+}
+
+struct PageGenerator<'a> {
+    page: Page,
+    format: DocsFormat,
+    namespace: &'a str,
+    /// Namespace of every type, to link to.
+    types: &'a HashMap<String, String>,
+}
+
+impl PageGenerator<'_> {
+    fn generate(mut self, schemas: &[&DocsSchema]) -> String {
+        let index = format!("index.{}", self.format.extension());
+        let index_link = self.page.link("Index", &index);
+        self.page.paragraph(&index_link);
+        self.page.heading(1, &self.page.text(self.namespace), None);
+
+        for schema in schemas {
+            for node in &schema.ast {
+                if let ASTNode::DocComments { comments } = node {
+                    self.page.doc_comments(comments);
+                }
+            }
+        }
+
+        let sources = schemas
+            .iter()
+            .map(|schema| self.page.code(&schema.source))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        self.page.paragraph(&format!("Schemas: {}", sources));
+
+        let nodes = schemas
+            .iter()
+            .flat_map(|schema| schema.ast.iter())
+            .collect::<Vec<&ASTNode>>();
+
+        if nodes
+            .iter()
+            .any(|node| matches!(node, ASTNode::Struct(_) | ASTNode::Enum(_)))
+        {
+            self.page.heading(2, "Types", None);
+
+            for node in &nodes {
+                match node {
+                    ASTNode::Struct(node) => self.generate_struct(node),
+                    ASTNode::Enum(node) => self.generate_enum(node),
+                    _ => (),
+                }
+            }
+        }
+
+        if nodes.iter().any(|node| matches!(node, ASTNode::Const(_))) {
+            self.page.heading(2, "Constants", None);
+
+            for node in &nodes {
+                if let ASTNode::Const(node) = node {
+                    self.generate_const_block(node);
+                }
+            }
+        }
+
+        if schemas
+            .iter()
+            .any(|schema| ast::contains_fn_nodes(&schema.ast))
+        {
+            self.page.heading(2, "RPC", None);
+
+            for schema in schemas {
+                self.generate_rpc(schema);
+            }
+        }
+
+        self.page.finish()
+    }
+
+    fn generate_struct(&mut self, node: &StructASTNode) {
+        self.page
+            .heading(3, &self.page.text(&node.id), Some(&node.id));
+        self.page.doc_comments(&node.doc_comments);
+        self.page
+            .paragraph("Encoded as its fields in position order, without a header.");
+
+        if !node.fields.is_empty() {
+            let rows = self.generate_field_rows(&node.fields);
+            self.page.table(
+                &["Position", "Field", "Type", "Encoding", "Description"],
+                &rows,
+            );
+        }
+    }
+
+    fn generate_field_rows(&self, fields: &[StructFieldASTNode]) -> Vec<Vec<String>> {
+        fields
+            .iter()
+            .map(|field| {
+                vec![
+                    field.position.to_string(),
+                    self.page.code(&field.name),
+                    self.generate_type(&field.type_id),
+                    self.generate_encoding(&field.type_id),
+                    self.page.summary(&field.doc_comments),
+                ]
+            })
+            .collect()
+    }
+
+    fn generate_enum(&mut self, node: &EnumASTNode) {
+        self.page
+            .heading(3, &self.page.text(&node.id), Some(&node.id));
+        self.page.doc_comments(&node.doc_comments);
+        self.page.paragraph(&format!(
+            "Encoded as the position of the variant as a little-endian {}, followed by its \
+             fields or values in position order.",
+            self.page.code("u32")
+        ));
+
+        let rows = node
+            .items
+            .iter()
+            .map(|item| {
+                let fields = match item {
+                    EnumItemASTNode::Empty { .. } => String::new(),
+                    EnumItemASTNode::Tuple { values, .. } => values
+                        .iter()
+                        .map(|value| self.generate_type(&value.type_id))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    EnumItemASTNode::Struct { fields, .. } => fields
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                "{}: {}",
+                                self.page.code(&field.name),
+                                self.generate_type(&field.type_id)
+                            )
+                        })
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                };
+
+                vec![
+                    item.position().to_string(),
+                    self.page.code(item.id()),
+                    fields,
+                    self.page.summary(item.doc_comment()),
+                ]
+            })
+            .collect::<Vec<Vec<String>>>();
+
+        self.page
+            .table(&["Position", "Variant", "Fields", "Description"], &rows);
+    }
+
+    fn generate_const_block(&mut self, node: &ConstBlockASTNode) {
+        let mut rows = vec![];
+        self.generate_const_rows(&node.id, node, &mut rows);
+        self.page.heading(3, &self.page.text(&node.id), None);
+        self.page.table(&["Name", "Type", "Value"], &rows);
+    }
+
+    /// Nested blocks are flattened into paths like `addr::groups::MAIN`.
+    fn generate_const_rows(
+        &self,
+        path: &str,
+        node: &ConstBlockASTNode,
+        rows: &mut Vec<Vec<String>>,
+    ) {
+        for item in &node.items {
+            match item {
+                ConstItemASTNode::Value { id, type_id, value } => rows.push(vec![
+                    self.page.code(&format!("{}::{}", path, id)),
+                    self.generate_type(type_id),
+                    self.page.code(&generate_const_value(value)),
+                ]),
+                ConstItemASTNode::ConstsBlock { node } => {
+                    self.generate_const_rows(&format!("{}::{}", path, node.id), node, rows);
+                }
+            }
+        }
+    }
+
+    fn generate_rpc(&mut self, schema: &DocsSchema) {
+        let fn_nodes = ast::find_fn_nodes(&schema.ast);
+
+        if fn_nodes.is_empty() {
+            return;
+        }
+
+        let scope = match ast::find_directive_value(&schema.ast, "id") {
+            Some(ConstValueASTNode::Literal {
+                literal: Literal::StringLiteral(id),
+                ..
+            }) => format!("scope {}", self.page.code(&id)),
+            _ => String::from("no scope id"),
+        };
+
+        self.page.paragraph(&format!(
+            "Methods of {}, {}. Arguments are encoded as a struct with a field per \
+             argument, results and signal values as their type.",
+            self.page.code(&schema.source),
+            scope
+        ));
+
+        let rows = fn_nodes
+            .iter()
+            .map(|node| self.generate_fn_row(node))
+            .collect::<Vec<Vec<String>>>();
+
+        self.page.table(
+            &[
+                "Address",
+                "Method",
+                "Kind",
+                "Arguments",
+                "Returns",
+                "Description",
+            ],
+            &rows,
+        );
+    }
+
+    fn generate_fn_row(&self, node: &FnASTNode) -> Vec<String> {
+        let kind = if node.is_signal {
+            "signal"
+        } else if node.is_async {
+            "async fn"
+        } else {
+            "fn"
+        };
+
+        let args = node
+            .args
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{}: {}",
+                    self.page.code(&arg.id),
+                    self.generate_type(&arg.type_id)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let return_type_id = node
+            .return_type_id
+            .as_ref()
+            .map(|type_id| self.generate_type(type_id))
+            .unwrap_or_default();
+
+        vec![
+            self.page
+                .code(&format!("RpcMethodAddress({})", node.position)),
+            self.page.code(&node.id),
+            kind.to_string(),
+            args,
+            return_type_id,
+            self.page.summary(&node.doc_comments),
+        ]
+    }
+
+    /// The type with links to the documented types in it.
+    fn generate_type(&self, type_id: &TypeIDASTNode) -> String {
+        self.page.type_ref(&self.generate_type_parts(type_id))
+    }
+
+    fn generate_type_parts(&self, type_id: &TypeIDASTNode) -> String {
+        match type_id {
+            TypeIDASTNode::Generic { id, generics } => {
+                let generics = generics
+                    .iter()
+                    .map(|type_id| self.generate_type_parts(type_id))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                format!(
+                    "{}{}{}{}",
+                    self.page.text(id),
+                    self.page.text("<"),
+                    generics,
+                    self.page.text(">")
+                )
+            }
+            TypeIDASTNode::Other { id } => match self.types.get(id) {
+                Some(namespace) if namespace == self.namespace => {
+                    self.page.link(&self.page.text(id), &format!("#{}", id))
+                }
+                Some(namespace) => self.page.link(
+                    &self.page.text(id),
+                    &format!("{}.{}#{}", namespace, self.format.extension(), id),
+                ),
+                None => self.page.text(id),
+            },
+            TypeIDASTNode::Integer { id, .. }
+            | TypeIDASTNode::Number { id, .. }
+            | TypeIDASTNode::Bool { id }
+            | TypeIDASTNode::Char { id } => self.page.text(id),
+        }
+    }
+
+    fn generate_encoding(&self, type_id: &TypeIDASTNode) -> String {
+        let size = codec::primitive_size(type_id);
+        let bytes = |size: usize| {
+            if size == 1 {
+                String::from("1 byte")
+            } else {
+                format!("{} bytes, little-endian", size)
+            }
+        };
+
+        match (type_id, size) {
+            (TypeIDASTNode::Integer { signed: true, .. }, Some(size)) => {
+                format!("{}, two's complement", bytes(size))
+            }
+            (TypeIDASTNode::Number { .. }, Some(size)) => format!("{}, IEEE 754", bytes(size)),
+            (TypeIDASTNode::Bool { .. }, _) => String::from("1 byte, 0 or 1"),
+            (TypeIDASTNode::Char { .. }, Some(size)) => {
+                format!("{}, Unicode scalar value", bytes(size))
+            }
+            (_, Some(size)) => bytes(size),
+            (TypeIDASTNode::Other { id }, None) if id == "String" => {
+                format!("{} byte length, then UTF-8 bytes", self.page.code("u64"))
+            }
+            (TypeIDASTNode::Generic { id, generics }, None)
+                if id == "Vec" && generics.len() == 1 =>
+            {
+                format!("{} item count, then the items", self.page.code("u64"))
+            }
+            (TypeIDASTNode::Generic { id, generics }, None)
+                if id == "Option" && generics.len() == 1 =>
+            {
+                String::from("1 byte tag, 0 or 1 followed by the value")
+            }
+            (TypeIDASTNode::Other { id }, None) if self.types.contains_key(id) => {
+                String::from("see the type")
+            }
+            _ => String::new(),
+        }
+    }
+}
+
+/// Literals are folded by the lexer, `0x0002_0001` is `131073`.
+fn generate_const_value(node: &ConstValueASTNode) -> String {
+    match node {
+        ConstValueASTNode::Literal { literal, .. } => match literal {
+            Literal::StringLiteral(value) => format!("{:?}", value),
+            Literal::IntLiteral(value) => value.to_string(),
+            Literal::NumberLiteral(value) => format!("{:?}", value),
+            Literal::BoolLiteral(value) => value.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    fn read_schemas(names: &[&str]) -> Vec<DocsSchema> {
+        names
+            .iter()
+            .map(|name| {
+                let source = format!("{}.tpb", name);
+                let src = fs::read_to_string(format!("test_resources/{}", source)).unwrap();
+                let mut lexer = Lexer::tokenize(&src);
+
+                DocsSchema {
+                    source,
+                    ast: parse(&mut lexer),
+                }
+            })
+            .collect()
+    }
+
+    fn assert_files(files: &[DocsFile]) {
+        for file in files {
+            let target = fs::read_to_string(format!("test_resources/docs/{}", file.name)).unwrap();
+            println!("{}", file.content);
+            assert_eq!(file.content.trim(), target.trim(), "{}", file.name);
+        }
+    }
+
+    #[test]
+    fn generate_markdown_docs_test() {
+        let schemas = read_schemas(&["doc_comments", "consts", "struct_nested"]);
+        let files = generate_docs(&schemas, DocsFormat::Markdown);
+        let names = files
+            .iter()
+            .map(|file| file.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            names,
+            vec!["index.md", "test.md", "consts.md", "struct_nested.md"]
+        );
+        assert_files(&files);
+    }
+
+    #[test]
+    fn generate_html_docs_test() {
+        let schemas = read_schemas(&["doc_comments"]);
+        let files = generate_docs(&schemas, DocsFormat::Html);
+        let names = files
+            .iter()
+            .map(|file| file.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["index.html", "test.html"]);
+        assert_files(&files[1..]);
+    }
+}
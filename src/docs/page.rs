@@ -0,0 +1,258 @@
+use crate::writer::Writer;
+
+use super::DocsFormat;
+
+const STYLE: &str = "body { font-family: sans-serif; max-width: 960px; margin: 2em auto; \
+                     padding: 0 1em; line-height: 1.5; }\n\
+                     table { border-collapse: collapse; margin: 1em 0; }\n\
+                     th, td { border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: left; \
+                     vertical-align: top; }\n\
+                     code { background: #f4f4f4; padding: 0 0.2em; }";
+
+/// Blocks of a page, and inline text, links and code formatted as Markdown
+/// or HTML. Inline methods return strings to compose table cells from.
+pub struct Page {
+    format: DocsFormat,
+    writer: Writer,
+}
+
+impl Page {
+    pub fn new(format: DocsFormat, title: &str) -> Self {
+        let mut page = Page {
+            format,
+            writer: Writer::new(2),
+        };
+
+        if let DocsFormat::Html = format {
+            page.writer.writeln("<!DOCTYPE html>");
+            page.writer.writeln("<html>");
+            page.writer.writeln("<head>");
+            page.writer.push_tab();
+            page.writer.writeln("<meta charset=\"utf-8\">");
+            page.writer
+                .writeln(&format!("<title>{}</title>", escape_html(title)));
+            page.writer.writeln("<style>");
+            page.writer.write(STYLE);
+            page.writer.new_line();
+            page.writer.writeln("</style>");
+            page.writer.pop_tab();
+            page.writer.writeln("</head>");
+            page.writer.writeln("<body>");
+        }
+
+        page
+    }
+
+    pub fn finish(mut self) -> String {
+        if let DocsFormat::Html = self.format {
+            self.writer.writeln("</body>");
+            self.writer.writeln("</html>");
+        }
+
+        self.writer.show().to_string()
+    }
+
+    pub fn heading(&mut self, level: usize, text: &str, anchor: Option<&str>) {
+        match self.format {
+            DocsFormat::Markdown => {
+                if let Some(anchor) = anchor {
+                    self.writer.writeln(&format!("<a id=\"{}\"></a>", anchor));
+                    self.writer.new_line();
+                }
+
+                self.writer
+                    .writeln(&format!("{} {}", "#".repeat(level), text));
+                self.writer.new_line();
+            }
+            DocsFormat::Html => match anchor {
+                Some(anchor) => self
+                    .writer
+                    .writeln(&format!("<h{level} id=\"{}\">{}</h{level}>", anchor, text)),
+                None => self
+                    .writer
+                    .writeln(&format!("<h{level}>{}</h{level}>", text)),
+            },
+        }
+    }
+
+    pub fn paragraph(&mut self, text: &str) {
+        match self.format {
+            DocsFormat::Markdown => {
+                self.writer.writeln(text);
+                self.writer.new_line();
+            }
+            DocsFormat::Html => self.writer.writeln(&format!("<p>{}</p>", text)),
+        }
+    }
+
+    /// Doc comments are Markdown already, their headings are demoted below
+    /// the headings of the page. HTML gets a paragraph per block of lines.
+    pub fn doc_comments(&mut self, doc_comments: &[String]) {
+        if doc_comments.is_empty() {
+            return;
+        }
+
+        let lines = doc_comments
+            .iter()
+            .map(|comment| comment.strip_prefix(' ').unwrap_or(comment))
+            .collect::<Vec<&str>>();
+
+        match self.format {
+            DocsFormat::Markdown => {
+                for line in lines {
+                    if line.starts_with('#') {
+                        self.writer.writeln(&format!("###{}", line));
+                    } else {
+                        self.writer.writeln(line);
+                    }
+                }
+
+                self.writer.new_line();
+            }
+            DocsFormat::Html => {
+                for block in lines.split(|line| line.trim().is_empty()) {
+                    if block.is_empty() {
+                        continue;
+                    }
+
+                    let text = block
+                        .iter()
+                        .map(|line| escape_html(line.trim_start_matches('#').trim()))
+                        .collect::<Vec<String>>()
+                        .join(" ");
+
+                    self.paragraph(&text);
+                }
+            }
+        }
+    }
+
+    pub fn table(&mut self, headers: &[&str], rows: &[Vec<String>]) {
+        match self.format {
+            DocsFormat::Markdown => {
+                self.writer.writeln(&format!("| {} |", headers.join(" | ")));
+                self.writer.writeln(&format!(
+                    "|{}",
+                    headers.iter().map(|_| " --- |").collect::<String>()
+                ));
+
+                for row in rows {
+                    self.writer.writeln(&format!("| {} |", row.join(" | ")));
+                }
+
+                self.writer.new_line();
+            }
+            DocsFormat::Html => {
+                self.writer.writeln("<table>");
+                self.writer.push_tab();
+                self.writer.writeln(&format!(
+                    "<tr>{}</tr>",
+                    headers
+                        .iter()
+                        .map(|header| format!("<th>{}</th>", header))
+                        .collect::<String>()
+                ));
+
+                for row in rows {
+                    self.writer.writeln(&format!(
+                        "<tr>{}</tr>",
+                        row.iter()
+                            .map(|cell| format!("<td>{}</td>", cell))
+                            .collect::<String>()
+                    ));
+                }
+
+                self.writer.pop_tab();
+                self.writer.writeln("</table>");
+            }
+        }
+    }
+
+    pub fn list(&mut self, items: &[String]) {
+        match self.format {
+            DocsFormat::Markdown => {
+                for item in items {
+                    self.writer.writeln(&format!("- {}", item));
+                }
+
+                self.writer.new_line();
+            }
+            DocsFormat::Html => {
+                self.writer.writeln("<ul>");
+                self.writer.push_tab();
+
+                for item in items {
+                    self.writer.writeln(&format!("<li>{}</li>", item));
+                }
+
+                self.writer.pop_tab();
+                self.writer.writeln("</ul>");
+            }
+        }
+    }
+
+    /// First paragraph of doc comments on one line, for table cells.
+    pub fn summary(&self, doc_comments: &[String]) -> String {
+        let summary = doc_comments
+            .iter()
+            .map(|comment| comment.trim())
+            .take_while(|comment| !comment.is_empty())
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        match self.format {
+            DocsFormat::Markdown => summary.replace('|', "\\|"),
+            DocsFormat::Html => escape_html(&summary),
+        }
+    }
+
+    pub fn text(&self, text: &str) -> String {
+        match self.format {
+            DocsFormat::Markdown => {
+                let mut res = String::with_capacity(text.len());
+
+                for c in text.chars() {
+                    if matches!(c, '\\' | '<' | '>' | '|' | '*' | '[' | ']' | '`') {
+                        res.push('\\');
+                    }
+
+                    res.push(c);
+                }
+
+                res
+            }
+            DocsFormat::Html => escape_html(text),
+        }
+    }
+
+    pub fn code(&self, code: &str) -> String {
+        match self.format {
+            DocsFormat::Markdown => format!("`{}`", code.replace('|', "\\|")),
+            DocsFormat::Html => format!("<code>{}</code>", escape_html(code)),
+        }
+    }
+
+    /// `text` is formatted already.
+    pub fn link(&self, text: &str, href: &str) -> String {
+        match self.format {
+            DocsFormat::Markdown => format!("[{}]({})", text, href),
+            DocsFormat::Html => format!("<a href=\"{}\">{}</a>", escape_html(href), text),
+        }
+    }
+
+    /// A type with links to its parts, Markdown can't have links in code
+    /// spans, so only HTML wraps it in `<code>`.
+    pub fn type_ref(&self, text: &str) -> String {
+        match self.format {
+            DocsFormat::Markdown => text.to_string(),
+            DocsFormat::Html => format!("<code>{}</code>", text),
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,98 @@
+pub mod printer;
+pub mod syntax;
+
+use std::fmt;
+
+use crate::parser::{self, ParseError};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatOptions {
+    /// Pads `#[n]` positions of fields and enum items so the names
+    /// after them start at the same column.
+    pub align_positions: bool,
+}
+
+#[derive(Debug)]
+pub enum FormatError {
+    Parse(ParseError),
+    /// The formatted schema parses to a different AST, this is a bug of the
+    /// formatter.
+    ChangedAst,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Parse(err) => write!(f, "{}", err),
+            FormatError::ChangedAst => write!(f, "formatting changed the meaning of the schema"),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+/// Formats a schema, keeping comments.
+pub fn format_source(src: &str, options: &FormatOptions) -> Result<String, FormatError> {
+    let ast = parser::parse_source(src).map_err(FormatError::Parse)?;
+    let file = syntax::parse(src).map_err(FormatError::Parse)?;
+
+    let mut printer = printer::Printer::new(options);
+    printer.file(&file);
+    let formatted = printer.finish();
+
+    let formatted_ast = parser::parse_source(&formatted).map_err(|_| FormatError::ChangedAst)?;
+
+    if serde_json::to_value(&ast).unwrap() != serde_json::to_value(&formatted_ast).unwrap() {
+        return Err(FormatError::ChangedAst);
+    }
+
+    Ok(formatted)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn format_comments_test() {
+        let src = fs::read_to_string("test_resources/fmt/comments.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/fmt/comments.formatted.tpb").unwrap();
+        let actual = format_source(&src, &FormatOptions::default()).unwrap();
+        assert_eq!(actual, target);
+    }
+
+    #[test]
+    fn format_align_positions_test() {
+        let src = fs::read_to_string("test_resources/fmt/comments.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/fmt/comments.aligned.tpb").unwrap();
+        let options = FormatOptions {
+            align_positions: true,
+        };
+        let actual = format_source(&src, &options).unwrap();
+        assert_eq!(actual, target);
+    }
+
+    #[test]
+    fn format_is_idempotent_test() {
+        for entry in fs::read_dir("test_resources").unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.extension().is_none_or(|ext| ext != "tpb") || path.ends_with("consts_new.tpb") {
+                continue;
+            }
+
+            let src = fs::read_to_string(&path).unwrap();
+            let formatted = format_source(&src, &FormatOptions::default()).unwrap();
+            let formatted_twice = format_source(&formatted, &FormatOptions::default()).unwrap();
+            assert_eq!(formatted, formatted_twice, "{}", path.display());
+        }
+    }
+
+    #[test]
+    fn format_parse_error_test() {
+        let result = format_source("struct A {", &FormatOptions::default());
+        assert!(matches!(result, Err(FormatError::Parse(_))));
+    }
+}
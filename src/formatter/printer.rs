@@ -0,0 +1,606 @@
+use super::{
+    syntax::{
+        CommentKind, ConstBlock, ConstItem, Directive, DirectiveBody, Enum, EnumItem, EnumItemBody,
+        Field, Fields, Fn, Item, Struct, StructBody, SyntaxFile, SyntaxKind, SyntaxToken, Tokens,
+        Type,
+    },
+    FormatOptions,
+};
+
+const INDENT: &str = "    ";
+
+pub struct Printer<'a> {
+    options: &'a FormatOptions,
+    out: String,
+    indent: usize,
+    /// Nothing is written on the current line yet.
+    line_start: bool,
+    /// Comment written at the end of the current line.
+    trailing: Option<String>,
+}
+
+impl<'a> Printer<'a> {
+    pub fn new(options: &'a FormatOptions) -> Self {
+        Printer {
+            options,
+            out: String::new(),
+            indent: 0,
+            line_start: true,
+            trailing: None,
+        }
+    }
+
+    pub fn finish(mut self) -> String {
+        self.newline();
+
+        while self.out.ends_with("\n\n") {
+            self.out.pop();
+        }
+
+        if self.out.trim().is_empty() {
+            String::new()
+        } else {
+            self.out
+        }
+    }
+
+    fn write(&mut self, text: &str) {
+        // A comma or semicolon stays before the comment ending the line.
+        if matches!(text, "," | ";") && self.trailing.is_some() {
+            self.out += text;
+            return;
+        }
+
+        if let Some(comment) = self.trailing.take() {
+            self.out += " ";
+            self.out += &comment;
+
+            if comment.starts_with("//") {
+                self.newline();
+            } else {
+                self.out += " ";
+            }
+        }
+
+        if self.line_start {
+            self.out += &INDENT.repeat(self.indent);
+            self.line_start = false;
+        }
+
+        self.out += text;
+    }
+
+    fn space(&mut self) {
+        if !self.line_start && self.trailing.is_none() {
+            self.out += " ";
+        }
+    }
+
+    fn newline(&mut self) {
+        if let Some(comment) = self.trailing.take() {
+            self.out += " ";
+            self.out += &comment;
+        }
+
+        if !self.line_start {
+            self.out += "\n";
+            self.line_start = true;
+        }
+    }
+
+    fn blank_line(&mut self) {
+        self.newline();
+
+        let block_start = self.out.is_empty()
+            || self.out.ends_with("{\n")
+            || self.out.ends_with("(\n")
+            || self.out.ends_with("\n\n");
+
+        if !block_start {
+            self.out += "\n";
+        }
+    }
+
+    fn token(&mut self, token: &SyntaxToken) {
+        let is_separator = matches!(token.text.as_str(), "," | ";");
+
+        // `B /* c */,` is written as `B, /* c */`, unless another comment
+        // follows, which would make `/* c */` lead the next token.
+        if let (true, [comment], None) = (is_separator, token.leading.as_slice(), &token.trailing) {
+            if !comment.own_line {
+                self.write(&token.text);
+                self.push_trailing(&comment.text);
+                return;
+            }
+        }
+
+        for comment in &token.leading {
+            if comment.own_line {
+                if comment.blank_line_before {
+                    self.blank_line();
+                } else {
+                    self.newline();
+                }
+
+                self.write(&comment.text);
+
+                if comment.kind == CommentKind::Line || comment.newline_after {
+                    self.newline();
+                } else {
+                    self.out += " ";
+                }
+            } else {
+                let after_open = self.out.ends_with(['(', '<', '[', ' ']);
+
+                if !self.line_start && self.trailing.is_none() && !after_open {
+                    self.out += " ";
+                }
+
+                self.write(&comment.text);
+
+                if comment.kind == CommentKind::Line {
+                    self.newline();
+                } else {
+                    self.out += " ";
+                }
+            }
+        }
+
+        let is_close = token.kind == SyntaxKind::Symbol && matches!(token.text.as_str(), "}" | ")");
+
+        if self.line_start && token.blank_line_before && !is_close {
+            self.blank_line();
+        }
+
+        self.write(&token.text);
+
+        if let Some(comment) = &token.trailing {
+            self.push_trailing(&comment.text);
+        }
+    }
+
+    /// Adds a comment to the end of the current line.
+    fn push_trailing(&mut self, comment: &str) {
+        self.trailing = match self.trailing.take() {
+            Some(previous) => Some(format!("{} {}", previous, comment)),
+            None => Some(comment.to_string()),
+        };
+    }
+
+    /// Writes `token` or `text` when the source doesn't have the token,
+    /// e.g. a trailing comma.
+    fn optional_token(&mut self, token: &Option<SyntaxToken>, text: &str) {
+        match token {
+            Some(token) => self.token(token),
+            None => self.write(text),
+        }
+    }
+
+    /// Starts a line of a block, keeping a blank line from the source.
+    fn line(&mut self, first: &SyntaxToken) {
+        let blank_line_before = match first.leading.first() {
+            Some(comment) => comment.blank_line_before,
+            None => first.blank_line_before,
+        };
+
+        if blank_line_before {
+            self.blank_line();
+        } else {
+            self.newline();
+        }
+    }
+
+    pub fn file(&mut self, file: &SyntaxFile) {
+        let mut after_block = false;
+
+        for item in &file.items {
+            if after_block {
+                self.blank_line();
+            }
+
+            after_block = self.item(item);
+        }
+
+        self.line(&file.eof);
+        self.token(&file.eof);
+    }
+
+    /// Returns true for items ending with a block.
+    fn item(&mut self, item: &Item) -> bool {
+        match item {
+            Item::DocComment(token) => {
+                self.line(token);
+                self.token(token);
+                false
+            }
+            Item::Directive(directive) => {
+                self.line(&directive.hash);
+                self.directive(directive);
+                false
+            }
+            Item::Struct(node) => self.struct_node(node),
+            Item::Enum(node) => {
+                self.enum_node(node);
+                true
+            }
+            Item::Fn(node) => {
+                self.fn_node(node);
+                false
+            }
+            Item::Const(node) => {
+                self.line(&node.keyword);
+                self.const_block(node);
+                true
+            }
+        }
+    }
+
+    fn directive(&mut self, directive: &Directive) {
+        self.token(&directive.hash);
+        self.token(&directive.open);
+
+        match &directive.body {
+            DirectiveBody::Position(value) => self.token(value),
+            DirectiveBody::Value { id, eq, value } => {
+                self.token(id);
+                self.space();
+                self.token(eq);
+                self.space();
+                self.token(value);
+            }
+            DirectiveBody::Group {
+                id,
+                open,
+                args,
+                close,
+            } => {
+                self.token(id);
+                self.token(open);
+
+                for (i, arg) in args.iter().enumerate() {
+                    self.token(&arg.id);
+
+                    if let Some((eq, value)) = &arg.value {
+                        self.space();
+                        self.token(eq);
+                        self.space();
+                        self.token(value);
+                    }
+
+                    if i + 1 < args.len() {
+                        self.optional_token(&arg.comma, ",");
+                        self.space();
+                    } else if let Some(comma) = &arg.comma {
+                        self.comments_only(comma);
+                    }
+                }
+
+                self.token(close);
+            }
+        }
+
+        self.token(&directive.close);
+    }
+
+    /// Keeps comments of a token that is dropped, e.g. a trailing comma of
+    /// a single line list.
+    fn comments_only(&mut self, token: &SyntaxToken) {
+        let mut token = token.clone();
+        token.text = String::new();
+        token.blank_line_before = false;
+        self.token(&token);
+    }
+
+    fn type_id(&mut self, type_id: &Type) {
+        self.token(&type_id.id);
+
+        if let Some(generics) = &type_id.generics {
+            self.token(&generics.open);
+
+            for (i, (type_id, comma)) in generics.types.iter().enumerate() {
+                self.type_id(type_id);
+
+                if i + 1 < generics.types.len() {
+                    self.optional_token(comma, ",");
+                    self.space();
+                } else if let Some(comma) = comma {
+                    self.comments_only(comma);
+                }
+            }
+
+            self.token(&generics.close);
+        }
+    }
+
+    fn position_width(&self, positions: Vec<&Option<Directive>>) -> usize {
+        if !self.options.align_positions {
+            return 0;
+        }
+
+        positions
+            .into_iter()
+            .flatten()
+            .map(|position| match &position.body {
+                DirectiveBody::Position(value) => value.text.len() + 3,
+                _ => 0,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn position(&mut self, position: &Option<Directive>, width: usize) {
+        if let Some(position) = position {
+            self.directive(position);
+
+            let len = match &position.body {
+                DirectiveBody::Position(value) => value.text.len() + 3,
+                _ => 0,
+            };
+
+            self.write(&" ".repeat(width.saturating_sub(len) + 1));
+        }
+    }
+
+    fn doc_comments(&mut self, doc_comments: &[SyntaxToken]) {
+        for token in doc_comments {
+            self.token(token);
+            self.newline();
+        }
+    }
+
+    fn struct_node(&mut self, node: &Struct) -> bool {
+        self.line(&node.keyword);
+        self.token(&node.keyword);
+        self.space();
+        self.token(&node.id);
+
+        match &node.body {
+            StructBody::Unit(semicolon) => {
+                self.token(semicolon);
+                false
+            }
+            StructBody::Fields(fields) => {
+                self.space();
+                self.fields_block(fields);
+                true
+            }
+        }
+    }
+
+    fn fields_block(&mut self, fields: &Fields) {
+        let width = self.position_width(fields.fields.iter().map(|it| &it.position).collect());
+
+        self.token(&fields.open);
+        self.indent += 1;
+
+        for field in &fields.fields {
+            self.line(first_token(field));
+            self.doc_comments(&field.doc_comments);
+            self.position(&field.position, width);
+            self.field(field);
+            self.optional_token(&field.comma, ",");
+        }
+
+        self.indent -= 1;
+        self.newline();
+        self.token(&fields.close);
+    }
+
+    fn field(&mut self, field: &Field) {
+        self.token(&field.name);
+        self.token(&field.colon);
+        self.space();
+        self.type_id(&field.type_id);
+    }
+
+    fn enum_node(&mut self, node: &Enum) {
+        let width = self.position_width(node.items.iter().map(|it| &it.position).collect());
+
+        self.line(&node.keyword);
+        self.token(&node.keyword);
+        self.space();
+        self.token(&node.id);
+        self.space();
+        self.token(&node.open);
+        self.indent += 1;
+
+        for item in &node.items {
+            self.line(first_token(item));
+            self.doc_comments(&item.doc_comments);
+            self.position(&item.position, width);
+            self.enum_item(item);
+            self.optional_token(&item.comma, ",");
+        }
+
+        self.indent -= 1;
+        self.newline();
+        self.token(&node.close);
+    }
+
+    fn enum_item(&mut self, item: &EnumItem) {
+        self.token(&item.id);
+
+        match &item.body {
+            EnumItemBody::Empty => (),
+            EnumItemBody::Tuple {
+                open,
+                values,
+                close,
+            } => {
+                if values.has_inner_comments() {
+                    let width = self.position_width(values.iter().map(|it| &it.position).collect());
+
+                    self.token(open);
+                    self.indent += 1;
+
+                    for value in values {
+                        self.line(first_token(value));
+                        self.doc_comments(&value.doc_comments);
+                        self.position(&value.position, width);
+                        self.type_id(&value.type_id);
+                        self.optional_token(&value.comma, ",");
+                    }
+
+                    self.indent -= 1;
+                    self.newline();
+                    self.token(close);
+                } else {
+                    self.token(open);
+
+                    for (i, value) in values.iter().enumerate() {
+                        self.position(&value.position, 0);
+                        self.type_id(&value.type_id);
+
+                        if i + 1 < values.len() {
+                            self.optional_token(&value.comma, ",");
+                            self.space();
+                        } else if let Some(comma) = &value.comma {
+                            self.comments_only(comma);
+                        }
+                    }
+
+                    self.token(close);
+                }
+            }
+            EnumItemBody::Struct(fields) => {
+                self.space();
+
+                if fields.is_single_line() && !fields.has_inner_comments() {
+                    self.token(&fields.open);
+                    self.space();
+
+                    for (i, field) in fields.fields.iter().enumerate() {
+                        self.position(&field.position, 0);
+                        self.field(field);
+
+                        if i + 1 < fields.fields.len() {
+                            self.optional_token(&field.comma, ",");
+                            self.space();
+                        } else if let Some(comma) = &field.comma {
+                            self.comments_only(comma);
+                        }
+                    }
+
+                    self.space();
+                    self.token(&fields.close);
+                } else {
+                    self.fields_block(fields);
+                }
+            }
+        }
+    }
+
+    fn fn_node(&mut self, node: &Fn) {
+        self.line(first_token(node));
+
+        if let Some(async_keyword) = &node.async_keyword {
+            self.token(async_keyword);
+            self.space();
+        }
+
+        self.token(&node.keyword);
+        self.space();
+        self.token(&node.id);
+
+        if let Some(args) = &node.args {
+            let multiline = args.has_inner_comments();
+
+            self.token(&args.open);
+            self.indent += 1;
+
+            for (i, arg) in args.args.iter().enumerate() {
+                if multiline {
+                    self.line(&arg.id);
+                }
+
+                self.token(&arg.id);
+                self.token(&arg.colon);
+                self.space();
+                self.type_id(&arg.type_id);
+
+                if multiline || i + 1 < args.args.len() {
+                    self.optional_token(&arg.comma, ",");
+                } else if let Some(comma) = &arg.comma {
+                    self.comments_only(comma);
+                }
+
+                if !multiline && i + 1 < args.args.len() {
+                    self.space();
+                }
+            }
+
+            self.indent -= 1;
+
+            if multiline {
+                self.newline();
+            }
+
+            self.token(&args.close);
+        }
+
+        if let Some((minus, gt, type_id)) = &node.return_type_id {
+            self.space();
+            self.token(minus);
+            self.token(gt);
+            self.space();
+            self.type_id(type_id);
+        }
+
+        self.token(&node.semicolon);
+    }
+
+    fn const_block(&mut self, node: &ConstBlock) {
+        self.token(&node.keyword);
+        self.space();
+        self.token(&node.id);
+        self.space();
+        self.token(&node.open);
+
+        if node.items.is_empty() && !node.close.has_comments() {
+            self.token(&node.close);
+            return;
+        }
+
+        self.indent += 1;
+
+        let mut after_block = false;
+
+        for item in &node.items {
+            if after_block {
+                self.blank_line();
+            }
+
+            after_block = false;
+
+            match item {
+                ConstItem::Value(node) => {
+                    self.line(&node.id);
+                    self.token(&node.id);
+                    self.token(&node.colon);
+                    self.space();
+                    self.type_id(&node.type_id);
+                    self.space();
+                    self.token(&node.eq);
+                    self.space();
+                    self.token(&node.value);
+                    self.token(&node.semicolon);
+                }
+                ConstItem::Block(node) => {
+                    self.line(&node.keyword);
+                    self.const_block(node);
+                    after_block = true;
+                }
+            }
+        }
+
+        self.indent -= 1;
+        self.newline();
+        self.token(&node.close);
+    }
+}
+
+fn first_token<T: Tokens>(node: &T) -> &SyntaxToken {
+    let mut tokens = vec![];
+    node.tokens(&mut tokens);
+    tokens[0]
+}
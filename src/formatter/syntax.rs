@@ -0,0 +1,1020 @@
+//! Lossless syntax tree of a schema: unlike `lexer::Lexer`, every token keeps
+//! its source text and the comments around it.
+
+use crate::parser::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyntaxKind {
+    Ident,
+    String,
+    Number,
+    Symbol,
+    DocComment,
+    EOF,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CommentKind {
+    /// `// ...`
+    Line,
+    /// `/* ... */`
+    Block,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    pub kind: CommentKind,
+    pub text: String,
+    /// The comment starts a line.
+    pub own_line: bool,
+    pub blank_line_before: bool,
+    /// Nothing but whitespace follows the comment on its line.
+    pub newline_after: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxToken {
+    pub kind: SyntaxKind,
+    pub text: String,
+    pub line: usize,
+    pub pos: usize,
+    /// Comments between the previous token and this one.
+    pub leading: Vec<Comment>,
+    /// A comment after this token on the same line.
+    pub trailing: Option<Comment>,
+    /// The token starts a line.
+    pub own_line: bool,
+    pub blank_line_before: bool,
+}
+
+impl SyntaxToken {
+    pub fn is(&self, text: &str) -> bool {
+        self.kind != SyntaxKind::String && self.text == text
+    }
+
+    pub fn has_comments(&self) -> bool {
+        !self.leading.is_empty() || self.trailing.is_some()
+    }
+}
+
+#[derive(Debug)]
+pub struct SyntaxFile {
+    pub items: Vec<Item>,
+    /// Holds the comments at the end of the file.
+    pub eof: SyntaxToken,
+}
+
+#[derive(Debug)]
+pub enum Item {
+    DocComment(SyntaxToken),
+    Directive(Box<Directive>),
+    Struct(Box<Struct>),
+    Enum(Box<Enum>),
+    Fn(Box<Fn>),
+    Const(Box<ConstBlock>),
+}
+
+#[derive(Debug)]
+pub struct Directive {
+    pub hash: SyntaxToken,
+    pub open: SyntaxToken,
+    pub body: DirectiveBody,
+    pub close: SyntaxToken,
+}
+
+#[derive(Debug)]
+pub enum DirectiveBody {
+    /// `#[1]`
+    Position(SyntaxToken),
+    /// `#[id = value]`
+    Value {
+        id: SyntaxToken,
+        eq: SyntaxToken,
+        value: SyntaxToken,
+    },
+    /// `#[id(arg, arg = value)]`
+    Group {
+        id: SyntaxToken,
+        open: SyntaxToken,
+        args: Vec<GroupArg>,
+        close: SyntaxToken,
+    },
+}
+
+#[derive(Debug)]
+pub struct GroupArg {
+    pub id: SyntaxToken,
+    pub value: Option<(SyntaxToken, SyntaxToken)>,
+    pub comma: Option<SyntaxToken>,
+}
+
+#[derive(Debug)]
+pub struct Struct {
+    pub keyword: SyntaxToken,
+    pub id: SyntaxToken,
+    pub body: StructBody,
+}
+
+#[derive(Debug)]
+pub enum StructBody {
+    Unit(SyntaxToken),
+    Fields(Fields),
+}
+
+#[derive(Debug)]
+pub struct Fields {
+    pub open: SyntaxToken,
+    pub fields: Vec<Field>,
+    pub close: SyntaxToken,
+}
+
+#[derive(Debug)]
+pub struct Field {
+    pub doc_comments: Vec<SyntaxToken>,
+    pub position: Option<Directive>,
+    pub name: SyntaxToken,
+    pub colon: SyntaxToken,
+    pub type_id: Type,
+    pub comma: Option<SyntaxToken>,
+}
+
+#[derive(Debug)]
+pub struct Enum {
+    pub keyword: SyntaxToken,
+    pub id: SyntaxToken,
+    pub open: SyntaxToken,
+    pub items: Vec<EnumItem>,
+    pub close: SyntaxToken,
+}
+
+#[derive(Debug)]
+pub struct EnumItem {
+    pub doc_comments: Vec<SyntaxToken>,
+    pub position: Option<Directive>,
+    pub id: SyntaxToken,
+    pub body: EnumItemBody,
+    pub comma: Option<SyntaxToken>,
+}
+
+#[derive(Debug)]
+pub enum EnumItemBody {
+    Empty,
+    Tuple {
+        open: SyntaxToken,
+        values: Vec<TupleValue>,
+        close: SyntaxToken,
+    },
+    Struct(Fields),
+}
+
+#[derive(Debug)]
+pub struct TupleValue {
+    pub doc_comments: Vec<SyntaxToken>,
+    pub position: Option<Directive>,
+    pub type_id: Type,
+    pub comma: Option<SyntaxToken>,
+}
+
+#[derive(Debug)]
+pub struct Type {
+    pub id: SyntaxToken,
+    pub generics: Option<Generics>,
+}
+
+#[derive(Debug)]
+pub struct Generics {
+    pub open: SyntaxToken,
+    pub types: Vec<(Type, Option<SyntaxToken>)>,
+    pub close: SyntaxToken,
+}
+
+/// `fn` or `signal`.
+#[derive(Debug)]
+pub struct Fn {
+    pub async_keyword: Option<SyntaxToken>,
+    pub keyword: SyntaxToken,
+    pub id: SyntaxToken,
+    pub args: Option<FnArgs>,
+    pub return_type_id: Option<(SyntaxToken, SyntaxToken, Type)>,
+    pub semicolon: SyntaxToken,
+}
+
+#[derive(Debug)]
+pub struct FnArgs {
+    pub open: SyntaxToken,
+    pub args: Vec<FnArg>,
+    pub close: SyntaxToken,
+}
+
+#[derive(Debug)]
+pub struct FnArg {
+    pub id: SyntaxToken,
+    pub colon: SyntaxToken,
+    pub type_id: Type,
+    pub comma: Option<SyntaxToken>,
+}
+
+#[derive(Debug)]
+pub struct ConstBlock {
+    pub keyword: SyntaxToken,
+    pub id: SyntaxToken,
+    pub open: SyntaxToken,
+    pub items: Vec<ConstItem>,
+    pub close: SyntaxToken,
+}
+
+#[derive(Debug)]
+pub enum ConstItem {
+    Value(Box<ConstValue>),
+    Block(Box<ConstBlock>),
+}
+
+#[derive(Debug)]
+pub struct ConstValue {
+    pub id: SyntaxToken,
+    pub colon: SyntaxToken,
+    pub type_id: Type,
+    pub eq: SyntaxToken,
+    pub value: SyntaxToken,
+    pub semicolon: SyntaxToken,
+}
+
+/// Collects the tokens of a node in source order.
+pub trait Tokens {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>);
+
+    /// Comments or doc comments between the first and the last token.
+    fn has_inner_comments(&self) -> bool {
+        let mut tokens = vec![];
+        self.tokens(&mut tokens);
+        let last = tokens.len().saturating_sub(1);
+
+        tokens.iter().enumerate().any(|(i, token)| {
+            token.kind == SyntaxKind::DocComment
+                || (i > 0 && !token.leading.is_empty())
+                || (i < last && token.trailing.is_some())
+        })
+    }
+
+    /// The first and the last token are on the same line.
+    fn is_single_line(&self) -> bool {
+        let mut tokens = vec![];
+        self.tokens(&mut tokens);
+
+        match (tokens.first(), tokens.last()) {
+            (Some(first), Some(last)) => first.line == last.line,
+            _ => true,
+        }
+    }
+}
+
+impl Tokens for SyntaxToken {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        out.push(self);
+    }
+}
+
+impl<T: Tokens> Tokens for Option<T> {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        if let Some(node) = self {
+            node.tokens(out);
+        }
+    }
+}
+
+impl<T: Tokens> Tokens for Vec<T> {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        for node in self {
+            node.tokens(out);
+        }
+    }
+}
+
+impl Tokens for Directive {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        out.push(&self.hash);
+        out.push(&self.open);
+
+        match &self.body {
+            DirectiveBody::Position(value) => out.push(value),
+            DirectiveBody::Value { id, eq, value } => {
+                out.push(id);
+                out.push(eq);
+                out.push(value);
+            }
+            DirectiveBody::Group {
+                id,
+                open,
+                args,
+                close,
+            } => {
+                out.push(id);
+                out.push(open);
+
+                for arg in args {
+                    out.push(&arg.id);
+
+                    if let Some((eq, value)) = &arg.value {
+                        out.push(eq);
+                        out.push(value);
+                    }
+
+                    arg.comma.tokens(out);
+                }
+
+                out.push(close);
+            }
+        }
+
+        out.push(&self.close);
+    }
+}
+
+impl Tokens for Type {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        out.push(&self.id);
+
+        if let Some(generics) = &self.generics {
+            out.push(&generics.open);
+
+            for (type_id, comma) in &generics.types {
+                type_id.tokens(out);
+                comma.tokens(out);
+            }
+
+            out.push(&generics.close);
+        }
+    }
+}
+
+impl Tokens for Field {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        self.doc_comments.tokens(out);
+        self.position.tokens(out);
+        out.push(&self.name);
+        out.push(&self.colon);
+        self.type_id.tokens(out);
+        self.comma.tokens(out);
+    }
+}
+
+impl Tokens for Fields {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        out.push(&self.open);
+        self.fields.tokens(out);
+        out.push(&self.close);
+    }
+}
+
+impl Tokens for TupleValue {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        self.doc_comments.tokens(out);
+        self.position.tokens(out);
+        self.type_id.tokens(out);
+        self.comma.tokens(out);
+    }
+}
+
+impl Tokens for EnumItem {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        self.doc_comments.tokens(out);
+        self.position.tokens(out);
+        out.push(&self.id);
+        self.body.tokens(out);
+        self.comma.tokens(out);
+    }
+}
+
+impl Tokens for EnumItemBody {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        match self {
+            EnumItemBody::Empty => (),
+            EnumItemBody::Tuple {
+                open,
+                values,
+                close,
+            } => {
+                out.push(open);
+                values.tokens(out);
+                out.push(close);
+            }
+            EnumItemBody::Struct(fields) => fields.tokens(out),
+        }
+    }
+}
+
+impl Tokens for FnArgs {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        out.push(&self.open);
+
+        for arg in &self.args {
+            out.push(&arg.id);
+            out.push(&arg.colon);
+            arg.type_id.tokens(out);
+            arg.comma.tokens(out);
+        }
+
+        out.push(&self.close);
+    }
+}
+
+impl Tokens for Fn {
+    fn tokens<'a>(&'a self, out: &mut Vec<&'a SyntaxToken>) {
+        self.async_keyword.tokens(out);
+        out.push(&self.keyword);
+        out.push(&self.id);
+        self.args.tokens(out);
+
+        if let Some((minus, gt, type_id)) = &self.return_type_id {
+            out.push(minus);
+            out.push(gt);
+            type_id.tokens(out);
+        }
+
+        out.push(&self.semicolon);
+    }
+}
+
+/// Splits `src` into tokens, attaching comments to the closest token.
+pub fn tokenize(src: &str) -> Vec<SyntaxToken> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut tokens: Vec<SyntaxToken> = vec![];
+    let mut leading: Vec<Comment> = vec![];
+    let mut cursor = 0;
+    let mut line = 1;
+    let mut pos = 0;
+    // Newlines since the last token or comment.
+    let mut newlines = 0;
+    let mut at_file_start = true;
+
+    macro_rules! advance {
+        () => {{
+            if chars[cursor] == '\n' {
+                line += 1;
+                pos = 0;
+            } else {
+                pos += 1;
+            }
+
+            cursor += 1;
+        }};
+    }
+
+    while cursor < chars.len() {
+        let ch = chars[cursor];
+
+        if ch == '\n' {
+            newlines += 1;
+            advance!();
+            continue;
+        }
+
+        if ch.is_whitespace() {
+            advance!();
+            continue;
+        }
+
+        let own_line = newlines > 0 || at_file_start;
+        let blank_line_before = newlines > 1;
+        let start_line = line;
+        let start_pos = pos;
+        let start = cursor;
+
+        let next = chars.get(cursor + 1).copied();
+        let is_doc_comment = ch == '/' && next == Some('/') && chars.get(cursor + 2) == Some(&'/');
+
+        if ch == '/' && (next == Some('/') || next == Some('*')) && !is_doc_comment {
+            let kind = if next == Some('/') {
+                while cursor < chars.len() && chars[cursor] != '\n' {
+                    advance!();
+                }
+
+                CommentKind::Line
+            } else {
+                advance!();
+                advance!();
+
+                while cursor < chars.len()
+                    && !(chars[cursor] == '*' && chars.get(cursor + 1) == Some(&'/'))
+                {
+                    advance!();
+                }
+
+                if cursor < chars.len() {
+                    advance!();
+                    advance!();
+                }
+
+                CommentKind::Block
+            };
+
+            let text: String = chars[start..cursor].iter().collect();
+            let text = text.trim_end().to_string();
+
+            let rest_of_line_is_empty = chars[cursor..]
+                .iter()
+                .take_while(|ch| **ch != '\n')
+                .all(|ch| ch.is_whitespace());
+
+            let comment = Comment {
+                kind,
+                text,
+                own_line,
+                blank_line_before,
+                newline_after: rest_of_line_is_empty,
+            };
+
+            match tokens.last_mut() {
+                Some(token) if !own_line && token.trailing.is_none() && rest_of_line_is_empty => {
+                    token.trailing = Some(comment);
+                }
+                _ => leading.push(comment),
+            }
+
+            newlines = 0;
+            at_file_start = false;
+            continue;
+        }
+
+        let kind = if is_doc_comment {
+            while cursor < chars.len() && chars[cursor] != '\n' {
+                advance!();
+            }
+
+            SyntaxKind::DocComment
+        } else if ch.is_ascii_alphabetic() || ch == '_' {
+            while cursor < chars.len()
+                && (chars[cursor].is_ascii_alphanumeric() || chars[cursor] == '_')
+            {
+                advance!();
+            }
+
+            SyntaxKind::Ident
+        } else if ch.is_ascii_digit() {
+            while cursor < chars.len()
+                && (chars[cursor].is_ascii_alphanumeric()
+                    || chars[cursor] == '_'
+                    || chars[cursor] == '.')
+            {
+                advance!();
+            }
+
+            SyntaxKind::Number
+        } else if ch == '"' {
+            advance!();
+
+            while cursor < chars.len() && chars[cursor] != '"' {
+                advance!();
+            }
+
+            if cursor < chars.len() {
+                advance!();
+            }
+
+            SyntaxKind::String
+        } else {
+            advance!();
+            SyntaxKind::Symbol
+        };
+
+        let text: String = chars[start..cursor].iter().collect();
+
+        tokens.push(SyntaxToken {
+            kind,
+            text: text.trim_end().to_string(),
+            line: start_line,
+            pos: start_pos,
+            leading: std::mem::take(&mut leading),
+            trailing: None,
+            own_line,
+            blank_line_before,
+        });
+
+        newlines = 0;
+        at_file_start = false;
+    }
+
+    tokens.push(SyntaxToken {
+        kind: SyntaxKind::EOF,
+        text: String::new(),
+        line,
+        pos,
+        leading,
+        trailing: None,
+        own_line: true,
+        blank_line_before: newlines > 1,
+    });
+
+    tokens
+}
+
+struct SyntaxParser {
+    tokens: Vec<SyntaxToken>,
+    cursor: usize,
+}
+
+/// Parses the lossless syntax tree, `src` is expected to be accepted by
+/// `parser::parse_source`.
+pub fn parse(src: &str) -> Result<SyntaxFile, ParseError> {
+    let mut parser = SyntaxParser {
+        tokens: tokenize(src),
+        cursor: 0,
+    };
+
+    let mut items = vec![];
+
+    while parser.current().kind != SyntaxKind::EOF {
+        items.push(parser.item()?);
+    }
+
+    Ok(SyntaxFile {
+        items,
+        eof: parser.bump(),
+    })
+}
+
+impl SyntaxParser {
+    fn current(&self) -> &SyntaxToken {
+        &self.tokens[self.cursor.min(self.tokens.len() - 1)]
+    }
+
+    fn peek(&self, offset: usize) -> &SyntaxToken {
+        &self.tokens[(self.cursor + offset).min(self.tokens.len() - 1)]
+    }
+
+    fn at(&self, text: &str) -> bool {
+        self.current().is(text)
+    }
+
+    fn bump(&mut self) -> SyntaxToken {
+        let token = self.current().clone();
+        self.cursor += 1;
+        token
+    }
+
+    fn error<T>(&self, message: String) -> Result<T, ParseError> {
+        let token = self.current();
+
+        Err(ParseError {
+            line: token.line,
+            pos: token.pos,
+            message,
+        })
+    }
+
+    fn expect(&mut self, text: &str) -> Result<SyntaxToken, ParseError> {
+        if self.at(text) {
+            Ok(self.bump())
+        } else {
+            self.error(format!(
+                "Expected '{}', but got '{}'",
+                text,
+                self.current().text
+            ))
+        }
+    }
+
+    fn expect_kind(&mut self, kind: SyntaxKind) -> Result<SyntaxToken, ParseError> {
+        if self.current().kind == kind {
+            Ok(self.bump())
+        } else {
+            self.error(format!(
+                "Expected {:?}, but got '{}'",
+                kind,
+                self.current().text
+            ))
+        }
+    }
+
+    fn optional(&mut self, text: &str) -> Option<SyntaxToken> {
+        if self.at(text) {
+            Some(self.bump())
+        } else {
+            None
+        }
+    }
+
+    fn item(&mut self) -> Result<Item, ParseError> {
+        let token = self.current();
+
+        match (token.kind, token.text.as_str()) {
+            (SyntaxKind::DocComment, _) => Ok(Item::DocComment(self.bump())),
+            (SyntaxKind::Symbol, "#") => Ok(Item::Directive(Box::new(self.directive()?))),
+            (SyntaxKind::Ident, "struct") => Ok(Item::Struct(Box::new(self.struct_node()?))),
+            (SyntaxKind::Ident, "enum") => Ok(Item::Enum(Box::new(self.enum_node()?))),
+            (SyntaxKind::Ident, "fn" | "signal" | "async") => {
+                Ok(Item::Fn(Box::new(self.fn_node()?)))
+            }
+            (SyntaxKind::Ident, "const") => Ok(Item::Const(Box::new(self.const_block()?))),
+            _ => self.error(format!("Unexpected token: '{}'", token.text)),
+        }
+    }
+
+    fn doc_comments(&mut self) -> Vec<SyntaxToken> {
+        let mut doc_comments = vec![];
+
+        while self.current().kind == SyntaxKind::DocComment {
+            doc_comments.push(self.bump());
+        }
+
+        doc_comments
+    }
+
+    fn directive(&mut self) -> Result<Directive, ParseError> {
+        let hash = self.expect("#")?;
+        let open = self.expect("[")?;
+
+        let body = if self.current().kind == SyntaxKind::Number {
+            DirectiveBody::Position(self.bump())
+        } else {
+            let id = self.expect_kind(SyntaxKind::Ident)?;
+
+            if self.at("=") {
+                DirectiveBody::Value {
+                    id,
+                    eq: self.bump(),
+                    value: self.bump(),
+                }
+            } else {
+                let open = self.expect("(")?;
+                let mut args = vec![];
+
+                while self.current().kind == SyntaxKind::Ident {
+                    let id = self.bump();
+                    let value = if self.at("=") {
+                        Some((self.bump(), self.bump()))
+                    } else {
+                        None
+                    };
+                    let comma = self.optional(",");
+                    let is_last = comma.is_none();
+
+                    args.push(GroupArg { id, value, comma });
+
+                    if is_last {
+                        break;
+                    }
+                }
+
+                DirectiveBody::Group {
+                    id,
+                    open,
+                    args,
+                    close: self.expect(")")?,
+                }
+            }
+        };
+
+        Ok(Directive {
+            hash,
+            open,
+            body,
+            close: self.expect("]")?,
+        })
+    }
+
+    fn position(&mut self) -> Result<Option<Directive>, ParseError> {
+        if self.at("#") {
+            Ok(Some(self.directive()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn type_id(&mut self) -> Result<Type, ParseError> {
+        let id = self.expect_kind(SyntaxKind::Ident)?;
+
+        let generics = if self.at("<") {
+            let open = self.bump();
+            let mut types = vec![];
+
+            while self.current().kind == SyntaxKind::Ident {
+                let type_id = self.type_id()?;
+                let comma = self.optional(",");
+                let is_last = comma.is_none();
+
+                types.push((type_id, comma));
+
+                if is_last {
+                    break;
+                }
+            }
+
+            Some(Generics {
+                open,
+                types,
+                close: self.expect(">")?,
+            })
+        } else {
+            None
+        };
+
+        Ok(Type { id, generics })
+    }
+
+    fn fields(&mut self) -> Result<Fields, ParseError> {
+        let open = self.expect("{")?;
+        let mut fields = vec![];
+
+        while !self.at("}") && self.current().kind != SyntaxKind::EOF {
+            let doc_comments = self.doc_comments();
+            let position = self.position()?;
+            let name = self.expect_kind(SyntaxKind::Ident)?;
+            let colon = self.expect(":")?;
+            let type_id = self.type_id()?;
+            let comma = self.optional(",");
+            let is_last = comma.is_none();
+
+            fields.push(Field {
+                doc_comments,
+                position,
+                name,
+                colon,
+                type_id,
+                comma,
+            });
+
+            if is_last {
+                break;
+            }
+        }
+
+        Ok(Fields {
+            open,
+            fields,
+            close: self.expect("}")?,
+        })
+    }
+
+    fn struct_node(&mut self) -> Result<Struct, ParseError> {
+        let keyword = self.expect("struct")?;
+        let id = self.expect_kind(SyntaxKind::Ident)?;
+
+        let body = if self.at(";") {
+            StructBody::Unit(self.bump())
+        } else {
+            StructBody::Fields(self.fields()?)
+        };
+
+        Ok(Struct { keyword, id, body })
+    }
+
+    fn enum_node(&mut self) -> Result<Enum, ParseError> {
+        let keyword = self.expect("enum")?;
+        let id = self.expect_kind(SyntaxKind::Ident)?;
+        let open = self.expect("{")?;
+        let mut items = vec![];
+
+        while !self.at("}") && self.current().kind != SyntaxKind::EOF {
+            let doc_comments = self.doc_comments();
+            let position = self.position()?;
+            let id = self.expect_kind(SyntaxKind::Ident)?;
+
+            let body = if self.at("(") {
+                let open = self.bump();
+                let mut values = vec![];
+
+                while !self.at(")") && self.current().kind != SyntaxKind::EOF {
+                    let doc_comments = self.doc_comments();
+                    let position = self.position()?;
+                    let type_id = self.type_id()?;
+                    let comma = self.optional(",");
+                    let is_last = comma.is_none();
+
+                    values.push(TupleValue {
+                        doc_comments,
+                        position,
+                        type_id,
+                        comma,
+                    });
+
+                    if is_last {
+                        break;
+                    }
+                }
+
+                EnumItemBody::Tuple {
+                    open,
+                    values,
+                    close: self.expect(")")?,
+                }
+            } else if self.at("{") {
+                EnumItemBody::Struct(self.fields()?)
+            } else {
+                EnumItemBody::Empty
+            };
+
+            let comma = self.optional(",");
+            let is_last = comma.is_none();
+
+            items.push(EnumItem {
+                doc_comments,
+                position,
+                id,
+                body,
+                comma,
+            });
+
+            if is_last {
+                break;
+            }
+        }
+
+        Ok(Enum {
+            keyword,
+            id,
+            open,
+            items,
+            close: self.expect("}")?,
+        })
+    }
+
+    fn fn_node(&mut self) -> Result<Fn, ParseError> {
+        let async_keyword = self.optional("async");
+        let keyword = if self.at("signal") {
+            self.bump()
+        } else {
+            self.expect("fn")?
+        };
+        let id = self.expect_kind(SyntaxKind::Ident)?;
+
+        let args = if self.at("(") {
+            let open = self.bump();
+            let mut args = vec![];
+
+            while self.current().kind == SyntaxKind::Ident {
+                let id = self.bump();
+                let colon = self.expect(":")?;
+                let type_id = self.type_id()?;
+                let comma = self.optional(",");
+                let is_last = comma.is_none();
+
+                args.push(FnArg {
+                    id,
+                    colon,
+                    type_id,
+                    comma,
+                });
+
+                if is_last {
+                    break;
+                }
+            }
+
+            Some(FnArgs {
+                open,
+                args,
+                close: self.expect(")")?,
+            })
+        } else {
+            None
+        };
+
+        let return_type_id = if self.at("-") && self.peek(1).is(">") {
+            let minus = self.bump();
+            let gt = self.bump();
+            Some((minus, gt, self.type_id()?))
+        } else {
+            None
+        };
+
+        Ok(Fn {
+            async_keyword,
+            keyword,
+            id,
+            args,
+            return_type_id,
+            semicolon: self.expect(";")?,
+        })
+    }
+
+    fn const_block(&mut self) -> Result<ConstBlock, ParseError> {
+        let keyword = self.expect("const")?;
+        let id = self.expect_kind(SyntaxKind::Ident)?;
+        let open = self.expect("{")?;
+        let mut items = vec![];
+
+        while !self.at("}") && self.current().kind != SyntaxKind::EOF {
+            if self.at("const") {
+                items.push(ConstItem::Block(Box::new(self.const_block()?)));
+            } else {
+                items.push(ConstItem::Value(Box::new(ConstValue {
+                    id: self.expect_kind(SyntaxKind::Ident)?,
+                    colon: self.expect(":")?,
+                    type_id: self.type_id()?,
+                    eq: self.expect("=")?,
+                    value: self.bump(),
+                    semicolon: self.expect(";")?,
+                })));
+            }
+        }
+
+        Ok(ConstBlock {
+            keyword,
+            id,
+            open,
+            items,
+            close: self.expect("}")?,
+        })
+    }
+}
@@ -0,0 +1,838 @@
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{
+        self, ASTNode, ConstBlockASTNode, ConstItemASTNode, ConstValueASTNode, EnumASTNode,
+        EnumItemASTNode, FnASTNode, StructASTNode, StructFieldASTNode, TypeIDASTNode,
+    },
+    lexer::Literal,
+    writer::Writer,
+};
+
+/// A `const` group per block, Go has no nested namespaces, so the names of
+/// nested values start with the block path, e.g. `AddrGroupsMain`.
+pub fn generate_consts(ast: &[ASTNode]) -> String {
+    let mut blocks = vec![];
+
+    for node in ast {
+        if let ASTNode::Const(node) = node {
+            let mut specs = vec![];
+            collect_const_specs(&mut specs, "", node);
+
+            let mut writer = Writer::default();
+            writer.writeln("const (");
+            writer.push_tab();
+            generate_aligned(&mut writer, &specs);
+            writer.pop_tab();
+            writer.writeln(")");
+            blocks.push(writer.show().to_string());
+        }
+    }
+
+    blocks.join("\n")
+}
+
+/// `None` is a blank line, it also ends the run of aligned specs.
+fn collect_const_specs(
+    specs: &mut Vec<Option<Vec<String>>>,
+    prefix: &str,
+    node: &ConstBlockASTNode,
+) {
+    let prefix = format!("{}{}", prefix, node.id.to_case(Case::Pascal));
+    let mut is_value = true;
+
+    for item in &node.items {
+        let is_first = specs.is_empty() || specs.last() == Some(&None);
+
+        match item {
+            ConstItemASTNode::Value { id, type_id, value } => {
+                if !is_value && !is_first {
+                    specs.push(None);
+                }
+
+                specs.push(Some(vec![
+                    format!("{}{}", prefix, id.to_case(Case::Pascal)),
+                    generate_type_id(type_id),
+                    format!("= {}", generate_const_value(value)),
+                ]));
+                is_value = true;
+            }
+            ConstItemASTNode::ConstsBlock { node } => {
+                if !is_first {
+                    specs.push(None);
+                }
+
+                collect_const_specs(specs, &prefix, node);
+                is_value = false;
+            }
+        }
+    }
+}
+
+/// Pads the columns like gofmt does, runs of lines are separated by blank
+/// lines.
+fn generate_aligned(writer: &mut Writer, lines: &[Option<Vec<String>>]) {
+    let mut is_first = true;
+
+    for run in lines.split(|line| line.is_none()) {
+        if run.is_empty() {
+            continue;
+        }
+
+        if !is_first {
+            writer.new_line();
+        }
+
+        is_first = false;
+
+        let columns = run
+            .iter()
+            .flatten()
+            .map(|line| line.len())
+            .max()
+            .unwrap_or(0);
+        let widths = (0..columns)
+            .map(|i| {
+                run.iter()
+                    .flatten()
+                    .filter_map(|line| line.get(i))
+                    .map(|cell| cell.len())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<usize>>();
+
+        for line in run.iter().flatten() {
+            let mut res = String::new();
+
+            for (i, cell) in line.iter().enumerate() {
+                if i == line.len() - 1 {
+                    res += cell;
+                } else {
+                    res += &format!("{:width$} ", cell, width = widths[i]);
+                }
+            }
+
+            writer.writeln(&res);
+        }
+    }
+}
+
+pub fn generate_models(ast: &[ASTNode]) -> String {
+    let mut blocks = vec![];
+
+    for node in ast {
+        match node {
+            ASTNode::Struct(node) => blocks.push(generate_struct_model(node)),
+            ASTNode::Enum(node) => blocks.push(generate_enum_model(node)),
+            _ => (),
+        }
+    }
+
+    blocks.join("\n")
+}
+
+fn generate_struct_model(node: &StructASTNode) -> String {
+    let mut writer = Writer::default();
+
+    generate_struct(&mut writer, &node.id, &node.fields);
+    writer.new_line();
+
+    writer.writeln(&format!("func Default{}() {} {{", node.id, node.id));
+    writer.push_tab();
+    generate_default_literal(&mut writer, &node.id, &node.fields);
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.new_line();
+
+    writer.writeln(&format!(
+        "func Read{}(r io.Reader) ({}, error) {{",
+        node.id, node.id
+    ));
+    writer.push_tab();
+    writer.writeln(&format!("var m {}", node.id));
+    writer.writeln("err := m.ReadFromBuffers(r)");
+    writer.writeln("return m, err");
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.new_line();
+
+    generate_skip_function(&mut writer, &node.id);
+    writer.new_line();
+
+    writer.writeln(&format!(
+        "func (m *{}) ReadFromBuffers(r io.Reader) error {{",
+        node.id
+    ));
+    writer.push_tab();
+
+    if !node.fields.is_empty() {
+        writer.writeln("var err error");
+    }
+
+    generate_field_reads(&mut writer, "m", &node.fields, "err");
+    writer.writeln("return nil");
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.new_line();
+
+    writer.writeln(&format!(
+        "func (m {}) WriteToBuffers(buf []byte) []byte {{",
+        node.id
+    ));
+    writer.push_tab();
+    generate_field_writes(&mut writer, "m", &node.fields);
+    writer.writeln("return buf");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+/// An interface with an unexported marker method, so only the item structs
+/// of the enum implement it.
+fn generate_enum_model(node: &EnumASTNode) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!("type {} interface {{", node.id));
+    writer.push_tab();
+    writer.writeln(&format!("is{}()", node.id));
+    writer.writeln("WriteToBuffers(buf []byte) []byte");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    for item in &node.items {
+        writer.new_line();
+        generate_struct(
+            &mut writer,
+            &enum_item_struct_id(node, item),
+            &enum_item_fields(item),
+        );
+    }
+
+    writer.new_line();
+    writer.writeln(&format!("func Default{}() {} {{", node.id, node.id));
+    writer.push_tab();
+    let item = node
+        .items
+        .first()
+        .expect("enum should have at least one item");
+    generate_default_literal(
+        &mut writer,
+        &enum_item_struct_id(node, item),
+        &enum_item_fields(item),
+    );
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.new_line();
+
+    writer.writeln(&format!(
+        "func Read{}(r io.Reader) ({}, error) {{",
+        node.id, node.id
+    ));
+    writer.push_tab();
+    writer.writeln("kind, err := readU32(r)");
+    writer.writeln("if err != nil {");
+    writer.writeln("    return nil, err");
+    writer.writeln("}");
+    writer.new_line();
+    writer.writeln("switch kind {");
+
+    for item in &node.items {
+        let struct_id = enum_item_struct_id(node, item);
+        let fields = enum_item_fields(item);
+
+        writer.writeln(&format!("case {}:", item.position()));
+        writer.push_tab();
+
+        if fields.is_empty() {
+            writer.writeln(&format!("return {}{{}}, nil", struct_id));
+        } else {
+            writer.writeln(&format!("var v {}", struct_id));
+            generate_field_reads(&mut writer, "v", &fields, "nil, err");
+            writer.writeln("return v, nil");
+        }
+
+        writer.pop_tab();
+    }
+
+    writer.writeln("default:");
+    writer.writeln(&format!(
+        "    return nil, invalidKind(\"{}\", kind)",
+        node.id
+    ));
+    writer.writeln("}");
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.new_line();
+
+    generate_skip_function(&mut writer, &node.id);
+
+    for item in &node.items {
+        let struct_id = enum_item_struct_id(node, item);
+        let fields = enum_item_fields(item);
+
+        writer.new_line();
+        writer.writeln(&format!("func ({}) is{}() {{}}", struct_id, node.id));
+        writer.new_line();
+
+        if fields.is_empty() {
+            writer.writeln(&format!(
+                "func ({}) WriteToBuffers(buf []byte) []byte {{",
+                struct_id
+            ));
+            writer.writeln(&format!("    return appendU32(buf, {})", item.position()));
+        } else {
+            writer.writeln(&format!(
+                "func (v {}) WriteToBuffers(buf []byte) []byte {{",
+                struct_id
+            ));
+            writer.push_tab();
+            writer.writeln(&format!("buf = appendU32(buf, {})", item.position()));
+            generate_field_writes(&mut writer, "v", &fields);
+            writer.writeln("return buf");
+            writer.pop_tab();
+        }
+
+        writer.writeln("}");
+    }
+
+    writer.show().to_string()
+}
+
+fn enum_item_struct_id(node: &EnumASTNode, item: &EnumItemASTNode) -> String {
+    format!("{}{}", node.id, item.id())
+}
+
+/// Fields of the item, tuple values are named `P` with the position like
+/// in the Swift models.
+fn enum_item_fields(item: &EnumItemASTNode) -> Vec<StructFieldASTNode> {
+    match item {
+        EnumItemASTNode::Empty { .. } => vec![],
+        EnumItemASTNode::Tuple { values, .. } => values
+            .iter()
+            .map(|value| StructFieldASTNode {
+                doc_comments: vec![],
+                position: value.position,
+                name: format!("p{}", value.position),
+                type_id: value.type_id.clone(),
+            })
+            .collect(),
+        EnumItemASTNode::Struct { fields, .. } => fields.clone(),
+    }
+}
+
+fn generate_struct(writer: &mut Writer, id: &str, fields: &[StructFieldASTNode]) {
+    if fields.is_empty() {
+        writer.writeln(&format!("type {} struct{{}}", id));
+        return;
+    }
+
+    let lines = fields
+        .iter()
+        .map(|field| {
+            Some(vec![
+                field.name.to_case(Case::Pascal),
+                generate_type_id(&field.type_id),
+            ])
+        })
+        .collect::<Vec<Option<Vec<String>>>>();
+
+    writer.writeln(&format!("type {} struct {{", id));
+    writer.push_tab();
+    generate_aligned(writer, &lines);
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+/// Fields with a zero value that can't be written, enums and models, are
+/// set to their defaults.
+fn generate_default_literal(writer: &mut Writer, id: &str, fields: &[StructFieldASTNode]) {
+    let values = fields
+        .iter()
+        .filter_map(|field| {
+            generate_default_value(&field.type_id).map(|value| {
+                Some(vec![
+                    format!("{}:", field.name.to_case(Case::Pascal)),
+                    format!("{},", value),
+                ])
+            })
+        })
+        .collect::<Vec<Option<Vec<String>>>>();
+
+    if values.is_empty() {
+        writer.writeln(&format!("return {}{{}}", id));
+        return;
+    }
+
+    writer.writeln(&format!("return {}{{", id));
+    writer.push_tab();
+    generate_aligned(writer, &values);
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+fn generate_field_reads(
+    writer: &mut Writer,
+    receiver: &str,
+    fields: &[StructFieldASTNode],
+    error: &str,
+) {
+    for field in fields {
+        writer.writeln(&format!(
+            "if {}.{}, err = {}; err != nil {{",
+            receiver,
+            field.name.to_case(Case::Pascal),
+            generate_read(&field.type_id)
+        ));
+        writer.writeln(&format!("    return {}", error));
+        writer.writeln("}");
+    }
+}
+
+fn generate_field_writes(writer: &mut Writer, receiver: &str, fields: &[StructFieldASTNode]) {
+    for field in fields {
+        let accessor = format!("{}.{}", receiver, field.name.to_case(Case::Pascal));
+        writer.writeln(&format!(
+            "buf = {}",
+            generate_write(&field.type_id, &accessor)
+        ));
+    }
+}
+
+fn generate_skip_function(writer: &mut Writer, id: &str) {
+    writer.writeln(&format!(
+        "func Skip{}(r io.Reader, count uint64) error {{",
+        id
+    ));
+    writer.push_tab();
+    writer.writeln("for i := uint64(0); i < count; i++ {");
+    writer.writeln(&format!("    if _, err := Read{}(r); err != nil {{", id));
+    writer.writeln("        return err");
+    writer.writeln("    }");
+    writer.writeln("}");
+    writer.writeln("return nil");
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+pub fn generate_rpc(ast: &[ASTNode]) -> String {
+    let namespace = ast::find_directive_value(ast, "namespace").expect("namespace is required");
+    let namespace = match namespace {
+        ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("namespace should be a string literal"),
+        },
+    };
+
+    let scope_id = ast::find_directive_value(ast, "id").expect("id is required");
+    let scope_id = match scope_id {
+        ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("id should be a string literal"),
+        },
+    };
+
+    let struct_id = format!("{}RPC", namespace.to_case(Case::Pascal));
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!("type {} struct {{", struct_id));
+    writer.writeln("    runtime Runtime");
+    writer.writeln("}");
+    writer.new_line();
+    writer.writeln(&format!(
+        "func New{}(runtime Runtime) *{} {{",
+        struct_id, struct_id
+    ));
+    writer.writeln(&format!("    return &{}{{runtime: runtime}}", struct_id));
+    writer.writeln("}");
+    writer.new_line();
+    // A method rather than a package constant, so schemas can share a package.
+    writer.writeln("// ScopeID is the id of the schema the methods are called in.");
+    writer.writeln(&format!("func (rpc *{}) ScopeID() string {{", struct_id));
+    writer.writeln(&format!("    return \"{}\"", scope_id));
+    writer.writeln("}");
+
+    for node in ast::find_fn_nodes(ast) {
+        writer.new_line();
+
+        if node.is_signal {
+            generate_consume_signal_method(&mut writer, &struct_id, node);
+        } else {
+            generate_call_method(&mut writer, &struct_id, node);
+        }
+    }
+
+    writer.show().to_string()
+}
+
+fn generate_call_method(writer: &mut Writer, struct_id: &str, node: &FnASTNode) {
+    let mut args = node
+        .args
+        .iter()
+        .map(|arg| format!("{} {}", arg_id(&arg.id), generate_type_id(&arg.type_id)))
+        .collect::<Vec<String>>();
+
+    if node.is_async {
+        args.insert(0, String::from("ctx context.Context"));
+    }
+
+    let return_type_id = match &node.return_type_id {
+        Some(type_id) => format!("({}, error)", generate_type_id(type_id)),
+        None => String::from("error"),
+    };
+
+    writer.writeln(&format!(
+        "func (rpc *{}) {}({}) {} {{",
+        struct_id,
+        node.id.to_case(Case::Pascal),
+        args.join(", "),
+        return_type_id
+    ));
+    writer.push_tab();
+
+    let args = if node.args.is_empty() {
+        "nil"
+    } else {
+        writer.writeln("var buf []byte");
+
+        for arg in &node.args {
+            writer.writeln(&format!(
+                "buf = {}",
+                generate_write(&arg.type_id, &arg_id(&arg.id))
+            ));
+        }
+
+        writer.new_line();
+        "buf"
+    };
+
+    let call = if node.is_async {
+        format!(
+            "rpc.runtime.CallContext(ctx, rpc.ScopeID(), {}, {})",
+            node.position, args
+        )
+    } else {
+        format!(
+            "rpc.runtime.Call(rpc.ScopeID(), {}, {})",
+            node.position, args
+        )
+    };
+
+    match &node.return_type_id {
+        Some(type_id) => {
+            writer.writeln(&format!("res, err := {}", call));
+            writer.writeln(&format!(
+                "return readResult(res, err, {})",
+                generate_read_fn(type_id)
+            ));
+        }
+        None => {
+            writer.writeln(&format!("_, err := {}", call));
+            writer.writeln("return err");
+        }
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+fn generate_consume_signal_method(writer: &mut Writer, struct_id: &str, node: &FnASTNode) {
+    let method_id = format!("Consume{}", node.id.to_case(Case::Pascal));
+
+    match &node.return_type_id {
+        Some(type_id) => {
+            writer.writeln(&format!(
+                "func (rpc *{}) {}() ({}, bool, error) {{",
+                struct_id,
+                method_id,
+                generate_type_id(type_id)
+            ));
+            writer.push_tab();
+            writer.writeln(&format!(
+                "res, ok := rpc.runtime.Consume(rpc.ScopeID(), {})",
+                node.position
+            ));
+            writer.writeln(&format!(
+                "return consumeResult(res, ok, {})",
+                generate_read_fn(type_id)
+            ));
+        }
+        None => {
+            writer.writeln(&format!(
+                "func (rpc *{}) {}() bool {{",
+                struct_id, method_id
+            ));
+            writer.push_tab();
+            writer.writeln(&format!(
+                "_, ok := rpc.runtime.Consume(rpc.ScopeID(), {})",
+                node.position
+            ));
+            writer.writeln("return ok");
+        }
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+/// Arguments are camel case, so they can collide with Go keywords.
+fn arg_id(id: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "break",
+        "case",
+        "chan",
+        "const",
+        "continue",
+        "default",
+        "defer",
+        "else",
+        "fallthrough",
+        "for",
+        "func",
+        "go",
+        "goto",
+        "if",
+        "import",
+        "interface",
+        "map",
+        "package",
+        "range",
+        "return",
+        "select",
+        "struct",
+        "switch",
+        "type",
+        "var",
+    ];
+
+    let id = id.to_case(Case::Camel);
+
+    if KEYWORDS.contains(&id.as_str()) {
+        format!("{}_", id)
+    } else {
+        id
+    }
+}
+
+fn is_address(id: &str) -> bool {
+    id == "GroupAddress" || id == "CommandsBufferAddress"
+}
+
+pub fn generate_type_id(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { size, signed, .. } => {
+            format!("{}int{}", if *signed { "" } else { "u" }, size * 8)
+        }
+        TypeIDASTNode::Number { size, .. } => format!("float{}", size * 8),
+        TypeIDASTNode::Bool { .. } => String::from("bool"),
+        TypeIDASTNode::Char { .. } => String::from("rune"),
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => String::from("string"),
+            id if is_address(id) => String::from("uint64"),
+            _ => id.clone(),
+        },
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Option" => format!(
+                "*{}",
+                generate_type_id(generics.first().expect("Optional type cannot be empty"))
+            ),
+            "Vec" => format!(
+                "[]{}",
+                generate_type_id(generics.first().expect("Vec type cannot be empty"))
+            ),
+            _ => format!("{}[{}]", id, generate_generics(generics)),
+        },
+    }
+}
+
+fn generate_generics(generics: &[TypeIDASTNode]) -> String {
+    generics
+        .iter()
+        .map(generate_type_id)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Function value reading the type, `func(io.Reader) (T, error)`.
+pub fn generate_read_fn(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { size, signed, .. } => {
+            format!("read{}{}", if *signed { "I" } else { "U" }, size * 8)
+        }
+        TypeIDASTNode::Number { size, .. } => format!("readF{}", size * 8),
+        TypeIDASTNode::Bool { .. } => String::from("readBool"),
+        TypeIDASTNode::Char { .. } => String::from("readChar"),
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => String::from("readString"),
+            id if is_address(id) => String::from("readU64"),
+            _ => format!("Read{}", id),
+        },
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Option" | "Vec" => format!(
+                "func(r io.Reader) ({}, error) {{ return {} }}",
+                generate_type_id(type_id),
+                generate_read(type_id)
+            ),
+            _ => format!("Read{}[{}]", id, generate_generics(generics)),
+        },
+    }
+}
+
+/// Call reading the type from `r`, returns `(T, error)`.
+pub fn generate_read(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Generic { id, generics } if id == "Option" => format!(
+            "readOption(r, {})",
+            generate_read_fn(generics.first().expect("Optional type cannot be empty"))
+        ),
+        TypeIDASTNode::Generic { id, generics } if id == "Vec" => format!(
+            "readVec(r, {})",
+            generate_read_fn(generics.first().expect("Vec type cannot be empty"))
+        ),
+        _ => format!("{}(r)", generate_read_fn(type_id)),
+    }
+}
+
+/// Function value appending the type, `func([]byte, T) []byte`.
+pub fn generate_write_fn(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { size, signed, .. } => {
+            format!("append{}{}", if *signed { "I" } else { "U" }, size * 8)
+        }
+        TypeIDASTNode::Number { size, .. } => format!("appendF{}", size * 8),
+        TypeIDASTNode::Bool { .. } => String::from("appendBool"),
+        TypeIDASTNode::Char { .. } => String::from("appendChar"),
+        TypeIDASTNode::Other { id } if id == "String" => String::from("appendString"),
+        TypeIDASTNode::Other { id } if is_address(id) => String::from("appendU64"),
+        _ => format!(
+            "func(buf []byte, value {}) []byte {{ return {} }}",
+            generate_type_id(type_id),
+            generate_write(type_id, "value")
+        ),
+    }
+}
+
+/// Expression appending the value of `accessor` to `buf`.
+pub fn generate_write(type_id: &TypeIDASTNode, accessor: &str) -> String {
+    match type_id {
+        TypeIDASTNode::Other { id } if id != "String" && !is_address(id) => {
+            format!("{}.WriteToBuffers(buf)", accessor)
+        }
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Option" => format!(
+                "appendOption(buf, {}, {})",
+                accessor,
+                generate_write_fn(generics.first().expect("Optional type cannot be empty"))
+            ),
+            "Vec" => format!(
+                "appendVec(buf, {}, {})",
+                accessor,
+                generate_write_fn(generics.first().expect("Vec type cannot be empty"))
+            ),
+            _ => format!("{}.WriteToBuffers(buf)", accessor),
+        },
+        _ => format!("{}(buf, {})", generate_write_fn(type_id), accessor),
+    }
+}
+
+/// `None` if the zero value is the default.
+pub fn generate_default_value(type_id: &TypeIDASTNode) -> Option<String> {
+    match type_id {
+        TypeIDASTNode::Other { id } if id != "String" && !is_address(id) => {
+            Some(format!("Default{}()", id))
+        }
+        TypeIDASTNode::Generic { id, generics } if id != "Option" && id != "Vec" => {
+            Some(format!("Default{}[{}]()", id, generate_generics(generics)))
+        }
+        _ => None,
+    }
+}
+
+pub fn generate_const_value(node: &ConstValueASTNode) -> String {
+    match node {
+        ConstValueASTNode::Literal { literal, .. } => match literal {
+            Literal::StringLiteral(value) => format!("\"{}\"", value),
+            Literal::IntLiteral(value) => format!("{}", value),
+            Literal::NumberLiteral(value) => {
+                if value.floor() == *value {
+                    format!("{}.0", value)
+                } else {
+                    format!("{}", value)
+                }
+            }
+            Literal::BoolLiteral(value) => format!("{}", value),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{go::indent_with_tabs, lexer::Lexer, parser::parse};
+    use std::fs;
+
+    fn parse_resource(name: &str) -> Vec<ASTNode> {
+        let src = fs::read_to_string(format!("test_resources/{}.tpb", name)).unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        parse(&mut lexer)
+    }
+
+    fn read_target(name: &str) -> String {
+        fs::read_to_string(format!("test_resources/go/{}.go", name)).unwrap()
+    }
+
+    #[test]
+    fn generate_consts_test() {
+        let ast = parse_resource("consts");
+        let actual = indent_with_tabs(&generate_consts(&ast));
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("consts").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_basic() {
+        let ast = parse_resource("struct_basic");
+        let actual = indent_with_tabs(&generate_models(&ast));
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_basic").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_types() {
+        let ast = parse_resource("struct_types");
+        let actual = indent_with_tabs(&generate_models(&ast));
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_types").trim());
+    }
+
+    #[test]
+    fn generate_enum_model_extended_test() {
+        let ast = parse_resource("enum_extended");
+        let actual = indent_with_tabs(&generate_models(&ast));
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("enum_extended").trim());
+    }
+
+    #[test]
+    fn generate_rpc_sync_methods_test() {
+        let ast = parse_resource("rpc_sync_methods");
+        let actual = indent_with_tabs(&generate_rpc(&ast));
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_sync_methods").trim());
+    }
+
+    #[test]
+    fn generate_rpc_stream_methods_test() {
+        let ast = parse_resource("rpc_stream_methods");
+        let actual = indent_with_tabs(&generate_rpc(&ast));
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_stream_methods").trim());
+    }
+}
@@ -0,0 +1,168 @@
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{self, ASTNode, ConstValueASTNode},
+    lexer::Literal,
+    options::{GeneratorOptions, DEFAULT_HEADER},
+    writer::Writer,
+};
+
+use self::generator::{generate_consts, generate_models, generate_rpc};
+
+pub mod generator;
+
+/// Readers, writers and the `Runtime` interface, unexported helpers only
+/// depending on the standard library.
+const PRIMITIVES: &str = include_str!("primitives.go");
+
+/// Written instead of [`PRIMITIVES`] with `#[go(shared_primitives)]`, the
+/// imports are needed by the helpers in the file from `go-primitives`.
+const IMPORT_REFERENCES: &str =
+    "// Keeps the imports used, the helpers are in the shared primitives file.
+var (
+	_ = bytes.NewReader
+	_ = context.Background
+	_ = fmt.Errorf
+	_ = io.EOF
+	_ = math.Float32bits
+)
+";
+
+/// The form `go generate` and linters recognize as generated code.
+const GENERATED_HEADER: &str = "// Code generated by tech_paws_buffers_generator. DO NOT EDIT.";
+
+pub fn generate(ast: &[ASTNode]) -> String {
+    generate_with_options(ast, &GeneratorOptions::default())
+}
+
+/// Go code is indented with tabs and unused imports don't compile, so the
+/// `indent` and `imports` options don't apply.
+pub fn generate_with_options(ast: &[ASTNode], options: &GeneratorOptions) -> String {
+    let mut writer = Writer::default();
+    write_package(&mut writer, &package_name(ast, options), options);
+
+    // Schemas sharing a package share the helpers, since they can't be
+    // declared twice.
+    if ast::contains_directive_group_flag(ast, "go", "shared_primitives") {
+        writer.write(IMPORT_REFERENCES);
+    } else {
+        writer.write(PRIMITIVES);
+    }
+
+    let mut sections = vec![generate_consts(ast), generate_models(ast)];
+
+    if ast::contains_fn_nodes(ast) {
+        sections.push(generate_rpc(ast));
+    }
+
+    for section in sections.iter().filter(|section| !section.is_empty()) {
+        writer.new_line();
+        writer.write(section);
+    }
+
+    indent_with_tabs(writer.show())
+}
+
+/// The readers, writers and `Runtime` interface for the schemas of a package
+/// with `#[go(shared_primitives)]`.
+pub fn generate_primitives(package: &str, options: &GeneratorOptions) -> String {
+    let mut writer = Writer::default();
+    write_package(&mut writer, package, options);
+    writer.write(PRIMITIVES);
+    indent_with_tabs(writer.show())
+}
+
+fn write_package(writer: &mut Writer, package: &str, options: &GeneratorOptions) {
+    let header = match options.header() {
+        DEFAULT_HEADER => GENERATED_HEADER,
+        header => header,
+    };
+
+    if !header.is_empty() {
+        writer.writeln(header);
+        writer.new_line();
+    }
+
+    writer.writeln(&format!("package {}", package));
+    writer.new_line();
+    writer.writeln("import (");
+    writer.writeln("    \"bytes\"");
+    writer.writeln("    \"context\"");
+    writer.writeln("    \"fmt\"");
+    writer.writeln("    \"io\"");
+    writer.writeln("    \"math\"");
+    writer.writeln(")");
+    writer.new_line();
+}
+
+/// The `namespace` directive, then the `package` option, the directive is
+/// snake case, so the underscores are dropped: `examples_sync` is
+/// `examplessync`.
+fn package_name(ast: &[ASTNode], options: &GeneratorOptions) -> String {
+    match ast::find_directive_value(ast, "namespace") {
+        Some(ConstValueASTNode::Literal {
+            literal: Literal::StringLiteral(namespace),
+            ..
+        }) => namespace.to_case(Case::Flat),
+        _ => options
+            .module
+            .clone()
+            .unwrap_or_else(|| String::from("buffers")),
+    }
+}
+
+/// Replaces the 4 spaces per level of the writers with tabs, like gofmt.
+fn indent_with_tabs(src: &str) -> String {
+    let mut res = String::with_capacity(src.len());
+
+    for line in src.split_inclusive('\n') {
+        let code = line.trim_start_matches(' ');
+        let spaces = line.len() - code.len();
+
+        res += &"\t".repeat(spaces / 4);
+        res += code;
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    #[test]
+    fn generate_with_custom_options() {
+        let src = fs::read_to_string("test_resources/struct_basic.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/go/struct_basic_options.go").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let options = GeneratorOptions {
+            indent: Some(2),
+            header: Some(String::from("// Copyright (c) Tech Paws")),
+            module: Some(String::from("snapshots")),
+            ..GeneratorOptions::default()
+        };
+        let actual = generate_with_options(&ast, &options);
+        assert_eq!(actual.trim(), target.trim());
+    }
+
+    #[test]
+    fn generate_with_shared_primitives() {
+        let src = "#[go(shared_primitives)]\n\nstruct Point {\n    x: f32,\n}";
+        let mut lexer = Lexer::tokenize(src);
+        let ast = parse(&mut lexer);
+        let options = GeneratorOptions::default();
+        let actual = generate_with_options(&ast, &options);
+
+        assert!(actual.contains(IMPORT_REFERENCES));
+        assert!(!actual.contains("func readFull("));
+        assert!(actual.contains("type Point struct {"));
+
+        let primitives = generate_primitives("buffers", &options);
+
+        assert!(primitives.starts_with(&format!("{}\n\npackage buffers\n", GENERATED_HEADER)));
+        assert!(primitives.ends_with(PRIMITIVES));
+    }
+}
@@ -0,0 +1,512 @@
+//! Class diagrams of schemas for design reviews, as Graphviz DOT or
+//! Mermaid.
+//!
+//! Structs and enums are nodes listing their fields and variants, fields
+//! and variants referencing other types of the schema, also inside
+//! generics like `Vec<T>`, are edges. RPC methods are grouped under the
+//! namespace with edges to their argument and result types.
+
+use clap::clap_derive::ArgEnum;
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{
+        self, ASTNode, ConstValueASTNode, EnumASTNode, EnumItemASTNode, FnASTNode, StructASTNode,
+        StructFieldASTNode, TypeIDASTNode,
+    },
+    lexer::Literal,
+    rust_generator::generate_type_id,
+    writer::Writer,
+};
+
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+/// Edge from a type or a method to a type it references, labeled with the
+/// field, variant or method.
+struct Edge {
+    from: String,
+    to: String,
+    label: String,
+}
+
+pub fn generate_graph(ast: &[ASTNode], format: GraphFormat) -> String {
+    match format {
+        GraphFormat::Dot => generate_dot(ast),
+        GraphFormat::Mermaid => generate_mermaid(ast),
+    }
+}
+
+fn generate_dot(ast: &[ASTNode]) -> String {
+    let mut writer = Writer::default();
+    let mut edges = vec![];
+    let name = namespace(ast).unwrap_or_else(|| String::from("schema"));
+
+    writer.writeln(&format!("digraph \"{}\" {{", escape_dot(&name)));
+    writer.push_tab();
+    writer.writeln("rankdir=LR;");
+    writer.writeln("node [shape=record, fontname=\"Helvetica\"];");
+    writer.writeln("edge [fontname=\"Helvetica\", fontsize=10];");
+
+    for node in ast {
+        match node {
+            ASTNode::Struct(node) => {
+                let fields = node
+                    .fields
+                    .iter()
+                    .map(generate_field)
+                    .collect::<Vec<String>>();
+
+                writer.new_line();
+                writer.writeln(&generate_dot_node(&node.id, None, &fields));
+                edges.extend(find_struct_edges(ast, node));
+            }
+            ASTNode::Enum(node) => {
+                let items = node
+                    .items
+                    .iter()
+                    .map(generate_enum_item)
+                    .collect::<Vec<String>>();
+
+                writer.new_line();
+                writer.writeln(&generate_dot_node(&node.id, Some("enum"), &items));
+                edges.extend(find_enum_edges(ast, node));
+            }
+            _ => (),
+        }
+    }
+
+    let fn_nodes = ast::find_fn_nodes(ast);
+
+    if !fn_nodes.is_empty() {
+        let mut label = format!("{} RPC", escape_dot(&name));
+
+        if let Some(id) = string_directive(ast, "id") {
+            label += &format!("\\n{}", escape_dot(&id));
+        }
+
+        writer.new_line();
+        writer.writeln("subgraph \"cluster_rpc\" {");
+        writer.push_tab();
+        writer.writeln(&format!("label=\"{}\";", label));
+        writer.writeln("style=dashed;");
+        writer.writeln("node [shape=box, style=rounded];");
+
+        for node in &fn_nodes {
+            let id = format!("rpc.{}", node.id);
+            writer.writeln(&format!(
+                "\"{}\" [label=\"{}\"];",
+                escape_dot(&id),
+                escape_dot(&generate_fn(node))
+            ));
+            edges.extend(find_fn_edges(ast, node, &id));
+        }
+
+        writer.pop_tab();
+        writer.writeln("}");
+    }
+
+    if !edges.is_empty() {
+        writer.new_line();
+    }
+
+    for edge in &edges {
+        let style = if edge.from.starts_with("rpc.") {
+            ", style=dashed"
+        } else {
+            ""
+        };
+
+        writer.writeln(&format!(
+            "\"{}\" -> \"{}\" [label=\"{}\"{}];",
+            escape_dot(&edge.from),
+            escape_dot(&edge.to),
+            escape_dot(&edge.label),
+            style
+        ));
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+/// Record node with the name, then a line per field or variant.
+fn generate_dot_node(id: &str, stereotype: Option<&str>, members: &[String]) -> String {
+    let title = match stereotype {
+        Some(stereotype) => format!("«{}»\\n{}", stereotype, escape_record(id)),
+        None => escape_record(id),
+    };
+
+    let members = members
+        .iter()
+        .map(|member| format!("{}\\l", escape_record(member)))
+        .collect::<String>();
+
+    format!(
+        "\"{}\" [label=\"{{{}|{}}}\"];",
+        escape_dot(id),
+        title,
+        members
+    )
+}
+
+fn generate_mermaid(ast: &[ASTNode]) -> String {
+    let mut writer = Writer::default();
+    let mut edges = vec![];
+
+    writer.writeln("classDiagram");
+    writer.push_tab();
+
+    for node in ast {
+        match node {
+            ASTNode::Struct(node) => {
+                let fields = node
+                    .fields
+                    .iter()
+                    .map(|field| generate_mermaid_type(&generate_field(field)))
+                    .collect::<Vec<String>>();
+
+                generate_mermaid_class(&mut writer, &node.id, None, &fields);
+                edges.extend(find_struct_edges(ast, node));
+            }
+            ASTNode::Enum(node) => {
+                let items = node
+                    .items
+                    .iter()
+                    .map(generate_mermaid_enum_item)
+                    .collect::<Vec<String>>();
+
+                generate_mermaid_class(&mut writer, &node.id, Some("enumeration"), &items);
+                edges.extend(find_enum_edges(ast, node));
+            }
+            _ => (),
+        }
+    }
+
+    let fn_nodes = ast::find_fn_nodes(ast);
+
+    if !fn_nodes.is_empty() {
+        let name = namespace(ast).unwrap_or_else(|| String::from("schema"));
+        let id = format!("{}Rpc", name.to_case(Case::Pascal));
+        let stereotype = match string_directive(ast, "id") {
+            Some(scope_id) => format!("service {}", scope_id),
+            None => String::from("service"),
+        };
+        let methods = fn_nodes
+            .iter()
+            .map(|node| generate_mermaid_method(node))
+            .collect::<Vec<String>>();
+
+        writer.writeln(&format!("namespace {} {{", name));
+        writer.push_tab();
+        generate_mermaid_class(&mut writer, &id, Some(&stereotype), &methods);
+        writer.pop_tab();
+        writer.writeln("}");
+
+        for node in &fn_nodes {
+            edges.extend(find_fn_edges(ast, node, &id));
+        }
+    }
+
+    for edge in &edges {
+        writer.writeln(&format!("{} --> {} : {}", edge.from, edge.to, edge.label));
+    }
+
+    writer.pop_tab();
+
+    writer.show().to_string()
+}
+
+fn generate_mermaid_class(
+    writer: &mut Writer,
+    id: &str,
+    stereotype: Option<&str>,
+    members: &[String],
+) {
+    if stereotype.is_none() && members.is_empty() {
+        writer.writeln(&format!("class {}", id));
+        return;
+    }
+
+    writer.writeln(&format!("class {} {{", id));
+    writer.push_tab();
+
+    if let Some(stereotype) = stereotype {
+        writer.writeln(&format!("<<{}>>", stereotype));
+    }
+
+    for member in members {
+        writer.writeln(member);
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+/// Mermaid writes generics as `Vec~Point~`.
+fn generate_mermaid_type(member: &str) -> String {
+    member.replace(['<', '>'], "~")
+}
+
+/// Members with parentheses are methods and braces close the class, so the
+/// values and fields of variants are in brackets.
+fn generate_mermaid_enum_item(item: &EnumItemASTNode) -> String {
+    let values = match item {
+        EnumItemASTNode::Empty { .. } => return item.id().to_string(),
+        EnumItemASTNode::Tuple { values, .. } => values
+            .iter()
+            .map(|value| generate_type_id(&value.type_id))
+            .collect::<Vec<String>>(),
+        EnumItemASTNode::Struct { fields, .. } => {
+            fields.iter().map(generate_field).collect::<Vec<String>>()
+        }
+    };
+
+    generate_mermaid_type(&format!("{}[{}]", item.id(), values.join(", ")))
+}
+
+fn generate_mermaid_method(node: &FnASTNode) -> String {
+    let args = node
+        .args
+        .iter()
+        .map(|arg| format!("{}: {}", arg.id, generate_type_id(&arg.type_id)))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let prefix = if node.is_signal {
+        "signal "
+    } else if node.is_async {
+        "async "
+    } else {
+        ""
+    };
+
+    let return_type_id = match &node.return_type_id {
+        Some(type_id) => format!(" {}", generate_type_id(type_id)),
+        None => String::new(),
+    };
+
+    generate_mermaid_type(&format!(
+        "+{}{}({}){}",
+        prefix, node.id, args, return_type_id
+    ))
+}
+
+fn generate_field(field: &StructFieldASTNode) -> String {
+    format!("{}: {}", field.name, generate_type_id(&field.type_id))
+}
+
+fn generate_enum_item(item: &EnumItemASTNode) -> String {
+    match item {
+        EnumItemASTNode::Empty { id, .. } => id.clone(),
+        EnumItemASTNode::Tuple { id, values, .. } => format!(
+            "{}({})",
+            id,
+            values
+                .iter()
+                .map(|value| generate_type_id(&value.type_id))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        EnumItemASTNode::Struct { id, fields, .. } => format!(
+            "{} {{ {} }}",
+            id,
+            fields
+                .iter()
+                .map(generate_field)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn generate_fn(node: &FnASTNode) -> String {
+    let args = node
+        .args
+        .iter()
+        .map(|arg| format!("{}: {}", arg.id, generate_type_id(&arg.type_id)))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let return_type_id = match &node.return_type_id {
+        Some(type_id) => format!(" -> {}", generate_type_id(type_id)),
+        None => String::new(),
+    };
+
+    if node.is_signal {
+        format!("signal {}{}", node.id, return_type_id)
+    } else if node.is_async {
+        format!("async fn {}({}){}", node.id, args, return_type_id)
+    } else {
+        format!("fn {}({}){}", node.id, args, return_type_id)
+    }
+}
+
+fn find_struct_edges(ast: &[ASTNode], node: &StructASTNode) -> Vec<Edge> {
+    let mut edges = vec![];
+
+    for field in &node.fields {
+        push_edges(ast, &mut edges, &node.id, &field.name, &field.type_id);
+    }
+
+    edges
+}
+
+fn find_enum_edges(ast: &[ASTNode], node: &EnumASTNode) -> Vec<Edge> {
+    let mut edges = vec![];
+
+    for item in &node.items {
+        match item {
+            EnumItemASTNode::Empty { .. } => (),
+            EnumItemASTNode::Tuple { id, values, .. } => {
+                for value in values {
+                    push_edges(ast, &mut edges, &node.id, id, &value.type_id);
+                }
+            }
+            EnumItemASTNode::Struct { id, fields, .. } => {
+                for field in fields {
+                    let label = format!("{}.{}", id, field.name);
+                    push_edges(ast, &mut edges, &node.id, &label, &field.type_id);
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+fn find_fn_edges(ast: &[ASTNode], node: &FnASTNode, from: &str) -> Vec<Edge> {
+    let mut edges = vec![];
+
+    for arg in &node.args {
+        push_edges(ast, &mut edges, from, &node.id, &arg.type_id);
+    }
+
+    if let Some(type_id) = &node.return_type_id {
+        push_edges(ast, &mut edges, from, &node.id, type_id);
+    }
+
+    edges
+}
+
+/// Edges to the structs and enums of the schema in `type_id`, unknown types
+/// have no node to point to.
+fn push_edges(
+    ast: &[ASTNode],
+    edges: &mut Vec<Edge>,
+    from: &str,
+    label: &str,
+    type_id: &TypeIDASTNode,
+) {
+    match type_id {
+        TypeIDASTNode::Generic { generics, .. } => {
+            for type_id in generics {
+                push_edges(ast, edges, from, label, type_id);
+            }
+        }
+        TypeIDASTNode::Other { id } => {
+            let is_defined =
+                ast::find_struct_node(ast, id).is_some() || ast::find_enum_node(ast, id).is_some();
+            let is_duplicate = edges
+                .iter()
+                .any(|edge| edge.from == from && edge.to == *id && edge.label == label);
+
+            if is_defined && !is_duplicate {
+                edges.push(Edge {
+                    from: from.to_string(),
+                    to: id.clone(),
+                    label: label.to_string(),
+                });
+            }
+        }
+        _ => (),
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Record labels also treat braces, bars and angle brackets as fields and
+/// ports.
+fn escape_record(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+
+    for c in escape_dot(text).chars() {
+        if matches!(c, '{' | '}' | '|' | '<' | '>') {
+            res.push('\\');
+        }
+
+        res.push(c);
+    }
+
+    res
+}
+
+fn namespace(ast: &[ASTNode]) -> Option<String> {
+    string_directive(ast, "namespace")
+}
+
+fn string_directive(ast: &[ASTNode], id: &str) -> Option<String> {
+    match ast::find_directive_value(ast, id) {
+        Some(ConstValueASTNode::Literal {
+            literal: Literal::StringLiteral(value),
+            ..
+        }) => Some(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    fn parse_resource(name: &str) -> Vec<ASTNode> {
+        let src = fs::read_to_string(format!("test_resources/{}.tpb", name)).unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        parse(&mut lexer)
+    }
+
+    fn read_target(name: &str) -> String {
+        fs::read_to_string(format!("test_resources/graph/{}", name)).unwrap()
+    }
+
+    #[test]
+    fn generate_dot_test() {
+        let ast = parse_resource("struct_nested");
+        let actual = generate_graph(&ast, GraphFormat::Dot);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_nested.dot").trim());
+    }
+
+    #[test]
+    fn generate_dot_rpc_test() {
+        let ast = parse_resource("doc_comments");
+        let actual = generate_graph(&ast, GraphFormat::Dot);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("doc_comments.dot").trim());
+    }
+
+    #[test]
+    fn generate_mermaid_test() {
+        let ast = parse_resource("struct_nested");
+        let actual = generate_graph(&ast, GraphFormat::Mermaid);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_nested.mmd").trim());
+    }
+
+    #[test]
+    fn generate_mermaid_rpc_test() {
+        let ast = parse_resource("doc_comments");
+        let actual = generate_graph(&ast, GraphFormat::Mermaid);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("doc_comments.mmd").trim());
+    }
+}
@@ -0,0 +1,410 @@
+//! JSON Schema (draft 2020-12) of the JSON form of the models, for clients
+//! validating the models they receive as JSON.
+//!
+//! Property names are the camelCase names of the Swift and Kotlin models,
+//! enums are adjacently tagged: the item id is in [`ENUM_TAG`] and its
+//! fields or tuple values in [`ENUM_CONTENT`], e.g.
+//! `{ "type": "Move", "value": { "x": 1.0, "y": 2.0 } }`. Tuple items with
+//! a single value store it without the array.
+//!
+//! The `encode` and `decode` commands read and print this form with
+//! [`crate::codec::JsonForm::Models`].
+
+use convert_case::{Case, Casing};
+use serde_json::{json, Map, Number, Value};
+
+use crate::{
+    ast::{
+        self, ASTNode, ConstValueASTNode, EnumASTNode, EnumItemASTNode, FnASTNode, StructASTNode,
+        StructFieldASTNode, TypeIDASTNode,
+    },
+    lexer::Literal,
+};
+
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Property with the id of the enum item.
+pub const ENUM_TAG: &str = "type";
+
+/// Property with the fields or values of the enum item.
+pub const ENUM_CONTENT: &str = "value";
+
+#[derive(Debug, Clone, Default)]
+pub struct JsonSchemaOptions {
+    /// Describe the arguments and results of RPC methods under `x-rpc`.
+    pub rpc: bool,
+}
+
+/// Returns the document with a definition per struct and enum in `$defs`,
+/// and the warnings about the types that can't be described.
+pub fn export_json_schema(ast: &[ASTNode], options: &JsonSchemaOptions) -> (Value, Vec<String>) {
+    let mut exporter = Exporter {
+        ast,
+        warnings: vec![],
+    };
+    let mut document = Map::new();
+    let mut defs = Map::new();
+    let mut doc_comments = vec![];
+
+    for node in ast {
+        match node {
+            ASTNode::Struct(node) => {
+                defs.insert(node.id.clone(), exporter.export_struct(node));
+            }
+            ASTNode::Enum(node) => {
+                defs.insert(node.id.clone(), exporter.export_enum(node));
+            }
+            ASTNode::DocComments { comments } => doc_comments.extend(comments.iter().cloned()),
+            _ => (),
+        }
+    }
+
+    document.insert(String::from("$schema"), json!(JSON_SCHEMA_DIALECT));
+
+    if let Some(namespace) = string_directive(ast, "namespace") {
+        document.insert(String::from("title"), json!(namespace));
+    }
+
+    insert_description(&mut document, &doc_comments);
+    document.insert(String::from("$defs"), Value::Object(defs));
+
+    if options.rpc && ast::contains_fn_nodes(ast) {
+        document.insert(String::from("x-rpc"), exporter.export_rpc());
+    }
+
+    (Value::Object(document), exporter.warnings)
+}
+
+struct Exporter<'a> {
+    ast: &'a [ASTNode],
+    warnings: Vec<String>,
+}
+
+impl Exporter<'_> {
+    fn export_struct(&mut self, node: &StructASTNode) -> Value {
+        let mut schema = self.export_fields(&node.id, &node.fields);
+        insert_description(&mut schema, &node.doc_comments);
+        Value::Object(schema)
+    }
+
+    fn export_enum(&mut self, node: &EnumASTNode) -> Value {
+        let mut schema = Map::new();
+        let items = node
+            .items
+            .iter()
+            .map(|item| self.export_enum_item(&node.id, item))
+            .collect::<Vec<Value>>();
+
+        insert_description(&mut schema, &node.doc_comments);
+        schema.insert(String::from("oneOf"), Value::Array(items));
+        schema.insert(
+            String::from("discriminator"),
+            json!({ "propertyName": ENUM_TAG }),
+        );
+
+        Value::Object(schema)
+    }
+
+    fn export_enum_item(&mut self, enum_id: &str, item: &EnumItemASTNode) -> Value {
+        let location = format!("{}::{}", enum_id, item.id());
+        let mut properties = Map::new();
+        let mut required = vec![json!(ENUM_TAG)];
+
+        properties.insert(String::from(ENUM_TAG), json!({ "const": item.id() }));
+
+        match item {
+            EnumItemASTNode::Empty { .. } => (),
+            EnumItemASTNode::Tuple { values, .. } => {
+                let mut values = values
+                    .iter()
+                    .map(|value| {
+                        let mut schema = self.export_type(&location, &value.type_id);
+                        insert_description(&mut schema, &value.doc_comments);
+                        Value::Object(schema)
+                    })
+                    .collect::<Vec<Value>>();
+
+                let content = if values.len() == 1 {
+                    values.remove(0)
+                } else {
+                    json!({
+                        "type": "array",
+                        "prefixItems": values,
+                        "items": false,
+                        "minItems": values.len(),
+                    })
+                };
+
+                properties.insert(String::from(ENUM_CONTENT), content);
+                required.push(json!(ENUM_CONTENT));
+            }
+            EnumItemASTNode::Struct { fields, .. } => {
+                let content = self.export_fields(&location, fields);
+                properties.insert(String::from(ENUM_CONTENT), Value::Object(content));
+                required.push(json!(ENUM_CONTENT));
+            }
+        }
+
+        let mut schema = Map::new();
+        insert_description(&mut schema, item.doc_comment());
+        schema.insert(String::from("type"), json!("object"));
+        schema.insert(String::from("properties"), Value::Object(properties));
+        schema.insert(String::from("required"), Value::Array(required));
+        schema.insert(String::from("additionalProperties"), json!(false));
+
+        Value::Object(schema)
+    }
+
+    /// Object with a required property per field, `None` is `null`.
+    fn export_fields(
+        &mut self,
+        location: &str,
+        fields: &[StructFieldASTNode],
+    ) -> Map<String, Value> {
+        let mut properties = Map::new();
+        let mut required = vec![];
+
+        for field in fields {
+            let name = field.name.to_case(Case::Camel);
+            let location = format!("{}.{}", location, field.name);
+            let mut schema = self.export_type(&location, &field.type_id);
+            insert_description(&mut schema, &field.doc_comments);
+            properties.insert(name.clone(), Value::Object(schema));
+            required.push(json!(name));
+        }
+
+        let mut schema = Map::new();
+        schema.insert(String::from("type"), json!("object"));
+        schema.insert(String::from("properties"), Value::Object(properties));
+        schema.insert(String::from("required"), Value::Array(required));
+        schema.insert(String::from("additionalProperties"), json!(false));
+
+        schema
+    }
+
+    fn export_type(&mut self, location: &str, type_id: &TypeIDASTNode) -> Map<String, Value> {
+        let schema = match type_id {
+            TypeIDASTNode::Integer { size, signed, .. } => {
+                let (minimum, maximum) = integer_range(*size, *signed);
+                json!({ "type": "integer", "minimum": minimum, "maximum": maximum })
+            }
+            TypeIDASTNode::Number { .. } => json!({ "type": "number" }),
+            TypeIDASTNode::Bool { .. } => json!({ "type": "boolean" }),
+            TypeIDASTNode::Char { .. } => {
+                json!({ "type": "string", "minLength": 1, "maxLength": 1 })
+            }
+            TypeIDASTNode::Generic { id, generics } => match (id.as_str(), generics.as_slice()) {
+                ("Vec", [item]) => {
+                    json!({ "type": "array", "items": self.export_type(location, item) })
+                }
+                ("Option", [value]) => {
+                    json!({ "anyOf": [self.export_type(location, value), { "type": "null" }] })
+                }
+                _ => {
+                    self.warnings.push(format!(
+                        "{}: generic type `{}` can't be described, any value is accepted",
+                        location, id
+                    ));
+                    json!({})
+                }
+            },
+            TypeIDASTNode::Other { id } => match id.as_str() {
+                "String" => json!({ "type": "string" }),
+                "GroupAddress" | "CommandsBufferAddress" => {
+                    let (minimum, maximum) = integer_range(8, false);
+                    json!({ "type": "integer", "minimum": minimum, "maximum": maximum })
+                }
+                id => {
+                    let is_defined = ast::find_struct_node(self.ast, id).is_some()
+                        || ast::find_enum_node(self.ast, id).is_some();
+
+                    if !is_defined {
+                        self.warnings.push(format!(
+                            "{}: `{}` is not defined in the schema",
+                            location, id
+                        ));
+                    }
+
+                    json!({ "$ref": format!("#/$defs/{}", id) })
+                }
+            },
+        };
+
+        match schema {
+            Value::Object(schema) => schema,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Arguments and results of the methods, as the `args` and `result` roots
+    /// of `encode` and `decode`.
+    fn export_rpc(&mut self) -> Value {
+        let mut methods = Map::new();
+
+        for node in ast::find_fn_nodes(self.ast) {
+            methods.insert(node.id.clone(), self.export_fn(node));
+        }
+
+        let mut rpc = Map::new();
+
+        if let Some(id) = string_directive(self.ast, "id") {
+            rpc.insert(String::from("id"), json!(id));
+        }
+
+        rpc.insert(String::from("methods"), Value::Object(methods));
+        Value::Object(rpc)
+    }
+
+    fn export_fn(&mut self, node: &FnASTNode) -> Value {
+        let mut method = Map::new();
+        let kind = if node.is_signal { "signal" } else { "fn" };
+
+        insert_description(&mut method, &node.doc_comments);
+        method.insert(String::from("kind"), json!(kind));
+        method.insert(String::from("async"), json!(node.is_async));
+
+        if !node.is_signal {
+            let fields = node
+                .args
+                .iter()
+                .map(|arg| StructFieldASTNode {
+                    doc_comments: vec![],
+                    position: 0,
+                    name: arg.id.clone(),
+                    type_id: arg.type_id.clone(),
+                })
+                .collect::<Vec<StructFieldASTNode>>();
+
+            let args = self.export_fields(&node.id, &fields);
+            method.insert(String::from("args"), Value::Object(args));
+        }
+
+        if let Some(return_type_id) = &node.return_type_id {
+            let result = self.export_type(&node.id, return_type_id);
+            method.insert(String::from("result"), Value::Object(result));
+        }
+
+        Value::Object(method)
+    }
+}
+
+/// Bounds of an integer of `size` bytes.
+fn integer_range(size: usize, signed: bool) -> (Number, Number) {
+    let bits = size as u32 * 8;
+
+    if signed {
+        let maximum = (1_i128 << (bits - 1)) - 1;
+        (
+            Number::from(-maximum as i64 - 1),
+            Number::from(maximum as i64),
+        )
+    } else {
+        (Number::from(0), Number::from(u64::MAX >> (64 - bits)))
+    }
+}
+
+/// Doc comments without the space after `///`, one line per comment.
+fn insert_description(schema: &mut Map<String, Value>, doc_comments: &[String]) {
+    if doc_comments.is_empty() {
+        return;
+    }
+
+    let description = doc_comments
+        .iter()
+        .map(|comment| comment.strip_prefix(' ').unwrap_or(comment))
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    schema.insert(String::from("description"), json!(description));
+}
+
+fn string_directive(ast: &[ASTNode], id: &str) -> Option<String> {
+    match ast::find_directive_value(ast, id) {
+        Some(ConstValueASTNode::Literal {
+            literal: Literal::StringLiteral(value),
+            ..
+        }) => Some(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    fn parse_resource(name: &str) -> Vec<ASTNode> {
+        let src = fs::read_to_string(format!("test_resources/{}.tpb", name)).unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        parse(&mut lexer)
+    }
+
+    fn read_target(name: &str) -> String {
+        fs::read_to_string(format!("test_resources/json_schema/{}.json", name)).unwrap()
+    }
+
+    #[test]
+    fn export_json_schema_struct_types_test() {
+        let ast = parse_resource("struct_types");
+        let (actual, warnings) = export_json_schema(&ast, &JsonSchemaOptions::default());
+        let actual = serde_json::to_string_pretty(&actual).unwrap();
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_types").trim());
+        assert_eq!(
+            warnings,
+            vec![
+                "BasicTypesModel.generic: generic type `LinearTable` can't be described, any \
+                 value is accepted",
+                "BasicTypesModel.custom: `MyModel` is not defined in the schema",
+            ]
+        );
+    }
+
+    #[test]
+    fn export_json_schema_enum_test() {
+        let ast = parse_resource("doc_comments");
+        let (actual, warnings) = export_json_schema(&ast, &JsonSchemaOptions::default());
+        let actual = serde_json::to_string_pretty(&actual).unwrap();
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("doc_comments").trim());
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn export_json_schema_rpc_test() {
+        let ast = parse_resource("rpc_methods");
+        let options = JsonSchemaOptions { rpc: true };
+        let (actual, warnings) = export_json_schema(&ast, &options);
+        let actual = serde_json::to_string_pretty(&actual).unwrap();
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_methods").trim());
+        assert_eq!(
+            warnings,
+            vec![
+                "theme: `Theme` is not defined in the schema",
+                "theme_async: `Theme` is not defined in the schema",
+            ]
+        );
+    }
+
+    #[test]
+    fn integer_range_test() {
+        assert_eq!(
+            integer_range(1, true),
+            (Number::from(-128), Number::from(127))
+        );
+        assert_eq!(
+            integer_range(1, false),
+            (Number::from(0), Number::from(255))
+        );
+        assert_eq!(
+            integer_range(8, true),
+            (Number::from(i64::MIN), Number::from(i64::MAX))
+        );
+        assert_eq!(
+            integer_range(8, false),
+            (Number::from(0), Number::from(u64::MAX))
+        );
+    }
+}
@@ -676,6 +676,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn generate_const_value_test_float32() {
         assert_eq!(
             generate_const_value(
@@ -712,6 +713,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn generate_const_value_test_float64() {
         assert_eq!(
             generate_const_value(
@@ -1,2 +1,3 @@
 pub mod generator;
 pub mod ir;
+pub mod test_vectors;
@@ -0,0 +1,231 @@
+use convert_case::{Case, Casing};
+use serde_json::Value;
+
+use crate::{
+    ast::{self, ASTNode, EnumItemASTNode, StructFieldASTNode, TypeIDASTNode},
+    codec::samples::TestVector,
+    writer::Writer,
+};
+
+/// Generates a JUnit test class that reads every vector and checks that both
+/// the expected and the read models are written back to the same bytes.
+pub fn generate_test_vectors(ast: &[ASTNode], vectors: &[TestVector]) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln("// GENERATED, DO NOT EDIT");
+    writer.writeln("");
+    writer.writeln("import org.junit.Assert.assertArrayEquals");
+    writer.writeln("import org.junit.Test");
+    writer.writeln("");
+    writer.writeln("class TestVectorsTest {");
+
+    for vector in vectors {
+        writer.writeln_tab(1, "@Test");
+        writer.writeln_tab(1, &format!("fun {}() {{", vector.name.to_case(Case::Camel)));
+        writer.writeln_tab(
+            2,
+            &format!("val data = bytesOf({})", generate_bytes(&vector.data)),
+        );
+        writer.writeln_tab(
+            2,
+            &format!(
+                "val expected = {}",
+                generate_value(
+                    ast,
+                    &TypeIDASTNode::Other {
+                        id: vector.type_id.clone()
+                    },
+                    &vector.value
+                )
+            ),
+        );
+        writer.writeln_tab(
+            2,
+            &format!(
+                "val value = {}.readFromBuffers(TechPawsBuffersBytesReader(data))",
+                vector.type_id
+            ),
+        );
+        writer.new_line();
+        writer.writeln_tab(2, "assertArrayEquals(data, writeVector(expected))");
+        writer.writeln_tab(2, "assertArrayEquals(data, writeVector(value))");
+        writer.writeln_tab(1, "}");
+        writer.new_line();
+    }
+
+    writer.writeln_tab(
+        1,
+        "private fun bytesOf(vararg values: Int): ByteArray = ByteArray(values.size) { values[it].toByte() }",
+    );
+    writer.new_line();
+    writer.writeln_tab(
+        1,
+        "private fun writeVector(value: TechPawsBuffersModel): ByteArray {",
+    );
+    writer.writeln_tab(2, "val bytesWriter = TechPawsBuffersBytesWriter()");
+    writer.writeln_tab(2, "value.writeToBuffers(bytesWriter)");
+    writer.writeln_tab(2, "return bytesWriter.toByteArray()");
+    writer.writeln_tab(1, "}");
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+fn generate_bytes(data: &[u8]) -> String {
+    data.iter()
+        .map(|byte| format!("0x{:02X}", byte))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn escape_char(c: char, quote: char, literal: &mut String) {
+    match c {
+        '\\' => literal.push_str("\\\\"),
+        '\n' => literal.push_str("\\n"),
+        '$' => literal.push_str("\\$"),
+        _ if c == quote => {
+            literal.push('\\');
+            literal.push(c);
+        }
+        ' '..='~' => literal.push(c),
+        _ => {
+            let mut units = [0; 2];
+
+            for unit in c.encode_utf16(&mut units) {
+                literal.push_str(&format!("\\u{:04X}", unit));
+            }
+        }
+    }
+}
+
+fn generate_string(value: &str) -> String {
+    let mut literal = String::from("\"");
+
+    for c in value.chars() {
+        escape_char(c, '"', &mut literal);
+    }
+
+    literal.push('"');
+    literal
+}
+
+fn generate_integer(size: usize, signed: bool, value: &Value) -> String {
+    match (size, signed) {
+        (4, true) if value.as_i64() == Some(i32::MIN as i64) => String::from("Int.MIN_VALUE"),
+        (8, true) if value.as_i64() == Some(i64::MIN) => String::from("Long.MIN_VALUE"),
+        (8, true) => format!("{}L", value),
+        (8, false) => format!("{}uL", value),
+        (_, true) => value.to_string(),
+        (_, false) => format!("{}u", value),
+    }
+}
+
+/// Kotlin expression of a value produced by the codec sampler.
+pub fn generate_value(ast: &[ASTNode], type_id: &TypeIDASTNode, value: &Value) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { size, signed, .. } => generate_integer(*size, *signed, value),
+        TypeIDASTNode::Number { size: 4, .. } => {
+            format!("{:?}f", value.as_f64().unwrap() as f32)
+        }
+        TypeIDASTNode::Number { .. } => format!("{:?}", value.as_f64().unwrap()),
+        TypeIDASTNode::Bool { .. } => value.to_string(),
+        TypeIDASTNode::Char { .. } => {
+            let mut literal = String::from("'");
+            escape_char(
+                value.as_str().unwrap().chars().next().unwrap(),
+                '\'',
+                &mut literal,
+            );
+            literal.push('\'');
+            literal
+        }
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => generate_string(value.as_str().unwrap()),
+            "GroupAddress" | "CommandsBufferAddress" => generate_integer(8, false, value),
+            _ => {
+                if let Some(node) = ast::find_struct_node(ast, id) {
+                    format!("{}({})", id, generate_fields(ast, &node.fields, value))
+                } else if let Some(node) = ast::find_enum_node(ast, id) {
+                    generate_enum_value(ast, id, &node.items, value)
+                } else {
+                    panic!("Unknown type: {}", id)
+                }
+            }
+        },
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Vec" => format!(
+                "listOf({})",
+                value
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|item| generate_value(ast, &generics[0], item))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            "Option" if value.is_null() => String::from("null"),
+            "Option" => generate_value(ast, &generics[0], value),
+            _ => panic!("Unsupported type: {}", id),
+        },
+    }
+}
+
+fn generate_fields(ast: &[ASTNode], fields: &[StructFieldASTNode], value: &Value) -> String {
+    fields
+        .iter()
+        .map(|field| generate_value(ast, &field.type_id, &value[&field.name]))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn generate_enum_value(
+    ast: &[ASTNode],
+    id: &str,
+    items: &[EnumItemASTNode],
+    value: &Value,
+) -> String {
+    let (item_id, item_value) = match value {
+        Value::String(item_id) => (item_id.as_str(), &Value::Null),
+        Value::Object(values) => {
+            let (item_id, item_value) = values.iter().next().unwrap();
+            (item_id.as_str(), item_value)
+        }
+        _ => panic!("Invalid enum value: {}", value),
+    };
+    let case_id = format!("{}{}", id, item_id);
+
+    match items.iter().find(|item| item.id() == item_id).unwrap() {
+        EnumItemASTNode::Empty { .. } => case_id,
+        EnumItemASTNode::Tuple { values, .. } => format!(
+            "{}({})",
+            case_id,
+            values
+                .iter()
+                .zip(item_value.as_array().unwrap())
+                .map(|(tuple_value, item)| generate_value(ast, &tuple_value.type_id, item))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        EnumItemASTNode::Struct { fields, .. } => {
+            format!("{}({})", case_id, generate_fields(ast, fields, item_value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{codec::samples, lexer::Lexer, parser::parse};
+    use std::fs;
+
+    #[test]
+    fn generate_test_vectors_test() {
+        let src = fs::read_to_string("test_resources/test_vectors.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/kotlin/test_vectors.kt").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let (vectors, _) = samples::generate_test_vectors(&ast, 0, 1);
+        let actual = generate_test_vectors(&ast, &vectors);
+        assert_eq!(actual.trim(), target.trim());
+    }
+}
@@ -0,0 +1,305 @@
+//! Code generator for Tech Paws Buffers.
+//!
+//! Besides the command line tool, the crate can be used from build scripts:
+//!
+//! ```no_run
+//! // build.rs
+//! tech_paws_buffers_generator::Config::new()
+//!     .schema("schema/editor.tpb")
+//!     .compile()
+//!     .unwrap();
+//! ```
+//!
+//! ```ignore
+//! // src/buffers.rs
+//! include!(concat!(env!("OUT_DIR"), "/editor.rs"));
+//! ```
+
+pub mod ast;
+pub mod c;
+pub mod codec;
+pub mod csharp;
+pub mod dart;
+pub mod docs;
+pub mod formatter;
+pub mod go;
+pub mod graph;
+pub mod json_schema;
+pub mod kotlin;
+pub mod lexer;
+pub mod lsp;
+pub mod options;
+pub mod parser;
+pub mod payload;
+pub mod plugin;
+pub mod proto;
+pub mod python;
+pub mod rust;
+pub mod rust_generator;
+pub mod swift;
+pub mod typescript;
+pub mod validate;
+pub mod writer;
+
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::clap_derive::ArgEnum;
+
+use options::GeneratorOptions;
+
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Lang {
+    Rust,
+    Swift,
+    Kotlin,
+    Typescript,
+    C,
+    Python,
+    Csharp,
+    Go,
+}
+
+impl Lang {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Lang::Rust => "rs",
+            Lang::Swift => "swift",
+            Lang::Kotlin => "kt",
+            Lang::Typescript => "ts",
+            Lang::C => "h",
+            Lang::Python => "py",
+            Lang::Csharp => "cs",
+            Lang::Go => "go",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse {
+        path: Option<PathBuf>,
+        error: parser::ParseError,
+    },
+    /// Invalid schema detected by a generator, e.g. a missing `#[id]`.
+    Generate(String),
+    Unsupported(Lang),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Parse {
+                path: Some(path),
+                error,
+            } => write!(f, "{}:{}", path.display(), error),
+            Error::Parse { path: None, error } => write!(f, "{}", error),
+            Error::Generate(message) => write!(f, "{}", message),
+            Error::Unsupported(lang) => write!(f, "{:?} generator is not implemented", lang),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Parses a schema file.
+pub fn parse_file(input: impl AsRef<Path>) -> Result<Vec<ast::ASTNode>, Error> {
+    let input = input.as_ref();
+    let src = fs::read_to_string(input)?;
+
+    parser::parse_source(&src).map_err(|error| Error::Parse {
+        path: Some(input.to_path_buf()),
+        error,
+    })
+}
+
+/// Generates code for the schema at `input`.
+pub fn generate_file(input: impl AsRef<Path>, lang: Lang) -> Result<String, Error> {
+    generate_ast(&parse_file(input)?, lang)
+}
+
+pub fn generate_ast(ast: &[ast::ASTNode], lang: Lang) -> Result<String, Error> {
+    generate_ast_with_options(ast, lang, &GeneratorOptions::default())
+}
+
+pub fn generate_ast_with_options(
+    ast: &[ast::ASTNode],
+    lang: Lang,
+    options: &GeneratorOptions,
+) -> Result<String, Error> {
+    let generate = match lang {
+        Lang::Rust => rust_generator::generate_with_options,
+        Lang::Swift => swift::generate_with_options,
+        Lang::Kotlin => return Err(Error::Unsupported(lang)),
+        Lang::Typescript => typescript::generate_with_options,
+        Lang::C => c::generate_with_options,
+        Lang::Python => python::generate_with_options,
+        Lang::Csharp => csharp::generate_with_options,
+        Lang::Go => go::generate_with_options,
+    };
+
+    parser::catch_panic(|| generate(ast, options)).map_err(Error::Generate)
+}
+
+/// Build script helper generating code for schemas into `OUT_DIR`.
+#[derive(Debug, Clone)]
+pub struct Config {
+    schemas: Vec<PathBuf>,
+    lang: Lang,
+    out_dir: Option<PathBuf>,
+    options: GeneratorOptions,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            schemas: vec![],
+            lang: Lang::Rust,
+            out_dir: None,
+            options: GeneratorOptions::default(),
+        }
+    }
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn schema(mut self, path: impl AsRef<Path>) -> Self {
+        self.schemas.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn lang(mut self, lang: Lang) -> Self {
+        self.lang = lang;
+        self
+    }
+
+    pub fn options(mut self, options: GeneratorOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Overrides `OUT_DIR`, e.g. to generate outside of a build script.
+    pub fn out_dir(mut self, path: impl AsRef<Path>) -> Self {
+        self.out_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Generates `<schema name>.<ext>` for every schema and emits
+    /// `cargo:rerun-if-changed` for them. Rust files are generated
+    /// without inner attributes, so they can be used with `include!`.
+    pub fn compile(&self) -> Result<Vec<PathBuf>, Error> {
+        let out_dir = match &self.out_dir {
+            Some(out_dir) => out_dir.clone(),
+            None => PathBuf::from(
+                env::var_os("OUT_DIR")
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "OUT_DIR is not set"))?,
+            ),
+        };
+
+        let mut paths = vec![];
+
+        for schema in &self.schemas {
+            println!("cargo:rerun-if-changed={}", schema.display());
+
+            let ast = parse_file(schema)?;
+            let data = match self.lang {
+                Lang::Rust => {
+                    for warning in rust_generator::find_skipped_types(&ast) {
+                        println!("cargo:warning={}: {}", schema.display(), warning);
+                    }
+
+                    let items = parser::catch_panic(|| {
+                        rust_generator::generate_items_with_options(&ast, &self.options)
+                    })
+                    .map_err(Error::Generate)?;
+                    let items = writer::reindent(&items, self.options.indent());
+
+                    match self.options.header() {
+                        "" => items,
+                        header => format!("{}\n\n{}", header, items),
+                    }
+                }
+                lang => generate_ast_with_options(&ast, lang, &self.options)?,
+            };
+
+            let file_stem = schema.file_stem().unwrap_or(schema.as_os_str());
+            let path = out_dir
+                .join(file_stem)
+                .with_extension(self.lang.extension());
+
+            fs::write(&path, data)?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compile_schemas() {
+        let out_dir = env::temp_dir().join("tech_paws_buffers_generator_compile_schemas");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        let paths = Config::new()
+            .schema("test_resources/struct_basic.tpb")
+            .out_dir(&out_dir)
+            .compile()
+            .unwrap();
+
+        assert_eq!(paths, vec![out_dir.join("struct_basic.rs")]);
+
+        let data = fs::read_to_string(&paths[0]).unwrap();
+
+        assert!(data.starts_with("// GENERATED, DO NOT EDIT\n\nuse tech_paws_buffers::memory::"));
+        assert!(!data.contains("#!["));
+    }
+
+    #[test]
+    fn generate_file_parse_error() {
+        let out_dir = env::temp_dir().join("tech_paws_buffers_generator_parse_error");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        let path = out_dir.join("invalid.tpb");
+        fs::write(&path, "struct Point {\n    x f32,\n}").unwrap();
+
+        let err = generate_file(&path, Lang::Rust).unwrap_err();
+
+        assert!(
+            err.to_string()
+                .starts_with(&format!("{}:2:", path.display())),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn generate_file_generator_error() {
+        let out_dir = env::temp_dir().join("tech_paws_buffers_generator_generator_error");
+        fs::create_dir_all(&out_dir).unwrap();
+
+        let path = out_dir.join("missing_id.tpb");
+        fs::write(&path, "#[namespace = \"test\"]\n\nfn hello();").unwrap();
+
+        let err = generate_file(&path, Lang::Swift).unwrap_err();
+
+        assert!(matches!(err, Error::Generate(_)), "{}", err);
+        assert_eq!(err.to_string(), "id is required");
+    }
+}
@@ -0,0 +1,659 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    formatter::syntax::{self, SyntaxKind, SyntaxToken},
+    parser,
+    validate::{self, Namespace},
+};
+
+pub const BUILTIN_TYPES: [&str; 13] = [
+    "i8", "i32", "i64", "u8", "u32", "u64", "f32", "f64", "bool", "char", "String", "Vec", "Option",
+];
+
+pub const DIRECTIVES: [&str; 8] = [
+    "memory",
+    "rust",
+    "swift",
+    "dart",
+    "kotlin",
+    "go",
+    "id",
+    "namespace",
+];
+
+pub const MEMORY_FLAGS: [&str; 2] = ["copy", "emplace"];
+
+/// Zero-based line and UTF-16 column, as in the protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Position {
+    pub line: u32,
+    pub character: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Range {
+    pub fn contains(&self, position: Position) -> bool {
+        self.start <= position && position <= self.end
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Struct,
+    Enum,
+    Fn,
+    Signal,
+    Const,
+    ConstValue,
+}
+
+impl SymbolKind {
+    fn from_keyword(keyword: &str) -> Option<SymbolKind> {
+        match keyword {
+            "struct" => Some(SymbolKind::Struct),
+            "enum" => Some(SymbolKind::Enum),
+            "fn" => Some(SymbolKind::Fn),
+            "signal" => Some(SymbolKind::Signal),
+            "const" => Some(SymbolKind::Const),
+            _ => None,
+        }
+    }
+
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            SymbolKind::Struct => "struct",
+            SymbolKind::Enum => "enum",
+            SymbolKind::Fn => "fn",
+            SymbolKind::Signal => "signal",
+            SymbolKind::Const | SymbolKind::ConstValue => "const",
+        }
+    }
+
+    pub fn is_type(&self) -> bool {
+        matches!(self, SymbolKind::Struct | SymbolKind::Enum)
+    }
+
+    fn namespace(&self) -> Namespace {
+        match self {
+            SymbolKind::Struct | SymbolKind::Enum => Namespace::Type,
+            SymbolKind::Fn | SymbolKind::Signal => Namespace::Method,
+            SymbolKind::Const | SymbolKind::ConstValue => Namespace::Const,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub docs: Vec<String>,
+    /// From the first keyword to the closing `}` or `;`.
+    pub range: Range,
+    /// The name of the symbol.
+    pub selection_range: Range,
+    /// Nested const blocks and values.
+    pub children: Vec<Symbol>,
+}
+
+/// A type name used by a field, an enum item, a generic argument or an
+/// RPC signature.
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub name: String,
+    pub range: Range,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error = 1,
+    Warning = 2,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub range: Range,
+    pub severity: Severity,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionContext {
+    Type,
+    Directive,
+    MemoryFlag,
+    None,
+}
+
+/// What the language server knows about a single document. Symbols and
+/// references come from the lossless tokens, so they are available even
+/// when the document doesn't parse.
+#[derive(Debug)]
+pub struct Analysis {
+    pub symbols: Vec<Symbol>,
+    pub references: Vec<Reference>,
+    /// Problems found in the document alone, unknown types depend on the
+    /// other documents, see [`Analysis::unknown_types`].
+    pub diagnostics: Vec<Diagnostic>,
+    parsed: bool,
+    tokens: Vec<SyntaxToken>,
+    lines: Vec<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Scope {
+    Struct,
+    Enum,
+    Const,
+    Other,
+}
+
+pub fn analyze(src: &str) -> Analysis {
+    let mut analysis = Analysis {
+        symbols: vec![],
+        references: vec![],
+        diagnostics: vec![],
+        parsed: false,
+        tokens: syntax::tokenize(src),
+        lines: src.split('\n').map(String::from).collect(),
+    };
+
+    analysis.scan();
+    analysis.validate(src);
+    analysis
+}
+
+impl Analysis {
+    fn scan(&mut self) {
+        let tokens = std::mem::take(&mut self.tokens);
+        let mut scopes: Vec<Scope> = vec![];
+        // Symbols waiting for their `}` or `;` with the scope depth they
+        // were opened at.
+        let mut open: Vec<(Symbol, usize)> = vec![];
+        let mut docs = vec![];
+        let mut start = None;
+        let mut next_scope = Scope::Other;
+        let mut expect_type = false;
+        let mut angle_depth = 0usize;
+        let mut paren_depth = 0usize;
+        let mut i = 0;
+
+        while i < tokens.len() {
+            let token = &tokens[i];
+            let next = tokens.get(i + 1);
+
+            match token.kind {
+                SyntaxKind::EOF => break,
+                SyntaxKind::DocComment => {
+                    docs.push(doc_comment_text(&token.text));
+                    i += 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            if token.is("#") && next.is_some_and(|next| next.is("[")) {
+                i = skip_directive(&tokens, i + 1);
+                continue;
+            }
+
+            let in_type = expect_type
+                || angle_depth > 0
+                || (paren_depth > 0 && scopes.last() == Some(&Scope::Enum));
+
+            expect_type = false;
+
+            if token.kind == SyntaxKind::Ident {
+                let definition = SymbolKind::from_keyword(&token.text).zip(next);
+
+                if let Some((kind, name)) =
+                    definition.filter(|(_, name)| name.kind == SyntaxKind::Ident)
+                {
+                    let start = start
+                        .take()
+                        .unwrap_or_else(|| self.token_range(token).start);
+                    let selection_range = self.token_range(name);
+
+                    next_scope = match kind {
+                        SymbolKind::Struct => Scope::Struct,
+                        SymbolKind::Enum => Scope::Enum,
+                        SymbolKind::Const => Scope::Const,
+                        _ => Scope::Other,
+                    };
+
+                    let symbol = Symbol {
+                        name: name.text.clone(),
+                        kind,
+                        docs: std::mem::take(&mut docs),
+                        range: Range {
+                            start,
+                            end: selection_range.end,
+                        },
+                        selection_range,
+                        children: vec![],
+                    };
+
+                    open.push((symbol, scopes.len()));
+                    i += 2;
+                    continue;
+                }
+
+                if token.text == "async" {
+                    start = Some(self.token_range(token).start);
+                    i += 1;
+                    continue;
+                }
+
+                if in_type {
+                    self.references.push(Reference {
+                        name: token.text.clone(),
+                        range: self.token_range(token),
+                    });
+                } else if scopes.last() == Some(&Scope::Const)
+                    && next.is_some_and(|next| next.is(":"))
+                {
+                    let range = self.token_range(token);
+                    let symbol = Symbol {
+                        name: token.text.clone(),
+                        kind: SymbolKind::ConstValue,
+                        docs: std::mem::take(&mut docs),
+                        range,
+                        selection_range: range,
+                        children: vec![],
+                    };
+
+                    match open.last_mut() {
+                        Some((parent, _)) => parent.children.push(symbol),
+                        None => self.symbols.push(symbol),
+                    }
+                }
+            } else if token.kind == SyntaxKind::Symbol {
+                let end = self.token_range(token).end;
+
+                match token.text.as_str() {
+                    ":" => expect_type = true,
+                    "-" if next.is_some_and(|next| next.is(">")) => {
+                        expect_type = true;
+                        i += 2;
+                        continue;
+                    }
+                    "<" => angle_depth += 1,
+                    ">" => angle_depth = angle_depth.saturating_sub(1),
+                    "(" => paren_depth += 1,
+                    ")" => paren_depth = paren_depth.saturating_sub(1),
+                    "{" => {
+                        scopes.push(next_scope);
+                        next_scope = Scope::Other;
+                    }
+                    "}" => {
+                        scopes.pop();
+                        self.close(&mut open, scopes.len(), end);
+                    }
+                    ";" => self.close(&mut open, scopes.len(), end),
+                    _ => {}
+                }
+            }
+
+            docs.clear();
+            start = None;
+            i += 1;
+        }
+
+        let end = self.token_range(&tokens[tokens.len() - 1]).end;
+        self.close(&mut open, 0, end);
+        self.tokens = tokens;
+    }
+
+    fn close(&mut self, open: &mut Vec<(Symbol, usize)>, depth: usize, end: Position) {
+        while open
+            .last()
+            .is_some_and(|(_, open_depth)| *open_depth >= depth)
+        {
+            let (mut symbol, _) = open.pop().unwrap();
+            symbol.range.end = end;
+
+            match open.last_mut() {
+                Some((parent, _)) => parent.children.push(symbol),
+                None => self.symbols.push(symbol),
+            }
+        }
+    }
+
+    fn validate(&mut self, src: &str) {
+        let ast = match parser::parse_source(src) {
+            Ok(ast) => ast,
+            Err(err) => {
+                let position = self.position(err.line, err.pos);
+                let range = self
+                    .tokens
+                    .iter()
+                    .find(|token| token.line == err.line && token.pos == err.pos)
+                    .map(|token| self.token_range(token))
+                    .unwrap_or(Range {
+                        start: position,
+                        end: position,
+                    });
+
+                self.diagnostics.push(Diagnostic {
+                    range,
+                    severity: Severity::Error,
+                    message: err.message,
+                });
+                return;
+            }
+        };
+
+        self.parsed = true;
+
+        for error in validate::validate(&ast) {
+            let symbol = self
+                .symbols
+                .iter()
+                .filter(|symbol| symbol.kind.namespace() == error.namespace)
+                .filter(|symbol| symbol.name == error.id)
+                .nth(error.occurrence);
+
+            if let Some(symbol) = symbol {
+                self.diagnostics.push(Diagnostic {
+                    range: symbol.selection_range,
+                    severity: Severity::Error,
+                    message: error.message,
+                });
+            }
+        }
+    }
+
+    /// Warnings for referenced types that are neither built in nor known
+    /// to `is_known`. Documents that don't parse are skipped to avoid noise
+    /// while typing.
+    pub fn unknown_types(&self, is_known: impl Fn(&str) -> bool) -> Vec<Diagnostic> {
+        if !self.parsed {
+            return vec![];
+        }
+
+        self.references
+            .iter()
+            .filter(|reference| !BUILTIN_TYPES.contains(&reference.name.as_str()))
+            .filter(|reference| !is_known(&reference.name))
+            .map(|reference| Diagnostic {
+                range: reference.range,
+                severity: Severity::Warning,
+                message: format!("unknown type `{}`", reference.name),
+            })
+            .collect()
+    }
+
+    /// Top level symbol with the given name.
+    pub fn symbol_named(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.iter().find(|symbol| symbol.name == name)
+    }
+
+    pub fn references_to<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Reference> {
+        self.references
+            .iter()
+            .filter(move |reference| reference.name == name)
+    }
+
+    /// The identifier under or right before the cursor.
+    pub fn ident_at(&self, position: Position) -> Option<&str> {
+        self.tokens
+            .iter()
+            .filter(|token| token.kind == SyntaxKind::Ident)
+            .find(|token| self.token_range(token).contains(position))
+            .map(|token| token.text.as_str())
+    }
+
+    pub fn completion_context(&self, position: Position) -> CompletionContext {
+        let (line, pos) = self.offset(position);
+        let before: Vec<&SyntaxToken> = self
+            .tokens
+            .iter()
+            .filter(|token| token.kind != SyntaxKind::EOF)
+            .filter(|token| (token.line, token.pos + token.text.chars().count()) <= (line, pos))
+            .collect();
+
+        let mut brackets = 0;
+        let mut parens = 0;
+        let mut group = None;
+
+        for (i, token) in before.iter().enumerate().rev() {
+            if token.kind != SyntaxKind::Symbol {
+                continue;
+            }
+
+            match token.text.as_str() {
+                "]" => brackets += 1,
+                ")" => parens += 1,
+                "(" if parens > 0 => parens -= 1,
+                "(" if group.is_none() && i > 0 => group = Some(before[i - 1].text.as_str()),
+                "(" => {}
+                "[" if brackets > 0 => brackets -= 1,
+                "[" => {
+                    if i == 0 || !before[i - 1].is("#") {
+                        return CompletionContext::Type;
+                    }
+
+                    return match group {
+                        None => CompletionContext::Directive,
+                        Some("memory") => CompletionContext::MemoryFlag,
+                        Some(_) => CompletionContext::None,
+                    };
+                }
+                "{" | "}" | ";" => break,
+                _ => {}
+            }
+        }
+
+        CompletionContext::Type
+    }
+
+    /// Converts a 1-based line and a column counted in chars, as the lexer
+    /// reports them, to a protocol position.
+    fn position(&self, line: usize, pos: usize) -> Position {
+        let line = line.saturating_sub(1);
+        let character = match self.lines.get(line) {
+            Some(text) => text.chars().take(pos).map(char::len_utf16).sum(),
+            None => pos,
+        };
+
+        Position {
+            line: line as u32,
+            character: character as u32,
+        }
+    }
+
+    /// The reverse of [`Analysis::position`].
+    fn offset(&self, position: Position) -> (usize, usize) {
+        let mut units = 0;
+        let mut pos = 0;
+
+        if let Some(text) = self.lines.get(position.line as usize) {
+            for ch in text.chars() {
+                if units >= position.character as usize {
+                    break;
+                }
+
+                units += ch.len_utf16();
+                pos += 1;
+            }
+        }
+
+        (position.line as usize + 1, pos)
+    }
+
+    fn token_range(&self, token: &SyntaxToken) -> Range {
+        Range {
+            start: self.position(token.line, token.pos),
+            end: self.position(token.line, token.pos + token.text.chars().count()),
+        }
+    }
+}
+
+fn doc_comment_text(text: &str) -> String {
+    let text = text.trim_start_matches("///");
+    text.strip_prefix(' ').unwrap_or(text).to_string()
+}
+
+/// Returns the index after the `]` matching the `[` at `open`.
+fn skip_directive(tokens: &[SyntaxToken], open: usize) -> usize {
+    let mut depth = 0;
+
+    for (i, token) in tokens.iter().enumerate().skip(open) {
+        if token.is("[") {
+            depth += 1;
+        } else if token.is("]") {
+            depth -= 1;
+
+            if depth == 0 {
+                return i + 1;
+            }
+        } else if token.kind == SyntaxKind::EOF {
+            return i;
+        }
+    }
+
+    tokens.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SRC: &str = "\
+/// A point.
+#[memory(copy)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+enum Shape {
+    Dot(Point),
+    Line { from: Point, to: Point },
+}
+
+/// Draws shapes.
+async fn draw(shapes: Vec<Shape>) -> Option<Point>;
+
+const Limits {
+    max: i32 = 10;
+    const Inner {
+        min: i32 = 0;
+    }
+}
+";
+
+    fn position(line: u32, character: u32) -> Position {
+        Position { line, character }
+    }
+
+    #[test]
+    fn symbols_test() {
+        let analysis = analyze(SRC);
+        let names: Vec<(&str, SymbolKind)> = analysis
+            .symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind))
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                ("Point", SymbolKind::Struct),
+                ("Shape", SymbolKind::Enum),
+                ("draw", SymbolKind::Fn),
+                ("Limits", SymbolKind::Const),
+            ]
+        );
+
+        let point = &analysis.symbols[0];
+        assert_eq!(point.docs, vec!["A point."]);
+        assert_eq!(point.range.start, position(2, 0));
+        assert_eq!(point.range.end, position(5, 1));
+        assert_eq!(point.selection_range.start, position(2, 7));
+
+        let draw = &analysis.symbols[2];
+        assert_eq!(draw.docs, vec!["Draws shapes."]);
+        assert_eq!(draw.range.start, position(13, 0));
+        assert_eq!(draw.range.end, position(13, 51));
+
+        let limits = &analysis.symbols[3];
+        assert_eq!(limits.children.len(), 2);
+        assert_eq!(limits.children[0].name, "max");
+        assert_eq!(limits.children[1].name, "Inner");
+        assert_eq!(limits.children[1].children[0].name, "min");
+        assert!(analysis.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn references_test() {
+        let analysis = analyze(SRC);
+        let points: Vec<Position> = analysis
+            .references_to("Point")
+            .map(|reference| reference.range.start)
+            .collect();
+
+        assert_eq!(
+            points,
+            vec![
+                position(8, 8),
+                position(9, 17),
+                position(9, 28),
+                position(13, 44)
+            ]
+        );
+        assert_eq!(analysis.references_to("Shape").count(), 1);
+        assert_eq!(analysis.references_to("x").count(), 0);
+        assert_eq!(analysis.ident_at(position(8, 10)), Some("Point"));
+        assert_eq!(analysis.ident_at(position(8, 13)), Some("Point"));
+    }
+
+    #[test]
+    fn diagnostics_test() {
+        let analysis = analyze("struct A {\n    a i32,\n}\n");
+        assert_eq!(analysis.diagnostics.len(), 1);
+        assert_eq!(analysis.diagnostics[0].range.start, position(1, 6));
+        assert_eq!(analysis.diagnostics[0].range.end, position(1, 9));
+
+        let analysis = analyze("struct A {\n    a: i32,\n    a: B,\n}\n\nenum A { X }\n");
+        let messages: Vec<&str> = analysis
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.as_str())
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "`A` is defined more than once",
+                "`a` is defined more than once in `A`",
+            ]
+        );
+
+        let unknown = analysis.unknown_types(|_| false);
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(unknown[0].message, "unknown type `B`");
+        assert!(analysis.unknown_types(|name| name == "B").is_empty());
+    }
+
+    #[test]
+    fn completion_context_test() {
+        let analysis = analyze("#[memory(co)]\nstruct A {\n    a: Po\n}\n#[ru");
+        assert_eq!(
+            analysis.completion_context(position(0, 11)),
+            CompletionContext::MemoryFlag
+        );
+        assert_eq!(
+            analysis.completion_context(position(2, 9)),
+            CompletionContext::Type
+        );
+        assert_eq!(
+            analysis.completion_context(position(4, 4)),
+            CompletionContext::Directive
+        );
+    }
+}
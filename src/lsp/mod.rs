@@ -0,0 +1,633 @@
+//! Language server for `.tpb` files over stdio.
+//!
+//! Supports diagnostics, go to definition, find references, hover,
+//! completion and document symbols. Documents are synced in full, type
+//! names are resolved across all open documents.
+
+pub mod analysis;
+
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, Write},
+};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use analysis::{Analysis, CompletionContext, Position, Range, Symbol, SymbolKind};
+
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+#[derive(Debug, Serialize)]
+struct Location<'a> {
+    uri: &'a str,
+    range: Range,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DocumentSymbol {
+    name: String,
+    detail: String,
+    kind: u32,
+    range: Range,
+    selection_range: Range,
+    children: Vec<DocumentSymbol>,
+}
+
+#[derive(Debug, Serialize)]
+struct Diagnostic<'a> {
+    range: Range,
+    severity: u32,
+    source: &'static str,
+    message: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct CompletionItem {
+    label: String,
+    kind: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    documentation: Option<Value>,
+}
+
+/// Completion item kinds of the protocol.
+const COMPLETION_KEYWORD: u32 = 14;
+const COMPLETION_STRUCT: u32 = 22;
+const COMPLETION_ENUM: u32 = 13;
+const COMPLETION_PROPERTY: u32 = 10;
+
+#[derive(Default)]
+pub struct Server {
+    /// Open documents by URI.
+    documents: BTreeMap<String, Analysis>,
+    shutdown: bool,
+    exit_code: Option<i32>,
+}
+
+/// Serves requests from `reader` until the client sends `exit` or closes
+/// the stream. Returns the process exit code.
+pub fn run(mut reader: impl BufRead, mut writer: impl Write) -> io::Result<i32> {
+    let mut server = Server::default();
+
+    while let Some(message) = read_message(&mut reader)? {
+        for response in server.handle(message) {
+            write_message(&mut writer, &response)?;
+        }
+
+        if let Some(code) = server.exit_code {
+            return Ok(code);
+        }
+    }
+
+    Ok(if server.shutdown { 0 } else { 1 })
+}
+
+/// Reads a message framed with a `Content-Length` header.
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+
+    loop {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+
+    let content_length = content_length.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "missing Content-Length header")
+    })?;
+
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+
+    serde_json::from_slice(&content)
+        .map(Some)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let content = message.to_string();
+    write!(
+        writer,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )?;
+    writer.flush()
+}
+
+impl Server {
+    /// Handles a request or a notification and returns the messages to
+    /// send back.
+    pub fn handle(&mut self, message: Value) -> Vec<Value> {
+        let method = match message.get("method").and_then(Value::as_str) {
+            Some(method) => method,
+            // Responses, the server doesn't send requests.
+            None => return vec![],
+        };
+
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        match message.get("id") {
+            Some(id) => {
+                let response = match self.request(method, &params) {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err((code, message)) => json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": { "code": code, "message": message },
+                    }),
+                };
+
+                vec![response]
+            }
+            None => self.notification(method, &params),
+        }
+    }
+
+    fn request(&mut self, method: &str, params: &Value) -> Result<Value, (i64, String)> {
+        match method {
+            "initialize" => Ok(json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "definitionProvider": true,
+                    "referencesProvider": true,
+                    "hoverProvider": true,
+                    "completionProvider": { "triggerCharacters": ["[", "(", ":", "<"] },
+                    "documentSymbolProvider": true,
+                },
+                "serverInfo": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+            })),
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Value::Null)
+            }
+            "textDocument/definition" => {
+                let (uri, position) = text_document_position(params)?;
+                Ok(self.definition(uri, position))
+            }
+            "textDocument/references" => {
+                let (uri, position) = text_document_position(params)?;
+                let include_declaration = params["context"]["includeDeclaration"]
+                    .as_bool()
+                    .unwrap_or(true);
+                Ok(self.references(uri, position, include_declaration))
+            }
+            "textDocument/hover" => {
+                let (uri, position) = text_document_position(params)?;
+                Ok(self.hover(uri, position))
+            }
+            "textDocument/completion" => {
+                let (uri, position) = text_document_position(params)?;
+                Ok(self.completion(uri, position))
+            }
+            "textDocument/documentSymbol" => {
+                let uri = text_document_uri(params)?;
+                Ok(self.document_symbols(uri))
+            }
+            _ => Err((METHOD_NOT_FOUND, format!("unknown method {}", method))),
+        }
+    }
+
+    fn notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        match method {
+            "exit" => {
+                self.exit_code = Some(if self.shutdown { 0 } else { 1 });
+                vec![]
+            }
+            "textDocument/didOpen" => {
+                let document = &params["textDocument"];
+
+                match (document["uri"].as_str(), document["text"].as_str()) {
+                    (Some(uri), Some(text)) => {
+                        self.documents
+                            .insert(uri.to_string(), analysis::analyze(text));
+                        self.publish_diagnostics()
+                    }
+                    _ => vec![],
+                }
+            }
+            "textDocument/didChange" => {
+                let uri = params["textDocument"]["uri"].as_str();
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str());
+
+                match (uri, text) {
+                    (Some(uri), Some(text)) => {
+                        self.documents
+                            .insert(uri.to_string(), analysis::analyze(text));
+                        self.publish_diagnostics()
+                    }
+                    _ => vec![],
+                }
+            }
+            "textDocument/didClose" => match params["textDocument"]["uri"].as_str() {
+                Some(uri) => {
+                    self.documents.remove(uri);
+                    let mut messages = vec![publish_diagnostics(uri, vec![])];
+                    messages.extend(self.publish_diagnostics());
+                    messages
+                }
+                None => vec![],
+            },
+            _ => vec![],
+        }
+    }
+
+    /// Diagnostics of every open document, a change in one document can
+    /// define or remove types used by the others.
+    fn publish_diagnostics(&self) -> Vec<Value> {
+        self.documents
+            .iter()
+            .map(|(uri, document)| {
+                let unknown = document.unknown_types(|name| self.find_type(name).is_some());
+                let diagnostics = document
+                    .diagnostics
+                    .iter()
+                    .chain(unknown.iter())
+                    .map(|diagnostic| Diagnostic {
+                        range: diagnostic.range,
+                        severity: diagnostic.severity as u32,
+                        source: "tpb",
+                        message: &diagnostic.message,
+                    })
+                    .map(|diagnostic| serde_json::to_value(diagnostic).unwrap())
+                    .collect();
+
+                publish_diagnostics(uri, diagnostics)
+            })
+            .collect()
+    }
+
+    /// Looks up a struct or an enum in the open documents.
+    fn find_type(&self, name: &str) -> Option<(&str, &Symbol)> {
+        self.documents.iter().find_map(|(uri, document)| {
+            document
+                .symbol_named(name)
+                .filter(|symbol| symbol.kind.is_type())
+                .map(|symbol| (uri.as_str(), symbol))
+        })
+    }
+
+    /// A symbol defined in `uri` is preferred over a type from another
+    /// document.
+    fn find_symbol(&self, uri: &str, name: &str) -> Option<(&str, &Symbol)> {
+        let local = self
+            .documents
+            .get_key_value(uri)
+            .and_then(|(uri, document)| {
+                document
+                    .symbol_named(name)
+                    .map(|symbol| (uri.as_str(), symbol))
+            });
+
+        local.or_else(|| self.find_type(name))
+    }
+
+    fn ident_at(&self, uri: &str, position: Position) -> Option<&str> {
+        self.documents.get(uri)?.ident_at(position)
+    }
+
+    fn definition(&self, uri: &str, position: Position) -> Value {
+        let found = self
+            .ident_at(uri, position)
+            .and_then(|name| self.find_symbol(uri, name));
+
+        match found {
+            Some((uri, symbol)) => json!(Location {
+                uri,
+                range: symbol.selection_range,
+            }),
+            None => Value::Null,
+        }
+    }
+
+    fn references(&self, uri: &str, position: Position, include_declaration: bool) -> Value {
+        let name = match self.ident_at(uri, position) {
+            Some(name) => name,
+            None => return Value::Null,
+        };
+
+        let mut locations = vec![];
+
+        if include_declaration {
+            if let Some((uri, symbol)) = self.find_type(name) {
+                locations.push(Location {
+                    uri,
+                    range: symbol.selection_range,
+                });
+            }
+        }
+
+        for (uri, document) in &self.documents {
+            for reference in document.references_to(name) {
+                locations.push(Location {
+                    uri,
+                    range: reference.range,
+                });
+            }
+        }
+
+        json!(locations)
+    }
+
+    fn hover(&self, uri: &str, position: Position) -> Value {
+        let found = self
+            .ident_at(uri, position)
+            .and_then(|name| self.find_symbol(uri, name));
+
+        let (_, symbol) = match found {
+            Some(found) => found,
+            None => return Value::Null,
+        };
+
+        let mut value = format!("```tpb\n{} {}\n```", symbol.kind.keyword(), symbol.name);
+
+        if !symbol.docs.is_empty() {
+            value += "\n\n";
+            value += &symbol.docs.join("\n");
+        }
+
+        json!({ "contents": { "kind": "markdown", "value": value } })
+    }
+
+    fn completion(&self, uri: &str, position: Position) -> Value {
+        let context = match self.documents.get(uri) {
+            Some(document) => document.completion_context(position),
+            None => return Value::Null,
+        };
+
+        let keywords = |labels: &[&str], kind| -> Vec<CompletionItem> {
+            labels
+                .iter()
+                .map(|label| CompletionItem {
+                    label: label.to_string(),
+                    kind,
+                    documentation: None,
+                })
+                .collect()
+        };
+
+        let items = match context {
+            CompletionContext::Directive => keywords(&analysis::DIRECTIVES, COMPLETION_KEYWORD),
+            CompletionContext::MemoryFlag => keywords(&analysis::MEMORY_FLAGS, COMPLETION_PROPERTY),
+            CompletionContext::None => vec![],
+            CompletionContext::Type => {
+                let mut items = keywords(&analysis::BUILTIN_TYPES, COMPLETION_KEYWORD);
+                let mut types = BTreeMap::new();
+
+                for document in self.documents.values() {
+                    for symbol in &document.symbols {
+                        if symbol.kind.is_type() {
+                            types.entry(symbol.name.as_str()).or_insert(symbol);
+                        }
+                    }
+                }
+
+                items.extend(types.into_values().map(|symbol| {
+                    CompletionItem {
+                        label: symbol.name.clone(),
+                        kind: if symbol.kind == SymbolKind::Struct {
+                            COMPLETION_STRUCT
+                        } else {
+                            COMPLETION_ENUM
+                        },
+                        documentation: (!symbol.docs.is_empty()).then(
+                            || json!({ "kind": "markdown", "value": symbol.docs.join("\n") }),
+                        ),
+                    }
+                }));
+
+                items
+            }
+        };
+
+        json!(items)
+    }
+
+    fn document_symbols(&self, uri: &str) -> Value {
+        match self.documents.get(uri) {
+            Some(document) => json!(document
+                .symbols
+                .iter()
+                .map(document_symbol)
+                .collect::<Vec<_>>()),
+            None => Value::Null,
+        }
+    }
+}
+
+fn document_symbol(symbol: &Symbol) -> DocumentSymbol {
+    // `SymbolKind` numbers of the protocol.
+    let kind = match symbol.kind {
+        SymbolKind::Struct => 23,
+        SymbolKind::Enum => 10,
+        SymbolKind::Fn | SymbolKind::Signal => 12,
+        SymbolKind::Const => 2,
+        SymbolKind::ConstValue => 14,
+    };
+
+    DocumentSymbol {
+        name: symbol.name.clone(),
+        detail: symbol.kind.keyword().to_string(),
+        kind,
+        range: symbol.range,
+        selection_range: symbol.selection_range,
+        children: symbol.children.iter().map(document_symbol).collect(),
+    }
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics },
+    })
+}
+
+fn text_document_uri(params: &Value) -> Result<&str, (i64, String)> {
+    params["textDocument"]["uri"]
+        .as_str()
+        .ok_or_else(|| (INVALID_PARAMS, String::from("missing textDocument.uri")))
+}
+
+fn text_document_position(params: &Value) -> Result<(&str, Position), (i64, String)> {
+    let uri = text_document_uri(params)?;
+    let position = serde_json::from_value(params["position"].clone())
+        .map_err(|err| (INVALID_PARAMS, format!("invalid position: {}", err)))?;
+
+    Ok((uri, position))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn frame(messages: &[Value]) -> Vec<u8> {
+        let mut out = vec![];
+
+        for message in messages {
+            write_message(&mut out, message).unwrap();
+        }
+
+        out
+    }
+
+    fn open(uri: &str, text: &str) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": { "textDocument": { "uri": uri, "languageId": "tpb", "version": 1, "text": text } },
+        })
+    }
+
+    fn request(id: u32, method: &str, uri: &str, line: u32, character: u32) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": {
+                "textDocument": { "uri": uri },
+                "position": { "line": line, "character": character },
+                "context": { "includeDeclaration": true },
+            },
+        })
+    }
+
+    #[test]
+    fn session_test() {
+        let input = frame(&[
+            json!({ "jsonrpc": "2.0", "id": 0, "method": "initialize", "params": {} }),
+            open(
+                "file:///a.tpb",
+                "/// A point.\nstruct Point {\n    x: f32,\n}\n",
+            ),
+            open(
+                "file:///b.tpb",
+                "struct Line {\n    from: Point,\n    to: Pont,\n}\n",
+            ),
+            request(1, "textDocument/definition", "file:///b.tpb", 1, 11),
+            request(2, "textDocument/references", "file:///a.tpb", 1, 8),
+            request(3, "textDocument/hover", "file:///b.tpb", 1, 11),
+            json!({ "jsonrpc": "2.0", "id": 4, "method": "shutdown" }),
+            json!({ "jsonrpc": "2.0", "method": "exit" }),
+        ]);
+
+        let mut output = vec![];
+        let code = run(Cursor::new(input), &mut output).unwrap();
+        assert_eq!(code, 0);
+
+        let mut reader = Cursor::new(output);
+        let mut messages = vec![];
+
+        while let Some(message) = read_message(&mut reader).unwrap() {
+            messages.push(message);
+        }
+
+        // initialize, 1 + 2 diagnostics, 4 responses
+        assert_eq!(messages.len(), 8);
+        assert_eq!(messages[0]["result"]["capabilities"]["hoverProvider"], true);
+
+        let diagnostics = &messages[3]["params"];
+        assert_eq!(diagnostics["uri"], "file:///b.tpb");
+        assert_eq!(
+            diagnostics["diagnostics"][0]["message"],
+            "unknown type `Pont`"
+        );
+        assert_eq!(diagnostics["diagnostics"][0]["severity"], 2);
+
+        assert_eq!(
+            messages[4]["result"],
+            json!({
+                "uri": "file:///a.tpb",
+                "range": {
+                    "start": { "line": 1, "character": 7 },
+                    "end": { "line": 1, "character": 12 },
+                },
+            })
+        );
+
+        let references = messages[5]["result"].as_array().unwrap();
+        assert_eq!(references.len(), 2);
+        assert_eq!(references[1]["uri"], "file:///b.tpb");
+
+        assert_eq!(
+            messages[6]["result"]["contents"]["value"],
+            "```tpb\nstruct Point\n```\n\nA point."
+        );
+        assert_eq!(messages[7]["result"], Value::Null);
+    }
+
+    #[test]
+    fn completion_and_symbols_test() {
+        let mut server = Server::default();
+        server.handle(open(
+            "file:///a.tpb",
+            "enum Kind { A }\n\nstruct B {\n    kind: \n}\n#[memory(",
+        ));
+
+        let items = server.handle(request(
+            1,
+            "textDocument/completion",
+            "file:///a.tpb",
+            3,
+            10,
+        ));
+        let labels: Vec<&str> = items[0]["result"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["label"].as_str().unwrap())
+            .collect();
+        assert!(labels.contains(&"Kind") && labels.contains(&"Vec") && labels.contains(&"B"));
+
+        let items = server.handle(request(2, "textDocument/completion", "file:///a.tpb", 5, 9));
+        assert_eq!(
+            items[0]["result"],
+            json!([
+                { "label": "copy", "kind": COMPLETION_PROPERTY },
+                { "label": "emplace", "kind": COMPLETION_PROPERTY },
+            ])
+        );
+
+        let symbols = server.handle(request(
+            3,
+            "textDocument/documentSymbol",
+            "file:///a.tpb",
+            0,
+            0,
+        ));
+        let names: Vec<&str> = symbols[0]["result"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|symbol| symbol["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Kind", "B"]);
+
+        let unknown = server.handle(request(4, "textDocument/formatting", "file:///a.tpb", 0, 0));
+        assert_eq!(unknown[0]["error"]["code"], METHOD_NOT_FOUND);
+    }
+}
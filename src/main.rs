@@ -1,22 +1,19 @@
-pub mod ast;
-pub mod kotlin;
-pub mod lexer;
-pub mod parser;
-pub mod dart;
-pub mod rust;
-pub mod rust_generator;
-pub mod swift;
-pub mod writer;
-
 use std::{
+    collections::BTreeMap,
     env,
     fs::File,
-    io::{Read, Write},
+    io::{self, Read, Write},
     path,
 };
 
 use clap::{clap_derive::ArgEnum, Parser, Subcommand};
+use convert_case::{Case, Casing};
 use serde::{Deserialize, Serialize};
+use tech_paws_buffers_generator::{
+    ast, c, codec, csharp, docs, formatter, go, graph, json_schema, kotlin, lsp,
+    options::GeneratorOptions, plugin, proto, python, rust, rust_generator, swift, typescript,
+    Lang,
+};
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
@@ -30,12 +27,28 @@ struct YamlData {
     rust: Option<Vec<YamlParams>>,
     swift: Option<Vec<YamlParams>>,
     kotlin: Option<Vec<YamlParams>>,
+    typescript: Option<Vec<YamlParams>>,
+    c: Option<Vec<YamlParams>>,
+    python: Option<Vec<YamlParams>>,
+    csharp: Option<Vec<YamlParams>>,
+    go: Option<Vec<YamlParams>>,
+    plugins: Option<Vec<YamlPluginParams>>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 struct YamlParams {
     src: String,
     dest: String,
+    #[serde(default)]
+    options: GeneratorOptions,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct YamlPluginParams {
+    name: String,
+    src: String,
+    dest: String,
+    options: Option<BTreeMap<String, String>>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -55,13 +68,223 @@ enum Commands {
         #[clap(value_parser)]
         path: path::PathBuf,
     },
+    /// Decode a binary buffer using a schema
+    Decode {
+        /// Path to a .tpb schema
+        #[clap(short, long, value_parser)]
+        input: String,
+
+        /// Root type: a type like `Vec<Point>`, or `<method>.args` / `<method>.result`
+        #[clap(short = 't', long = "type", value_parser)]
+        root: String,
+
+        /// Path to a binary file, `-` to read from stdin
+        #[clap(short, long, value_parser, conflicts_with = "hex")]
+        file: Option<String>,
+
+        /// Bytes written as hex, e.g. "02 00 00 00"
+        #[clap(long, value_parser)]
+        hex: Option<String>,
+
+        /// Shape of the printed JSON, `models` is the one described by `export-json-schema`
+        #[clap(long, arg_enum, default_value = "schema")]
+        form: codec::JsonForm,
+    },
+    /// Encode a JSON value into a binary buffer using a schema
+    Encode {
+        /// Path to a .tpb schema
+        #[clap(short, long, value_parser)]
+        input: String,
+
+        /// Root type: a type like `Vec<Point>`, or `<method>.args` / `<method>.result`
+        #[clap(short = 't', long = "type", value_parser)]
+        root: String,
+
+        /// JSON value, read from stdin when omitted
+        #[clap(short, long, value_parser, conflicts_with = "json-file")]
+        json: Option<String>,
+
+        /// Path to a JSON file
+        #[clap(long, value_parser)]
+        json_file: Option<String>,
+
+        /// Path to the binary output, the bytes are printed as hex when omitted
+        #[clap(short, long, value_parser)]
+        output: Option<String>,
+
+        /// Shape of the JSON value, `models` is the one described by `export-json-schema`
+        #[clap(long, arg_enum, default_value = "schema")]
+        form: codec::JsonForm,
+    },
+    /// Generate conformance test vectors and Rust, Swift and Kotlin tests for them
+    TestVectors {
+        /// Path to a .tpb schema
+        #[clap(short, long, value_parser)]
+        input: String,
+
+        /// Output directory
+        #[clap(short, long, value_parser)]
+        output: path::PathBuf,
+
+        /// Seed of the random vectors
+        #[clap(long, value_parser, default_value_t = 0)]
+        seed: u64,
+
+        /// Number of random vectors per type
+        #[clap(long, value_parser, default_value_t = 4)]
+        count: usize,
+    },
+    /// Generate `cargo fuzz` targets for `try_read_from_buffers` of every root type
+    Fuzz {
+        /// Path to a .tpb schema
+        #[clap(short, long, value_parser)]
+        input: String,
+
+        /// Output directory, usually `fuzz` next to the crate manifest
+        #[clap(short, long, value_parser)]
+        output: path::PathBuf,
+
+        /// Path of the generated module, e.g. `my_app::buffers`
+        #[clap(short, long, value_parser)]
+        module: String,
+    },
+    /// Generate the checked reader shared by schemas with `#[rust(try_read_runtime = "...")]`
+    TryReadRuntime {
+        /// Output file, `-` prints the runtime
+        #[clap(short, long, value_parser, default_value = "-")]
+        output: String,
+
+        /// Maximum length of strings and vectors
+        #[clap(long, value_parser, default_value_t = rust::try_read::DEFAULT_MAX_COLLECTION_LEN)]
+        max_collection_len: i64,
+
+        /// Maximum nesting of structs and enums
+        #[clap(long, value_parser, default_value_t = rust::try_read::DEFAULT_MAX_DEPTH)]
+        max_depth: i64,
+    },
+    /// Generate the Go helpers shared by schemas with `#[go(shared_primitives)]`
+    GoPrimitives {
+        /// Output file, `-` prints the helpers
+        #[clap(short, long, value_parser, default_value = "-")]
+        output: String,
+
+        /// Go package of the schemas
+        #[clap(short, long, value_parser)]
+        package: String,
+    },
+    /// Print the parsed AST of a schema
+    Ast {
+        /// Path to a .tpb schema
+        #[clap(short, long, value_parser)]
+        input: String,
+
+        #[clap(short, long, arg_enum, default_value = "text")]
+        format: AstFormat,
+    },
+    /// Format schemas, keeping comments
+    Fmt {
+        /// Paths to .tpb schemas
+        #[clap(value_parser, required = true)]
+        paths: Vec<path::PathBuf>,
+
+        /// Don't write the files, exit with an error if some of them aren't formatted
+        #[clap(long, value_parser)]
+        check: bool,
+
+        /// Align the names after `#[n]` positions of fields and enum items
+        #[clap(long, value_parser)]
+        align_positions: bool,
+    },
+    /// Run the language server over stdio
+    Lsp {
+        /// Accepted for editors that always pass it, stdio is the only transport
+        #[clap(long, value_parser, hide = true)]
+        stdio: bool,
+    },
+    /// Generate code with an external `tpb-gen-<name>` plugin
+    Plugin {
+        /// Path to a .tpb schema
+        #[clap(short, long, value_parser)]
+        input: String,
+
+        /// Output directory
+        #[clap(short, long, value_parser)]
+        output: path::PathBuf,
+
+        /// Plugin name, `typescript` runs `tpb-gen-typescript`
+        #[clap(short, long, value_parser)]
+        name: String,
+
+        /// Plugin option, e.g. `--opt indent=2`
+        #[clap(long = "opt", value_parser = parse_plugin_option)]
+        options: Vec<(String, String)>,
+    },
+    /// Convert a schema to a proto3 file
+    ExportProto {
+        /// Path to a .tpb schema
+        #[clap(short, long, value_parser)]
+        input: String,
+
+        /// Path to the .proto output, `-` to print it
+        #[clap(short, long, value_parser, default_value = "-")]
+        output: String,
+    },
+    /// Convert a proto3 file to a schema, reporting unsupported features
+    ImportProto {
+        /// Path to a .proto file
+        #[clap(short, long, value_parser)]
+        input: String,
+
+        /// Path to the .tpb output, `-` to print it
+        #[clap(short, long, value_parser, default_value = "-")]
+        output: String,
+    },
+    /// Export a JSON Schema of the JSON form of the models
+    ExportJsonSchema {
+        /// Path to a .tpb schema
+        #[clap(short, long, value_parser)]
+        input: String,
+
+        /// Path to the .json output, `-` to print it
+        #[clap(short, long, value_parser, default_value = "-")]
+        output: String,
+
+        /// Describe the arguments and results of RPC methods under `x-rpc`
+        #[clap(long, value_parser)]
+        rpc: bool,
+    },
+    /// Render documentation pages for all schemas of a YAML config
+    Docs {
+        /// Path to a config
+        #[clap(value_parser)]
+        config: path::PathBuf,
+
+        /// Output directory
+        #[clap(short, long, value_parser)]
+        output: path::PathBuf,
+
+        #[clap(short, long, arg_enum, default_value = "markdown")]
+        format: docs::DocsFormat,
+    },
+    /// Print a class diagram of a schema
+    Graph {
+        /// Path to a .tpb schema
+        #[clap(short, long, value_parser)]
+        input: String,
+
+        /// Path to the output, `-` to print it
+        #[clap(short, long, value_parser, default_value = "-")]
+        output: String,
+
+        #[clap(short, long, arg_enum, default_value = "dot")]
+        format: graph::GraphFormat,
+    },
 }
 
 #[derive(ArgEnum, Clone, Debug)]
-enum Lang {
-    Rust,
-    Swift,
-    Kotlin,
+enum AstFormat {
+    Text,
+    Json,
 }
 
 fn main() -> std::io::Result<()> {
@@ -77,7 +300,106 @@ fn main() -> std::io::Result<()> {
             input,
             output,
             lang,
-        } => generate(input, output, lang)?,
+        } => generate(input, output, lang, &GeneratorOptions::default())?,
+
+        Commands::Decode {
+            input,
+            root,
+            file,
+            hex,
+            form,
+        } => decode(input, root, file.as_deref(), hex.as_deref(), *form)?,
+
+        Commands::Encode {
+            input,
+            root,
+            json,
+            json_file,
+            output,
+            form,
+        } => encode(
+            input,
+            root,
+            json.as_deref(),
+            json_file.as_deref(),
+            output.as_deref(),
+            *form,
+        )?,
+
+        Commands::TestVectors {
+            input,
+            output,
+            seed,
+            count,
+        } => test_vectors(input, output, *seed, *count)?,
+
+        Commands::Fuzz {
+            input,
+            output,
+            module,
+        } => fuzz(input, output, module)?,
+
+        Commands::TryReadRuntime {
+            output,
+            max_collection_len,
+            max_depth,
+        } => try_read_runtime(output, *max_collection_len, *max_depth)?,
+
+        Commands::GoPrimitives { output, package } => write_output(
+            output,
+            &go::generate_primitives(package, &GeneratorOptions::default()),
+        )?,
+
+        Commands::Ast { input, format } => print_ast(input, format)?,
+
+        Commands::Fmt {
+            paths,
+            check,
+            align_positions,
+        } => fmt(paths, *check, *align_positions)?,
+
+        Commands::Lsp { stdio: _ } => {
+            let code = lsp::run(io::stdin().lock(), io::stdout().lock())?;
+
+            if code != 0 {
+                std::process::exit(code);
+            }
+        }
+
+        Commands::Plugin {
+            input,
+            output,
+            name,
+            options,
+        } => plugin(
+            input,
+            output,
+            name,
+            options.iter().cloned().collect::<BTreeMap<_, _>>(),
+        )?,
+
+        Commands::ExportProto { input, output } => export_proto(input, output)?,
+
+        Commands::ImportProto { input, output } => import_proto(input, output)?,
+
+        Commands::ExportJsonSchema { input, output, rpc } => {
+            export_json_schema(input, output, *rpc)?
+        }
+
+        Commands::Docs {
+            config,
+            output,
+            format,
+        } => generate_docs(config, output, *format)?,
+
+        Commands::Graph {
+            input,
+            output,
+            format,
+        } => {
+            let ast = parse_file(input)?;
+            write_output(output, &graph::generate_graph(&ast, *format))?
+        }
 
         Commands::Yaml { path } => {
             let mut path_file = File::open(path)?;
@@ -95,9 +417,10 @@ fn main() -> std::io::Result<()> {
                     log::info!("Generate rust dest: {}", dest.display());
 
                     generate(
-                        &src.to_str().unwrap().to_string(),
-                        &dest.to_str().unwrap().to_string(),
+                        src.to_str().unwrap(),
+                        dest.to_str().unwrap(),
                         &Lang::Rust,
+                        &item.options,
                     )?
                 }
             }
@@ -111,9 +434,10 @@ fn main() -> std::io::Result<()> {
                     log::info!("Generate swift dest: {}", dest.display());
 
                     generate(
-                        &src.to_str().unwrap().to_string(),
-                        &dest.to_str().unwrap().to_string(),
+                        src.to_str().unwrap(),
+                        dest.to_str().unwrap(),
                         &Lang::Swift,
+                        &item.options,
                     )?
                 }
             }
@@ -127,30 +451,528 @@ fn main() -> std::io::Result<()> {
                     log::info!("Generate kotlin dest: {}", dest.display());
 
                     generate(
-                        &src.to_str().unwrap().to_string(),
-                        &dest.to_str().unwrap().to_string(),
+                        src.to_str().unwrap(),
+                        dest.to_str().unwrap(),
                         &Lang::Kotlin,
+                        &item.options,
+                    )?
+                }
+            }
+
+            if let Some(typescript) = data.typescript {
+                for item in typescript.iter() {
+                    let src = working_dir.join(&item.src);
+                    let dest = working_dir.join(&item.dest);
+
+                    log::info!("Generate typescript buffer: {}", src.display());
+                    log::info!("Generate typescript dest: {}", dest.display());
+
+                    generate(
+                        src.to_str().unwrap(),
+                        dest.to_str().unwrap(),
+                        &Lang::Typescript,
+                        &item.options,
+                    )?
+                }
+            }
+
+            if let Some(c) = data.c {
+                for item in c.iter() {
+                    let src = working_dir.join(&item.src);
+                    let dest = working_dir.join(&item.dest);
+
+                    log::info!("Generate c buffer: {}", src.display());
+                    log::info!("Generate c dest: {}", dest.display());
+
+                    generate(
+                        src.to_str().unwrap(),
+                        dest.to_str().unwrap(),
+                        &Lang::C,
+                        &item.options,
+                    )?
+                }
+            }
+
+            if let Some(python) = data.python {
+                for item in python.iter() {
+                    let src = working_dir.join(&item.src);
+                    let dest = working_dir.join(&item.dest);
+
+                    log::info!("Generate python buffer: {}", src.display());
+                    log::info!("Generate python dest: {}", dest.display());
+
+                    generate(
+                        src.to_str().unwrap(),
+                        dest.to_str().unwrap(),
+                        &Lang::Python,
+                        &item.options,
+                    )?
+                }
+            }
+
+            if let Some(csharp) = data.csharp {
+                for item in csharp.iter() {
+                    let src = working_dir.join(&item.src);
+                    let dest = working_dir.join(&item.dest);
+
+                    log::info!("Generate csharp buffer: {}", src.display());
+                    log::info!("Generate csharp dest: {}", dest.display());
+
+                    generate(
+                        src.to_str().unwrap(),
+                        dest.to_str().unwrap(),
+                        &Lang::Csharp,
+                        &item.options,
+                    )?
+                }
+            }
+
+            if let Some(go) = data.go {
+                for item in go.iter() {
+                    let src = working_dir.join(&item.src);
+                    let dest = working_dir.join(&item.dest);
+
+                    log::info!("Generate go buffer: {}", src.display());
+                    log::info!("Generate go dest: {}", dest.display());
+
+                    generate(
+                        src.to_str().unwrap(),
+                        dest.to_str().unwrap(),
+                        &Lang::Go,
+                        &item.options,
                     )?
                 }
             }
+
+            if let Some(plugins) = data.plugins {
+                for item in plugins.iter() {
+                    let src = working_dir.join(&item.src);
+                    let dest = working_dir.join(&item.dest);
+
+                    log::info!("Generate {} buffer: {}", item.name, src.display());
+
+                    plugin(
+                        src.to_str().unwrap(),
+                        &dest,
+                        &item.name,
+                        item.options.clone().unwrap_or_default(),
+                    )?
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_file(input: &str) -> std::io::Result<Vec<ast::ASTNode>> {
+    tech_paws_buffers_generator::parse_file(input).map_err(|err| match err {
+        tech_paws_buffers_generator::Error::Io(err) => err,
+        err => std::io::Error::new(std::io::ErrorKind::InvalidData, err.to_string()),
+    })
+}
+
+fn decode(
+    input: &str,
+    root: &str,
+    file: Option<&str>,
+    hex: Option<&str>,
+    form: codec::JsonForm,
+) -> std::io::Result<()> {
+    let ast = parse_file(input)?;
+    let root = codec::resolve_root(&ast, root)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+
+    let data = match (file, hex) {
+        (_, Some(hex)) => codec::parse_hex(hex)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?,
+        (Some("-"), None) | (None, None) => {
+            let mut data = vec![];
+            std::io::stdin().read_to_end(&mut data)?;
+            data
+        }
+        (Some(file), None) => std::fs::read(file)?,
+    };
+
+    let mut decoder = codec::decoder::Decoder::new(&ast, &data).with_form(form);
+    let result = decoder.decode(&root);
+
+    if let Ok(value) = &result {
+        println!("{}", serde_json::to_string_pretty(value).unwrap());
+        println!();
+    }
+
+    print!(
+        "{}",
+        codec::decoder::format_hexdump(&data, decoder.annotations())
+    );
+
+    match result {
+        Ok(_) => {
+            if decoder.cursor() < data.len() {
+                println!(
+                    "{:08x}  {} trailing bytes",
+                    decoder.cursor(),
+                    data.len() - decoder.cursor()
+                );
+            }
+
+            Ok(())
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn encode(
+    input: &str,
+    root: &str,
+    json: Option<&str>,
+    json_file: Option<&str>,
+    output: Option<&str>,
+    form: codec::JsonForm,
+) -> std::io::Result<()> {
+    let ast = parse_file(input)?;
+    let root = codec::resolve_root(&ast, root)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+
+    let json = match (json, json_file) {
+        (Some(json), _) => json.to_string(),
+        (None, Some(json_file)) => std::fs::read_to_string(json_file)?,
+        (None, None) => {
+            let mut json = String::new();
+            std::io::stdin().read_to_string(&mut json)?;
+            json
+        }
+    };
+
+    let value = serde_json::from_str::<serde_json::Value>(&json)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+
+    let data = match codec::encoder::Encoder::new(&ast)
+        .with_form(form)
+        .encode(&root, &value)
+    {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    match output {
+        Some(output) => File::create(output)?.write_all(&data)?,
+        None => println!(
+            "{}",
+            data.iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<Vec<String>>()
+                .join(" ")
+        ),
+    }
+
+    Ok(())
+}
+
+fn test_vectors(input: &str, output: &path::Path, seed: u64, count: usize) -> std::io::Result<()> {
+    let ast = parse_file(input)?;
+    let (vectors, skipped) = codec::samples::generate_test_vectors(&ast, seed, count);
+
+    for (type_id, reason) in skipped {
+        eprintln!("warning: skipped {}: {}", type_id, reason);
+    }
+
+    let vectors_path = output.join("vectors");
+    std::fs::create_dir_all(&vectors_path)?;
+
+    for vector in &vectors {
+        let json = serde_json::to_string_pretty(&vector.value).unwrap();
+        std::fs::write(
+            vectors_path.join(format!("{}.json", vector.name)),
+            json + "\n",
+        )?;
+        std::fs::write(
+            vectors_path.join(format!("{}.bin", vector.name)),
+            &vector.data,
+        )?;
+    }
+
+    let tests = [
+        (
+            "rust",
+            "test_vectors.rs",
+            rust::test_vectors::generate_test_vectors(&ast, &vectors),
+        ),
+        (
+            "swift",
+            "TestVectorsTests.swift",
+            swift::test_vectors::generate_test_vectors(&ast, &vectors),
+        ),
+        (
+            "kotlin",
+            "TestVectorsTest.kt",
+            kotlin::test_vectors::generate_test_vectors(&ast, &vectors),
+        ),
+    ];
+
+    for (dir, file_name, data) in tests {
+        std::fs::create_dir_all(output.join(dir))?;
+        std::fs::write(output.join(dir).join(file_name), data)?;
+    }
+
+    Ok(())
+}
+
+fn fuzz(input: &str, output: &path::Path, module: &str) -> std::io::Result<()> {
+    let ast = parse_file(input)?;
+
+    if !ast::contains_directive_group_flag(&ast, "rust", "try_read") {
+        eprintln!("warning: {} doesn't enable #[rust(try_read)]", input);
+    }
+
+    let ids = rust::try_read::find_try_read_types(&ast);
+
+    for (id, reason) in rust::buffers_tests::find_unsupported_types(&ast) {
+        eprintln!("warning: skipped {}: {}", id, reason);
+    }
+    let crate_name = module.split("::").next().unwrap_or(module);
+    let runtime = match rust::try_read::find_try_read_runtime(&ast) {
+        Some(runtime) => match runtime.strip_prefix("crate::") {
+            Some(path) => format!("{}::{}", crate_name, path),
+            None => runtime,
+        },
+        None => format!("{}::{}", module, rust::try_read::EMBEDDED_RUNTIME_MODULE),
+    };
+    let targets_path = output.join("fuzz_targets");
+    std::fs::create_dir_all(&targets_path)?;
+
+    std::fs::write(
+        output.join("Cargo.toml"),
+        rust::fuzz::generate_fuzz_manifest(crate_name, &ids),
+    )?;
+
+    for id in &ids {
+        std::fs::write(
+            targets_path.join(format!("{}.rs", id.to_case(Case::Snake))),
+            rust::fuzz::generate_fuzz_target(module, &runtime, id),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn try_read_runtime(output: &str, max_collection_len: i64, max_depth: i64) -> std::io::Result<()> {
+    if max_collection_len <= 0 || max_depth <= 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "limits should be positive",
+        ));
+    }
+
+    let runtime = rust::try_read::generate_try_read_runtime(max_collection_len, max_depth);
+    write_output(output, &format!("// GENERATED, DO NOT EDIT\n\n{}", runtime))
+}
+
+fn print_ast(input: &str, format: &AstFormat) -> std::io::Result<()> {
+    let ast = parse_file(input)?;
+
+    match format {
+        AstFormat::Text => print!("{}", ast::stringify_ast(&ast)),
+        AstFormat::Json => println!("{}", serde_json::to_string_pretty(&ast).unwrap()),
+    }
+
+    Ok(())
+}
+
+fn fmt(paths: &[path::PathBuf], check: bool, align_positions: bool) -> std::io::Result<()> {
+    let options = formatter::FormatOptions { align_positions };
+    let mut failed = false;
+
+    for path in paths {
+        let src = std::fs::read_to_string(path)?;
+
+        let formatted = match formatter::format_source(&src, &options) {
+            Ok(formatted) => formatted,
+            Err(err) => {
+                eprintln!("error: {}:{}", path.display(), err);
+                failed = true;
+                continue;
+            }
+        };
+
+        if formatted == src {
+            continue;
+        }
+
+        if check {
+            println!("{} is not formatted", path.display());
+            failed = true;
+        } else {
+            std::fs::write(path, formatted)?;
         }
     }
 
+    if failed {
+        std::process::exit(1);
+    }
+
     Ok(())
 }
 
-fn generate(input: &String, output: &String, lang: &Lang) -> std::io::Result<()> {
-    let mut input_file = File::open(input)?;
-    let mut contents = String::new();
-    input_file.read_to_string(&mut contents)?;
+fn parse_plugin_option(option: &str) -> Result<(String, String), String> {
+    match option.split_once('=') {
+        Some((key, value)) => Ok((key.to_string(), value.to_string())),
+        None => Err(format!("expected key=value, got \"{}\"", option)),
+    }
+}
+
+fn plugin(
+    input: &str,
+    output: &path::Path,
+    name: &str,
+    options: BTreeMap<String, String>,
+) -> std::io::Result<()> {
+    let ast = parse_file(input)?;
+    let request = plugin::PluginRequest::new(input, options, ast);
+    let response =
+        plugin::run_plugin(name, &request).map_err(|err| std::io::Error::other(err.to_string()))?;
 
-    let mut lexer = lexer::Lexer::tokenize(&contents);
-    let ast = parser::parse(&mut lexer);
+    for path in plugin::write_files(output, &response.files)? {
+        log::info!("Generate {} dest: {}", name, path.display());
+    }
+
+    Ok(())
+}
+
+fn export_proto(input: &str, output: &str) -> std::io::Result<()> {
+    let ast = parse_file(input)?;
+    let (data, warnings) = proto::export_proto(&ast);
+
+    for warning in warnings {
+        eprintln!("warning: {}", warning);
+    }
+
+    write_output(output, &data)
+}
+
+fn import_proto(input: &str, output: &str) -> std::io::Result<()> {
+    let src = std::fs::read_to_string(input)?;
+    let (data, warnings) = proto::import_proto(&src).map_err(|err| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{}:{}", input, err),
+        )
+    })?;
+
+    for warning in warnings {
+        eprintln!("warning: {}", warning);
+    }
+
+    write_output(output, &data)
+}
+
+fn export_json_schema(input: &str, output: &str, rpc: bool) -> std::io::Result<()> {
+    let ast = parse_file(input)?;
+    let options = json_schema::JsonSchemaOptions { rpc };
+    let (document, warnings) = json_schema::export_json_schema(&ast, &options);
+
+    for warning in warnings {
+        eprintln!("warning: {}", warning);
+    }
+
+    write_output(
+        output,
+        &(serde_json::to_string_pretty(&document).unwrap() + "\n"),
+    )
+}
+
+fn generate_docs(
+    config: &path::Path,
+    output: &path::Path,
+    format: docs::DocsFormat,
+) -> std::io::Result<()> {
+    let contents = std::fs::read_to_string(config)?;
+    let data = serde_yaml::from_str::<YamlData>(&contents)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+    let working_dir = config.parent().unwrap();
+    let mut sources = vec![];
+
+    let targets = [
+        &data.rust,
+        &data.swift,
+        &data.kotlin,
+        &data.typescript,
+        &data.c,
+        &data.python,
+        &data.csharp,
+        &data.go,
+    ];
+
+    for items in targets.into_iter().flatten() {
+        sources.extend(items.iter().map(|item| item.src.clone()));
+    }
+
+    if let Some(plugins) = &data.plugins {
+        sources.extend(plugins.iter().map(|item| item.src.clone()));
+    }
+
+    let mut schemas: Vec<docs::DocsSchema> = vec![];
+
+    for source in sources {
+        if schemas.iter().any(|schema| schema.source == source) {
+            continue;
+        }
+
+        let ast = parse_file(working_dir.join(&source).to_str().unwrap())?;
+        schemas.push(docs::DocsSchema { source, ast });
+    }
+
+    std::fs::create_dir_all(output)?;
+
+    for file in docs::generate_docs(&schemas, format) {
+        let dest = output.join(&file.name);
+        log::info!("Generate docs dest: {}", dest.display());
+        std::fs::write(dest, file.content)?;
+    }
+
+    Ok(())
+}
+
+fn write_output(output: &str, data: &str) -> std::io::Result<()> {
+    if output == "-" {
+        print!("{}", data);
+    } else {
+        std::fs::write(output, data)?;
+    }
+
+    Ok(())
+}
+
+fn generate(
+    input: &str,
+    output: &str,
+    lang: &Lang,
+    options: &GeneratorOptions,
+) -> std::io::Result<()> {
+    let ast = parse_file(input)?;
+
+    if let Lang::Rust = lang {
+        for warning in rust_generator::find_skipped_types(&ast) {
+            eprintln!("warning: {}", warning);
+        }
+    }
 
     let data: String = match lang {
-        Lang::Rust => rust_generator::generate(&ast),
-        Lang::Swift => swift::generate(&ast),
+        Lang::Rust => rust_generator::generate_with_options(&ast, options),
+        Lang::Swift => swift::generate_with_options(&ast, options),
         Lang::Kotlin => "Not Implemented".to_string(),
+        Lang::Typescript => typescript::generate_with_options(&ast, options),
+        Lang::C => c::generate_with_options(&ast, options),
+        Lang::Python => python::generate_with_options(&ast, options),
+        Lang::Csharp => csharp::generate_with_options(&ast, options),
+        Lang::Go => go::generate_with_options(&ast, options),
     };
 
     if output == "-" {
@@ -0,0 +1,90 @@
+//! Per-target generator options, the `options` of a target in the YAML
+//! config.
+
+use serde::{Deserialize, Serialize};
+
+use crate::writer::Writer;
+
+pub const DEFAULT_HEADER: &str = "// GENERATED, DO NOT EDIT";
+
+pub const DEFAULT_INDENT: usize = 4;
+
+/// Every option is optional, the defaults produce the same code as running
+/// the generator without options. Targets ignore options that don't apply
+/// to them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GeneratorOptions {
+    /// Spaces per indentation level.
+    pub indent: Option<usize>,
+    /// Replaces `// GENERATED, DO NOT EDIT`, e.g. with a license banner.
+    /// Written as is, so every line needs a comment marker.
+    pub header: Option<String>,
+    /// Access modifier of the generated declarations, e.g. `public` in
+    /// Swift or `internal` in C#.
+    pub access_level: Option<String>,
+    /// Imports added after the built in ones, paths of `use` in Rust.
+    pub imports: Vec<String>,
+    /// Derives added to `Debug, Clone, PartialEq` of Rust models.
+    pub derives: Vec<String>,
+    /// Attributes of Rust models without `#[]`, e.g. `non_exhaustive`.
+    pub attributes: Vec<String>,
+    /// Derives `serde::Serialize` and `serde::Deserialize` for Rust models,
+    /// with the JSON form described by `export-json-schema`.
+    pub serde: bool,
+    /// Rust module wrapping the generated items, the namespace in C#.
+    #[serde(alias = "package")]
+    pub module: Option<String>,
+    /// Emits `#![allow(warnings)]` and the other inner attributes of the
+    /// Rust header.
+    pub allow_warnings: Option<bool>,
+}
+
+impl GeneratorOptions {
+    pub fn indent(&self) -> usize {
+        self.indent.unwrap_or(DEFAULT_INDENT)
+    }
+
+    pub fn header(&self) -> &str {
+        self.header.as_deref().unwrap_or(DEFAULT_HEADER)
+    }
+
+    pub fn allow_warnings(&self) -> bool {
+        self.allow_warnings.unwrap_or(true)
+    }
+
+    pub fn writer(&self) -> Writer {
+        Writer::new(self.indent())
+    }
+
+    /// `access_level` followed by a space, or an empty string.
+    pub fn access_prefix(&self) -> String {
+        match &self.access_level {
+            Some(access_level) if !access_level.is_empty() => format!("{} ", access_level),
+            _ => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_options() {
+        let options: GeneratorOptions = serde_yaml::from_str(
+            "indent: 2\nheader: \"// Copyright\"\npackage: models\nderives: [Eq, Hash]\nserde: true\n",
+        )
+        .unwrap();
+
+        assert_eq!(options.indent(), 2);
+        assert_eq!(options.header(), "// Copyright");
+        assert_eq!(options.module.as_deref(), Some("models"));
+        assert_eq!(options.derives, vec!["Eq", "Hash"]);
+        assert!(options.serde);
+        assert!(options.allow_warnings());
+
+        let err = serde_yaml::from_str::<GeneratorOptions>("indnet: 2\n").unwrap_err();
+        assert!(err.to_string().contains("unknown field `indnet`"));
+    }
+}
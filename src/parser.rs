@@ -1,7 +1,17 @@
+use std::cell::Cell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
 use crate::ast::*;
 use crate::lexer::{Lexer, Literal, Token};
 
-static TOP_LEVEL_DIRECTIVES: [&str; 6] = ["id", "namespace", "dart", "rust", "swift", "kotlin"];
+static TOP_LEVEL_DIRECTIVES: [&str; 7] =
+    ["id", "namespace", "dart", "rust", "swift", "kotlin", "go"];
+
+/// Keys of `#[rust(...)]` that apply to the following struct or enum
+/// instead of the whole file.
+static RUST_TYPE_DIRECTIVES: [&str; 2] = ["derive", "attr"];
 
 macro_rules! parse_error {
     ($lexer:expr, $($arg:tt)*) => ({
@@ -17,6 +27,106 @@ struct ParseContext {
     directives: Vec<DirectiveASTNode>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub pos: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.pos, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// Lexer and parser errors are panics formatted as `line:pos: message`.
+    fn from_panic_message(message: &str) -> Self {
+        let mut parts = message.splitn(3, ':');
+
+        if let (Some(line), Some(pos), Some(message)) = (parts.next(), parts.next(), parts.next()) {
+            if let (Ok(line), Ok(pos)) = (line.parse(), pos.parse()) {
+                return ParseError {
+                    line,
+                    pos,
+                    message: message.trim_start().to_string(),
+                };
+            }
+        }
+
+        ParseError {
+            line: 0,
+            pos: 0,
+            message: message.to_string(),
+        }
+    }
+}
+
+thread_local! {
+    static SILENT_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, returning the panic message instead of unwinding. The
+/// panic hook is silenced for the current thread while `f` runs and the
+/// previous hook is restored afterwards.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    static PANIC_HOOK: Mutex<()> = Mutex::new(());
+
+    let result = if SILENT_PANICS.with(|silent| silent.get()) {
+        panic::catch_unwind(AssertUnwindSafe(f))
+    } else {
+        let _guard = PANIC_HOOK.lock().unwrap_or_else(|err| err.into_inner());
+        let previous_hook = Arc::new(panic::take_hook());
+
+        panic::set_hook(Box::new({
+            let previous_hook = previous_hook.clone();
+
+            move |info| {
+                if !SILENT_PANICS.with(|silent| silent.get()) {
+                    previous_hook(info);
+                }
+            }
+        }));
+
+        SILENT_PANICS.with(|silent| silent.set(true));
+        let result = panic::catch_unwind(AssertUnwindSafe(f));
+        SILENT_PANICS.with(|silent| silent.set(false));
+
+        drop(panic::take_hook());
+
+        match Arc::try_unwrap(previous_hook) {
+            Ok(previous_hook) => panic::set_hook(previous_hook),
+            Err(previous_hook) => panic::set_hook(Box::new(move |info| previous_hook(info))),
+        }
+
+        result
+    };
+
+    result.map_err(|payload| {
+        payload
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| {
+                payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+            })
+            .unwrap_or_else(|| String::from("Unknown error"))
+    })
+}
+
+/// Tokenizes and parses `src`, returning the error instead of panicking.
+pub fn parse_source(src: &str) -> Result<Vec<ASTNode>, ParseError> {
+    catch_panic(|| {
+        let mut lexer = Lexer::tokenize(src);
+        parse(&mut lexer)
+    })
+    .map_err(|message| ParseError::from_panic_message(&message))
+}
+
 pub fn parse(lexer: &mut Lexer) -> Vec<ASTNode> {
     let mut ast_nodes = vec![];
 
@@ -56,7 +166,8 @@ fn parse_with_context(context: Option<ParseContext>, lexer: &mut Lexer) -> ASTNo
             while let Token::Symbol('#') = lexer.current_token() {
                 let directive = parse_directive(lexer);
 
-                if TOP_LEVEL_DIRECTIVES.contains(&directive.id()) {
+                if TOP_LEVEL_DIRECTIVES.contains(&directive.id()) && !is_type_directive(&directive)
+                {
                     if !directives.is_empty() {
                         parse_error!(lexer, "Invalid directive: {}", directive.id());
                     }
@@ -519,6 +630,8 @@ pub fn parse_type_id(lexer: &mut Lexer) -> TypeIDASTNode {
                 ),
             }
         }
+
+        parse_error!(lexer, "Expected type but got {:?}", lexer.current_token());
     }
 
     match name.as_str() {
@@ -806,6 +919,21 @@ pub fn parse_position(lexer: &mut Lexer) -> u32 {
     position as u32
 }
 
+/// `#[rust(derive = "...")]` and `#[rust(attr = "...")]` belong to the next
+/// type, other `rust` groups configure the whole file.
+fn is_type_directive(directive: &DirectiveASTNode) -> bool {
+    match directive {
+        DirectiveASTNode::Group { group_id, values } => {
+            group_id == "rust"
+                && !values.is_empty()
+                && values
+                    .iter()
+                    .all(|value| RUST_TYPE_DIRECTIVES.contains(&value.id.as_str()))
+        }
+        DirectiveASTNode::Value { .. } => false,
+    }
+}
+
 /// Parse:
 /// #[<id> = <const>] | #[<id>(<args>)]
 /// args: <id> = <const>, args
@@ -889,264 +1017,14 @@ fn parse_group_directive(id: String, lexer: &mut Lexer) -> DirectiveASTNode {
 mod tests {
     use std::fs;
 
-    use crate::writer::Writer;
-
     use super::*;
 
     fn stringify_ast(ast: &[ASTNode]) -> String {
-        let result = stringify_ast_impl(0, ast);
+        let result = crate::ast::stringify_ast(ast);
         println!("{}", result);
         result
     }
 
-    fn stringify_ast_impl(tab: usize, ast: &[ASTNode]) -> String {
-        let mut writer = Writer::new(2);
-
-        for node in ast {
-            match node {
-                ASTNode::DocComments { comments } => {
-                    writer.writeln_tab(tab, "DocComment {");
-                    writer.writeln_tab(tab + 1, "comments: [");
-
-                    for comment in comments {
-                        writer.writeln_tab(tab + 2, &format!("\"{}\"", comment));
-                    }
-
-                    writer.writeln_tab(tab + 1, "]");
-                    writer.writeln_tab(tab, "}");
-                }
-                ASTNode::Directive(DirectiveASTNode::Group {
-                    group_id,
-                    values: args,
-                }) => {
-                    writer.writeln_tab(tab, "DirectiveASTNode::Group {");
-                    writer.writeln_tab(tab + 1, &format!("group_id: \"{}\",", group_id));
-                    writer.writeln_tab(tab + 1, "args: [");
-
-                    for arg in args {
-                        writer.writeln_tab(tab + 2, "IdValuePair {");
-                        writer.writeln_tab(tab + 3, &format!("id: \"{}\"", arg.id));
-                        writer.writeln_tab(tab + 3, &format!("value: {:?}", arg.value));
-                        writer.writeln_tab(tab + 2, "}");
-                    }
-
-                    writer.writeln_tab(tab + 1, "]");
-                    writer.writeln_tab(tab, "}");
-                }
-                ASTNode::Directive(DirectiveASTNode::Value { id, value }) => {
-                    writer.writeln_tab(tab, "DirectiveASTNode::Value {");
-                    writer.writeln_tab(tab + 1, &format!("id: \"{}\"", id));
-                    writer.writeln_tab(tab + 1, &format!("value: {:?}", value));
-                    writer.writeln_tab(tab, "}");
-                }
-                ASTNode::Enum(EnumASTNode {
-                    doc_comments,
-                    id,
-                    items,
-                    directives,
-                }) => {
-                    writer.writeln_tab(tab, "Enum {");
-                    writer.writeln_tab(tab + 1, "doc_comments: [");
-
-                    for comment in doc_comments {
-                        writer.writeln_tab(tab + 2, &format!("\"{}\"", comment));
-                    }
-
-                    writer.writeln_tab(tab + 1, "],");
-
-                    writer.writeln_tab(tab + 1, "directives: [");
-
-                    let nodes: Vec<ASTNode> = directives
-                        .iter()
-                        .map(|directive| ASTNode::Directive(directive.clone()))
-                        .collect();
-
-                    writer.write(&stringify_ast_impl(tab + 2, &nodes));
-
-                    writer.writeln_tab(tab + 1, "],");
-
-                    writer.writeln_tab(tab + 1, &format!("id: \"{}\",", id));
-                    writer.writeln_tab(tab + 1, "items: [");
-
-                    for item in items {
-                        match &item {
-                            EnumItemASTNode::Tuple {
-                                doc_comments,
-                                position,
-                                id,
-                                values,
-                            } => {
-                                writer.writeln_tab(tab + 2, "TupleFieldASTNode {");
-
-                                writer.writeln_tab(tab + 3, "doc_comments: [");
-
-                                for comment in doc_comments {
-                                    writer.writeln_tab(tab + 4, &format!("\"{}\"", comment));
-                                }
-
-                                writer.writeln_tab(tab + 3, "],");
-
-                                writer.writeln_tab(tab + 3, &format!("position: {},", position));
-                                writer.writeln_tab(tab + 3, &format!("id: \"{}\",", id));
-                                writer.writeln_tab(tab + 3, "items: [");
-
-                                for value in values {
-                                    writer.writeln_tab(tab + 4, &format!("{:?}", value));
-                                }
-
-                                writer.writeln_tab(tab + 3, "]");
-                                writer.writeln_tab(tab + 2, "}");
-                            }
-                            EnumItemASTNode::Struct {
-                                doc_comments,
-                                position,
-                                id,
-                                fields,
-                            } => {
-                                writer.writeln_tab(tab + 2, "EnumItemASTNode {");
-
-                                writer.writeln_tab(tab + 3, "doc_comments: [");
-
-                                for comment in doc_comments {
-                                    writer.writeln_tab(tab + 4, &format!("\"{}\"", comment));
-                                }
-
-                                writer.writeln_tab(tab + 3, "],");
-
-                                writer.writeln_tab(tab + 3, &format!("position: {},", position));
-                                writer.writeln_tab(tab + 3, &format!("id: \"{}\",", id));
-                                writer.writeln_tab(tab + 3, "fields: [");
-
-                                for field in fields {
-                                    writer.writeln_tab(tab + 4, &format!("{:?}", field));
-                                }
-
-                                writer.writeln_tab(tab + 3, "]");
-                                writer.writeln_tab(tab + 2, "}");
-                            }
-                            _ => writer.writeln_tab(tab + 2, &format!("{:?}", item)),
-                        }
-                    }
-
-                    writer.writeln_tab(tab + 1, "]");
-                    writer.writeln_tab(tab, "}");
-                }
-                ASTNode::Struct(StructASTNode {
-                    doc_comments,
-                    id,
-                    fields,
-                    emplace_buffers: _,
-                    into_buffers: _,
-                    directives,
-                }) => {
-                    writer.writeln_tab(tab, "Struct {");
-
-                    writer.writeln_tab(tab + 1, "doc_comments: [");
-
-                    for comment in doc_comments {
-                        writer.writeln_tab(tab + 2, &format!("\"{}\"", comment));
-                    }
-
-                    writer.writeln_tab(tab + 1, "],");
-
-                    writer.writeln_tab(tab + 1, "directives: [");
-
-                    let nodes: Vec<ASTNode> = directives
-                        .iter()
-                        .map(|directive| ASTNode::Directive(directive.clone()))
-                        .collect();
-
-                    writer.write(&stringify_ast_impl(tab + 2, &nodes));
-
-                    writer.writeln_tab(tab + 1, "],");
-
-                    writer.writeln_tab(tab + 1, &format!("id: \"{}\",", id));
-                    writer.writeln_tab(tab + 1, "fields: [");
-
-                    for field in fields {
-                        writer.writeln_tab(tab + 2, &format!("{:?}", field));
-                    }
-
-                    writer.writeln_tab(tab + 1, "]");
-                    writer.writeln_tab(tab, "}");
-                }
-                ASTNode::Fn(FnASTNode {
-                    id,
-                    position,
-                    args,
-                    return_type_id,
-                    is_signal,
-                    is_async,
-                    doc_comments,
-                    directives,
-                }) => {
-                    writer.writeln_tab(tab, "Fn {");
-
-                    writer.writeln_tab(tab + 1, "doc_comments: [");
-
-                    for comment in doc_comments {
-                        writer.writeln_tab(tab + 2, &format!("\"{}\"", comment));
-                    }
-
-                    writer.writeln_tab(tab + 1, "],");
-
-                    writer.writeln_tab(tab + 1, "directives: [");
-
-                    let nodes: Vec<ASTNode> = directives
-                        .iter()
-                        .map(|directive| ASTNode::Directive(directive.clone()))
-                        .collect();
-
-                    writer.write(&stringify_ast_impl(tab + 2, &nodes));
-
-                    writer.writeln_tab(tab + 1, "],");
-
-                    writer.writeln_tab(tab + 1, &format!("id: \"{}\",", id));
-                    writer.writeln_tab(tab + 1, &format!("position: {:?},", position));
-                    writer.writeln_tab(tab + 1, &format!("return_type_id: {:?},", return_type_id));
-                    writer.writeln_tab(tab + 1, &format!("is_signal: {:?},", is_signal));
-                    writer.writeln_tab(tab + 1, &format!("is_async: {:?},", is_async));
-                    writer.writeln_tab(tab + 1, "args: [");
-
-                    for arg in args {
-                        writer.writeln_tab(tab + 2, &format!("{:?}", arg));
-                    }
-
-                    writer.writeln_tab(tab + 1, "]");
-                    writer.writeln_tab(tab, "}");
-                }
-                ASTNode::Const(ConstBlockASTNode { id, items }) => {
-                    writer.writeln_tab(tab, "Const {");
-                    writer.writeln_tab(tab + 1, &format!("id: \"{}\",", id));
-                    writer.writeln_tab(tab + 1, "items: [");
-
-                    for item in items {
-                        match &item {
-                            ConstItemASTNode::Value { id, type_id, value } => {
-                                writer.writeln_tab(tab + 2, "Value {");
-                                writer.writeln_tab(tab + 3, &format!("id: {}", id));
-                                writer.writeln_tab(tab + 3, &format!("type_id: {:?}", type_id));
-                                writer.writeln_tab(tab + 3, &format!("value: {:?}", value));
-                                writer.writeln_tab(tab + 2, "}");
-                            }
-                            ConstItemASTNode::ConstsBlock { node } => {
-                                writer.write(&stringify_ast_impl(
-                                    tab + 2,
-                                    &[ASTNode::Const(node.clone())],
-                                ));
-                            }
-                        }
-                    }
-
-                    writer.writeln_tab(tab + 1, "]");
-                    writer.writeln_tab(tab, "}");
-                }
-            }
-        }
-
-        writer.show().to_string()
-    }
-
     #[test]
     fn parse_position_test() {
         let mut lexer = Lexer::tokenize("#[123]");
@@ -1233,4 +1111,33 @@ mod tests {
 
         assert_eq!(actual_ast, target_ast);
     }
+
+    #[test]
+    fn parse_source_error_test() {
+        let error = parse_source("struct Point {\n    x: f32,\n    y f32,\n}").unwrap_err();
+
+        assert_eq!(error.line, 3);
+        assert!(error.message.starts_with("Expected ':'"), "{}", error);
+        assert!(parse_source("struct Point { x: f32 }").is_ok());
+    }
+
+    #[test]
+    fn parse_source_restores_panic_hook_test() {
+        thread_local! {
+            static HOOK_CALLS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {
+            HOOK_CALLS.with(|calls| calls.set(calls.get() + 1))
+        }));
+
+        assert!(parse_source("struct Point {").is_err());
+        assert_eq!(HOOK_CALLS.with(|calls| calls.get()), 0);
+
+        assert!(panic::catch_unwind(|| panic!("hook")).is_err());
+        assert_eq!(HOOK_CALLS.with(|calls| calls.get()), 1);
+
+        panic::set_hook(previous_hook);
+    }
 }
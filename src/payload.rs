@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+use crate::{
+    ast::{
+        ASTNode, ConstValueASTNode, DirectiveASTNode, EnumItemASTNode, FnASTNode, TypeIDASTNode,
+    },
+    lexer::Literal,
+};
+
+/// Size class of the buffers allocated for an RPC method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadSize {
+    Zero,
+    Medium,
+    Large,
+}
+
+/// Byte counts up to this size get medium buffers, larger ones get large buffers.
+const MEDIUM_PAYLOAD_LIMIT: i64 = 4096;
+
+/// Resolves the payload size of the method, an explicit
+/// `#[payload(size = "large")]` or `#[payload(size = 4096)]` directive
+/// takes precedence over the size inferred from the method signature.
+pub fn find_payload_size(ast: &[ASTNode], node: &FnASTNode) -> PayloadSize {
+    if let Some(size) = find_payload_size_directive(node) {
+        return size;
+    }
+
+    if node.args.is_empty() && node.return_type_id.is_none() {
+        return PayloadSize::Zero;
+    }
+
+    let mut visited = HashSet::new();
+    let mut type_ids = node.args.iter().map(|arg| &arg.type_id).collect::<Vec<_>>();
+
+    if let Some(return_type_id) = &node.return_type_id {
+        type_ids.push(return_type_id);
+    }
+
+    for type_id in type_ids {
+        if contains_vec(ast, type_id, &mut visited) {
+            return PayloadSize::Large;
+        }
+    }
+
+    PayloadSize::Medium
+}
+
+fn find_payload_size_directive(node: &FnASTNode) -> Option<PayloadSize> {
+    for directive in node.directives.iter() {
+        if let DirectiveASTNode::Group { group_id, values } = directive {
+            if group_id != "payload" {
+                continue;
+            }
+
+            let value = values
+                .iter()
+                .find(|value| value.id == "size")
+                .unwrap_or_else(|| panic!("payload directive of {} requires size", node.id));
+
+            let literal = match &value.value {
+                Some(ConstValueASTNode::Literal { literal, .. }) => literal,
+                None => panic!("payload size of {} should have a value", node.id),
+            };
+
+            let size = match literal {
+                Literal::StringLiteral(size) => match size.as_str() {
+                    "zero" => PayloadSize::Zero,
+                    "medium" => PayloadSize::Medium,
+                    "large" => PayloadSize::Large,
+                    _ => panic!("Invalid payload size: {}", size),
+                },
+                Literal::IntLiteral(0) => PayloadSize::Zero,
+                Literal::IntLiteral(size) if *size > 0 && *size <= MEDIUM_PAYLOAD_LIMIT => {
+                    PayloadSize::Medium
+                }
+                Literal::IntLiteral(size) if *size > MEDIUM_PAYLOAD_LIMIT => PayloadSize::Large,
+                _ => panic!("Invalid payload size: {:?}", literal),
+            };
+
+            return Some(size);
+        }
+    }
+
+    None
+}
+
+/// Whether values of the type can hold a variable number of items,
+/// struct and enum types are resolved through the AST recursively.
+fn contains_vec<'a>(
+    ast: &'a [ASTNode],
+    type_id: &'a TypeIDASTNode,
+    visited: &mut HashSet<&'a str>,
+) -> bool {
+    match type_id {
+        TypeIDASTNode::Generic { id, generics } => {
+            id == "Vec"
+                || generics
+                    .iter()
+                    .any(|type_id| contains_vec(ast, type_id, visited))
+        }
+        TypeIDASTNode::Other { id } => {
+            if !visited.insert(id) {
+                return false;
+            }
+
+            for node in ast {
+                match node {
+                    ASTNode::Struct(node) if node.id == *id => {
+                        return node
+                            .fields
+                            .iter()
+                            .any(|field| contains_vec(ast, &field.type_id, visited));
+                    }
+                    ASTNode::Enum(node) if node.id == *id => {
+                        return node.items.iter().any(|item| match item {
+                            EnumItemASTNode::Empty { .. } => false,
+                            EnumItemASTNode::Tuple { values, .. } => values
+                                .iter()
+                                .any(|value| contains_vec(ast, &value.type_id, visited)),
+                            EnumItemASTNode::Struct { fields, .. } => fields
+                                .iter()
+                                .any(|field| contains_vec(ast, &field.type_id, visited)),
+                        });
+                    }
+                    _ => (),
+                }
+            }
+
+            false
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ast, lexer::Lexer, parser::parse};
+
+    fn payload_sizes(src: &str) -> Vec<PayloadSize> {
+        let mut lexer = Lexer::tokenize(src);
+        let ast = parse(&mut lexer);
+
+        ast::find_fn_nodes(&ast)
+            .iter()
+            .map(|node| find_payload_size(&ast, node))
+            .collect()
+    }
+
+    #[test]
+    fn payload_size_from_signature() {
+        let sizes = payload_sizes(
+            "
+            struct Points { items: Vec<Point> }
+            struct Point { x: f32, y: f32 }
+            enum Shape { Empty, Polygon(Points) }
+
+            fn empty();
+            fn point() -> Point;
+            fn add_points(points: Vec<Point>);
+            fn points() -> Points;
+            fn shape(id: u64) -> Shape;
+            fn optional_points() -> Option<Points>;
+            ",
+        );
+
+        assert_eq!(
+            sizes,
+            vec![
+                PayloadSize::Zero,
+                PayloadSize::Medium,
+                PayloadSize::Large,
+                PayloadSize::Large,
+                PayloadSize::Large,
+                PayloadSize::Large,
+            ]
+        );
+    }
+
+    #[test]
+    fn payload_size_from_directive() {
+        let sizes = payload_sizes(
+            "
+            #[payload(size = \"large\")]
+            fn name() -> String;
+
+            #[payload(size = 4096)]
+            signal points -> Vec<f32>;
+
+            #[payload(size = 65536)]
+            fn document() -> String;
+
+            #[payload(size = \"medium\")]
+            fn trigger();
+            ",
+        );
+
+        assert_eq!(
+            sizes,
+            vec![
+                PayloadSize::Large,
+                PayloadSize::Medium,
+                PayloadSize::Large,
+                PayloadSize::Medium,
+            ]
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! External generators, similar to protoc plugins.
+//!
+//! The plugin `<name>` is an executable called `tpb-gen-<name>` found in
+//! `PATH`. It reads a [`PluginRequest`] as JSON from stdin and writes a
+//! [`PluginResponse`] as JSON to stdout. The JSON schema of both is in
+//! `docs/plugin_protocol.schema.json`.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    io::Write,
+    path::{Component, Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ast::ASTNode, validate::validate};
+
+/// Version of the request and response format, bumped on breaking changes
+/// of the AST or the protocol.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+pub const PLUGIN_PREFIX: &str = "tpb-gen-";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PluginRequest {
+    pub version: u32,
+    /// Path of the schema as passed to the CLI.
+    pub source: String,
+    /// Options passed with `--opt key=value`.
+    pub options: BTreeMap<String, String>,
+    pub ast: Vec<ASTNode>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PluginResponse {
+    #[serde(default)]
+    pub files: Vec<PluginFile>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PluginFile {
+    /// Path relative to the output directory.
+    pub name: String,
+    pub content: String,
+}
+
+#[derive(Debug)]
+pub enum PluginError {
+    /// The schema has errors the parser doesn't catch, e.g. duplicate
+    /// definitions, it's not sent to the plugin.
+    InvalidSchema(Vec<String>),
+    Io(String, io::Error),
+    Failed(String, String),
+    InvalidResponse(String, String),
+    InvalidFileName(String, String),
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginError::InvalidSchema(errors) => write!(f, "{}", errors.join("\n")),
+            PluginError::Io(program, err) => write!(f, "{}: {}", program, err),
+            PluginError::Failed(program, message) => write!(f, "{}: {}", program, message),
+            PluginError::InvalidResponse(program, message) => {
+                write!(f, "{}: invalid response: {}", program, message)
+            }
+            PluginError::InvalidFileName(program, name) => {
+                write!(f, "{}: invalid file name \"{}\"", program, name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+impl PluginRequest {
+    pub fn new(source: &str, options: BTreeMap<String, String>, ast: Vec<ASTNode>) -> Self {
+        PluginRequest {
+            version: PROTOCOL_VERSION,
+            source: source.to_string(),
+            options,
+            ast,
+        }
+    }
+}
+
+pub fn plugin_program(name: &str) -> String {
+    format!("{}{}", PLUGIN_PREFIX, name)
+}
+
+/// Runs `tpb-gen-<name>` with the request on stdin and returns its response.
+pub fn run_plugin(name: &str, request: &PluginRequest) -> Result<PluginResponse, PluginError> {
+    let errors = validate(&request.ast)
+        .into_iter()
+        .map(|error| error.message)
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        return Err(PluginError::InvalidSchema(errors));
+    }
+
+    let program = plugin_program(name);
+    let io_error = |err| PluginError::Io(program.clone(), err);
+
+    let mut child = Command::new(&program)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(io_error)?;
+
+    // Written from another thread, a plugin may start answering before
+    // it has read the whole request and block on a full stdout pipe.
+    let request = serde_json::to_vec(request).unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let writer = thread::spawn(move || stdin.write_all(&request));
+
+    let output = child.wait_with_output().map_err(io_error)?;
+    let written = writer.join().unwrap();
+
+    if !output.status.success() {
+        return Err(PluginError::Failed(
+            program,
+            format!("exited with {}", output.status),
+        ));
+    }
+
+    // A plugin that doesn't need the whole request may exit without
+    // reading it.
+    match written {
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => return Err(io_error(err)),
+        _ => (),
+    }
+
+    let response = serde_json::from_slice::<PluginResponse>(&output.stdout)
+        .map_err(|err| PluginError::InvalidResponse(program.clone(), err.to_string()))?;
+
+    if let Some(error) = response.error {
+        return Err(PluginError::Failed(program, error));
+    }
+
+    for file in &response.files {
+        if !is_relative_file_name(&file.name) {
+            return Err(PluginError::InvalidFileName(program, file.name.clone()));
+        }
+    }
+
+    Ok(response)
+}
+
+/// Writes the returned files into `output` and returns their paths.
+pub fn write_files(output: &Path, files: &[PluginFile]) -> io::Result<Vec<PathBuf>> {
+    let mut paths = vec![];
+
+    for file in files {
+        let path = output.join(&file.name);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&path, &file.content)?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+/// Plugins can only write into the output directory.
+fn is_relative_file_name(name: &str) -> bool {
+    let path = Path::new(name);
+
+    !name.is_empty()
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::Read};
+
+    use crate::{lexer::Lexer, parser};
+
+    use super::*;
+
+    #[test]
+    fn serialize_request() {
+        let mut file = File::open("test_resources/plugin/request.tpb").unwrap();
+        let mut data = String::new();
+        file.read_to_string(&mut data).unwrap();
+
+        let mut lexer = Lexer::tokenize(&data);
+        let ast = parser::parse(&mut lexer);
+
+        let mut options = BTreeMap::new();
+        options.insert(String::from("indent"), String::from("2"));
+
+        let request = PluginRequest::new("request.tpb", options, ast);
+        let actual = serde_json::to_string_pretty(&request).unwrap();
+        println!("{}", actual);
+
+        let mut file = File::open("test_resources/plugin/request.json").unwrap();
+        let mut expected = String::new();
+        file.read_to_string(&mut expected).unwrap();
+
+        assert_eq!(actual, expected.trim_end());
+
+        let request = serde_json::from_str::<PluginRequest>(&actual).unwrap();
+        assert_eq!(serde_json::to_string_pretty(&request).unwrap(), actual);
+    }
+
+    #[test]
+    fn relative_file_names() {
+        assert!(is_relative_file_name("models.ts"));
+        assert!(is_relative_file_name("./src/models.ts"));
+        assert!(!is_relative_file_name(""));
+        assert!(!is_relative_file_name("../models.ts"));
+        assert!(!is_relative_file_name("/tmp/models.ts"));
+    }
+
+    #[test]
+    fn invalid_schema_is_not_sent() {
+        let ast = parser::parse_source("struct A { a: i32 }\nenum A { X }").unwrap();
+        let request = PluginRequest::new("invalid.tpb", BTreeMap::new(), ast);
+
+        match run_plugin("missing", &request) {
+            Err(PluginError::InvalidSchema(errors)) => {
+                assert_eq!(errors, vec!["`A` is defined more than once"])
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+}
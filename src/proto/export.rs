@@ -0,0 +1,495 @@
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{
+        self, ASTNode, ConstValueASTNode, EnumASTNode, EnumItemASTNode, FnASTNode, StructASTNode,
+        StructFieldASTNode, TypeIDASTNode,
+    },
+    lexer::Literal,
+    options::DEFAULT_HEADER,
+    writer::Writer,
+};
+
+use super::{EMPTY_IMPORT, EMPTY_TYPE, PROTO_INDENT};
+
+/// Field of a message, struct fields, tuple values and fn arguments.
+struct Field {
+    doc_comments: Vec<String>,
+    number: u32,
+    name: String,
+    type_id: TypeIDASTNode,
+}
+
+/// Converts a schema to a proto3 file, returns the file and the warnings
+/// about everything that was skipped or changed.
+///
+/// Field numbers are the positions plus one, since protobuf numbers start at
+/// 1, enums with payloads become messages with a `oneof`, and `fn` and
+/// `signal` nodes become rpcs of a service named after the namespace.
+pub fn export_proto(ast: &[ASTNode]) -> (String, Vec<String>) {
+    let mut exporter = Exporter {
+        package: string_directive(ast, "namespace"),
+        ..Exporter::default()
+    };
+    let body = exporter.export(ast);
+    let mut writer = Writer::new(PROTO_INDENT);
+
+    writer.writeln(DEFAULT_HEADER);
+    writer.new_line();
+    writer.writeln("syntax = \"proto3\";");
+
+    if let Some(namespace) = string_directive(ast, "namespace") {
+        writer.new_line();
+        writer.writeln(&format!("package {};", namespace));
+    }
+
+    if exporter.uses_empty {
+        writer.new_line();
+        writer.writeln(&format!("import \"{}\";", EMPTY_IMPORT));
+    }
+
+    if !body.is_empty() {
+        writer.new_line();
+        writer.write(&body);
+    }
+
+    (writer.show().to_string(), exporter.warnings)
+}
+
+#[derive(Default)]
+struct Exporter {
+    warnings: Vec<String>,
+    uses_empty: bool,
+    package: Option<String>,
+    /// Set while writing the item messages nested in an enum message, where
+    /// a type named like an item would resolve to the item.
+    is_nested: bool,
+}
+
+impl Exporter {
+    fn export(&mut self, ast: &[ASTNode]) -> String {
+        let mut blocks = vec![];
+
+        for node in ast {
+            match node {
+                ASTNode::Struct(node) => blocks.push(self.export_struct(node)),
+                ASTNode::Enum(node) => blocks.push(self.export_enum(node)),
+                ASTNode::Const(node) => self.warnings.push(format!(
+                    "const block `{}` has no protobuf equivalent, skipped",
+                    node.id
+                )),
+                _ => (),
+            }
+        }
+
+        if ast::contains_fn_nodes(ast) {
+            blocks.push(self.export_service(ast));
+        }
+
+        blocks.join("\n")
+    }
+
+    fn export_struct(&mut self, node: &StructASTNode) -> String {
+        let mut writer = Writer::new(PROTO_INDENT);
+        let fields = node
+            .fields
+            .iter()
+            .map(Field::from_struct_field)
+            .collect::<Vec<Field>>();
+
+        generate_doc_comments(&mut writer, &node.doc_comments);
+        self.generate_message(&mut writer, &node.id, &fields);
+        writer.show().to_string()
+    }
+
+    fn export_enum(&mut self, node: &EnumASTNode) -> String {
+        let mut writer = Writer::new(PROTO_INDENT);
+        generate_doc_comments(&mut writer, &node.doc_comments);
+
+        let is_plain = node
+            .items
+            .iter()
+            .all(|item| matches!(item, EnumItemASTNode::Empty { .. }));
+
+        if is_plain {
+            let prefix = node.id.to_case(Case::UpperSnake);
+
+            writer.writeln(&format!("enum {} {{", node.id));
+            writer.push_tab();
+
+            // The first value of a proto3 enum has to be 0.
+            if !node.items.iter().any(|item| item.position() == 0) {
+                writer.writeln(&format!("{}_UNSPECIFIED = 0;", prefix));
+            }
+
+            for item in &node.items {
+                generate_doc_comments(&mut writer, item.doc_comment());
+                writer.writeln(&format!(
+                    "{}_{} = {};",
+                    prefix,
+                    item.id().to_case(Case::UpperSnake),
+                    item.position()
+                ));
+            }
+
+            writer.pop_tab();
+            writer.writeln("}");
+
+            return writer.show().to_string();
+        }
+
+        writer.writeln(&format!("message {} {{", node.id));
+        writer.push_tab();
+
+        for item in &node.items {
+            let fields = match item {
+                EnumItemASTNode::Empty { .. } => vec![],
+                EnumItemASTNode::Tuple { values, .. } => values
+                    .iter()
+                    .map(|value| Field {
+                        doc_comments: value.doc_comments.clone(),
+                        number: value.position + 1,
+                        name: format!("p{}", value.position),
+                        type_id: value.type_id.clone(),
+                    })
+                    .collect(),
+                EnumItemASTNode::Struct { fields, .. } => {
+                    fields.iter().map(Field::from_struct_field).collect()
+                }
+            };
+
+            self.is_nested = true;
+            self.generate_message(&mut writer, item.id(), &fields);
+            self.is_nested = false;
+            writer.new_line();
+        }
+
+        writer.writeln("oneof value {");
+        writer.push_tab();
+
+        for item in &node.items {
+            generate_doc_comments(&mut writer, item.doc_comment());
+            writer.writeln(&format!(
+                "{} {} = {};",
+                item.id(),
+                item.id().to_case(Case::Snake),
+                item.position() + 1
+            ));
+        }
+
+        writer.pop_tab();
+        writer.writeln("}");
+        writer.pop_tab();
+        writer.writeln("}");
+
+        writer.show().to_string()
+    }
+
+    fn export_service(&mut self, ast: &[ASTNode]) -> String {
+        let namespace = string_directive(ast, "namespace").expect("namespace is required");
+        let mut messages = vec![];
+        let mut writer = Writer::new(PROTO_INDENT);
+
+        if let Some(id) = string_directive(ast, "id") {
+            writer.writeln(&format!("// id: {}", id));
+        }
+
+        writer.writeln(&format!("service {} {{", namespace.to_case(Case::Pascal)));
+        writer.push_tab();
+
+        for node in ast::find_fn_nodes(ast) {
+            let request = self.export_request(node, &mut messages);
+            let response = self.export_response(node, &mut messages);
+
+            generate_doc_comments(&mut writer, &node.doc_comments);
+
+            if node.is_signal {
+                writer.writeln(&format!(
+                    "rpc {}({}) returns (stream {});",
+                    node.id.to_case(Case::Pascal),
+                    request,
+                    response
+                ));
+            } else {
+                writer.writeln(&format!(
+                    "rpc {}({}) returns ({});",
+                    node.id.to_case(Case::Pascal),
+                    request,
+                    response
+                ));
+            }
+        }
+
+        writer.pop_tab();
+        writer.writeln("}");
+        messages.push(writer.show().to_string());
+
+        messages.join("\n")
+    }
+
+    /// `{Fn}Request` with the arguments as fields, or `Empty`.
+    fn export_request(&mut self, node: &FnASTNode, messages: &mut Vec<String>) -> String {
+        if node.args.is_empty() {
+            self.uses_empty = true;
+            return String::from(EMPTY_TYPE);
+        }
+
+        let id = format!("{}Request", node.id.to_case(Case::Pascal));
+        let fields = node
+            .args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| Field {
+                doc_comments: vec![],
+                number: i as u32 + 1,
+                name: arg.id.clone(),
+                type_id: arg.type_id.clone(),
+            })
+            .collect::<Vec<Field>>();
+
+        let mut writer = Writer::new(PROTO_INDENT);
+        self.generate_message(&mut writer, &id, &fields);
+        messages.push(writer.show().to_string());
+
+        id
+    }
+
+    /// `{Fn}Response` with the result as `value`, or `Empty`.
+    fn export_response(&mut self, node: &FnASTNode, messages: &mut Vec<String>) -> String {
+        let type_id = match &node.return_type_id {
+            Some(type_id) => type_id,
+            None => {
+                self.uses_empty = true;
+                return String::from(EMPTY_TYPE);
+            }
+        };
+
+        let id = format!("{}Response", node.id.to_case(Case::Pascal));
+        let field = Field {
+            doc_comments: vec![],
+            number: 1,
+            name: String::from("value"),
+            type_id: type_id.clone(),
+        };
+
+        let mut writer = Writer::new(PROTO_INDENT);
+        self.generate_message(&mut writer, &id, &[field]);
+        messages.push(writer.show().to_string());
+
+        id
+    }
+
+    fn generate_message(&mut self, writer: &mut Writer, id: &str, fields: &[Field]) {
+        if fields.is_empty() {
+            writer.writeln(&format!("message {} {{}}", id));
+            return;
+        }
+
+        writer.writeln(&format!("message {} {{", id));
+        writer.push_tab();
+
+        for field in fields {
+            generate_doc_comments(writer, &field.doc_comments);
+
+            let location = format!("{}.{}", id, field.name);
+
+            match self.field_type(&location, &field.type_id) {
+                Ok(type_id) => {
+                    writer.writeln(&format!("{} {} = {};", type_id, field.name, field.number))
+                }
+                Err(reason) => {
+                    self.warnings
+                        .push(format!("{}: {}, skipped", location, reason));
+                    writer.writeln(&format!(
+                        "// {}: {} has no protobuf equivalent",
+                        field.name,
+                        generate_type_id(&field.type_id)
+                    ));
+                    writer.writeln(&format!("reserved {};", field.number));
+                }
+            }
+        }
+
+        writer.pop_tab();
+        writer.writeln("}");
+    }
+
+    /// Type of a field with the `repeated` or `optional` label.
+    fn field_type(&mut self, location: &str, type_id: &TypeIDASTNode) -> Result<String, String> {
+        match type_id {
+            TypeIDASTNode::Generic { id, generics } if id == "Vec" => {
+                let item = generics.first().expect("Vec type cannot be empty");
+
+                match item {
+                    TypeIDASTNode::Integer {
+                        size: 1,
+                        signed: false,
+                        ..
+                    } => Ok(String::from("bytes")),
+                    _ => Ok(format!("repeated {}", self.scalar_type(location, item)?)),
+                }
+            }
+            TypeIDASTNode::Generic { id, generics } if id == "Option" => {
+                let value = generics.first().expect("Optional type cannot be empty");
+                Ok(format!("optional {}", self.scalar_type(location, value)?))
+            }
+            _ => self.scalar_type(location, type_id),
+        }
+    }
+
+    fn scalar_type(&mut self, location: &str, type_id: &TypeIDASTNode) -> Result<String, String> {
+        match type_id {
+            TypeIDASTNode::Integer { id, size, signed } => {
+                let proto_id = match (size, signed) {
+                    (8, true) => "int64",
+                    (8, false) => "uint64",
+                    (_, true) => "int32",
+                    (_, false) => "uint32",
+                };
+
+                if *size < 4 {
+                    self.warnings.push(format!(
+                        "{}: `{}` is widened to `{}`",
+                        location, id, proto_id
+                    ));
+                }
+
+                Ok(String::from(proto_id))
+            }
+            TypeIDASTNode::Number { size: 4, .. } => Ok(String::from("float")),
+            TypeIDASTNode::Number { .. } => Ok(String::from("double")),
+            TypeIDASTNode::Bool { .. } => Ok(String::from("bool")),
+            TypeIDASTNode::Char { .. } => {
+                self.warnings.push(format!(
+                    "{}: `char` is exported as a `uint32` code point",
+                    location
+                ));
+                Ok(String::from("uint32"))
+            }
+            TypeIDASTNode::Other { id } => match id.as_str() {
+                "String" => Ok(String::from("string")),
+                "GroupAddress" | "CommandsBufferAddress" => Ok(String::from("uint64")),
+                _ if self.is_nested => match &self.package {
+                    Some(package) => Ok(format!(".{}.{}", package, id)),
+                    None => Ok(format!(".{}", id)),
+                },
+                _ => Ok(id.clone()),
+            },
+            TypeIDASTNode::Generic { id, .. } if id == "Vec" || id == "Option" => Err(format!(
+                "`{}` inside `Vec` or `Option` needs a wrapper message",
+                generate_type_id(type_id)
+            )),
+            TypeIDASTNode::Generic { .. } => Err(format!(
+                "generic type `{}` can't be expressed in protobuf",
+                generate_type_id(type_id)
+            )),
+        }
+    }
+}
+
+impl Field {
+    fn from_struct_field(field: &StructFieldASTNode) -> Self {
+        Field {
+            doc_comments: field.doc_comments.clone(),
+            number: field.position + 1,
+            name: field.name.clone(),
+            type_id: field.type_id.clone(),
+        }
+    }
+}
+
+fn generate_doc_comments(writer: &mut Writer, doc_comments: &[String]) {
+    for comment in doc_comments {
+        writer.writeln(&format!("//{}", comment));
+    }
+}
+
+fn string_directive(ast: &[ASTNode], id: &str) -> Option<String> {
+    match ast::find_directive_value(ast, id) {
+        Some(ConstValueASTNode::Literal {
+            literal: Literal::StringLiteral(value),
+            ..
+        }) => Some(value),
+        _ => None,
+    }
+}
+
+/// The type as written in the schema, for comments and warnings.
+fn generate_type_id(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { id, .. }
+        | TypeIDASTNode::Number { id, .. }
+        | TypeIDASTNode::Bool { id }
+        | TypeIDASTNode::Char { id }
+        | TypeIDASTNode::Other { id } => id.clone(),
+        TypeIDASTNode::Generic { id, generics } => format!(
+            "{}<{}>",
+            id,
+            generics
+                .iter()
+                .map(generate_type_id)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    fn parse_resource(name: &str) -> Vec<ASTNode> {
+        let src = fs::read_to_string(format!("test_resources/{}.tpb", name)).unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        parse(&mut lexer)
+    }
+
+    fn read_target(name: &str) -> String {
+        fs::read_to_string(format!("test_resources/proto/{}.proto", name)).unwrap()
+    }
+
+    #[test]
+    fn export_struct_types_test() {
+        let ast = parse_resource("struct_types");
+        let (actual, warnings) = export_proto(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_types").trim());
+        assert_eq!(
+            warnings,
+            vec![
+                "BasicTypesModel.byte: `u8` is widened to `uint32`",
+                "BasicTypesModel.generic: generic type `LinearTable<f32, Test>` can't be \
+                 expressed in protobuf, skipped",
+            ]
+        );
+    }
+
+    #[test]
+    fn export_enum_test() {
+        let ast = parse_resource("doc_comments");
+        let (actual, warnings) = export_proto(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("doc_comments").trim());
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn export_enum_item_names_test() {
+        let ast = parse_resource("enum_item_names");
+        let (actual, warnings) = export_proto(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("enum_item_names").trim());
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn export_rpc_methods_test() {
+        let ast = parse_resource("rpc_methods");
+        let (actual, warnings) = export_proto(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_methods").trim());
+        assert!(warnings.is_empty());
+    }
+}
@@ -0,0 +1,1409 @@
+use std::{collections::HashMap, fmt};
+
+use convert_case::{Case, Casing};
+
+use crate::writer::Writer;
+
+use super::EMPTY_IMPORT;
+
+#[derive(Debug)]
+pub struct ImportError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+/// Converts a `.proto` file to a schema, returns the schema and the warnings
+/// about everything that was skipped: options, maps, extensions, client
+/// streaming and types from other files.
+///
+/// Messages with nothing but a `oneof` become enums, `{Rpc}Request` and
+/// `{Rpc}Response` messages used by a single rpc become its arguments and
+/// result, and server streaming rpcs become signals.
+pub fn import_proto(src: &str) -> Result<(String, Vec<String>), ImportError> {
+    let tokens = tokenize(src)?;
+    let mut parser = Parser {
+        tokens,
+        cursor: 0,
+        warnings: vec![],
+    };
+    let file = parser.file()?;
+    let mut importer = Importer {
+        warnings: parser.warnings,
+        package: file.package.clone(),
+        names: HashMap::new(),
+    };
+    let schema = importer.import(&file);
+
+    Ok((schema, importer.warnings))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(String),
+    Str(String),
+    Symbol(char),
+    /// `//` comment, `trailing` if it follows code on the same line.
+    Comment {
+        text: String,
+        trailing: bool,
+    },
+}
+
+fn tokenize(src: &str) -> Result<Vec<(usize, Token)>, ImportError> {
+    let chars = src.chars().collect::<Vec<char>>();
+    let mut tokens = vec![];
+    let mut line = 1;
+    let mut last_code_line = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '\n' {
+            line += 1;
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            let start = i + 2;
+
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+
+            tokens.push((
+                line,
+                Token::Comment {
+                    text: chars[start..i].iter().collect(),
+                    trailing: last_code_line == line,
+                },
+            ));
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                if chars[i] == '\n' {
+                    line += 1;
+                }
+
+                i += 1;
+            }
+
+            i += 2;
+        } else if c == '"' || c == '\'' {
+            let start = i + 1;
+            i += 1;
+
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+
+                i += 1;
+            }
+
+            if i >= chars.len() {
+                return Err(ImportError {
+                    line,
+                    message: String::from("unterminated string"),
+                });
+            }
+
+            tokens.push((line, Token::Str(chars[start..i].iter().collect())));
+            last_code_line = line;
+            i += 1;
+        } else if c.is_alphabetic() || c == '_' || c == '.' && !next_is_digit(&chars, i) {
+            let start = i;
+
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+            {
+                i += 1;
+            }
+
+            tokens.push((line, Token::Ident(chars[start..i].iter().collect())));
+            last_code_line = line;
+        } else if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' {
+            let start = i;
+            i += 1;
+
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '.') {
+                i += 1;
+            }
+
+            tokens.push((line, Token::Number(chars[start..i].iter().collect())));
+            last_code_line = line;
+        } else {
+            tokens.push((line, Token::Symbol(c)));
+            last_code_line = line;
+            i += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn next_is_digit(chars: &[char], i: usize) -> bool {
+    chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())
+}
+
+#[derive(Debug, Default)]
+struct ProtoFile {
+    package: Option<String>,
+    messages: Vec<Message>,
+    enums: Vec<Enum>,
+    services: Vec<Service>,
+}
+
+#[derive(Debug, Default)]
+struct Message {
+    doc_comments: Vec<String>,
+    name: String,
+    fields: Vec<Field>,
+    oneofs: Vec<Oneof>,
+    messages: Vec<Message>,
+    enums: Vec<Enum>,
+}
+
+#[derive(Debug)]
+struct Oneof {
+    name: String,
+    fields: Vec<Field>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Label {
+    None,
+    Optional,
+    Repeated,
+}
+
+#[derive(Debug)]
+struct Field {
+    doc_comments: Vec<String>,
+    label: Label,
+    type_id: String,
+    name: String,
+    number: u32,
+}
+
+#[derive(Debug)]
+struct Enum {
+    doc_comments: Vec<String>,
+    name: String,
+    values: Vec<(Vec<String>, String, i64)>,
+}
+
+#[derive(Debug)]
+struct Service {
+    doc_comments: Vec<String>,
+    name: String,
+    rpcs: Vec<Rpc>,
+}
+
+#[derive(Debug)]
+struct Rpc {
+    doc_comments: Vec<String>,
+    name: String,
+    request: String,
+    request_stream: bool,
+    response: String,
+    response_stream: bool,
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    cursor: usize,
+    warnings: Vec<String>,
+}
+
+impl Parser {
+    /// Line of the last read token.
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.cursor.saturating_sub(1))
+            .map_or(1, |(line, _)| *line)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, ImportError> {
+        Err(ImportError {
+            line: self.line(),
+            message: message.into(),
+        })
+    }
+
+    fn warn(&mut self, message: impl Into<String>) {
+        let message = format!("{}: {}", self.line(), message.into());
+        self.warnings.push(message);
+    }
+
+    /// Leading comments of the next declaration, trailing comments are
+    /// dropped.
+    fn doc_comments(&mut self) -> Vec<String> {
+        let mut comments = vec![];
+
+        while let Some((_, Token::Comment { text, trailing })) = self.tokens.get(self.cursor) {
+            if !trailing {
+                comments.push(text.clone());
+            }
+
+            self.cursor += 1;
+        }
+
+        comments
+    }
+
+    fn peek(&mut self) -> Option<&Token> {
+        self.doc_comments();
+        self.tokens.get(self.cursor).map(|(_, token)| token)
+    }
+
+    fn next(&mut self) -> Result<Token, ImportError> {
+        self.doc_comments();
+
+        match self.tokens.get(self.cursor) {
+            Some((_, token)) => {
+                self.cursor += 1;
+                Ok(token.clone())
+            }
+            None => self.error("unexpected end of file"),
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, ImportError> {
+        match self.next()? {
+            Token::Ident(ident) => Ok(ident),
+            token => self.error(format!("expected an identifier, got {:?}", token)),
+        }
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<(), ImportError> {
+        match self.next()? {
+            Token::Symbol(c) if c == symbol => Ok(()),
+            token => self.error(format!("expected `{}`, got {:?}", symbol, token)),
+        }
+    }
+
+    fn is_symbol(&mut self, symbol: char) -> bool {
+        self.peek() == Some(&Token::Symbol(symbol))
+    }
+
+    fn number(&mut self) -> Result<i64, ImportError> {
+        match self.next()? {
+            Token::Number(number) => {
+                let parsed = match number.strip_prefix("0x") {
+                    Some(hex) => i64::from_str_radix(hex, 16),
+                    None => number.parse::<i64>(),
+                };
+
+                match parsed {
+                    Ok(number) => Ok(number),
+                    Err(_) => self.error(format!("invalid number `{}`", number)),
+                }
+            }
+            token => self.error(format!("expected a number, got {:?}", token)),
+        }
+    }
+
+    /// Skips everything up to the `;` or the block ending the statement.
+    fn skip_statement(&mut self) -> Result<(), ImportError> {
+        let mut depth = 0;
+
+        loop {
+            match self.next()? {
+                Token::Symbol(';') if depth == 0 => return Ok(()),
+                Token::Symbol('{') => depth += 1,
+                Token::Symbol('}') => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Skips `[...]` field options.
+    fn skip_field_options(&mut self, location: &str) -> Result<(), ImportError> {
+        if self.is_symbol('[') {
+            self.warn(format!("options of `{}` are ignored", location));
+
+            while self.next()? != Token::Symbol(']') {}
+        }
+
+        Ok(())
+    }
+
+    fn file(&mut self) -> Result<ProtoFile, ImportError> {
+        let mut file = ProtoFile::default();
+
+        loop {
+            let doc_comments = self.doc_comments();
+
+            let keyword = match self.tokens.get(self.cursor) {
+                Some((_, Token::Ident(keyword))) => {
+                    let keyword = keyword.clone();
+                    self.cursor += 1;
+                    keyword
+                }
+                Some((_, Token::Symbol(';'))) => {
+                    self.cursor += 1;
+                    continue;
+                }
+                Some((_, token)) => {
+                    let token = token.clone();
+                    self.cursor += 1;
+                    return self.error(format!("unexpected {:?}", token));
+                }
+                None => return Ok(file),
+            };
+
+            match keyword.as_str() {
+                "syntax" | "edition" => {
+                    self.expect('=')?;
+
+                    match self.next()? {
+                        Token::Str(syntax) if syntax == "proto3" => (),
+                        Token::Str(syntax) => {
+                            self.warn(format!(
+                                "`{}` is read as proto3, defaults and groups are ignored",
+                                syntax
+                            ));
+                        }
+                        token => return self.error(format!("expected a string, got {:?}", token)),
+                    }
+
+                    self.expect(';')?;
+                }
+                "package" => {
+                    file.package = Some(self.ident()?);
+                    self.expect(';')?;
+                }
+                "import" => {
+                    let path = loop {
+                        match self.next()? {
+                            Token::Str(path) => break path,
+                            Token::Ident(_) => continue,
+                            token => {
+                                return self.error(format!("expected a string, got {:?}", token))
+                            }
+                        }
+                    };
+
+                    if path != EMPTY_IMPORT {
+                        self.warn(format!(
+                            "import of `{}` is skipped, its types have to be imported separately",
+                            path
+                        ));
+                    }
+
+                    self.expect(';')?;
+                }
+                "option" => {
+                    self.warn("file options are ignored");
+                    self.skip_statement()?;
+                }
+                "message" => {
+                    file.messages.push(self.message(doc_comments)?);
+                }
+                "enum" => {
+                    file.enums.push(self.enumeration(doc_comments)?);
+                }
+                "service" => {
+                    file.services.push(self.service(doc_comments)?);
+                }
+                "extend" => {
+                    self.warn("extensions are not supported, skipped");
+                    self.skip_statement()?;
+                }
+                _ => return self.error(format!("unexpected `{}`", keyword)),
+            }
+        }
+    }
+
+    fn message(&mut self, doc_comments: Vec<String>) -> Result<Message, ImportError> {
+        let mut message = Message {
+            doc_comments,
+            name: self.ident()?,
+            ..Message::default()
+        };
+
+        self.expect('{')?;
+
+        loop {
+            let doc_comments = self.doc_comments();
+
+            let keyword = match self.next()? {
+                Token::Symbol('}') => return Ok(message),
+                Token::Symbol(';') => continue,
+                Token::Ident(keyword) => keyword,
+                token => return self.error(format!("unexpected {:?}", token)),
+            };
+
+            match keyword.as_str() {
+                "message" => message.messages.push(self.message(doc_comments)?),
+                "enum" => message.enums.push(self.enumeration(doc_comments)?),
+                "oneof" => {
+                    let name = self.ident()?;
+                    let mut fields = vec![];
+                    self.expect('{')?;
+
+                    loop {
+                        let doc_comments = self.doc_comments();
+
+                        match self.next()? {
+                            Token::Symbol('}') => break,
+                            Token::Ident(keyword) if keyword == "option" => {
+                                self.warn(format!("options of `{}` are ignored", name));
+                                self.skip_statement()?;
+                            }
+                            Token::Ident(type_id) => {
+                                let location = format!("{}.{}", message.name, name);
+                                fields.push(self.field(
+                                    doc_comments,
+                                    Label::None,
+                                    type_id,
+                                    &location,
+                                )?);
+                            }
+                            token => return self.error(format!("unexpected {:?}", token)),
+                        }
+                    }
+
+                    message.oneofs.push(Oneof { name, fields });
+                }
+                "reserved" => self.skip_statement()?,
+                "option" => {
+                    self.warn(format!("options of `{}` are ignored", message.name));
+                    self.skip_statement()?;
+                }
+                "extensions" | "extend" => {
+                    self.warn(format!(
+                        "extensions of `{}` are not supported, skipped",
+                        message.name
+                    ));
+                    self.skip_statement()?;
+                }
+                "map" => {
+                    self.warn(format!(
+                        "map fields of `{}` are not supported, skipped",
+                        message.name
+                    ));
+                    self.skip_statement()?;
+                }
+                "group" => {
+                    self.warn(format!(
+                        "groups of `{}` are not supported, skipped",
+                        message.name
+                    ));
+                    self.skip_statement()?;
+                }
+                "repeated" | "optional" | "required" => {
+                    let label = match keyword.as_str() {
+                        "repeated" => Label::Repeated,
+                        "optional" => Label::Optional,
+                        _ => Label::None,
+                    };
+
+                    let type_id = self.ident()?;
+
+                    if type_id == "group" {
+                        self.warn(format!(
+                            "groups of `{}` are not supported, skipped",
+                            message.name
+                        ));
+                        self.skip_statement()?;
+                        continue;
+                    }
+
+                    let location = message.name.clone();
+                    let field = self.field(doc_comments, label, type_id, &location)?;
+                    message.fields.push(field);
+                }
+                _ => {
+                    let location = message.name.clone();
+                    let field = self.field(doc_comments, Label::None, keyword, &location)?;
+                    message.fields.push(field);
+                }
+            }
+        }
+    }
+
+    fn field(
+        &mut self,
+        doc_comments: Vec<String>,
+        label: Label,
+        type_id: String,
+        location: &str,
+    ) -> Result<Field, ImportError> {
+        let name = self.ident()?;
+        self.expect('=')?;
+        let number = self.number()?;
+        self.skip_field_options(&format!("{}.{}", location, name))?;
+        self.expect(';')?;
+
+        Ok(Field {
+            doc_comments,
+            label,
+            type_id,
+            name,
+            number: number as u32,
+        })
+    }
+
+    fn enumeration(&mut self, doc_comments: Vec<String>) -> Result<Enum, ImportError> {
+        let mut enumeration = Enum {
+            doc_comments,
+            name: self.ident()?,
+            values: vec![],
+        };
+
+        self.expect('{')?;
+
+        loop {
+            let doc_comments = self.doc_comments();
+
+            match self.next()? {
+                Token::Symbol('}') => return Ok(enumeration),
+                Token::Symbol(';') => continue,
+                Token::Ident(keyword) if keyword == "reserved" => self.skip_statement()?,
+                Token::Ident(keyword) if keyword == "option" => {
+                    self.warn(format!("options of `{}` are ignored", enumeration.name));
+                    self.skip_statement()?;
+                }
+                Token::Ident(name) => {
+                    self.expect('=')?;
+                    let number = self.number()?;
+                    self.skip_field_options(&format!("{}.{}", enumeration.name, name))?;
+                    self.expect(';')?;
+                    enumeration.values.push((doc_comments, name, number));
+                }
+                token => return self.error(format!("unexpected {:?}", token)),
+            }
+        }
+    }
+
+    fn service(&mut self, doc_comments: Vec<String>) -> Result<Service, ImportError> {
+        let mut service = Service {
+            doc_comments,
+            name: self.ident()?,
+            rpcs: vec![],
+        };
+
+        self.expect('{')?;
+
+        loop {
+            let doc_comments = self.doc_comments();
+
+            match self.next()? {
+                Token::Symbol('}') => return Ok(service),
+                Token::Symbol(';') => continue,
+                Token::Ident(keyword) if keyword == "option" => {
+                    self.warn(format!("options of `{}` are ignored", service.name));
+                    self.skip_statement()?;
+                }
+                Token::Ident(keyword) if keyword == "rpc" => {
+                    let name = self.ident()?;
+                    let (request_stream, request) = self.rpc_type()?;
+
+                    if self.ident()? != "returns" {
+                        return self.error("expected `returns`");
+                    }
+
+                    let (response_stream, response) = self.rpc_type()?;
+
+                    if self.is_symbol('{') {
+                        self.warn(format!("options of `{}` are ignored", name));
+                        self.skip_statement()?;
+                    } else {
+                        self.expect(';')?;
+                    }
+
+                    service.rpcs.push(Rpc {
+                        doc_comments,
+                        name,
+                        request,
+                        request_stream,
+                        response,
+                        response_stream,
+                    });
+                }
+                token => return self.error(format!("unexpected {:?}", token)),
+            }
+        }
+    }
+
+    /// `(stream Type)` or `(Type)`.
+    fn rpc_type(&mut self) -> Result<(bool, String), ImportError> {
+        self.expect('(')?;
+        let mut type_id = self.ident()?;
+        let is_stream = type_id == "stream" && !self.is_symbol(')');
+
+        if is_stream {
+            type_id = self.ident()?;
+        }
+
+        self.expect(')')?;
+        Ok((is_stream, type_id))
+    }
+}
+
+struct Importer {
+    warnings: Vec<String>,
+    package: Option<String>,
+    /// Schema names of hoisted messages and enums by their path in the
+    /// file, `Outer.Inner`.
+    names: HashMap<String, String>,
+}
+
+impl Importer {
+    fn import(&mut self, file: &ProtoFile) -> String {
+        let mut blocks = vec![];
+        let mut hoisted_messages = vec![];
+        let mut enums = file
+            .enums
+            .iter()
+            .map(|enumeration| (enumeration.name.clone(), enumeration))
+            .collect::<Vec<(String, &Enum)>>();
+
+        for message in &file.messages {
+            collect_messages("", message, &mut hoisted_messages, &mut enums);
+        }
+
+        let paths = hoisted_messages
+            .iter()
+            .map(|(path, _)| path)
+            .chain(enums.iter().map(|(path, _)| path))
+            .cloned()
+            .collect::<Vec<String>>();
+        self.name_types(&paths);
+
+        let messages = hoisted_messages
+            .iter()
+            .map(|(_, message)| *message)
+            .collect::<Vec<&Message>>();
+        let rpc_messages = find_rpc_messages(file, &messages);
+
+        if !file.services.is_empty() {
+            let mut writer = Writer::default();
+            let mut doc_comments = file.services[0].doc_comments.clone();
+            let id_index = doc_comments
+                .iter()
+                .position(|comment| comment.trim().starts_with("id:"));
+
+            match id_index {
+                Some(index) => {
+                    let comment = doc_comments.remove(index);
+                    let id = comment.trim().trim_start_matches("id:").trim();
+                    writer.writeln(&format!("#[id = \"{}\"]", id));
+                }
+                None => {
+                    self.warnings.push(format!(
+                        "service `{}` has no `// id: <uuid>` comment, fill in the `#[id]` directive",
+                        file.services[0].name
+                    ));
+                    writer.writeln("#[id = \"00000000-0000-0000-0000-000000000000\"]");
+                }
+            }
+
+            let namespace = match &file.package {
+                Some(package) => package.replace('.', "_"),
+                None => file.services[0].name.to_case(Case::Snake),
+            };
+
+            writer.writeln(&format!("#[namespace = \"{}\"]", namespace));
+            blocks.push(writer.show().to_string());
+
+            if file.services.len() > 1 {
+                self.warnings.push(String::from(
+                    "schemas have a single namespace, rpcs of all services are merged",
+                ));
+            }
+        } else if let Some(package) = &file.package {
+            blocks.push(format!(
+                "#[namespace = \"{}\"]\n",
+                package.replace('.', "_")
+            ));
+        }
+
+        for (path, message) in &hoisted_messages {
+            if rpc_messages.iter().any(|id| id == path) {
+                continue;
+            }
+
+            let name = self.names[path].clone();
+
+            match as_enum(message) {
+                Some(items) => blocks.push(self.import_enum_message(
+                    &name,
+                    path,
+                    &message.doc_comments,
+                    &message.messages,
+                    items,
+                )),
+                None => {
+                    blocks.push(self.import_struct(&name, path, message));
+
+                    for oneof in &message.oneofs {
+                        blocks.push(self.import_enum_message(
+                            &oneof_enum_name(&name, oneof),
+                            path,
+                            &[],
+                            &message.messages,
+                            &oneof.fields,
+                        ));
+                    }
+                }
+            }
+        }
+
+        for (path, enumeration) in &enums {
+            let name = self.names[path].clone();
+            blocks.push(self.import_enum(&name, enumeration));
+        }
+
+        for service in &file.services {
+            for rpc in &service.rpcs {
+                if let Some(block) = self.import_rpc(rpc, &messages, &rpc_messages) {
+                    blocks.push(block);
+                }
+            }
+        }
+
+        blocks.join("\n")
+    }
+
+    /// Nested types keep their name unless another type has it, then they
+    /// are prefixed with their parents, `B.Item` is `BItem`.
+    fn name_types(&mut self, paths: &[String]) {
+        for path in paths {
+            let name = type_name(path);
+            let collides = paths
+                .iter()
+                .any(|other| other != path && type_name(other) == name);
+
+            if collides && path.contains('.') {
+                let prefixed = path
+                    .split('.')
+                    .map(|part| part.to_case(Case::Pascal))
+                    .collect::<String>();
+
+                self.warnings.push(format!(
+                    "`{}` is imported as `{}`, another type is named `{}`",
+                    path, prefixed, name
+                ));
+                self.names.insert(path.clone(), prefixed);
+            } else {
+                self.names.insert(path.clone(), name.to_string());
+            }
+        }
+    }
+
+    /// Schema name of a type referenced from the message at `scope`,
+    /// looked up from the innermost scope outwards like protoc does.
+    fn resolve_type(&self, scope: &str, type_id: &str) -> String {
+        let package_prefix = self.package.as_ref().map(|package| format!("{}.", package));
+        let strip_package = |type_id: &'_ str| -> String {
+            match &package_prefix {
+                Some(prefix) => type_id.strip_prefix(prefix.as_str()).unwrap_or(type_id),
+                None => type_id,
+            }
+            .to_string()
+        };
+
+        if let Some(absolute) = type_id.strip_prefix('.') {
+            if let Some(name) = self.names.get(&strip_package(absolute)) {
+                return name.clone();
+            }
+        } else {
+            let scopes = if scope.is_empty() {
+                vec![]
+            } else {
+                scope.split('.').collect::<Vec<&str>>()
+            };
+
+            for i in (0..=scopes.len()).rev() {
+                let mut path = scopes[..i].to_vec();
+                path.push(type_id);
+
+                if let Some(name) = self.names.get(&path.join(".")) {
+                    return name.clone();
+                }
+            }
+
+            if let Some(name) = self.names.get(&strip_package(type_id)) {
+                return name.clone();
+            }
+        }
+
+        type_name(type_id).to_string()
+    }
+
+    fn import_struct(&mut self, name: &str, path: &str, message: &Message) -> String {
+        let mut writer = Writer::default();
+        generate_doc_comments(&mut writer, &message.doc_comments);
+
+        let mut fields = self.import_fields(path, &message.fields);
+
+        for oneof in &message.oneofs {
+            let number = oneof.fields.iter().map(|field| field.number).min();
+
+            if let Some(number) = number {
+                fields.push((
+                    vec![],
+                    oneof.name.clone(),
+                    number,
+                    format!("Option<{}>", oneof_enum_name(name, oneof)),
+                ));
+            }
+        }
+
+        if fields.is_empty() {
+            writer.writeln(&format!("struct {} {{}}", name));
+        } else {
+            writer.writeln(&format!("struct {} {{", name));
+            writer.push_tab();
+            generate_fields(&mut writer, &fields);
+            writer.pop_tab();
+            writer.writeln("}");
+        }
+
+        writer.show().to_string()
+    }
+
+    /// Items of a `oneof` of the message at `scope`, `nested` are the
+    /// messages declared next to it, the ones used by items are inlined.
+    fn import_enum_message(
+        &mut self,
+        name: &str,
+        scope: &str,
+        doc_comments: &[String],
+        nested: &[Message],
+        items: &[Field],
+    ) -> String {
+        let mut writer = Writer::default();
+        let explicit = needs_positions(items.iter().map(|item| item.number));
+
+        generate_doc_comments(&mut writer, doc_comments);
+        writer.writeln(&format!("enum {} {{", name));
+        writer.push_tab();
+
+        for item in items {
+            let id = item.name.to_case(Case::Pascal);
+            let position = if explicit {
+                format!("#[{}] ", item.number - 1)
+            } else {
+                String::new()
+            };
+
+            generate_doc_comments(&mut writer, &item.doc_comments);
+
+            match nested
+                .iter()
+                .find(|nested| nested.name == type_name(&item.type_id))
+            {
+                Some(nested) if nested.fields.is_empty() => {
+                    writer.writeln(&format!("{}{},", position, id));
+                }
+                Some(nested) => {
+                    let location = format!("{}.{}", scope, nested.name);
+                    let fields = self.import_fields(&location, &nested.fields);
+                    let is_tuple = fields
+                        .iter()
+                        .all(|(_, name, number, _)| name == &format!("p{}", number - 1));
+
+                    if is_tuple {
+                        let explicit = needs_positions(fields.iter().map(|field| field.2));
+                        let values = fields
+                            .iter()
+                            .map(|(_, _, number, type_id)| {
+                                if explicit {
+                                    format!("#[{}] {}", number - 1, type_id)
+                                } else {
+                                    type_id.clone()
+                                }
+                            })
+                            .collect::<Vec<String>>();
+
+                        writer.writeln(&format!("{}{}({}),", position, id, values.join(", ")));
+                    } else {
+                        writer.writeln(&format!("{}{} {{", position, id));
+                        writer.push_tab();
+                        generate_fields(&mut writer, &fields);
+                        writer.pop_tab();
+                        writer.writeln("},");
+                    }
+                }
+                None => match self.import_type(scope, Label::None, &item.type_id) {
+                    Ok(type_id) => writer.writeln(&format!("{}{}({}),", position, id, type_id)),
+                    Err(reason) => {
+                        self.warnings.push(format!(
+                            "{}.{}: {}, imported as an empty item",
+                            name, item.name, reason
+                        ));
+                        writer.writeln(&format!("{}{},", position, id));
+                    }
+                },
+            }
+        }
+
+        writer.pop_tab();
+        writer.writeln("}");
+
+        writer.show().to_string()
+    }
+
+    fn import_enum(&mut self, name: &str, enumeration: &Enum) -> String {
+        let mut writer = Writer::default();
+        let prefix = format!("{}_", enumeration.name.to_case(Case::UpperSnake));
+        let mut numbers = vec![];
+
+        generate_doc_comments(&mut writer, &enumeration.doc_comments);
+        writer.writeln(&format!("enum {} {{", name));
+        writer.push_tab();
+
+        let explicit = needs_positions(
+            enumeration
+                .values
+                .iter()
+                .map(|(_, _, number)| *number as u32 + 1),
+        );
+
+        for (doc_comments, name, number) in &enumeration.values {
+            if *number < 0 || numbers.contains(number) {
+                self.warnings.push(format!(
+                    "{}.{}: negative and aliased values are not supported, skipped",
+                    enumeration.name, name
+                ));
+                continue;
+            }
+
+            numbers.push(*number);
+            generate_doc_comments(&mut writer, doc_comments);
+
+            let id = name
+                .strip_prefix(&prefix)
+                .unwrap_or(name)
+                .to_case(Case::Pascal);
+
+            if explicit {
+                writer.writeln(&format!("#[{}] {},", number, id));
+            } else {
+                writer.writeln(&format!("{},", id));
+            }
+        }
+
+        writer.pop_tab();
+        writer.writeln("}");
+
+        writer.show().to_string()
+    }
+
+    fn import_rpc(
+        &mut self,
+        rpc: &Rpc,
+        messages: &[&Message],
+        rpc_messages: &[String],
+    ) -> Option<String> {
+        if rpc.request_stream {
+            self.warnings.push(format!(
+                "{}: client streaming is not supported, skipped",
+                rpc.name
+            ));
+            return None;
+        }
+
+        let mut writer = Writer::default();
+        let id = rpc.name.to_case(Case::Snake);
+        let request = type_name(&rpc.request);
+        let response = type_name(&rpc.response);
+
+        let args = if rpc.request == super::EMPTY_TYPE {
+            vec![]
+        } else if rpc_messages.iter().any(|id| id == request) {
+            let message = find_message(messages, request).expect("request message exists");
+
+            self.import_fields(request, &message.fields)
+                .into_iter()
+                .map(|(_, name, _, type_id)| format!("{}: {}", name, type_id))
+                .collect()
+        } else {
+            vec![format!("request: {}", self.resolve_type("", &rpc.request))]
+        };
+
+        let return_type_id = if rpc.response == super::EMPTY_TYPE {
+            None
+        } else if rpc_messages.iter().any(|id| id == response) {
+            let message = find_message(messages, response).expect("response message exists");
+            self.import_fields(response, &message.fields)
+                .into_iter()
+                .next()
+                .map(|(_, _, _, type_id)| type_id)
+        } else {
+            Some(self.resolve_type("", &rpc.response))
+        };
+
+        let return_type_id = match return_type_id {
+            Some(type_id) => format!(" -> {}", type_id),
+            None => String::new(),
+        };
+
+        generate_doc_comments(&mut writer, &rpc.doc_comments);
+
+        if rpc.response_stream {
+            if !args.is_empty() {
+                self.warnings.push(format!(
+                    "{}: signals have no arguments, the request is dropped",
+                    rpc.name
+                ));
+            }
+
+            writer.writeln(&format!("signal {}{};", id, return_type_id));
+        } else {
+            writer.writeln(&format!(
+                "fn {}({}){};",
+                id,
+                args.join(", "),
+                return_type_id
+            ));
+        }
+
+        Some(writer.show().to_string())
+    }
+
+    /// Fields as doc comments, name, number and type, fields with types
+    /// that can't be imported are skipped. `location` is the path of the
+    /// message, types are resolved from it.
+    fn import_fields(
+        &mut self,
+        location: &str,
+        fields: &[Field],
+    ) -> Vec<(Vec<String>, String, u32, String)> {
+        let mut res = vec![];
+
+        for field in fields {
+            match self.import_type(location, field.label, &field.type_id) {
+                Ok(type_id) => res.push((
+                    field.doc_comments.clone(),
+                    field.name.clone(),
+                    field.number,
+                    type_id,
+                )),
+                Err(reason) => self
+                    .warnings
+                    .push(format!("{}.{}: {}, skipped", location, field.name, reason)),
+            }
+        }
+
+        res
+    }
+
+    fn import_type(&mut self, scope: &str, label: Label, type_id: &str) -> Result<String, String> {
+        let type_id = match type_id {
+            "double" => String::from("f64"),
+            "float" => String::from("f32"),
+            "int32" | "sint32" | "sfixed32" => String::from("i32"),
+            "int64" | "sint64" | "sfixed64" => String::from("i64"),
+            "uint32" | "fixed32" => String::from("u32"),
+            "uint64" | "fixed64" => String::from("u64"),
+            "bool" => String::from("bool"),
+            "string" => String::from("String"),
+            "bytes" => String::from("Vec<u8>"),
+            type_id
+                if type_id
+                    .trim_start_matches('.')
+                    .starts_with("google.protobuf.") =>
+            {
+                return Err(format!("well-known type `{}` is not supported", type_id));
+            }
+            type_id => self.resolve_type(scope, type_id),
+        };
+
+        Ok(match label {
+            Label::None => type_id,
+            Label::Optional => format!("Option<{}>", type_id),
+            Label::Repeated => format!("Vec<{}>", type_id),
+        })
+    }
+}
+
+/// Nested messages and enums are hoisted to the top level, except the
+/// messages of the items of `oneof` enums that nothing else uses.
+fn collect_messages<'a>(
+    scope: &str,
+    message: &'a Message,
+    messages: &mut Vec<(String, &'a Message)>,
+    enums: &mut Vec<(String, &'a Enum)>,
+) {
+    let path = if scope.is_empty() {
+        message.name.clone()
+    } else {
+        format!("{}.{}", scope, message.name)
+    };
+
+    messages.push((path.clone(), message));
+    enums.extend(
+        message
+            .enums
+            .iter()
+            .map(|enumeration| (format!("{}.{}", path, enumeration.name), enumeration)),
+    );
+
+    for nested in &message.messages {
+        let uses_nested = |field: &Field| type_name(&field.type_id) == nested.name;
+        let is_item = message
+            .oneofs
+            .iter()
+            .flat_map(|oneof| &oneof.fields)
+            .any(uses_nested)
+            && !message.fields.iter().any(uses_nested);
+
+        if !is_item {
+            collect_messages(&path, nested, messages, enums);
+        }
+    }
+}
+
+/// The `oneof` fields if the message has nothing else.
+fn as_enum(message: &Message) -> Option<&[Field]> {
+    match message.oneofs.as_slice() {
+        [oneof] if message.fields.is_empty() => Some(&oneof.fields),
+        _ => None,
+    }
+}
+
+/// `oneof` next to other fields becomes an enum of its own and an optional
+/// field of the struct, `Outer.choice` is `OuterChoice`.
+fn oneof_enum_name(message_name: &str, oneof: &Oneof) -> String {
+    format!("{}{}", message_name, oneof.name.to_case(Case::Pascal))
+}
+
+/// `{Rpc}Request` and `{Rpc}Response` messages that are only used by their
+/// rpc, they become the arguments and the result.
+fn find_rpc_messages(file: &ProtoFile, messages: &[&Message]) -> Vec<String> {
+    let mut res = vec![];
+    let is_used_by_fields = |id: &str| {
+        messages.iter().any(|message| {
+            message
+                .fields
+                .iter()
+                .chain(message.oneofs.iter().flat_map(|oneof| &oneof.fields))
+                .any(|field| type_name(&field.type_id) == id)
+        })
+    };
+
+    for rpc in file.services.iter().flat_map(|service| &service.rpcs) {
+        let request = type_name(&rpc.request);
+        let response = type_name(&rpc.response);
+
+        if request == format!("{}Request", rpc.name)
+            && find_message(messages, request).is_some()
+            && !is_used_by_fields(request)
+        {
+            res.push(request.to_string());
+        }
+
+        let is_value = find_message(messages, response).is_some_and(
+            |message| matches!(message.fields.as_slice(), [field] if field.name == "value"),
+        );
+
+        if response == format!("{}Response", rpc.name) && is_value && !is_used_by_fields(response) {
+            res.push(response.to_string());
+        }
+    }
+
+    res
+}
+
+fn find_message<'a>(messages: &[&'a Message], id: &str) -> Option<&'a Message> {
+    messages.iter().find(|message| message.name == id).copied()
+}
+
+/// Schemas have no packages, `.pkg.Outer.Inner` is `Inner`.
+fn type_name(type_id: &str) -> &str {
+    type_id.rsplit('.').next().unwrap_or(type_id)
+}
+
+/// Positions are written only if they differ from the implicit ones.
+fn needs_positions(numbers: impl Iterator<Item = u32>) -> bool {
+    numbers
+        .enumerate()
+        .any(|(i, number)| number as usize != i + 1)
+}
+
+fn generate_fields(writer: &mut Writer, fields: &[(Vec<String>, String, u32, String)]) {
+    let explicit = needs_positions(fields.iter().map(|field| field.2));
+
+    for (doc_comments, name, number, type_id) in fields {
+        generate_doc_comments(writer, doc_comments);
+
+        if explicit {
+            writer.writeln(&format!("#[{}] {}: {},", number - 1, name, type_id));
+        } else {
+            writer.writeln(&format!("{}: {},", name, type_id));
+        }
+    }
+}
+
+fn generate_doc_comments(writer: &mut Writer, doc_comments: &[String]) {
+    for comment in doc_comments {
+        writer.writeln(&format!("///{}", comment));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parser::parse_source, proto::export::export_proto};
+    use std::fs;
+
+    #[test]
+    fn import_proto_test() {
+        let src = fs::read_to_string("test_resources/proto/greeter.proto").unwrap();
+        let target = fs::read_to_string("test_resources/proto/greeter.tpb").unwrap();
+        let (actual, warnings) = import_proto(&src).unwrap();
+        println!("{}", actual);
+        assert_eq!(actual.trim(), target.trim());
+        assert!(parse_source(&actual).is_ok());
+        assert_eq!(
+            warnings,
+            vec![
+                "3: file options are ignored",
+                "8: import of `google/protobuf/timestamp.proto` is skipped, its types have to \
+                 be imported separately",
+                "19: map fields of `HelloRequest` are not supported, skipped",
+                "22: options of `HelloRequest.times` are ignored",
+                "HelloReply.sent_at: well-known type `google.protobuf.Timestamp` is not \
+                 supported, skipped",
+                "Chat: client streaming is not supported, skipped",
+            ]
+        );
+    }
+
+    #[test]
+    fn import_exported_proto_test() {
+        for name in [
+            "struct_basic",
+            "enum_extended",
+            "rpc_sync_methods",
+            "rpc_stream_methods",
+        ] {
+            let src = fs::read_to_string(format!("test_resources/{}.tpb", name)).unwrap();
+            let ast = parse_source(&src).unwrap();
+            let (proto, _) = export_proto(&ast);
+            let (schema, warnings) = import_proto(&proto).unwrap();
+            let imported = parse_source(&schema).unwrap();
+
+            assert!(warnings.is_empty(), "{}: {:?}", name, warnings);
+            assert_eq!(
+                serde_json::to_value(&ast).unwrap(),
+                serde_json::to_value(&imported).unwrap(),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn import_proto_oneof_with_fields_test() {
+        let src = "syntax = \"proto3\";
+message Outer {
+  message Point { int32 x = 1; }
+  int32 x = 1;
+  oneof choice {
+    int32 a = 9;
+    string b = 10;
+    Point p = 11;
+  }
+}";
+        let (actual, warnings) = import_proto(src).unwrap();
+        println!("{}", actual);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert!(parse_source(&actual).is_ok());
+        assert_eq!(
+            actual.trim(),
+            "struct Outer {
+    #[0] x: i32,
+    #[8] choice: Option<OuterChoice>,
+}
+
+enum OuterChoice {
+    #[8] A(i32),
+    #[9] B(String),
+    #[10] P {
+        x: i32,
+    },
+}"
+        );
+    }
+
+    #[test]
+    fn import_proto_nested_names_test() {
+        let src = "syntax = \"proto3\";
+package demo;
+message A {
+  message Item { int32 a = 1; }
+  Item item = 1;
+}
+message B {
+  message Item { string b = 1; }
+  Item item = 1;
+  A.Item other = 2;
+  .demo.B.Item absolute = 3;
+}
+message Item { bool top = 1; }";
+        let (actual, warnings) = import_proto(src).unwrap();
+        println!("{}", actual);
+        assert!(parse_source(&actual).is_ok());
+        assert_eq!(
+            actual.trim(),
+            "#[namespace = \"demo\"]
+
+struct A {
+    item: AItem,
+}
+
+struct AItem {
+    a: i32,
+}
+
+struct B {
+    item: BItem,
+    other: AItem,
+    absolute: BItem,
+}
+
+struct BItem {
+    b: String,
+}
+
+struct Item {
+    top: bool,
+}"
+        );
+        assert_eq!(
+            warnings,
+            vec![
+                "`A.Item` is imported as `AItem`, another type is named `Item`",
+                "`B.Item` is imported as `BItem`, another type is named `Item`",
+            ]
+        );
+    }
+
+    #[test]
+    fn import_proto_error_test() {
+        let err = import_proto("syntax = \"proto3\";\nmessage A {\n  string a = ;\n}").unwrap_err();
+        assert_eq!(err.line, 3);
+    }
+}
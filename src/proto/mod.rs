@@ -0,0 +1,16 @@
+//! Conversions between schemas and proto3 files, so services that speak
+//! gRPC can share the models.
+
+pub mod export;
+pub mod import;
+
+pub use export::export_proto;
+pub use import::{import_proto, ImportError};
+
+/// Indent of the `.proto` files, the style guide uses 2 spaces.
+const PROTO_INDENT: usize = 2;
+
+/// Request and response of rpcs without arguments or results.
+const EMPTY_TYPE: &str = "google.protobuf.Empty";
+
+const EMPTY_IMPORT: &str = "google/protobuf/empty.proto";
@@ -0,0 +1,617 @@
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{
+        self, ASTNode, ConstBlockASTNode, ConstItemASTNode, ConstValueASTNode, EnumASTNode,
+        EnumItemASTNode, FnASTNode, StructASTNode, StructFieldASTNode, TypeIDASTNode,
+    },
+    lexer::Literal,
+    writer::Writer,
+};
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+pub fn generate_consts(ast: &[ASTNode]) -> String {
+    let mut blocks = vec![];
+
+    for node in ast {
+        if let ASTNode::Const(node) = node {
+            let mut writer = Writer::default();
+            generate_const_block(&mut writer, &node.id.to_uppercase(), node);
+            blocks.push(writer.show().to_string());
+        }
+    }
+
+    blocks.join("\n")
+}
+
+fn generate_const_block(writer: &mut Writer, prefix: &str, node: &ConstBlockASTNode) {
+    for item in &node.items {
+        match item {
+            ConstItemASTNode::Value { id, type_id, value } => {
+                writer.writeln(&format!(
+                    "{}_{}: Final[{}] = {}",
+                    prefix,
+                    id.to_uppercase(),
+                    generate_type_id(type_id),
+                    generate_const_value(value)
+                ));
+            }
+            ConstItemASTNode::ConstsBlock { node } => {
+                let prefix = format!("{}_{}", prefix, node.id.to_uppercase());
+                generate_const_block(writer, &prefix, node);
+            }
+        }
+    }
+}
+
+pub fn generate_models(ast: &[ASTNode]) -> String {
+    let mut blocks = vec![];
+
+    for node in ast {
+        match node {
+            ASTNode::Struct(node) => blocks.push(generate_struct_model(node)),
+            ASTNode::Enum(node) => blocks.append(&mut generate_enum_model(node)),
+            _ => (),
+        }
+    }
+
+    blocks.join("\n\n")
+}
+
+fn generate_struct_model(node: &StructASTNode) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln("@dataclass");
+    writer.writeln(&format!("class {}:", node.id));
+    writer.push_tab();
+    generate_fields(&mut writer, &node.fields);
+
+    writer.writeln("@staticmethod");
+    writer.writeln(&format!("def create_buffers_default() -> {}:", node.id));
+    writer.push_tab();
+    generate_constructor(&mut writer, &node.id, &node.fields, generate_default_value);
+    writer.pop_tab();
+
+    writer.new_line();
+    writer.writeln("@staticmethod");
+    writer.writeln(&format!(
+        "def read_from_buffers(reader: BytesReader) -> {}:",
+        node.id
+    ));
+    writer.push_tab();
+    generate_constructor(&mut writer, &node.id, &node.fields, generate_read);
+    writer.pop_tab();
+
+    writer.new_line();
+    generate_skip_method(&mut writer, &node.id);
+
+    writer.new_line();
+    writer.writeln("def write_to_buffers(self, writer: BytesWriter) -> None:");
+    writer.push_tab();
+
+    if node.fields.is_empty() {
+        writer.writeln("pass");
+    }
+
+    for field in &node.fields {
+        let accessor = format!("self.{}", field_name(&field.name));
+        writer.writeln(&generate_write(&field.type_id, &accessor));
+    }
+
+    writer.pop_tab();
+    writer.pop_tab();
+
+    writer.show().to_string()
+}
+
+/// The base class with the reading methods and a dataclass per item, the
+/// items write themselves.
+fn generate_enum_model(node: &EnumASTNode) -> Vec<String> {
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!("class {}:", node.id));
+    writer.push_tab();
+
+    writer.writeln("@staticmethod");
+    writer.writeln(&format!("def create_buffers_default() -> {}:", node.id));
+    writer.push_tab();
+    let item = node
+        .items
+        .first()
+        .expect("enum should have at least one item");
+    generate_constructor(
+        &mut writer,
+        &enum_item_class_id(node, item),
+        &enum_item_fields(item),
+        generate_default_value,
+    );
+    writer.pop_tab();
+
+    writer.new_line();
+    writer.writeln("@staticmethod");
+    writer.writeln(&format!(
+        "def read_from_buffers(reader: BytesReader) -> {}:",
+        node.id
+    ));
+    writer.push_tab();
+    writer.writeln("kind = reader.read_u32()");
+    writer.new_line();
+
+    for item in &node.items {
+        writer.writeln(&format!("if kind == {}:", item.position()));
+        writer.push_tab();
+        generate_constructor(
+            &mut writer,
+            &enum_item_class_id(node, item),
+            &enum_item_fields(item),
+            generate_read,
+        );
+        writer.pop_tab();
+    }
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "raise ValueError(\"Invalid {} kind: %d\" % kind)",
+        node.id
+    ));
+    writer.pop_tab();
+
+    writer.new_line();
+    generate_skip_method(&mut writer, &node.id);
+
+    writer.new_line();
+    writer.writeln("def write_to_buffers(self, writer: BytesWriter) -> None:");
+    writer.push_tab();
+    writer.writeln("raise NotImplementedError");
+    writer.pop_tab();
+    writer.pop_tab();
+
+    let mut blocks = vec![writer.show().to_string()];
+
+    for item in &node.items {
+        let mut writer = Writer::default();
+        let fields = enum_item_fields(item);
+
+        writer.writeln("@dataclass");
+        writer.writeln(&format!(
+            "class {}({}):",
+            enum_item_class_id(node, item),
+            node.id
+        ));
+        writer.push_tab();
+        generate_fields(&mut writer, &fields);
+        writer.writeln("def write_to_buffers(self, writer: BytesWriter) -> None:");
+        writer.push_tab();
+        writer.writeln(&format!("writer.write_u32({})", item.position()));
+
+        for field in &fields {
+            let accessor = format!("self.{}", field_name(&field.name));
+            writer.writeln(&generate_write(&field.type_id, &accessor));
+        }
+
+        writer.pop_tab();
+        writer.pop_tab();
+        blocks.push(writer.show().to_string());
+    }
+
+    blocks
+}
+
+fn enum_item_class_id(node: &EnumASTNode, item: &EnumItemASTNode) -> String {
+    format!("{}{}", node.id, item.id())
+}
+
+/// Fields of the item, tuple values are named `p` with the position like in
+/// the Swift models.
+fn enum_item_fields(item: &EnumItemASTNode) -> Vec<StructFieldASTNode> {
+    match item {
+        EnumItemASTNode::Empty { .. } => vec![],
+        EnumItemASTNode::Tuple { values, .. } => values
+            .iter()
+            .map(|value| StructFieldASTNode {
+                doc_comments: vec![],
+                position: value.position,
+                name: format!("p{}", value.position),
+                type_id: value.type_id.clone(),
+            })
+            .collect(),
+        EnumItemASTNode::Struct { fields, .. } => fields.clone(),
+    }
+}
+
+/// Writes the dataclass fields followed by a blank line.
+fn generate_fields(writer: &mut Writer, fields: &[StructFieldASTNode]) {
+    for field in fields {
+        writer.writeln(&format!(
+            "{}: {}",
+            field_name(&field.name),
+            generate_type_id(&field.type_id)
+        ));
+    }
+
+    if !fields.is_empty() {
+        writer.new_line();
+    }
+}
+
+/// Writes `return Id(field=value, ...)`, arguments are evaluated in order,
+/// so reads happen in the buffer order.
+fn generate_constructor(
+    writer: &mut Writer,
+    id: &str,
+    fields: &[StructFieldASTNode],
+    value: fn(&TypeIDASTNode) -> String,
+) {
+    if fields.is_empty() {
+        writer.writeln(&format!("return {}()", id));
+        return;
+    }
+
+    writer.writeln(&format!("return {}(", id));
+    writer.push_tab();
+
+    for field in fields {
+        writer.writeln(&format!(
+            "{}={},",
+            field_name(&field.name),
+            value(&field.type_id)
+        ));
+    }
+
+    writer.pop_tab();
+    writer.writeln(")");
+}
+
+fn generate_skip_method(writer: &mut Writer, id: &str) {
+    writer.writeln("@staticmethod");
+    writer.writeln("def skip_in_buffers(reader: BytesReader, count: int) -> None:");
+    writer.push_tab();
+    writer.writeln("for _ in range(count):");
+    writer.push_tab();
+    writer.writeln(&format!("{}.read_from_buffers(reader)", id));
+    writer.pop_tab();
+    writer.pop_tab();
+}
+
+fn field_name(name: &str) -> String {
+    if PYTHON_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+pub fn generate_rpc(ast: &[ASTNode]) -> String {
+    let namespace = ast::find_directive_value(ast, "namespace").expect("namespace is required");
+    let namespace = match namespace {
+        ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("namespace should be a string literal"),
+        },
+    };
+
+    let scope_id = ast::find_directive_value(ast, "id").expect("id is required");
+    let scope_id = match scope_id {
+        ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("id should be a string literal"),
+        },
+    };
+
+    let class_id = format!("{}Rpc", namespace.to_case(Case::Pascal));
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!("class {}:", class_id));
+    writer.push_tab();
+    writer.writeln(&format!("SCOPE_ID: Final[str] = \"{}\"", scope_id));
+    writer.new_line();
+    writer.writeln("def __init__(self, runtime: RpcRuntime) -> None:");
+    writer.push_tab();
+    writer.writeln("self.runtime = runtime");
+    writer.pop_tab();
+
+    for node in ast::find_fn_nodes(ast) {
+        writer.new_line();
+
+        if node.is_signal {
+            generate_consume_signal_method(&mut writer, &class_id, node);
+        } else {
+            generate_call_method(&mut writer, &class_id, node);
+        }
+    }
+
+    writer.pop_tab();
+
+    writer.show().to_string()
+}
+
+fn generate_call_method(writer: &mut Writer, class_id: &str, node: &FnASTNode) {
+    let mut args = vec![String::from("self")];
+
+    for arg in &node.args {
+        args.push(format!(
+            "{}: {}",
+            field_name(&arg.id),
+            generate_type_id(&arg.type_id)
+        ));
+    }
+
+    let return_type_id = node
+        .return_type_id
+        .as_ref()
+        .map_or(String::from("None"), generate_type_id);
+
+    let (def, call) = if node.is_async {
+        ("async def", "await self.runtime.call_async")
+    } else {
+        ("def", "self.runtime.call")
+    };
+
+    writer.writeln(&format!(
+        "{} {}({}) -> {}:",
+        def,
+        node.id,
+        args.join(", "),
+        return_type_id
+    ));
+    writer.push_tab();
+
+    let args = if node.args.is_empty() {
+        String::from("b\"\"")
+    } else {
+        writer.writeln("writer = BytesWriter()");
+
+        for arg in &node.args {
+            writer.writeln(&generate_write(&arg.type_id, &field_name(&arg.id)));
+        }
+
+        String::from("writer.to_bytes()")
+    };
+
+    let call = format!(
+        "{}({}.SCOPE_ID, {}, {})",
+        call, class_id, node.position, args
+    );
+
+    match &node.return_type_id {
+        Some(type_id) => {
+            writer.writeln(&format!("reader = BytesReader({})", call));
+            writer.writeln(&format!("return {}", generate_read(type_id)));
+        }
+        None => writer.writeln(&call),
+    }
+
+    writer.pop_tab();
+}
+
+/// Like the Rust client, returns the last value of the signal or `None`,
+/// signals without a value return whether they were emitted.
+fn generate_consume_signal_method(writer: &mut Writer, class_id: &str, node: &FnASTNode) {
+    let consume = format!(
+        "self.runtime.consume({}.SCOPE_ID, {})",
+        class_id, node.position
+    );
+
+    match &node.return_type_id {
+        Some(type_id) => {
+            writer.writeln(&format!(
+                "def consume_{}(self) -> Optional[{}]:",
+                node.id,
+                generate_type_id(type_id)
+            ));
+            writer.push_tab();
+            writer.writeln(&format!("data = {}", consume));
+            writer.new_line();
+            writer.writeln("if data is None:");
+            writer.push_tab();
+            writer.writeln("return None");
+            writer.pop_tab();
+            writer.new_line();
+            writer.writeln("reader = BytesReader(data)");
+            writer.writeln(&format!("return {}", generate_read(type_id)));
+            writer.pop_tab();
+        }
+        None => {
+            writer.writeln(&format!("def consume_{}(self) -> bool:", node.id));
+            writer.push_tab();
+            writer.writeln(&format!("return {} is not None", consume));
+            writer.pop_tab();
+        }
+    }
+}
+
+fn is_address(id: &str) -> bool {
+    id == "GroupAddress" || id == "CommandsBufferAddress"
+}
+
+pub fn generate_type_id(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { .. } => String::from("int"),
+        TypeIDASTNode::Number { .. } => String::from("float"),
+        TypeIDASTNode::Bool { .. } => String::from("bool"),
+        TypeIDASTNode::Char { .. } => String::from("str"),
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => String::from("str"),
+            id if is_address(id) => String::from("int"),
+            _ => id.clone(),
+        },
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Option" => format!(
+                "Optional[{}]",
+                generate_type_id(generics.first().expect("Optional type cannot be empty"))
+            ),
+            "Vec" => format!(
+                "List[{}]",
+                generate_type_id(generics.first().expect("Vec type cannot be empty"))
+            ),
+            _ => id.clone(),
+        },
+    }
+}
+
+fn generate_primitive_id(type_id: &TypeIDASTNode) -> Option<String> {
+    match type_id {
+        TypeIDASTNode::Integer { size, signed, .. } => {
+            Some(format!("{}{}", if *signed { "i" } else { "u" }, size * 8))
+        }
+        TypeIDASTNode::Number { size, .. } => Some(format!("f{}", size * 8)),
+        TypeIDASTNode::Bool { .. } => Some(String::from("bool")),
+        TypeIDASTNode::Char { .. } => Some(String::from("char")),
+        TypeIDASTNode::Other { id } if id == "String" => Some(String::from("string")),
+        TypeIDASTNode::Other { id } if is_address(id) => Some(String::from("u64")),
+        _ => None,
+    }
+}
+
+pub fn generate_read(type_id: &TypeIDASTNode) -> String {
+    if let Some(id) = generate_primitive_id(type_id) {
+        return format!("reader.read_{}()", id);
+    }
+
+    match type_id {
+        TypeIDASTNode::Generic { id, generics } if id == "Vec" => format!(
+            "reader.read_vec(lambda: {})",
+            generate_read(generics.first().expect("Vec type cannot be empty"))
+        ),
+        TypeIDASTNode::Generic { id, generics } if id == "Option" => format!(
+            "reader.read_option(lambda: {})",
+            generate_read(generics.first().expect("Optional type cannot be empty"))
+        ),
+        TypeIDASTNode::Generic { id, .. } | TypeIDASTNode::Other { id } => {
+            format!("{}.read_from_buffers(reader)", id)
+        }
+        _ => unreachable!(),
+    }
+}
+
+pub fn generate_write(type_id: &TypeIDASTNode, accessor: &str) -> String {
+    if let Some(id) = generate_primitive_id(type_id) {
+        return format!("writer.write_{}({})", id, accessor);
+    }
+
+    match type_id {
+        TypeIDASTNode::Generic { id, generics } if id == "Vec" => format!(
+            "writer.write_vec({}, lambda item: {})",
+            accessor,
+            generate_write(generics.first().expect("Vec type cannot be empty"), "item")
+        ),
+        TypeIDASTNode::Generic { id, generics } if id == "Option" => format!(
+            "writer.write_option({}, lambda value: {})",
+            accessor,
+            generate_write(
+                generics.first().expect("Optional type cannot be empty"),
+                "value"
+            )
+        ),
+        _ => format!("{}.write_to_buffers(writer)", accessor),
+    }
+}
+
+pub fn generate_default_value(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { .. } => String::from("0"),
+        TypeIDASTNode::Number { .. } => String::from("0.0"),
+        TypeIDASTNode::Bool { .. } => String::from("False"),
+        TypeIDASTNode::Char { .. } => String::from("\"\\0\""),
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => String::from("\"\""),
+            id if is_address(id) => String::from("0"),
+            _ => format!("{}.create_buffers_default()", id),
+        },
+        TypeIDASTNode::Generic { id, .. } => match id.as_str() {
+            "Option" => String::from("None"),
+            "Vec" => String::from("[]"),
+            _ => format!("{}.create_buffers_default()", id),
+        },
+    }
+}
+
+pub fn generate_const_value(node: &ConstValueASTNode) -> String {
+    match node {
+        ConstValueASTNode::Literal { literal, .. } => match literal {
+            Literal::StringLiteral(value) => format!("\"{}\"", value),
+            Literal::IntLiteral(value) => format!("{}", value),
+            Literal::NumberLiteral(value) if value.floor() == *value => format!("{}.0", value),
+            Literal::NumberLiteral(value) => format!("{}", value),
+            Literal::BoolLiteral(true) => String::from("True"),
+            Literal::BoolLiteral(false) => String::from("False"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    fn parse_resource(name: &str) -> Vec<ASTNode> {
+        let src = fs::read_to_string(format!("test_resources/{}.tpb", name)).unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        parse(&mut lexer)
+    }
+
+    fn read_target(name: &str) -> String {
+        fs::read_to_string(format!("test_resources/python/{}.py", name)).unwrap()
+    }
+
+    #[test]
+    fn generate_consts_test() {
+        let ast = parse_resource("consts");
+        let actual = generate_consts(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("consts").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_basic() {
+        let ast = parse_resource("struct_basic");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_basic").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_types() {
+        let ast = parse_resource("struct_types");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_types").trim());
+    }
+
+    #[test]
+    fn generate_enum_model_extended_test() {
+        let ast = parse_resource("enum_extended");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("enum_extended").trim());
+    }
+
+    #[test]
+    fn generate_rpc_sync_methods_test() {
+        let ast = parse_resource("rpc_sync_methods");
+        let actual = generate_rpc(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_sync_methods").trim());
+    }
+
+    #[test]
+    fn generate_rpc_stream_methods_test() {
+        let ast = parse_resource("rpc_stream_methods");
+        let actual = generate_rpc(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_stream_methods").trim());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    ast::{self, ASTNode},
+    options::{GeneratorOptions, DEFAULT_HEADER},
+    writer::reindent,
+};
+
+use self::generator::{generate_consts, generate_models, generate_rpc};
+
+pub mod generator;
+
+/// `BytesReader`, `BytesWriter` and the `RpcRuntime` protocol, the module
+/// doesn't depend on anything but the standard library.
+const PRIMITIVES: &str = include_str!("primitives.py");
+
+pub fn generate(ast: &[ASTNode]) -> String {
+    generate_with_options(ast, &GeneratorOptions::default())
+}
+
+pub fn generate_with_options(ast: &[ASTNode], options: &GeneratorOptions) -> String {
+    let mut writer = options.writer();
+
+    // The default header is a `//` comment.
+    let header = match &options.header {
+        Some(header) => header.clone(),
+        None => DEFAULT_HEADER.replacen("//", "#", 1),
+    };
+
+    if !header.is_empty() {
+        writer.writeln(&header);
+        writer.new_line();
+    }
+
+    writer.writeln("from __future__ import annotations");
+    writer.new_line();
+    writer.writeln("import struct");
+    writer.writeln("from dataclasses import dataclass");
+    writer.writeln(
+        "from typing import Any, Callable, Final, List, Optional, Protocol, Tuple, TypeVar",
+    );
+
+    for import in options.imports.iter() {
+        if import.starts_with("import ") || import.starts_with("from ") {
+            writer.writeln(import);
+        } else {
+            writer.writeln(&format!("import {}", import));
+        }
+    }
+
+    writer.new_line();
+    writer.new_line();
+    writer.write(PRIMITIVES);
+
+    let mut sections = vec![generate_consts(ast), generate_models(ast)];
+
+    if ast::contains_fn_nodes(ast) {
+        sections.push(generate_rpc(ast));
+    }
+
+    for section in sections.iter().filter(|section| !section.is_empty()) {
+        writer.new_line();
+        writer.new_line();
+        writer.write(section);
+    }
+
+    reindent(writer.show(), options.indent())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    #[test]
+    fn generate_with_custom_options() {
+        let src = fs::read_to_string("test_resources/struct_basic.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/python/struct_basic_options.py").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let options = GeneratorOptions {
+            indent: Some(2),
+            header: Some(String::from("# Copyright (c) Tech Paws")),
+            imports: vec![
+                String::from("math"),
+                String::from("from enum import IntEnum"),
+            ],
+            ..GeneratorOptions::default()
+        };
+        let actual = generate_with_options(&ast, &options);
+        assert_eq!(actual.trim(), target.trim());
+    }
+}
@@ -0,0 +1,130 @@
+T = TypeVar("T")
+
+
+class BytesReader:
+    def __init__(self, data: bytes, offset: int = 0) -> None:
+        self.data = data
+        self.offset = offset
+
+    def _unpack(self, fmt: str) -> Tuple[Any, ...]:
+        values = struct.unpack_from(fmt, self.data, self.offset)
+        self.offset += struct.calcsize(fmt)
+        return values
+
+    def read_i8(self) -> int:
+        return int(self._unpack("<b")[0])
+
+    def read_i32(self) -> int:
+        return int(self._unpack("<i")[0])
+
+    def read_i64(self) -> int:
+        return int(self._unpack("<q")[0])
+
+    def read_u8(self) -> int:
+        return int(self._unpack("<B")[0])
+
+    def read_u32(self) -> int:
+        return int(self._unpack("<I")[0])
+
+    def read_u64(self) -> int:
+        return int(self._unpack("<Q")[0])
+
+    def read_f32(self) -> float:
+        return float(self._unpack("<f")[0])
+
+    def read_f64(self) -> float:
+        return float(self._unpack("<d")[0])
+
+    def read_bool(self) -> bool:
+        return self.read_u8() != 0
+
+    def read_char(self) -> str:
+        return chr(self.read_u32())
+
+    def read_string(self) -> str:
+        length = self.read_u64()
+
+        if self.offset + length > len(self.data):
+            raise struct.error("unpack requires a buffer of %d bytes" % length)
+
+        value = self.data[self.offset : self.offset + length].decode("utf-8")
+        self.offset += length
+        return value
+
+    def read_vec(self, read: Callable[[], T]) -> List[T]:
+        return [read() for _ in range(self.read_u64())]
+
+    def read_option(self, read: Callable[[], T]) -> Optional[T]:
+        return read() if self.read_u8() != 0 else None
+
+
+class BytesWriter:
+    def __init__(self) -> None:
+        self.data = bytearray()
+
+    def to_bytes(self) -> bytes:
+        return bytes(self.data)
+
+    def write_i8(self, value: int) -> None:
+        self.data += struct.pack("<b", value)
+
+    def write_i32(self, value: int) -> None:
+        self.data += struct.pack("<i", value)
+
+    def write_i64(self, value: int) -> None:
+        self.data += struct.pack("<q", value)
+
+    def write_u8(self, value: int) -> None:
+        self.data += struct.pack("<B", value)
+
+    def write_u32(self, value: int) -> None:
+        self.data += struct.pack("<I", value)
+
+    def write_u64(self, value: int) -> None:
+        self.data += struct.pack("<Q", value)
+
+    def write_f32(self, value: float) -> None:
+        self.data += struct.pack("<f", value)
+
+    def write_f64(self, value: float) -> None:
+        self.data += struct.pack("<d", value)
+
+    def write_bool(self, value: bool) -> None:
+        self.write_u8(1 if value else 0)
+
+    def write_char(self, value: str) -> None:
+        self.write_u32(ord(value))
+
+    def write_string(self, value: str) -> None:
+        data = value.encode("utf-8")
+        self.write_u64(len(data))
+        self.data += data
+
+    def write_vec(self, items: List[T], write: Callable[[T], None]) -> None:
+        self.write_u64(len(items))
+
+        for item in items:
+            write(item)
+
+    def write_option(self, value: Optional[T], write: Callable[[T], None]) -> None:
+        if value is None:
+            self.write_u8(0)
+        else:
+            self.write_u8(1)
+            write(value)
+
+
+class RpcRuntime(Protocol):
+    """Runtime the RPC stubs call into, e.g. a ctypes binding."""
+
+    def call(self, scope_id: str, method_id: int, args: bytes) -> bytes:
+        """Calls the method with the encoded args and returns the encoded result."""
+        ...
+
+    async def call_async(self, scope_id: str, method_id: int, args: bytes) -> bytes:
+        """Same as `call` for async methods."""
+        ...
+
+    def consume(self, scope_id: str, method_id: int) -> Optional[bytes]:
+        """Takes the last value emitted by the signal, `None` if there is none."""
+        ...
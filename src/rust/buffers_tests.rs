@@ -0,0 +1,358 @@
+use std::collections::HashSet;
+
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{
+        self, ASTNode, EnumASTNode, EnumItemASTNode, StructASTNode, StructFieldASTNode,
+        TypeIDASTNode,
+    },
+    rust_generator::generate_type_id,
+    writer::Writer,
+};
+
+const ARBITRARY_IMPLS: &str = "
+impl Arbitrary for u8 {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        rng.next_u64() as u8
+    }
+}
+
+impl Arbitrary for i8 {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        rng.next_u64() as i8
+    }
+}
+
+impl Arbitrary for u32 {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        rng.next_u64() as u32
+    }
+}
+
+impl Arbitrary for i32 {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        rng.next_u64() as i32
+    }
+}
+
+impl Arbitrary for u64 {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        rng.next_u64()
+    }
+}
+
+impl Arbitrary for i64 {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        rng.next_u64() as i64
+    }
+}
+
+impl Arbitrary for f32 {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        (rng.next_below(1 << 24) as f32 - 8388608.0) / 256.0
+    }
+}
+
+impl Arbitrary for f64 {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        (rng.next_below(1 << 53) as f64 - 4503599627370496.0) / 1048576.0
+    }
+}
+
+impl Arbitrary for bool {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        rng.next_u64() & 1 == 1
+    }
+}
+
+impl Arbitrary for char {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        char::from_u32(rng.next_below(0xD800) as u32).unwrap()
+    }
+}
+
+impl Arbitrary for String {
+    fn arbitrary(rng: &mut Rng, depth: usize) -> Self {
+        (0..rng.next_below(8)).map(|_| char::arbitrary(rng, depth)).collect()
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Vec<T> {
+    fn arbitrary(rng: &mut Rng, depth: usize) -> Self {
+        if depth >= MAX_DEPTH {
+            return vec![];
+        }
+
+        (0..rng.next_below(4)).map(|_| T::arbitrary(rng, depth + 1)).collect()
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Option<T> {
+    fn arbitrary(rng: &mut Rng, depth: usize) -> Self {
+        if depth >= MAX_DEPTH || rng.next_u64() & 1 == 0 {
+            None
+        } else {
+            Some(T::arbitrary(rng, depth + 1))
+        }
+    }
+}
+";
+
+const CHECK_ROUND_TRIP: &str = "
+fn check_round_trip<T: Arbitrary + TechPawsBuffersModel + PartialEq + std::fmt::Debug>() {
+    let mut rng = Rng(0);
+
+    for _ in 0..ROUND_TRIP_ITERATIONS {
+        let first = T::arbitrary(&mut rng, 0);
+        let second = T::arbitrary(&mut rng, 0);
+
+        let mut bytes_writer = BytesWriter::default();
+        first.write_to_buffers(&mut bytes_writer);
+        second.write_to_buffers(&mut bytes_writer);
+        bytes_writer.write_u32(END_MARKER);
+        let data = bytes_writer.buffer;
+
+        let mut bytes_reader = BytesReader::new(&data);
+        assert_eq!(T::read_from_buffers(&mut bytes_reader), first);
+        assert_eq!(T::read_from_buffers(&mut bytes_reader), second);
+        assert_eq!(bytes_reader.read_u32(), END_MARKER, \"read_from_buffers stopped at a wrong offset\");
+
+        let mut bytes_reader = BytesReader::new(&data);
+        T::skip_in_buffers(&mut bytes_reader, 1);
+        assert_eq!(T::read_from_buffers(&mut bytes_reader), second, \"skip_in_buffers stopped at a wrong offset\");
+
+        let mut bytes_reader = BytesReader::new(&data);
+        T::skip_in_buffers(&mut bytes_reader, 2);
+        assert_eq!(bytes_reader.read_u32(), END_MARKER, \"skip_in_buffers stopped at a wrong offset\");
+    }
+}
+";
+
+/// Generates a `#[cfg(test)]` module that writes arbitrary instances of every
+/// struct and enum, reads them back and checks that `skip_in_buffers` stops
+/// at the same offset as `read_from_buffers`.
+pub fn generate_buffers_tests(ast: &[ASTNode]) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln("#[cfg(test)]");
+    writer.writeln("mod buffers_tests {");
+    writer.writeln_tab(1, "use super::*;");
+    writer.new_line();
+    writer.writeln_tab(1, "const ROUND_TRIP_ITERATIONS: usize = 64;");
+    writer.writeln_tab(1, "const MAX_DEPTH: usize = 3;");
+    writer.writeln_tab(1, "const END_MARKER: u32 = 0xC0FFEE;");
+    writer.new_line();
+    writer.writeln_tab(1, "struct Rng(u64);");
+    writer.new_line();
+    writer.writeln_tab(1, "impl Rng {");
+    writer.writeln_tab(2, "fn next_u64(&mut self) -> u64 {");
+    writer.writeln_tab(3, "self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);");
+    writer.writeln_tab(3, "let mut z = self.0;");
+    writer.writeln_tab(3, "z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);");
+    writer.writeln_tab(3, "z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);");
+    writer.writeln_tab(3, "z ^ (z >> 31)");
+    writer.writeln_tab(2, "}");
+    writer.new_line();
+    writer.writeln_tab(2, "fn next_below(&mut self, bound: u64) -> u64 {");
+    writer.writeln_tab(3, "self.next_u64() % bound");
+    writer.writeln_tab(2, "}");
+    writer.writeln_tab(1, "}");
+    writer.new_line();
+    writer.writeln_tab(1, "trait Arbitrary {");
+    writer.writeln_tab(2, "fn arbitrary(rng: &mut Rng, depth: usize) -> Self;");
+    writer.writeln_tab(1, "}");
+
+    write_snippet(&mut writer, ARBITRARY_IMPLS);
+
+    let mut tested = vec![];
+
+    for node in ast {
+        match node {
+            ASTNode::Struct(node) if is_supported_node(ast, &node.id, &mut HashSet::new()) => {
+                writer.new_line();
+                generate_struct_arbitrary(&mut writer, node);
+                tested.push(node.id.clone());
+            }
+            ASTNode::Enum(node) if is_supported_node(ast, &node.id, &mut HashSet::new()) => {
+                writer.new_line();
+                generate_enum_arbitrary(&mut writer, node);
+                tested.push(node.id.clone());
+            }
+            _ => (),
+        }
+    }
+
+    write_snippet(&mut writer, CHECK_ROUND_TRIP);
+
+    for id in tested {
+        writer.new_line();
+        writer.writeln_tab(1, "#[test]");
+        writer.writeln_tab(
+            1,
+            &format!("fn {}_round_trip() {{", id.to_case(Case::Snake)),
+        );
+        writer.writeln_tab(2, &format!("check_round_trip::<{}>();", id));
+        writer.writeln_tab(1, "}");
+    }
+
+    writer.writeln("}");
+    writer.show().to_string()
+}
+
+fn write_snippet(writer: &mut Writer, snippet: &str) {
+    for line in snippet.lines() {
+        if line.is_empty() {
+            writer.new_line();
+        } else {
+            writer.writeln_tab(1, line);
+        }
+    }
+}
+
+/// Types without an `Arbitrary` impl, e.g. generics other than `Vec` and
+/// `Option`, are left out of the round trip tests.
+pub fn is_supported_node(ast: &[ASTNode], id: &str, visited: &mut HashSet<String>) -> bool {
+    find_unsupported_type(ast, id, visited).is_none()
+}
+
+/// Structs and enums that can't be generated, with the reason, for the
+/// warnings about the skipped types.
+pub fn find_unsupported_types(ast: &[ASTNode]) -> Vec<(String, String)> {
+    let mut types = vec![];
+
+    for node in ast {
+        if let ASTNode::Struct(StructASTNode { id, .. }) | ASTNode::Enum(EnumASTNode { id, .. }) =
+            node
+        {
+            if let Some(reason) = find_unsupported_type(ast, id, &mut HashSet::new()) {
+                types.push((id.clone(), reason));
+            }
+        }
+    }
+
+    types
+}
+
+fn find_unsupported_type(
+    ast: &[ASTNode],
+    id: &str,
+    visited: &mut HashSet<String>,
+) -> Option<String> {
+    if !visited.insert(id.to_string()) {
+        return None;
+    }
+
+    if let Some(node) = ast::find_struct_node(ast, id) {
+        find_unsupported_field(ast, &node.fields, visited)
+    } else if let Some(node) = ast::find_enum_node(ast, id) {
+        if node.items.is_empty() {
+            return Some(format!("`{}` has no items", id));
+        }
+
+        node.items.iter().find_map(|item| match item {
+            EnumItemASTNode::Empty { .. } => None,
+            EnumItemASTNode::Tuple { values, .. } => values
+                .iter()
+                .find_map(|value| find_unsupported_field_type(ast, &value.type_id, visited)),
+            EnumItemASTNode::Struct { fields, .. } => find_unsupported_field(ast, fields, visited),
+        })
+    } else {
+        Some(format!("`{}` is not supported", id))
+    }
+}
+
+fn find_unsupported_field(
+    ast: &[ASTNode],
+    fields: &[StructFieldASTNode],
+    visited: &mut HashSet<String>,
+) -> Option<String> {
+    fields
+        .iter()
+        .find_map(|field| find_unsupported_field_type(ast, &field.type_id, visited))
+}
+
+fn find_unsupported_field_type(
+    ast: &[ASTNode],
+    type_id: &TypeIDASTNode,
+    visited: &mut HashSet<String>,
+) -> Option<String> {
+    match type_id {
+        TypeIDASTNode::Integer { .. } => None,
+        TypeIDASTNode::Number { .. } => None,
+        TypeIDASTNode::Bool { .. } => None,
+        TypeIDASTNode::Char { .. } => None,
+        TypeIDASTNode::Other { id } if id == "String" => None,
+        TypeIDASTNode::Other { id } => find_unsupported_type(ast, id, visited),
+        TypeIDASTNode::Generic { id, generics } if id == "Vec" || id == "Option" => generics
+            .iter()
+            .find_map(|generic| find_unsupported_field_type(ast, generic, visited)),
+        TypeIDASTNode::Generic { .. } => {
+            Some(format!("`{}` is not supported", generate_type_id(type_id)))
+        }
+    }
+}
+
+fn generate_fields_arbitrary(writer: &mut Writer, tab: usize, fields: &[StructFieldASTNode]) {
+    for field in fields {
+        writer.writeln_tab(
+            tab,
+            &format!("{}: Arbitrary::arbitrary(rng, depth + 1),", field.name),
+        );
+    }
+}
+
+fn generate_struct_arbitrary(writer: &mut Writer, node: &StructASTNode) {
+    writer.writeln_tab(1, &format!("impl Arbitrary for {} {{", node.id));
+
+    if node.fields.is_empty() {
+        writer.writeln_tab(2, "fn arbitrary(_: &mut Rng, _: usize) -> Self {");
+        writer.writeln_tab(3, "Self");
+    } else {
+        writer.writeln_tab(2, "fn arbitrary(rng: &mut Rng, depth: usize) -> Self {");
+        writer.writeln_tab(3, "Self {");
+        generate_fields_arbitrary(writer, 4, &node.fields);
+        writer.writeln_tab(3, "}");
+    }
+
+    writer.writeln_tab(2, "}");
+    writer.writeln_tab(1, "}");
+}
+
+fn generate_enum_arbitrary(writer: &mut Writer, node: &EnumASTNode) {
+    writer.writeln_tab(1, &format!("impl Arbitrary for {} {{", node.id));
+    writer.writeln_tab(2, "fn arbitrary(rng: &mut Rng, depth: usize) -> Self {");
+    writer.writeln_tab(3, &format!("match rng.next_below({}) {{", node.items.len()));
+
+    for (i, item) in node.items.iter().enumerate() {
+        let pattern = if i + 1 == node.items.len() {
+            String::from("_")
+        } else {
+            i.to_string()
+        };
+
+        match item {
+            EnumItemASTNode::Empty { id, .. } => {
+                writer.writeln_tab(4, &format!("{} => Self::{},", pattern, id));
+            }
+            EnumItemASTNode::Tuple { id, values, .. } => {
+                writer.writeln_tab(4, &format!("{} => Self::{}(", pattern, id));
+
+                for _ in values {
+                    writer.writeln_tab(5, "Arbitrary::arbitrary(rng, depth + 1),");
+                }
+
+                writer.writeln_tab(4, "),");
+            }
+            EnumItemASTNode::Struct { id, fields, .. } => {
+                writer.writeln_tab(4, &format!("{} => Self::{} {{", pattern, id));
+                generate_fields_arbitrary(writer, 5, fields);
+                writer.writeln_tab(4, "},");
+            }
+        }
+    }
+
+    writer.writeln_tab(3, "}");
+    writer.writeln_tab(2, "}");
+    writer.writeln_tab(1, "}");
+}
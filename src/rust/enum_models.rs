@@ -1,15 +1,22 @@
 use crate::{
     ast::{EnumASTNode, EnumItemASTNode, TupleFieldASTNode},
+    options::GeneratorOptions,
     rust_generator::{generate_default_const, generate_type_id},
     writer::Writer,
 };
 
-use super::struct_models::generate_struct_parameters;
+use super::struct_models::{
+    generate_attributes, generate_enum_serde_attribute, generate_struct_parameters,
+};
 
-pub fn generate_enum_model(node: &EnumASTNode) -> String {
+pub fn generate_enum_model(node: &EnumASTNode, options: &GeneratorOptions) -> String {
     let mut writer = Writer::default();
 
-    writer.writeln("#[derive(Debug, Clone, PartialEq)]");
+    writer.write(&generate_attributes(
+        &node.directives,
+        Some(generate_enum_serde_attribute()),
+        options,
+    ));
     writer.writeln(&format!("pub enum {} {{", node.id));
 
     for item in node.items.iter() {
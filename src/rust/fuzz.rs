@@ -0,0 +1,93 @@
+use convert_case::{Case, Casing};
+
+use crate::writer::Writer;
+
+/// Generates a `cargo fuzz` target feeding arbitrary bytes into
+/// `try_read_from_buffers` of the root type from `module`, `runtime` is the
+/// module with the checked reader.
+pub fn generate_fuzz_target(module: &str, runtime: &str, id: &str) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln("// GENERATED, DO NOT EDIT");
+    writer.writeln("");
+    writer.writeln("#![no_main]");
+    writer.writeln("");
+    writer.writeln("use libfuzzer_sys::fuzz_target;");
+    writer.writeln(&format!("use {}::{};", module, id));
+    writer.writeln(&format!(
+        "use {}::{{CheckedBytesReader, TryReadFromBuffers}};",
+        runtime
+    ));
+    writer.writeln("");
+    writer.writeln("fuzz_target!(|data: &[u8]| {");
+    writer.writeln_tab(1, "let mut bytes_reader = CheckedBytesReader::new(data);");
+    writer.writeln_tab(
+        1,
+        &format!("let _ = {}::try_read_from_buffers(&mut bytes_reader);", id),
+    );
+    writer.writeln("});");
+
+    writer.show().to_string()
+}
+
+/// Generates `Cargo.toml` of the fuzz crate, placed in a `fuzz` directory
+/// next to the manifest of `crate_name`.
+pub fn generate_fuzz_manifest(crate_name: &str, ids: &[String]) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln("# GENERATED, DO NOT EDIT");
+    writer.writeln("");
+    writer.writeln("[package]");
+    writer.writeln(&format!("name = \"{}-fuzz\"", crate_name));
+    writer.writeln("version = \"0.0.0\"");
+    writer.writeln("publish = false");
+    writer.writeln("edition = \"2021\"");
+    writer.writeln("");
+    writer.writeln("[package.metadata]");
+    writer.writeln("cargo-fuzz = true");
+    writer.writeln("");
+    writer.writeln("[dependencies]");
+    writer.writeln("libfuzzer-sys = \"0.4\"");
+    writer.writeln("");
+    writer.writeln(&format!("[dependencies.{}]", crate_name));
+    writer.writeln("path = \"..\"");
+
+    for id in ids {
+        let target_id = id.to_case(Case::Snake);
+
+        writer.writeln("");
+        writer.writeln("[[bin]]");
+        writer.writeln(&format!("name = \"{}\"", target_id));
+        writer.writeln(&format!("path = \"fuzz_targets/{}.rs\"", target_id));
+        writer.writeln("test = false");
+        writer.writeln("doc = false");
+        writer.writeln("bench = false");
+    }
+
+    writer.show().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_fuzz_target_test() {
+        assert_eq!(
+            generate_fuzz_target("my_app::buffers", "my_app::buffers::try_read", "Point"),
+            "// GENERATED, DO NOT EDIT
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use my_app::buffers::Point;
+use my_app::buffers::try_read::{CheckedBytesReader, TryReadFromBuffers};
+
+fuzz_target!(|data: &[u8]| {
+    let mut bytes_reader = CheckedBytesReader::new(data);
+    let _ = Point::try_read_from_buffers(&mut bytes_reader);
+});
+"
+        );
+    }
+}
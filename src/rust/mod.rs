@@ -1,6 +1,11 @@
+pub mod buffers_tests;
 pub mod enum_buffers;
 pub mod enum_models;
+pub mod fuzz;
 pub mod rpc;
+pub mod rpc_client;
 pub mod struct_buffers;
 pub mod struct_models;
+pub mod test_vectors;
+pub mod try_read;
 pub mod consts;
@@ -1,26 +1,146 @@
+use convert_case::{Case, Casing};
+
 use crate::{
-    ast::{self, ASTNode, FnASTNode, StructASTNode, StructFieldASTNode, TypeIDASTNode},
+    ast::{self, ASTNode, FnASTNode, StructASTNode, StructFieldASTNode},
     lexer::Literal,
-    rust_generator::generate_write,
+    options::GeneratorOptions,
+    payload::{find_payload_size, PayloadSize},
+    rust_generator::{generate_type_id, generate_write},
     writer::Writer,
 };
 
 use super::{struct_buffers::generate_struct_buffers, struct_models::generate_struct_model};
 
-pub fn generate_rpc_method(node: &FnASTNode) -> String {
+pub fn generate_rpc_method(node: &FnASTNode, service_id: Option<&str>) -> String {
     if node.is_signal {
-        generate_stream_rpc_method(node)
+        generate_stream_rpc_method(node, service_id)
     } else if node.is_async {
         panic!("async is not supported");
     } else {
-        generate_sync_rpc_method(node)
+        generate_sync_rpc_method(node, service_id)
+    }
+}
+
+/// Returns the name of the service trait when `#[rust(service)]` is set,
+/// otherwise handlers call free functions with the same name as the method.
+pub fn generate_service_id(ast: &[ASTNode]) -> Option<String> {
+    if !ast::contains_directive_group_flag(ast, "rust", "service") {
+        return None;
     }
+
+    let namespace = ast::find_directive_value(ast, "namespace").expect("namespace is required");
+    let namespace = match namespace {
+        ast::ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("namespace should be a string literal"),
+        },
+    };
+
+    Some(format!("{}Service", namespace.to_case(Case::Pascal)))
 }
 
-pub fn generate_register_fn(ast: &[ASTNode]) -> String {
+pub fn generate_service_trait(ast: &[ASTNode], service_id: &str) -> String {
     let mut writer = Writer::default();
 
-    writer.writeln("pub fn register_rpc(runtime: &mut TechPawsBuffersRuntime) {");
+    writer.writeln(&format!("pub trait {}: Send + Sync {{", service_id));
+    writer.push_tab();
+
+    let fn_nodes = ast::find_fn_nodes(ast);
+
+    for (i, node) in fn_nodes.iter().enumerate() {
+        if i != 0 {
+            writer.new_line();
+        }
+
+        let mut args = vec![String::from("&self")];
+
+        for arg in &node.args {
+            args.push(format!("{}: {}", arg.id, generate_type_id(&arg.type_id)));
+        }
+
+        let return_type_id = if node.is_signal {
+            let type_id = node
+                .return_type_id
+                .as_ref()
+                .map_or(String::from("()"), generate_type_id);
+
+            format!(" -> TechPawsSignalRpcResult<{}>", type_id)
+        } else {
+            node.return_type_id
+                .as_ref()
+                .map_or(String::new(), |type_id| {
+                    format!(" -> {}", generate_type_id(type_id))
+                })
+        };
+
+        writer.writeln(&format!(
+            "fn {}({}){};",
+            node.id,
+            args.join(", "),
+            return_type_id
+        ));
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.new_line();
+    writer.writeln("/// Services passed to `register_rpc`, keyed by the address of the runtime");
+    writer.writeln("/// memory, so handlers can stay plain functions and every runtime calls");
+    writer.writeln("/// its own service.");
+    writer.writeln(&format!(
+        "static SERVICES: std::sync::Mutex<Vec<(usize, std::sync::Arc<dyn {}>)>> =",
+        service_id
+    ));
+    writer.writeln_tab(1, "std::sync::Mutex::new(Vec::new());");
+    writer.new_line();
+    writer.writeln(&format!(
+        "fn registered_service(memory: &TechPawsRuntimeMemory) -> std::sync::Arc<dyn {}> {{",
+        service_id
+    ));
+    writer.push_tab();
+    writer.writeln("let key = memory as *const TechPawsRuntimeMemory as usize;");
+    writer.writeln("let services = SERVICES.lock().unwrap();");
+    writer.new_line();
+    writer.writeln("services");
+    writer.push_tab();
+    writer.writeln(".iter()");
+    writer.writeln(".find(|(service_key, _)| *service_key == key)");
+    writer.writeln(".map(|(_, service)| service.clone())");
+    writer.writeln(&format!(
+        ".expect(\"{} is not registered with this runtime\")",
+        service_id
+    ));
+    writer.pop_tab();
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+pub fn generate_register_fn(ast: &[ASTNode], service_id: Option<&str>) -> String {
+    let mut writer = Writer::default();
+
+    if let Some(service_id) = service_id {
+        writer.writeln("pub fn register_rpc(");
+        writer.push_tab();
+        writer.writeln("runtime: &mut TechPawsBuffersRuntime,");
+        writer.writeln(&format!("service: std::sync::Arc<dyn {}>,", service_id));
+        writer.pop_tab();
+        writer.writeln(") {");
+        writer.push_tab();
+        writer.writeln("let key = &runtime.memory as *const TechPawsRuntimeMemory as usize;");
+        writer.writeln("let mut services = SERVICES.lock().unwrap();");
+        writer.writeln("services.retain(|(service_key, _)| *service_key != key);");
+        writer.writeln("services.push((key, service));");
+        writer.writeln("drop(services);");
+        writer.new_line();
+        writer.pop_tab();
+    } else {
+        writer.writeln("pub fn register_rpc(runtime: &mut TechPawsBuffersRuntime) {");
+    }
 
     let id = ast::find_directive_value(ast, "id").expect("id is required");
     let id = match id {
@@ -51,16 +171,10 @@ pub fn generate_register_fn(ast: &[ASTNode]) -> String {
             "register_rpc_method"
         };
 
-        let buffer_size = if node.args.is_empty() && node.return_type_id.is_none() {
-            "TechPawsRuntimeRpcMethodPayloadSize::Zero"
-        } else if let Some(TypeIDASTNode::Generic { id, .. }) = node.return_type_id.clone() {
-            if id == "Vec" {
-                "TechPawsRuntimeRpcMethodPayloadSize::Large"
-            } else {
-                "TechPawsRuntimeRpcMethodPayloadSize::Medium"
-            }
-        } else {
-            "TechPawsRuntimeRpcMethodPayloadSize::Medium"
+        let buffer_size = match find_payload_size(ast, node) {
+            PayloadSize::Zero => String::from("TechPawsRuntimeRpcMethodPayloadSize::Zero"),
+            PayloadSize::Medium => String::from("TechPawsRuntimeRpcMethodPayloadSize::Medium"),
+            PayloadSize::Large => String::from("TechPawsRuntimeRpcMethodPayloadSize::Large"),
         };
 
         writer.writeln(&format!("runtime.{}(", register_method));
@@ -72,7 +186,9 @@ pub fn generate_register_fn(ast: &[ASTNode]) -> String {
             "rpc_method_address: RpcMethodAddress({}),",
             node.position
         ));
+
         writer.writeln(&format!("handler: {}_rpc_handler,", node.id));
+
         writer.pop_tab();
         writer.writeln("},");
         writer.writeln(&format!("{buffer_size},"));
@@ -86,7 +202,26 @@ pub fn generate_register_fn(ast: &[ASTNode]) -> String {
     writer.show().to_string()
 }
 
-fn generate_sync_rpc_method(node: &FnASTNode) -> String {
+fn write_handler_signature(writer: &mut Writer, node: &FnASTNode) {
+    writer.writeln(&format!("pub fn {}_rpc_handler(", node.id));
+    writer.push_tab();
+    writer.writeln("scope_id: TechPawsScopeId,");
+    writer.writeln("memory: &mut TechPawsRuntimeMemory,");
+    writer.writeln("rpc_method_address: RpcMethodAddress,");
+    writer.pop_tab();
+    writer.writeln(") {");
+}
+
+/// With a service the handlers call the implementation registered with
+/// `register_rpc` for the runtime owning `memory`.
+fn write_service_lookup(writer: &mut Writer, service_id: Option<&str>) {
+    if service_id.is_some() {
+        writer.writeln("let service = registered_service(memory);");
+        writer.new_line();
+    }
+}
+
+fn generate_sync_rpc_method(node: &FnASTNode, service_id: Option<&str>) -> String {
     let mut writer = Writer::default();
 
     let args_struct_id = format!("__{}_rpc_args__", node.id);
@@ -111,19 +246,18 @@ fn generate_sync_rpc_method(node: &FnASTNode) -> String {
             into_buffers: true,
         };
 
-        writer.writeln(&generate_struct_model(&args_struct, false));
+        writer.writeln(&generate_struct_model(
+            &args_struct,
+            false,
+            &GeneratorOptions::default(),
+        ));
         writer.writeln(&generate_struct_buffers(&args_struct));
     }
 
-    writer.writeln(&format!("pub fn {}_rpc_handler(", node.id));
-    writer.push_tab();
-    writer.writeln("scope_id: TechPawsScopeId,");
-    writer.writeln("memory: &mut TechPawsRuntimeMemory,");
-    writer.writeln("rpc_method_address: RpcMethodAddress,");
-    writer.pop_tab();
-    writer.writeln(") {");
+    write_handler_signature(&mut writer, node);
 
     writer.push_tab();
+    write_service_lookup(&mut writer, service_id);
 
     if !node.args.is_empty() {
         writer.writeln("let args = memory.get_scope_mut(scope_id).rpc_buffer_read(");
@@ -145,6 +279,10 @@ fn generate_sync_rpc_method(node: &FnASTNode) -> String {
         writer.write("let result = ");
     }
 
+    if service_id.is_some() {
+        writer.write("service.");
+    }
+
     writer.write(&node.id);
 
     if node.args.is_empty() {
@@ -186,21 +324,22 @@ fn generate_sync_rpc_method(node: &FnASTNode) -> String {
     writer.show().to_string()
 }
 
-fn generate_stream_rpc_method(node: &FnASTNode) -> String {
+fn generate_stream_rpc_method(node: &FnASTNode, service_id: Option<&str>) -> String {
     let mut writer = Writer::default();
 
-    writer.writeln(&format!("pub fn {}_rpc_handler(", node.id));
-    writer.push_tab();
-    writer.writeln("scope_id: TechPawsScopeId,");
-    writer.writeln("memory: &mut TechPawsRuntimeMemory,");
-    writer.writeln("rpc_method_address: RpcMethodAddress,");
-    writer.pop_tab();
-    writer.writeln(") {");
+    write_handler_signature(&mut writer, node);
 
     writer.push_tab();
+    write_service_lookup(&mut writer, service_id);
+
     writer.write_tabs();
 
     writer.write("let result = ");
+
+    if service_id.is_some() {
+        writer.write("service.");
+    }
+
     writer.write(&node.id);
 
     if node.args.is_empty() {
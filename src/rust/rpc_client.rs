@@ -0,0 +1,216 @@
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{self, ASTNode, FnASTNode},
+    lexer::Literal,
+    rust_generator::{generate_read, generate_type_id},
+    writer::Writer,
+};
+
+pub fn generate_rpc_client(ast: &[ASTNode]) -> String {
+    let mut writer = Writer::default();
+
+    let namespace = ast::find_directive_value(ast, "namespace").expect("namespace is required");
+    let namespace = match namespace {
+        ast::ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("namespace should be a string literal"),
+        },
+    };
+
+    let id = ast::find_directive_value(ast, "id").expect("id is required");
+    let id = match id {
+        ast::ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("id should be a string literal"),
+        },
+    };
+
+    let client_id = format!("{}Rpc", namespace.to_case(Case::Pascal));
+
+    writer.writeln(&format!("pub struct {};", client_id));
+    writer.new_line();
+    writer.writeln(&format!("impl {} {{", client_id));
+    writer.push_tab();
+    writer.writeln(&format!(
+        "pub const SCOPE_ID: TechPawsScopeId = TechPawsScopeId(uuid!(\"{}\"));",
+        id
+    ));
+
+    for node in ast::find_fn_nodes(ast) {
+        let method = if node.is_signal {
+            generate_consume_signal_method(node)
+        } else if node.is_async {
+            panic!("async is not supported");
+        } else {
+            generate_sync_rpc_call_method(node)
+        };
+
+        writer.new_line();
+
+        for line in method.lines() {
+            if line.is_empty() {
+                writer.new_line();
+            } else {
+                writer.writeln(line);
+            }
+        }
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+fn generate_sync_rpc_call_method(node: &FnASTNode) -> String {
+    let mut writer = Writer::default();
+    let return_type_id = node
+        .return_type_id
+        .as_ref()
+        .map_or(String::new(), |type_id| {
+            format!(" -> {}", generate_type_id(type_id))
+        });
+
+    if node.args.is_empty() {
+        writer.writeln(&format!(
+            "pub fn {}(runtime: &mut TechPawsBuffersRuntime){} {{",
+            node.id, return_type_id
+        ));
+    } else {
+        writer.writeln(&format!("pub fn {}(", node.id));
+        writer.push_tab();
+        writer.writeln("runtime: &mut TechPawsBuffersRuntime,");
+
+        for arg in &node.args {
+            writer.writeln(&format!("{}: {},", arg.id, generate_type_id(&arg.type_id)));
+        }
+
+        writer.pop_tab();
+        writer.writeln(&format!("){} {{", return_type_id));
+    }
+
+    writer.push_tab();
+
+    if !node.args.is_empty() {
+        writer.writeln(&format!("let args = __{}_rpc_args__ {{", node.id));
+        writer.push_tab();
+
+        for arg in &node.args {
+            writer.writeln(&format!("{},", arg.id));
+        }
+
+        writer.pop_tab();
+        writer.writeln("};");
+        writer.new_line();
+        writer.writeln("runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_write(");
+        writer.push_tab();
+        writer.writeln(&format!("RpcMethodAddress({}),", node.position));
+        writer.writeln("TechPawsRuntimeRpcMethodBuffer::Server,");
+        writer.writeln("|bytes_writer| {");
+        writer.writeln_tab(1, "args.write_to_buffers(bytes_writer);");
+        writer.writeln("},");
+        writer.pop_tab();
+        writer.writeln(");");
+        writer.new_line();
+    }
+
+    writer.writeln(&format!("{}_rpc_handler(", node.id));
+    writer.push_tab();
+    writer.writeln("Self::SCOPE_ID,");
+    writer.writeln("&mut runtime.memory,");
+    writer.writeln(&format!("RpcMethodAddress({}),", node.position));
+    writer.pop_tab();
+    writer.writeln(");");
+
+    if let Some(return_type_id) = &node.return_type_id {
+        writer.new_line();
+        writer.writeln("runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_read(");
+        writer.push_tab();
+        writer.writeln(&format!("RpcMethodAddress({}),", node.position));
+        writer.writeln("TechPawsRuntimeRpcMethodBuffer::Client,");
+        writer.writeln(&format!(
+            "|bytes_reader| {},",
+            generate_read(return_type_id)
+        ));
+        writer.pop_tab();
+        writer.writeln(")");
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+/// Reads the last value written by the signal handler and resets the
+/// new data marker, so the same value is consumed only once.
+fn generate_consume_signal_method(node: &FnASTNode) -> String {
+    let mut writer = Writer::default();
+
+    let result_type_id = node
+        .return_type_id
+        .as_ref()
+        .map_or(String::from("bool"), |type_id| {
+            format!("Option<{}>", generate_type_id(type_id))
+        });
+
+    writer.writeln(&format!(
+        "pub fn consume_{}(runtime: &mut TechPawsBuffersRuntime) -> {} {{",
+        node.id, result_type_id
+    ));
+    writer.push_tab();
+    writer.writeln("let result = runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_read(");
+    writer.push_tab();
+    writer.writeln(&format!("RpcMethodAddress({}),", node.position));
+    writer.writeln("TechPawsRuntimeRpcMethodBuffer::Client,");
+
+    if let Some(return_type_id) = &node.return_type_id {
+        writer.writeln("|bytes_reader| {");
+        writer.push_tab();
+        writer.writeln("if bytes_reader.read_u8() != 0xFF {");
+        writer.writeln_tab(1, "return None;");
+        writer.writeln("}");
+        writer.new_line();
+        writer.writeln(&format!("Some({})", generate_read(return_type_id)));
+        writer.pop_tab();
+        writer.writeln("},");
+    } else {
+        writer.writeln("|bytes_reader| bytes_reader.read_u8() == 0xFF,");
+    }
+
+    writer.pop_tab();
+    writer.writeln(");");
+    writer.new_line();
+
+    if node.return_type_id.is_some() {
+        writer.writeln("if result.is_some() {");
+    } else {
+        writer.writeln("if result {");
+    }
+
+    writer.push_tab();
+    writer.writeln("runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_write(");
+    writer.push_tab();
+    writer.writeln(&format!("RpcMethodAddress({}),", node.position));
+    writer.writeln("TechPawsRuntimeRpcMethodBuffer::Client,");
+    writer.writeln("|bytes_writer| {");
+    writer.writeln_tab(1, "bytes_writer.write_u8(0x00);");
+    writer.writeln("},");
+    writer.pop_tab();
+    writer.writeln(");");
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.new_line();
+    writer.writeln("result");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
@@ -1,14 +1,21 @@
 use crate::{
-    ast::{StructASTNode, StructFieldASTNode},
+    ast::{ConstValueASTNode, DirectiveASTNode, StructASTNode, StructFieldASTNode},
+    json_schema::{ENUM_CONTENT, ENUM_TAG},
+    lexer::Literal,
+    options::GeneratorOptions,
     rust_generator::{generate_default_const, generate_type_id},
     writer::Writer,
 };
 
-pub fn generate_struct_model(node: &StructASTNode, generate_default: bool) -> String {
+pub fn generate_struct_model(
+    node: &StructASTNode,
+    generate_default: bool,
+    options: &GeneratorOptions,
+) -> String {
     let mut writer = Writer::default();
 
     if node.fields.is_empty() {
-        writer.writeln("#[derive(Debug, Clone, PartialEq)]");
+        writer.write(&generate_attributes(&node.directives, None, options));
         writer.writeln(&format!("pub struct {};", node.id));
 
         if generate_default {
@@ -20,7 +27,8 @@ pub fn generate_struct_model(node: &StructASTNode, generate_default: bool) -> St
             writer.writeln("}");
         }
     } else {
-        writer.writeln("#[derive(Debug, Clone, PartialEq)]");
+        let serde = String::from("rename_all = \"camelCase\"");
+        writer.write(&generate_attributes(&node.directives, Some(serde), options));
         writer.writeln(&format!("pub struct {} {{", node.id));
         writer.write(&generate_struct_parameters(1, true, &node.fields));
         writer.writeln("}");
@@ -34,6 +42,84 @@ pub fn generate_struct_model(node: &StructASTNode, generate_default: bool) -> St
     writer.show().to_string()
 }
 
+/// `#[derive(...)]` and the other attributes of models, from the options
+/// and from `#[rust(derive = "...", attr = "...")]` of the type. With the
+/// `serde` option, `serde` is the content of the `#[serde(...)]` attribute
+/// of the model.
+pub fn generate_attributes(
+    directives: &[DirectiveASTNode],
+    serde: Option<String>,
+    options: &GeneratorOptions,
+) -> String {
+    let mut writer = Writer::default();
+    let mut derives = vec![
+        String::from("Debug"),
+        String::from("Clone"),
+        String::from("PartialEq"),
+    ];
+
+    if options.serde {
+        derives.push(String::from("serde::Serialize"));
+        derives.push(String::from("serde::Deserialize"));
+    }
+
+    derives.extend(options.derives.iter().cloned());
+
+    for value in find_rust_directive_values(directives, "derive") {
+        derives.extend(value.split(',').map(|derive| derive.trim().to_string()));
+    }
+
+    writer.writeln(&format!("#[derive({})]", derives.join(", ")));
+
+    if let (true, Some(serde)) = (options.serde, serde) {
+        writer.writeln(&format!("#[serde({})]", serde));
+    }
+
+    let attributes = options
+        .attributes
+        .iter()
+        .cloned()
+        .chain(find_rust_directive_values(directives, "attr"));
+
+    for attribute in attributes {
+        writer.writeln(&format!("#[{}]", attribute));
+    }
+
+    writer.show().to_string()
+}
+
+/// Adjacently tagged, like the enums of the JSON schema.
+pub fn generate_enum_serde_attribute() -> String {
+    format!(
+        "tag = \"{}\", content = \"{}\", rename_all_fields = \"camelCase\"",
+        ENUM_TAG, ENUM_CONTENT
+    )
+}
+
+fn find_rust_directive_values(directives: &[DirectiveASTNode], id: &str) -> Vec<String> {
+    let mut res = vec![];
+
+    for directive in directives {
+        if let DirectiveASTNode::Group { group_id, values } = directive {
+            if group_id != "rust" {
+                continue;
+            }
+
+            for value in values.iter().filter(|value| value.id == id) {
+                match &value.value {
+                    Some(ConstValueASTNode::Literal {
+                        literal: Literal::StringLiteral(value),
+                        ..
+                    }) => res.push(value.clone()),
+                    _ => panic!("rust {} directive should be a string", id),
+                }
+            }
+        }
+    }
+
+    res
+}
+
 fn generate_struct_default(node: &StructASTNode) -> String {
     let mut writer = Writer::default();
 
@@ -0,0 +1,197 @@
+use serde_json::Value;
+
+use crate::{
+    ast::{self, ASTNode, EnumItemASTNode, StructFieldASTNode, TypeIDASTNode},
+    codec::samples::TestVector,
+    writer::Writer,
+};
+
+/// Generates a test module that reads every vector, compares it with the
+/// expected model and writes it back. Meant to be declared next to the
+/// generated models, e.g. `#[cfg(test)] mod test_vectors;`.
+pub fn generate_test_vectors(ast: &[ASTNode], vectors: &[TestVector]) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln("// GENERATED, DO NOT EDIT");
+    writer.writeln("");
+    writer.writeln("use super::*;");
+    writer.writeln(
+        "use tech_paws_buffers::memory::{BytesReader, BytesWriter, TechPawsBuffersModel};",
+    );
+    writer.writeln("");
+    writer.writeln("fn read_vector<T: TechPawsBuffersModel>(data: &[u8]) -> T {");
+    writer.writeln_tab(1, "let mut bytes_reader = BytesReader::new(data);");
+    writer.writeln_tab(1, "T::read_from_buffers(&mut bytes_reader)");
+    writer.writeln("}");
+    writer.writeln("");
+    writer.writeln("fn write_vector<T: TechPawsBuffersModel>(value: &T) -> Vec<u8> {");
+    writer.writeln_tab(1, "let mut bytes_writer = BytesWriter::default();");
+    writer.writeln_tab(1, "value.write_to_buffers(&mut bytes_writer);");
+    writer.writeln_tab(1, "bytes_writer.buffer");
+    writer.writeln("}");
+
+    for vector in vectors {
+        writer.writeln("");
+        writer.writeln("#[test]");
+        writer.writeln(&format!("fn {}() {{", vector.name));
+        writer.writeln_tab(
+            1,
+            &format!("let data: &[u8] = &[{}];", generate_bytes(&vector.data)),
+        );
+        writer.writeln_tab(
+            1,
+            &format!(
+                "let expected = {};",
+                generate_value(
+                    ast,
+                    &TypeIDASTNode::Other {
+                        id: vector.type_id.clone()
+                    },
+                    &vector.value
+                )
+            ),
+        );
+        writer.writeln_tab(
+            1,
+            &format!("let value = read_vector::<{}>(data);", vector.type_id),
+        );
+        writer.new_line();
+        writer.writeln_tab(1, "assert_eq!(value, expected);");
+        writer.writeln_tab(1, "assert_eq!(write_vector(&value), data);");
+        writer.writeln("}");
+    }
+
+    writer.show().to_string()
+}
+
+fn generate_bytes(data: &[u8]) -> String {
+    data.iter()
+        .map(|byte| format!("0x{:02X}", byte))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Rust expression of a value produced by the codec sampler.
+pub fn generate_value(ast: &[ASTNode], type_id: &TypeIDASTNode, value: &Value) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { .. } => value.to_string(),
+        TypeIDASTNode::Number { size: 4, .. } => format!("{:?}", value.as_f64().unwrap() as f32),
+        TypeIDASTNode::Number { .. } => format!("{:?}", value.as_f64().unwrap()),
+        TypeIDASTNode::Bool { .. } => value.to_string(),
+        TypeIDASTNode::Char { .. } => {
+            format!("{:?}", value.as_str().unwrap().chars().next().unwrap())
+        }
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => format!("String::from({:?})", value.as_str().unwrap()),
+            "GroupAddress" | "CommandsBufferAddress" => {
+                format!("tech_paws_runtime::{}({})", id, value)
+            }
+            _ => {
+                if let Some(node) = ast::find_struct_node(ast, id) {
+                    generate_struct_value(ast, id, &node.fields, value)
+                } else if let Some(node) = ast::find_enum_node(ast, id) {
+                    generate_enum_value(ast, id, &node.items, value)
+                } else {
+                    panic!("Unknown type: {}", id)
+                }
+            }
+        },
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Vec" => format!(
+                "vec![{}]",
+                value
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|item| generate_value(ast, &generics[0], item))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            "Option" if value.is_null() => String::from("None"),
+            "Option" => format!("Some({})", generate_value(ast, &generics[0], value)),
+            _ => panic!("Unsupported type: {}", id),
+        },
+    }
+}
+
+fn generate_fields(ast: &[ASTNode], fields: &[StructFieldASTNode], value: &Value) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{}: {}",
+                field.name,
+                generate_value(ast, &field.type_id, &value[&field.name])
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn generate_struct_value(
+    ast: &[ASTNode],
+    id: &str,
+    fields: &[StructFieldASTNode],
+    value: &Value,
+) -> String {
+    if fields.is_empty() {
+        id.to_string()
+    } else {
+        format!("{} {{ {} }}", id, generate_fields(ast, fields, value))
+    }
+}
+
+fn generate_enum_value(
+    ast: &[ASTNode],
+    id: &str,
+    items: &[EnumItemASTNode],
+    value: &Value,
+) -> String {
+    let (item_id, item_value) = match value {
+        Value::String(item_id) => (item_id.as_str(), &Value::Null),
+        Value::Object(values) => {
+            let (item_id, item_value) = values.iter().next().unwrap();
+            (item_id.as_str(), item_value)
+        }
+        _ => panic!("Invalid enum value: {}", value),
+    };
+
+    match items.iter().find(|item| item.id() == item_id).unwrap() {
+        EnumItemASTNode::Empty { .. } => format!("{}::{}", id, item_id),
+        EnumItemASTNode::Tuple { values, .. } => format!(
+            "{}::{}({})",
+            id,
+            item_id,
+            values
+                .iter()
+                .zip(item_value.as_array().unwrap())
+                .map(|(tuple_value, item)| generate_value(ast, &tuple_value.type_id, item))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        EnumItemASTNode::Struct { fields, .. } => format!(
+            "{}::{} {{ {} }}",
+            id,
+            item_id,
+            generate_fields(ast, fields, item_value)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{codec::samples, lexer::Lexer, parser::parse};
+    use std::fs;
+
+    #[test]
+    fn generate_test_vectors_test() {
+        let src = fs::read_to_string("test_resources/test_vectors.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/rust/test_vectors.rs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let (vectors, _) = samples::generate_test_vectors(&ast, 0, 1);
+        let actual = generate_test_vectors(&ast, &vectors);
+        assert_eq!(actual.trim(), target.trim());
+    }
+}
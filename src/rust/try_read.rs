@@ -0,0 +1,404 @@
+use std::collections::HashSet;
+
+use crate::{
+    ast::{self, ASTNode, EnumASTNode, EnumItemASTNode, StructASTNode, StructFieldASTNode},
+    lexer::Literal,
+    writer::Writer,
+};
+
+use super::buffers_tests::is_supported_node;
+
+pub const DEFAULT_MAX_COLLECTION_LEN: i64 = 1024 * 1024;
+
+pub const DEFAULT_MAX_DEPTH: i64 = 128;
+
+/// Module of the checked reader embedded in generated files that don't set
+/// `#[rust(try_read_runtime = "...")]`.
+pub const EMBEDDED_RUNTIME_MODULE: &str = "try_read";
+
+const CHECKED_READER: &str = "
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeError {
+    UnexpectedEnd { offset: usize, size: usize },
+    CollectionTooLong { offset: usize, len: u64 },
+    TooDeep { offset: usize },
+    UnknownEnumValue { offset: usize, type_id: &'static str, value: u32 },
+    InvalidBool { offset: usize, value: u8 },
+    InvalidOption { offset: usize, value: u8 },
+    InvalidChar { offset: usize, value: u32 },
+    InvalidUtf8 { offset: usize },
+}
+
+pub struct CheckedBytesReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+    depth: u64,
+}
+
+impl<'a> CheckedBytesReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0, depth: 0 }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    pub fn read_bytes(&mut self, size: usize) -> Result<&'a [u8], DecodeError> {
+        if size > self.remaining() {
+            return Err(DecodeError::UnexpectedEnd { offset: self.offset, size });
+        }
+
+        let bytes = &self.data[self.offset..self.offset + size];
+        self.offset += size;
+        Ok(bytes)
+    }
+
+    pub fn read_len(&mut self) -> Result<usize, DecodeError> {
+        let offset = self.offset;
+        let len = u64::try_read_from_buffers(self)?;
+
+        if len > MAX_COLLECTION_LEN {
+            return Err(DecodeError::CollectionTooLong { offset, len });
+        }
+
+        Ok(len as usize)
+    }
+
+    pub fn nested<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, DecodeError>,
+    ) -> Result<T, DecodeError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(DecodeError::TooDeep { offset: self.offset });
+        }
+
+        self.depth += 1;
+        let res = read(self);
+        self.depth -= 1;
+        res
+    }
+}
+
+pub trait TryReadFromBuffers: Sized {
+    fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError>;
+}
+
+macro_rules! impl_try_read_from_buffers {
+    ($($type_id:ty),*) => {
+        $(
+            impl TryReadFromBuffers for $type_id {
+                fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+                    let bytes = bytes_reader.read_bytes(std::mem::size_of::<$type_id>())?;
+                    Ok(<$type_id>::from_le_bytes(bytes.try_into().unwrap()))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_read_from_buffers!(u8, i8, u32, i32, u64, i64, f32, f64);
+
+impl TryReadFromBuffers for bool {
+    fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+        let offset = bytes_reader.offset();
+
+        match u8::try_read_from_buffers(bytes_reader)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(DecodeError::InvalidBool { offset, value }),
+        }
+    }
+}
+
+impl TryReadFromBuffers for char {
+    fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+        let offset = bytes_reader.offset();
+        let value = u32::try_read_from_buffers(bytes_reader)?;
+
+        char::from_u32(value).ok_or(DecodeError::InvalidChar { offset, value })
+    }
+}
+
+impl TryReadFromBuffers for String {
+    fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+        let len = bytes_reader.read_len()?;
+        let offset = bytes_reader.offset();
+        let bytes = bytes_reader.read_bytes(len)?;
+
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::InvalidUtf8 { offset })
+    }
+}
+
+impl<T: TryReadFromBuffers> TryReadFromBuffers for Vec<T> {
+    fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+        let len = bytes_reader.read_len()?;
+        let mut items = Vec::with_capacity(len.min(bytes_reader.remaining()));
+
+        for _ in 0..len {
+            items.push(T::try_read_from_buffers(bytes_reader)?);
+        }
+
+        Ok(items)
+    }
+}
+
+impl<T: TryReadFromBuffers> TryReadFromBuffers for Option<T> {
+    fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+        let offset = bytes_reader.offset();
+
+        match u8::try_read_from_buffers(bytes_reader)? {
+            0 => Ok(None),
+            1 => Ok(Some(T::try_read_from_buffers(bytes_reader)?)),
+            value => Err(DecodeError::InvalidOption { offset, value }),
+        }
+    }
+}
+";
+
+/// Maximum length of strings and vectors accepted by `try_read_from_buffers`,
+/// set with `#[rust(max_collection_len = 65536)]`.
+pub fn find_max_collection_len(ast: &[ASTNode]) -> i64 {
+    find_limit(ast, "max_collection_len", DEFAULT_MAX_COLLECTION_LEN)
+}
+
+/// Maximum nesting of structs and enums accepted by `try_read_from_buffers`,
+/// so recursive types can't overflow the stack, set with
+/// `#[rust(max_depth = 32)]`.
+pub fn find_max_depth(ast: &[ASTNode]) -> i64 {
+    find_limit(ast, "max_depth", DEFAULT_MAX_DEPTH)
+}
+
+fn find_limit(ast: &[ASTNode], id: &str, default: i64) -> i64 {
+    match ast::find_directive_group_value(ast, "rust", id) {
+        Some(ast::ConstValueASTNode::Literal {
+            literal: Literal::IntLiteral(value),
+            ..
+        }) if value > 0 => value,
+        Some(_) => panic!("rust {} should be a positive integer literal", id),
+        None => default,
+    }
+}
+
+/// Path of the module with `CheckedBytesReader`, `DecodeError` and
+/// `TryReadFromBuffers`, set with
+/// `#[rust(try_read_runtime = "crate::try_read")]` to share one runtime
+/// between schemas. `None` embeds the runtime in the generated file.
+pub fn find_try_read_runtime(ast: &[ASTNode]) -> Option<String> {
+    match ast::find_directive_group_value(ast, "rust", "try_read_runtime") {
+        Some(ast::ConstValueASTNode::Literal {
+            literal: Literal::StringLiteral(value),
+            ..
+        }) => Some(value),
+        Some(_) => panic!("rust try_read_runtime should be a string literal"),
+        None => None,
+    }
+}
+
+/// Root types that get a fallible decoder: structs and enums whose fields
+/// are primitives, strings, vectors, options or other such types.
+pub fn find_try_read_types(ast: &[ASTNode]) -> Vec<String> {
+    let mut ids = vec![];
+
+    for node in ast {
+        if let ASTNode::Struct(StructASTNode { id, .. }) | ASTNode::Enum(EnumASTNode { id, .. }) =
+            node
+        {
+            if is_supported_node(ast, id, &mut HashSet::new()) {
+                ids.push(id.clone());
+            }
+        }
+    }
+
+    ids
+}
+
+/// The checked reader with its limits, the contents of the module
+/// `#[rust(try_read_runtime = "...")]` points to.
+pub fn generate_try_read_runtime(max_collection_len: i64, max_depth: i64) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!(
+        "pub const MAX_COLLECTION_LEN: u64 = {};",
+        max_collection_len
+    ));
+    writer.writeln(&format!("pub const MAX_DEPTH: u64 = {};", max_depth));
+
+    for line in CHECKED_READER.lines() {
+        writer.writeln(line);
+    }
+
+    writer.show().to_string()
+}
+
+pub fn generate_try_read(ast: &[ASTNode]) -> String {
+    let mut writer = Writer::default();
+    let ids = find_try_read_types(ast);
+    let runtime = match find_try_read_runtime(ast) {
+        Some(runtime) => {
+            if ast::contains_directive_group_flag(ast, "rust", "max_collection_len")
+                || ast::contains_directive_group_flag(ast, "rust", "max_depth")
+            {
+                panic!(
+                    "rust max_collection_len and max_depth apply to the embedded runtime only, \
+                     pass them to try-read-runtime"
+                );
+            }
+
+            runtime
+        }
+        None => {
+            let runtime =
+                generate_try_read_runtime(find_max_collection_len(ast), find_max_depth(ast));
+
+            writer.writeln(&format!("pub mod {} {{", EMBEDDED_RUNTIME_MODULE));
+
+            for line in runtime.lines() {
+                if line.is_empty() {
+                    writer.new_line();
+                } else {
+                    writer.writeln_tab(1, line);
+                }
+            }
+
+            writer.writeln("}");
+            String::from(EMBEDDED_RUNTIME_MODULE)
+        }
+    };
+
+    for node in ast {
+        match node {
+            ASTNode::Struct(node) if ids.contains(&node.id) => {
+                writer.writeln("");
+                generate_struct_try_read(&mut writer, &runtime, node);
+            }
+            ASTNode::Enum(node) if ids.contains(&node.id) => {
+                writer.writeln("");
+                generate_enum_try_read(&mut writer, &runtime, node);
+            }
+            _ => (),
+        }
+    }
+
+    writer.show().to_string()
+}
+
+/// Runtime items are referenced by path, so schema types named like them
+/// don't collide.
+fn generate_try_read_header(writer: &mut Writer, runtime: &str, id: &str, bytes_reader: &str) {
+    writer.writeln(&format!(
+        "impl {}::TryReadFromBuffers for {} {{",
+        runtime, id
+    ));
+    writer.writeln_tab(
+        1,
+        &format!(
+            "fn try_read_from_buffers({}: &mut {runtime}::CheckedBytesReader) -> Result<Self, {runtime}::DecodeError> {{",
+            bytes_reader,
+        ),
+    );
+}
+
+fn generate_fields_try_read(
+    writer: &mut Writer,
+    tab: usize,
+    runtime: &str,
+    fields: &[StructFieldASTNode],
+) {
+    for field in fields {
+        writer.writeln_tab(
+            tab,
+            &format!(
+                "{}: {}::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,",
+                field.name, runtime
+            ),
+        );
+    }
+}
+
+fn generate_struct_try_read(writer: &mut Writer, runtime: &str, node: &StructASTNode) {
+    if node.fields.is_empty() {
+        generate_try_read_header(writer, runtime, &node.id, "_");
+        writer.writeln_tab(2, "Ok(Self)");
+    } else {
+        generate_try_read_header(writer, runtime, &node.id, "bytes_reader");
+        writer.writeln_tab(2, "bytes_reader.nested(|bytes_reader| {");
+        writer.writeln_tab(3, "Ok(Self {");
+        generate_fields_try_read(writer, 4, runtime, &node.fields);
+        writer.writeln_tab(3, "})");
+        writer.writeln_tab(2, "})");
+    }
+
+    writer.writeln_tab(1, "}");
+    writer.writeln("}");
+}
+
+fn generate_enum_try_read(writer: &mut Writer, runtime: &str, node: &EnumASTNode) {
+    generate_try_read_header(writer, runtime, &node.id, "bytes_reader");
+    writer.writeln_tab(2, "bytes_reader.nested(|bytes_reader| {");
+    writer.writeln_tab(3, "let offset = bytes_reader.offset();");
+    writer.writeln_tab(
+        3,
+        &format!(
+            "let value: u32 = {}::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?;",
+            runtime
+        ),
+    );
+    writer.new_line();
+    writer.writeln_tab(3, "match value {");
+
+    for item in &node.items {
+        match item {
+            EnumItemASTNode::Empty { id, position, .. } => {
+                writer.writeln_tab(4, &format!("{} => Ok(Self::{}),", position, id));
+            }
+            EnumItemASTNode::Tuple {
+                id,
+                position,
+                values,
+                ..
+            } => {
+                writer.writeln_tab(4, &format!("{} => Ok(Self::{}(", position, id));
+
+                for _ in values {
+                    writer.writeln_tab(
+                        5,
+                        &format!(
+                            "{}::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,",
+                            runtime
+                        ),
+                    );
+                }
+
+                writer.writeln_tab(4, ")),");
+            }
+            EnumItemASTNode::Struct {
+                id,
+                position,
+                fields,
+                ..
+            } => {
+                writer.writeln_tab(4, &format!("{} => Ok(Self::{} {{", position, id));
+                generate_fields_try_read(writer, 5, runtime, fields);
+                writer.writeln_tab(4, "}),");
+            }
+        }
+    }
+
+    writer.writeln_tab(
+        4,
+        &format!("_ => Err({}::DecodeError::UnknownEnumValue {{", runtime),
+    );
+    writer.writeln_tab(5, "offset,");
+    writer.writeln_tab(5, &format!("type_id: \"{}\",", node.id));
+    writer.writeln_tab(5, "value,");
+    writer.writeln_tab(4, "}),");
+    writer.writeln_tab(3, "}");
+    writer.writeln_tab(2, "})");
+    writer.writeln_tab(1, "}");
+    writer.writeln("}");
+}
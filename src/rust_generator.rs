@@ -1,21 +1,80 @@
 use crate::ast::{self, *};
+use crate::options::GeneratorOptions;
+use crate::rust::buffers_tests::{find_unsupported_types, generate_buffers_tests};
 use crate::rust::consts::generate_const_block;
 use crate::rust::enum_buffers::generate_enum_buffers;
 use crate::rust::enum_models::generate_enum_model;
-use crate::rust::rpc::{generate_register_fn, generate_rpc_method};
+use crate::rust::rpc::{
+    generate_register_fn, generate_rpc_method, generate_service_id, generate_service_trait,
+};
+use crate::rust::rpc_client::generate_rpc_client;
 use crate::rust::struct_buffers::generate_struct_buffers;
 use crate::rust::struct_models::generate_struct_model;
+use crate::rust::try_read::generate_try_read;
+use crate::writer::reindent;
 use crate::{lexer::Literal, writer::Writer};
 
+/// Warnings about the types `#[rust(try_read)]` and `#[rust(tests)]` leave
+/// out, the generated code has no trace of them.
+pub fn find_skipped_types(ast: &[ASTNode]) -> Vec<String> {
+    let mut warnings = vec![];
+    let unsupported = find_unsupported_types(ast);
+
+    if ast::contains_directive_group_flag(ast, "rust", "try_read") {
+        for (id, reason) in &unsupported {
+            warnings.push(format!("{} has no TryReadFromBuffers impl: {}", id, reason));
+        }
+    }
+
+    if ast::contains_directive_group_flag(ast, "rust", "tests") {
+        for (id, reason) in &unsupported {
+            warnings.push(format!("{} has no round trip test: {}", id, reason));
+        }
+    }
+
+    warnings
+}
+
 pub fn generate(ast: &[ASTNode]) -> String {
+    generate_with_options(ast, &GeneratorOptions::default())
+}
+
+pub fn generate_with_options(ast: &[ASTNode], options: &GeneratorOptions) -> String {
     let mut writer = Writer::default();
 
-    writer.writeln("// GENERATED, DO NOT EDIT");
-    writer.writeln("");
-    writer.writeln("#![allow(warnings)]");
-    writer.writeln("#![allow(clippy)]");
-    writer.writeln("#![allow(unknown_lints)]");
-    writer.writeln("");
+    if !options.header().is_empty() {
+        writer.writeln(options.header());
+        writer.writeln("");
+    }
+
+    if options.allow_warnings() {
+        writer.writeln("#![allow(warnings)]");
+        writer.writeln("#![allow(clippy)]");
+        writer.writeln("#![allow(unknown_lints)]");
+        writer.writeln("");
+    }
+
+    writer.write(&generate_items_with_options(ast, options));
+
+    let mut res = writer.show().to_string();
+
+    while res.ends_with("\n\n") {
+        res.pop();
+    }
+
+    reindent(&res, options.indent())
+}
+
+/// Generates the file without the inner attributes of the header,
+/// so the result can be used with `include!`.
+pub fn generate_items(ast: &[ASTNode]) -> String {
+    generate_items_with_options(ast, &GeneratorOptions::default())
+}
+
+/// Like [`generate_items`], the indentation of the result isn't changed by
+/// `options.indent`.
+pub fn generate_items_with_options(ast: &[ASTNode], options: &GeneratorOptions) -> String {
+    let mut writer = Writer::default();
 
     let has_rpc = ast::contains_fn_nodes(ast);
     let has_buffers = ast::contains_buffers_nodes(ast);
@@ -55,6 +114,10 @@ pub fn generate(ast: &[ASTNode]) -> String {
         writer.writeln(&format!("use {};", import));
     }
 
+    for import in options.imports.iter() {
+        writer.writeln(&format!("use {};", import));
+    }
+
     if has_consts {
         writer.writeln("");
         writer.write(&generate_consts(ast));
@@ -62,9 +125,19 @@ pub fn generate(ast: &[ASTNode]) -> String {
 
     if has_buffers {
         writer.writeln("");
-        writer.write(&generate_models(ast));
+        writer.write(&generate_models(ast, options));
         writer.writeln("");
         writer.write(&generate_buffers(ast));
+
+        if ast::contains_directive_group_flag(ast, "rust", "try_read") {
+            writer.writeln("");
+            writer.write(&generate_try_read(ast));
+        }
+
+        if ast::contains_directive_group_flag(ast, "rust", "tests") {
+            writer.writeln("");
+            writer.write(&generate_buffers_tests(ast));
+        }
     }
 
     if has_rpc {
@@ -78,16 +151,37 @@ pub fn generate(ast: &[ASTNode]) -> String {
         res.pop();
     }
 
-    res
+    match &options.module {
+        Some(module) => generate_module(module, &res),
+        None => res,
+    }
+}
+
+/// Wraps `items` in `pub mod <module>`.
+fn generate_module(module: &str, items: &str) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!("pub mod {} {{", module));
+
+    for line in items.lines() {
+        if line.is_empty() {
+            writer.new_line();
+        } else {
+            writer.writeln_tab(1, line);
+        }
+    }
+
+    writer.writeln("}");
+    writer.show().to_string()
 }
 
-pub fn generate_models(ast: &[ASTNode]) -> String {
+pub fn generate_models(ast: &[ASTNode], options: &GeneratorOptions) -> String {
     let mut writer = Writer::default();
 
     for node in ast {
         match node {
-            ASTNode::Struct(node) => writer.writeln(&generate_struct_model(node, true)),
-            ASTNode::Enum(node) => writer.writeln(&generate_enum_model(node)),
+            ASTNode::Struct(node) => writer.writeln(&generate_struct_model(node, true, options)),
+            ASTNode::Enum(node) => writer.writeln(&generate_enum_model(node, options)),
             ASTNode::Fn(_) => (),
             ASTNode::Directive(_) => (),
             ASTNode::Const(_) => (),
@@ -142,14 +236,24 @@ pub fn generate_rpc(ast: &[ASTNode]) -> String {
         return String::new();
     }
 
-    writer.writeln(&generate_register_fn(ast));
+    let service_id = generate_service_id(ast);
+
+    if let Some(service_id) = &service_id {
+        writer.writeln(&generate_service_trait(ast, service_id));
+    }
+
+    writer.writeln(&generate_register_fn(ast, service_id.as_deref()));
 
     for node in ast {
         if let ASTNode::Fn(node) = node {
-            writer.writeln(&generate_rpc_method(node));
+            writer.writeln(&generate_rpc_method(node, service_id.as_deref()));
         }
     }
 
+    if ast::contains_directive_group_flag(ast, "rust", "client") {
+        writer.writeln(&generate_rpc_client(ast));
+    }
+
     let mut res = writer.show().to_string();
 
     if res.ends_with("\n\n") {
@@ -308,13 +412,35 @@ mod tests {
         assert_eq!(actual, target);
     }
 
+    #[test]
+    fn generate_with_custom_options() {
+        let src = fs::read_to_string("test_resources/struct_basic.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/rust/struct_basic_options.rs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let options = GeneratorOptions {
+            indent: Some(2),
+            header: Some(String::from(
+                "// Copyright (c) Tech Paws\n// GENERATED, DO NOT EDIT",
+            )),
+            imports: vec![String::from("std::fmt")],
+            derives: vec![String::from("Eq")],
+            module: Some(String::from("models")),
+            allow_warnings: Some(false),
+            ..GeneratorOptions::default()
+        };
+        let actual = generate_with_options(&ast, &options);
+        println!("{}", actual);
+        assert_eq!(actual, target);
+    }
+
     #[test]
     fn generate_struct_model() {
         let src = fs::read_to_string("test_resources/struct.tpb").unwrap();
         let target = fs::read_to_string("test_resources/rust/struct_models.rs").unwrap();
         let mut lexer = Lexer::tokenize(&src);
         let ast = parse(&mut lexer);
-        let actual = generate_models(&ast);
+        let actual = generate_models(&ast, &GeneratorOptions::default());
         println!("{}", actual);
         assert_eq!(actual, target);
     }
@@ -325,7 +451,23 @@ mod tests {
         let target = fs::read_to_string("test_resources/rust/enum_models.rs").unwrap();
         let mut lexer = Lexer::tokenize(&src);
         let ast = parse(&mut lexer);
-        let actual = generate_models(&ast);
+        let actual = generate_models(&ast, &GeneratorOptions::default());
+        println!("{}", actual);
+        assert_eq!(actual, target);
+    }
+
+    #[test]
+    fn generate_serde_models() {
+        let src = fs::read_to_string("test_resources/rust_attributes.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/rust/serde_models.rs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let options = GeneratorOptions {
+            attributes: vec![String::from("allow(clippy::derive_partial_eq_without_eq)")],
+            serde: true,
+            ..GeneratorOptions::default()
+        };
+        let actual = generate_models(&ast, &options);
         println!("{}", actual);
         assert_eq!(actual, target);
     }
@@ -384,4 +526,101 @@ mod tests {
         println!("{}", actual);
         assert_eq!(actual, target);
     }
+
+    #[test]
+    fn generate_rpc_client_methods() {
+        let src = fs::read_to_string("test_resources/rpc_client_methods.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/rust/rpc_client_methods.rs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let actual = generate_rpc(&ast);
+        println!("{}", actual);
+        assert_eq!(actual, target);
+    }
+
+    #[test]
+    fn generate_rpc_payload_methods() {
+        let src = fs::read_to_string("test_resources/rpc_payload_methods.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/rust/rpc_payload_methods.rs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let actual = generate_rpc(&ast);
+        println!("{}", actual);
+        assert_eq!(actual, target);
+    }
+
+    #[test]
+    fn generate_try_read() {
+        let src = fs::read_to_string("test_resources/try_read.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/rust/try_read.rs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let actual = generate(&ast);
+        println!("{}", actual);
+        assert_eq!(actual, target);
+        assert_eq!(
+            find_skipped_types(&ast),
+            vec![
+                "Table has no TryReadFromBuffers impl: `LinearTable<f32, Point>` is not supported"
+            ]
+        );
+    }
+
+    #[test]
+    fn generate_try_read_with_shared_runtime() {
+        let src = fs::read_to_string("test_resources/try_read_runtime.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/rust/try_read_runtime.rs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let actual = generate(&ast);
+        println!("{}", actual);
+        assert_eq!(actual, target);
+    }
+
+    #[test]
+    #[should_panic(expected = "rust max_collection_len should be a positive integer literal")]
+    fn generate_try_read_zero_max_collection_len() {
+        let src = "#[rust(try_read)]\n#[rust(max_collection_len = 0)]\nstruct Point { x: f32 }";
+        let mut lexer = Lexer::tokenize(src);
+        let ast = parse(&mut lexer);
+        generate(&ast);
+    }
+
+    #[test]
+    fn generate_buffers_tests() {
+        let src = fs::read_to_string("test_resources/buffers_tests.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/rust/buffers_tests.rs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let actual = generate(&ast);
+        println!("{}", actual);
+        assert_eq!(actual, target);
+        assert_eq!(
+            find_skipped_types(&ast),
+            vec!["Table has no round trip test: `LinearTable<f32, Point>` is not supported"]
+        );
+    }
+
+    #[test]
+    fn generate_rpc_service_methods() {
+        let src = fs::read_to_string("test_resources/rpc_service_methods.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/rust/rpc_service_methods.rs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let actual = generate_rpc(&ast);
+        println!("{}", actual);
+        assert_eq!(actual, target);
+    }
+
+    #[test]
+    fn generate_rpc_service_client_methods() {
+        let src = fs::read_to_string("test_resources/rpc_service_client_methods.tpb").unwrap();
+        let target =
+            fs::read_to_string("test_resources/rust/rpc_service_client_methods.rs").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let actual = generate_rpc(&ast);
+        println!("{}", actual);
+        assert_eq!(actual, target);
+    }
 }
@@ -4,6 +4,7 @@ use strum_macros::IntoStaticStr;
 use crate::{
     ast::{ConstValueASTNode, TypeIDASTNode},
     lexer::Literal,
+    options::GeneratorOptions,
     writer::Writer,
 };
 
@@ -138,16 +139,25 @@ pub enum SwiftIR {
 }
 
 pub fn stringify_ir(tokens: &[SwiftIR]) -> String {
-    let mut writer = Writer::default();
-    write_tokens(&mut writer, tokens);
+    stringify_ir_with_options(tokens, &GeneratorOptions::default())
+}
+
+pub fn stringify_ir_with_options(tokens: &[SwiftIR], options: &GeneratorOptions) -> String {
+    let mut writer = options.writer();
+    write_tokens(&mut writer, options, tokens);
     writer.show().to_string()
 }
 
-pub fn write_tokens_separated(writer: &mut Writer, tokens: &[SwiftIR], separator: &'static str) {
+pub fn write_tokens_separated(
+    writer: &mut Writer,
+    options: &GeneratorOptions,
+    tokens: &[SwiftIR],
+    separator: &'static str,
+) {
     let mut it = tokens.iter().peekable();
 
     while let Some(token) = it.next() {
-        write_token(writer, token);
+        write_token(writer, options, token);
 
         if it.peek().is_some() {
             writer.write(separator);
@@ -155,12 +165,16 @@ pub fn write_tokens_separated(writer: &mut Writer, tokens: &[SwiftIR], separator
     }
 }
 
-pub fn write_tokens_comma_separated(writer: &mut Writer, tokens: &[SwiftIR]) {
+pub fn write_tokens_comma_separated(
+    writer: &mut Writer,
+    options: &GeneratorOptions,
+    tokens: &[SwiftIR],
+) {
     let mut it = tokens.iter().peekable();
 
     while let Some(token) = it.next() {
         writer.write_tabs();
-        write_token(writer, token);
+        write_token(writer, options, token);
 
         if it.peek().is_some() {
             writer.write(",");
@@ -169,7 +183,7 @@ pub fn write_tokens_comma_separated(writer: &mut Writer, tokens: &[SwiftIR]) {
     }
 }
 
-pub fn write_tokens(writer: &mut Writer, tokens: &[SwiftIR]) {
+pub fn write_tokens(writer: &mut Writer, options: &GeneratorOptions, tokens: &[SwiftIR]) {
     let mut last_token: Option<&SwiftIR> = None;
 
     for token in tokens {
@@ -202,11 +216,11 @@ pub fn write_tokens(writer: &mut Writer, tokens: &[SwiftIR]) {
         }
 
         last_token = Some(token);
-        write_token(writer, token);
+        write_token(writer, options, token);
     }
 }
 
-fn write_token(writer: &mut Writer, token: &SwiftIR) {
+fn write_token(writer: &mut Writer, options: &GeneratorOptions, token: &SwiftIR) {
     match token {
         SwiftIR::Id(id) => writer.write(id),
         SwiftIR::Gap => (),
@@ -228,7 +242,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
                     writer.write_tabs();
                 }
 
-                write_token(writer, item);
+                write_token(writer, options, item);
 
                 if it.peek().is_some() {
                     writer.write(separator);
@@ -254,7 +268,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
                     SwiftIR::Gap => {}
                     _ => {
                         writer.write_tabs();
-                        write_token(writer, item);
+                        write_token(writer, options, item);
                     }
                 }
 
@@ -270,7 +284,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
                     SwiftIR::Gap => {}
                     _ => {
                         writer.write_tabs();
-                        write_token(writer, item);
+                        write_token(writer, options, item);
                     }
                 }
 
@@ -280,9 +294,9 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
             }
         }
         SwiftIR::Range { from, to } => {
-            write_token(writer, from);
+            write_token(writer, options, from);
             writer.write("...");
-            write_token(writer, to);
+            write_token(writer, options, to);
         }
         SwiftIR::ForLoop {
             item,
@@ -292,19 +306,19 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
             writer.write("for ");
 
             if let Some(item) = item {
-                write_token(writer, item);
+                write_token(writer, options, item);
             } else {
                 writer.write("_");
             }
 
             writer.write(" in ");
-            write_token(writer, collection_expr);
+            write_token(writer, options, collection_expr);
 
             writer.write(" {");
             writer.new_line();
 
             writer.push_tab();
-            write_token(writer, body);
+            write_token(writer, options, body);
             writer.pop_tab();
             writer.new_line();
             writer.write_tabs();
@@ -312,30 +326,30 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
         }
         SwiftIR::Switch { item, body } => {
             writer.write("switch ");
-            write_token(writer, item);
+            write_token(writer, options, item);
 
             writer.write(" {");
             writer.new_line();
-            write_token(writer, body);
+            write_token(writer, options, body);
             writer.new_line();
             writer.write_tabs();
             writer.write("}");
         }
         SwiftIR::Case { item, body } => {
             writer.write("case ");
-            write_token(writer, item);
+            write_token(writer, options, item);
 
             writer.write(":");
             writer.new_line();
             writer.push_tab();
-            write_token(writer, body);
+            write_token(writer, options, body);
             writer.pop_tab();
         }
         SwiftIR::DefaultCase { body } => {
             writer.write("default:");
             writer.new_line();
             writer.push_tab();
-            write_token(writer, body);
+            write_token(writer, options, body);
             writer.pop_tab();
         }
         SwiftIR::VarDeclaration {
@@ -354,12 +368,12 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
 
             if let Some(type_id) = type_id {
                 writer.write(": ");
-                write_token(writer, type_id);
+                write_token(writer, options, type_id);
             }
 
             if let Some(value) = value {
                 writer.write(" = ");
-                write_token(writer, value);
+                write_token(writer, options, value);
             }
         }
         SwiftIR::StaticVarDeclaration {
@@ -376,6 +390,8 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
                 } else {
                     writer.write("private ");
                 }
+            } else {
+                writer.write(&options.access_prefix());
             }
 
             writer.write("static ");
@@ -390,40 +406,42 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
 
             if let Some(type_id) = type_id {
                 writer.write(": ");
-                write_token(writer, type_id);
+                write_token(writer, options, type_id);
             }
 
             if let Some(value) = value {
                 writer.write(" = ");
-                write_token(writer, value);
+                write_token(writer, options, value);
             }
         }
         SwiftIR::Struct { id, body, extends } => {
             writer.write_tabs();
-            writer.write(&format!("struct {}", id));
+            writer.write(&format!("{}struct {}", options.access_prefix(), id));
 
             if !extends.is_empty() {
                 writer.write(": ");
-                write_tokens_separated(writer, extends, ", ");
+                write_tokens_separated(writer, options, extends, ", ");
             }
 
             writer.write(" {");
             writer.new_line();
             writer.push_tab();
-            write_tokens(writer, body);
+            write_tokens(writer, options, body);
             writer.pop_tab();
             writer.writeln("}");
         }
         SwiftIR::StructConstField { id, type_id, value } => {
             writer.writeln(&format!(
-                "static let {}: {} = {}",
+                "{}static let {}: {} = {}",
+                options.access_prefix(),
                 id.to_case(Case::Camel),
                 generate_type_id(type_id),
                 generate_const_value(value, type_id)
             ));
         }
         SwiftIR::StructField { id, type_id } => writer.writeln(&format!(
-            "let {}: {}",
+            "{}let {}: {}",
+            options.access_prefix(),
             id.to_case(Case::Camel),
             generate_type_id(type_id),
         )),
@@ -435,6 +453,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
             body,
         } => {
             writer.write_tabs();
+            writer.write(&options.access_prefix());
 
             if *is_static {
                 writer.write("static ");
@@ -443,12 +462,12 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
             writer.write(&format!("func {}(", id));
 
             if let Some(arguments) = arguments {
-                write_token(writer, arguments);
+                write_token(writer, options, arguments);
             }
 
             if let Some(return_type_id) = return_type_id {
                 writer.write(") -> ");
-                write_token(writer, return_type_id);
+                write_token(writer, options, return_type_id);
             } else {
                 writer.write(")");
             }
@@ -458,7 +477,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
 
             if let Some(body) = body {
                 writer.push_tab();
-                write_token(writer, body);
+                write_token(writer, options, body);
                 writer.pop_tab();
 
                 match body.as_ref() {
@@ -482,11 +501,11 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
 
             writer.write(id);
             writer.write(": ");
-            write_token(writer, type_id);
+            write_token(writer, options, type_id);
         }
         SwiftIR::ReturnStatement { body } => {
             writer.write("return ");
-            write_token(writer, body);
+            write_token(writer, options, body);
         }
         SwiftIR::Continue => {
             writer.write("continue");
@@ -498,7 +517,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
         } => {
             if let Some(value) = value {
                 writer.write(&format!("{}: ", id.to_case(Case::Camel),));
-                write_token(writer, value);
+                write_token(writer, options, value);
             } else if let Some(default_value_type_id) = default_value_type_id {
                 writer.write(&format!(
                     "{}: {}",
@@ -511,7 +530,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
         }
         SwiftIR::SetVar { id, value } => {
             writer.write(&format!("{} = ", id));
-            write_token(writer, value);
+            write_token(writer, options, value);
         }
         SwiftIR::AssignArgument {
             id,
@@ -522,12 +541,12 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
                 writer.write(&format!("/* {} */ ", id.to_case(Case::Camel),));
 
                 if let Some(value) = value {
-                    write_token(writer, value);
+                    write_token(writer, options, value);
                 } else {
                     writer.write(&generate_default_const_value(type_id));
                 }
             } else if let Some(value) = value {
-                write_token(writer, value);
+                write_token(writer, options, value);
             } else {
                 writer.write(&generate_default_const_value(type_id));
             }
@@ -537,7 +556,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
 
             if let Some(arguments) = arguments {
                 writer.write("(");
-                write_token(writer, arguments);
+                write_token(writer, options, arguments);
                 writer.write(")");
             } else {
                 writer.write("()");
@@ -552,7 +571,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
                 match item {
                     SwiftIR::Gap => {}
                     _ => {
-                        write_token(writer, item);
+                        write_token(writer, options, item);
                     }
                 }
 
@@ -569,7 +588,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
             writer.new_line();
 
             writer.push_tab();
-            write_token(writer, body);
+            write_token(writer, options, body);
             writer.pop_tab();
 
             match body.as_ref() {
@@ -596,7 +615,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
 
             if let Some(arguments) = arguments {
                 writer.write("(");
-                write_token(writer, arguments);
+                write_token(writer, options, arguments);
                 writer.write(")");
             } else {
                 writer.write("()");
@@ -606,14 +625,14 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
 
             if let Some(input) = input {
                 writer.write(" ");
-                write_token(writer, input);
+                write_token(writer, options, input);
                 writer.write(" in");
             }
 
             writer.new_line();
 
             writer.push_tab();
-            write_token(writer, body);
+            write_token(writer, options, body);
             writer.pop_tab();
 
             match body.as_ref() {
@@ -632,22 +651,26 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
         }
         SwiftIR::Enum { id, body, extends } => {
             writer.write_tabs();
-            writer.write(&format!("enum {}", id.to_case(Case::Pascal)));
+            writer.write(&format!(
+                "{}enum {}",
+                options.access_prefix(),
+                id.to_case(Case::Pascal)
+            ));
 
             if !extends.is_empty() {
                 writer.write(": ");
-                write_tokens_separated(writer, extends, ", ");
+                write_tokens_separated(writer, options, extends, ", ");
             }
 
             writer.write(" {");
             writer.new_line();
             writer.push_tab();
-            write_tokens(writer, body);
+            write_tokens(writer, options, body);
             writer.pop_tab();
             writer.writeln("}");
             // writer.writeln(&format!("enum {} {{", id.to_case(Case::Pascal)));
             // writer.push_tab();
-            // write_tokens(writer, body);
+            // write_tokens(writer, options, body);
             // writer.pop_tab();
             // writer.writeln("}");
         }
@@ -657,7 +680,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
             } else {
                 writer.writeln(&format!("case {}(", id.to_case(Case::Camel)));
                 writer.push_tab();
-                write_tokens_comma_separated(writer, parameters);
+                write_tokens_comma_separated(writer, options, parameters);
                 writer.pop_tab();
                 writer.writeln(")");
             }
@@ -671,7 +694,7 @@ fn write_token(writer: &mut Writer, token: &SwiftIR) {
         }
         SwiftIR::FieldAccess { instance, field } => {
             if let Some(instance) = instance {
-                write_token(writer, instance);
+                write_token(writer, options, instance);
                 writer.write(&format!(".{}", field.to_case(Case::Camel)));
             } else {
                 writer.write(&format!(".{}", field.to_case(Case::Camel)));
@@ -949,6 +972,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn generate_const_value_test_float() {
         assert_eq!(
             generate_const_value(
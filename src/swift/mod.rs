@@ -1,18 +1,23 @@
 use crate::{
     ast::{self, ASTNode},
     lexer::Literal,
-    writer::Writer,
+    options::GeneratorOptions,
 };
 
 use self::{
     generator::{generate_consts, generate_models, generate_rpc},
-    ir::stringify_ir,
+    ir::stringify_ir_with_options,
 };
 
 pub mod generator;
 pub mod ir;
+pub mod test_vectors;
 
 pub fn generate(ast: &[ASTNode]) -> String {
+    generate_with_options(ast, &GeneratorOptions::default())
+}
+
+pub fn generate_with_options(ast: &[ASTNode], options: &GeneratorOptions) -> String {
     let mut ir = vec![];
 
     ir.append(&mut generate_consts(ast));
@@ -22,12 +27,23 @@ pub fn generate(ast: &[ASTNode]) -> String {
         ir.append(&mut generate_rpc(ast));
     }
 
-    let mut writer = Writer::default();
+    let mut writer = options.writer();
+
+    if !options.header().is_empty() {
+        writer.writeln(options.header());
+        writer.writeln("");
+    }
 
-    writer.writeln("// GENERATED, DO NOT EDIT");
-    writer.writeln("");
     writer.writeln("import Foundation");
-    writer.writeln("import Combine");
+
+    if contains_signal_nodes(ast) {
+        writer.writeln("import Combine");
+    }
+
+    for import in options.imports.iter() {
+        writer.writeln(&format!("import {}", import));
+    }
+
     writer.writeln("");
 
     let imports = ast::find_directive_group_values(ast, "swift", "import");
@@ -45,10 +61,16 @@ pub fn generate(ast: &[ASTNode]) -> String {
         writer.writeln(&format!("import {}", import));
     }
 
-    writer.write(&stringify_ir(&ir));
+    writer.write(&stringify_ir_with_options(&ir, options));
     writer.show().to_string()
 }
 
+/// Signals are exposed as `AnyPublisher`, the only place Combine is used.
+fn contains_signal_nodes(ast: &[ASTNode]) -> bool {
+    ast.iter()
+        .any(|node| matches!(node, ASTNode::Fn(node) if node.is_signal))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,4 +86,33 @@ mod tests {
         let actual = generate(&ast);
         assert_eq!(actual.trim(), target.trim());
     }
+
+    #[test]
+    fn generate_with_custom_options() {
+        let src = fs::read_to_string("test_resources/struct_basic.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/swift/struct_basic_options.swift").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let options = GeneratorOptions {
+            indent: Some(2),
+            access_level: Some(String::from("public")),
+            imports: vec![String::from("Models")],
+            ..GeneratorOptions::default()
+        };
+        let actual = generate_with_options(&ast, &options);
+        assert_eq!(actual.trim(), target.trim());
+    }
+
+    #[test]
+    fn generate_combine_import_for_signals() {
+        let header = "#[id = \"test\"]\n#[namespace = \"test\"]\n";
+
+        let mut lexer = Lexer::tokenize(&format!("{}fn hello();", header));
+        let actual = generate(&parse(&mut lexer));
+        assert!(!actual.contains("import Combine"));
+
+        let mut lexer = Lexer::tokenize(&format!("{}signal counter -> i32;", header));
+        let actual = generate(&parse(&mut lexer));
+        assert!(actual.contains("import Combine"));
+    }
 }
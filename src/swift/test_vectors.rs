@@ -0,0 +1,212 @@
+use convert_case::{Case, Casing};
+use serde_json::Value;
+
+use crate::{
+    ast::{self, ASTNode, EnumItemASTNode, StructFieldASTNode, TypeIDASTNode},
+    codec::samples::TestVector,
+    writer::Writer,
+};
+
+/// Generates an XCTest case that reads every vector and checks that both
+/// the expected and the read models are written back to the same bytes.
+pub fn generate_test_vectors(ast: &[ASTNode], vectors: &[TestVector]) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln("// GENERATED, DO NOT EDIT");
+    writer.writeln("");
+    writer.writeln("import XCTest");
+    writer.writeln("");
+    writer.writeln("final class TestVectorsTests: XCTestCase {");
+
+    for vector in vectors {
+        writer.writeln_tab(
+            1,
+            &format!("func test{}() {{", vector.name.to_case(Case::Pascal)),
+        );
+        writer.writeln_tab(
+            2,
+            &format!("let data: [UInt8] = [{}]", generate_bytes(&vector.data)),
+        );
+        writer.writeln_tab(
+            2,
+            &format!(
+                "let expected = {}",
+                generate_value(
+                    ast,
+                    &TypeIDASTNode::Other {
+                        id: vector.type_id.clone()
+                    },
+                    &vector.value
+                )
+            ),
+        );
+        writer.writeln_tab(
+            2,
+            &format!(
+                "let value = {}.readFromBuffers(TechPawsBuffersBytesReader(data))",
+                vector.type_id
+            ),
+        );
+        writer.new_line();
+        writer.writeln_tab(2, "XCTAssertEqual(writeVector(expected), data)");
+        writer.writeln_tab(2, "XCTAssertEqual(writeVector(value), data)");
+        writer.writeln_tab(1, "}");
+        writer.new_line();
+    }
+
+    writer.writeln_tab(
+        1,
+        "private func writeVector<T: TechPawsBuffersModel>(_ value: T) -> [UInt8] {",
+    );
+    writer.writeln_tab(2, "let bytesWriter = TechPawsBuffersBytesWriter()");
+    writer.writeln_tab(2, "value.writeToBuffers(bytesWriter)");
+    writer.writeln_tab(2, "return bytesWriter.bytes");
+    writer.writeln_tab(1, "}");
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+fn generate_bytes(data: &[u8]) -> String {
+    data.iter()
+        .map(|byte| format!("0x{:02X}", byte))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn generate_string(value: &str) -> String {
+    let mut literal = String::from("\"");
+
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            ' '..='~' => literal.push(c),
+            _ => literal.push_str(&format!("\\u{{{:X}}}", c as u32)),
+        }
+    }
+
+    literal.push('"');
+    literal
+}
+
+/// Swift expression of a value produced by the codec sampler.
+pub fn generate_value(ast: &[ASTNode], type_id: &TypeIDASTNode, value: &Value) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { .. } => value.to_string(),
+        TypeIDASTNode::Number { size: 4, .. } => format!("{:?}", value.as_f64().unwrap() as f32),
+        TypeIDASTNode::Number { .. } => format!("{:?}", value.as_f64().unwrap()),
+        TypeIDASTNode::Bool { .. } => value.to_string(),
+        TypeIDASTNode::Char { .. } => {
+            format!("Character({})", generate_string(value.as_str().unwrap()))
+        }
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => generate_string(value.as_str().unwrap()),
+            "GroupAddress" | "CommandsBufferAddress" => value.to_string(),
+            _ => {
+                if let Some(node) = ast::find_struct_node(ast, id) {
+                    format!(
+                        "{}({})",
+                        id,
+                        generate_fields(ast, &node.fields, value, true)
+                    )
+                } else if let Some(node) = ast::find_enum_node(ast, id) {
+                    generate_enum_value(ast, id, &node.items, value)
+                } else {
+                    panic!("Unknown type: {}", id)
+                }
+            }
+        },
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Vec" => format!(
+                "[{}]",
+                value
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|item| generate_value(ast, &generics[0], item))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            "Option" if value.is_null() => String::from("nil"),
+            "Option" => generate_value(ast, &generics[0], value),
+            _ => panic!("Unsupported type: {}", id),
+        },
+    }
+}
+
+fn generate_fields(
+    ast: &[ASTNode],
+    fields: &[StructFieldASTNode],
+    value: &Value,
+    labels: bool,
+) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            let field_value = generate_value(ast, &field.type_id, &value[&field.name]);
+
+            if labels {
+                format!("{}: {}", field.name.to_case(Case::Camel), field_value)
+            } else {
+                field_value
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn generate_enum_value(
+    ast: &[ASTNode],
+    id: &str,
+    items: &[EnumItemASTNode],
+    value: &Value,
+) -> String {
+    let (item_id, item_value) = match value {
+        Value::String(item_id) => (item_id.as_str(), &Value::Null),
+        Value::Object(values) => {
+            let (item_id, item_value) = values.iter().next().unwrap();
+            (item_id.as_str(), item_value)
+        }
+        _ => panic!("Invalid enum value: {}", value),
+    };
+    let case_id = format!("{}.{}", id, item_id.to_case(Case::Camel));
+
+    match items.iter().find(|item| item.id() == item_id).unwrap() {
+        EnumItemASTNode::Empty { .. } => case_id,
+        EnumItemASTNode::Tuple { values, .. } => format!(
+            "{}({})",
+            case_id,
+            values
+                .iter()
+                .zip(item_value.as_array().unwrap())
+                .map(|(tuple_value, item)| generate_value(ast, &tuple_value.type_id, item))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        EnumItemASTNode::Struct { fields, .. } => format!(
+            "{}({})",
+            case_id,
+            generate_fields(ast, fields, item_value, false)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{codec::samples, lexer::Lexer, parser::parse};
+    use std::fs;
+
+    #[test]
+    fn generate_test_vectors_test() {
+        let src = fs::read_to_string("test_resources/test_vectors.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/swift/test_vectors.swift").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let (vectors, _) = samples::generate_test_vectors(&ast, 0, 1);
+        let actual = generate_test_vectors(&ast, &vectors);
+        assert_eq!(actual.trim(), target.trim());
+    }
+}
@@ -0,0 +1,769 @@
+use convert_case::{Case, Casing};
+
+use crate::{
+    ast::{
+        self, ASTNode, ConstBlockASTNode, ConstItemASTNode, ConstValueASTNode, EnumASTNode,
+        EnumItemASTNode, FnASTNode, StructASTNode, StructFieldASTNode, TypeIDASTNode,
+    },
+    lexer::Literal,
+    writer::Writer,
+};
+
+pub fn generate_consts(ast: &[ASTNode]) -> String {
+    let mut blocks = vec![];
+
+    for node in ast {
+        if let ASTNode::Const(node) = node {
+            let mut writer = Writer::default();
+            writer.writeln(&format!(
+                "export const {} = {{",
+                node.id.to_case(Case::Pascal)
+            ));
+            writer.push_tab();
+            generate_const_block_items(&mut writer, node);
+            writer.pop_tab();
+            writer.writeln("} as const;");
+            blocks.push(writer.show().to_string());
+        }
+    }
+
+    blocks.join("\n")
+}
+
+fn generate_const_block_items(writer: &mut Writer, node: &ConstBlockASTNode) {
+    for item in &node.items {
+        match item {
+            ConstItemASTNode::Value { id, type_id, value } => {
+                writer.writeln(&format!(
+                    "{}: {},",
+                    id.to_case(Case::Camel),
+                    generate_const_value(value, type_id)
+                ));
+            }
+            ConstItemASTNode::ConstsBlock { node } => {
+                writer.writeln(&format!("{}: {{", node.id.to_case(Case::Pascal)));
+                writer.push_tab();
+                generate_const_block_items(writer, node);
+                writer.pop_tab();
+                writer.writeln("},");
+            }
+        }
+    }
+}
+
+pub fn generate_models(ast: &[ASTNode]) -> String {
+    let mut blocks = vec![];
+
+    for node in ast {
+        match node {
+            ASTNode::Struct(node) => blocks.push(generate_struct_model(node)),
+            ASTNode::Enum(node) => blocks.push(generate_enum_model(node)),
+            _ => (),
+        }
+    }
+
+    blocks.join("\n")
+}
+
+fn generate_struct_model(node: &StructASTNode) -> String {
+    let mut writer = Writer::default();
+
+    if node.fields.is_empty() {
+        writer.writeln(&format!("export interface {} {{}}", node.id));
+    } else {
+        writer.writeln(&format!("export interface {} {{", node.id));
+        writer.push_tab();
+
+        for field in &node.fields {
+            writer.writeln(&format!(
+                "{}: {};",
+                field.name.to_case(Case::Camel),
+                generate_type_id(&field.type_id)
+            ));
+        }
+
+        writer.pop_tab();
+        writer.writeln("}");
+    }
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "export function read{}(reader: BytesReader): {} {{",
+        node.id, node.id
+    ));
+    writer.push_tab();
+    generate_object(&mut writer, "return ", &node.fields, |field| {
+        generate_read(&field.type_id)
+    });
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "export function write{}(writer: BytesWriter, value: {}): void {{",
+        node.id, node.id
+    ));
+    writer.push_tab();
+
+    for field in &node.fields {
+        let accessor = format!("value.{}", field.name.to_case(Case::Camel));
+        writer.writeln(&format!("{};", generate_write(&field.type_id, &accessor)));
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    write_skip_function(&mut writer, &node.id);
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "export function default{}(): {} {{",
+        node.id, node.id
+    ));
+    writer.push_tab();
+    generate_object(&mut writer, "return ", &node.fields, |field| {
+        generate_default_value(&field.type_id)
+    });
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+fn generate_enum_model(node: &EnumASTNode) -> String {
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!("export type {} =", node.id));
+    writer.push_tab();
+
+    for (i, item) in node.items.iter().enumerate() {
+        let end = if i == node.items.len() - 1 { ";" } else { "" };
+        writer.writeln(&format!("| {}{}", generate_enum_item_type(item), end));
+    }
+
+    writer.pop_tab();
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "export function read{}(reader: BytesReader): {} {{",
+        node.id, node.id
+    ));
+    writer.push_tab();
+    writer.writeln("const kind = reader.readU32();");
+    writer.new_line();
+    writer.writeln("switch (kind) {");
+    writer.push_tab();
+
+    for item in &node.items {
+        writer.writeln(&format!("case {}:", item.position()));
+        writer.push_tab();
+        generate_enum_item_value(&mut writer, item, generate_read);
+        writer.pop_tab();
+    }
+
+    writer.writeln("default:");
+    writer.push_tab();
+    writer.writeln(&format!(
+        "throw new Error(`Invalid {} kind: ${{kind}}`);",
+        node.id
+    ));
+    writer.pop_tab();
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "export function write{}(writer: BytesWriter, value: {}): void {{",
+        node.id, node.id
+    ));
+    writer.push_tab();
+    writer.writeln("switch (value.kind) {");
+    writer.push_tab();
+
+    for item in &node.items {
+        writer.writeln(&format!("case \"{}\":", item.id()));
+        writer.push_tab();
+        writer.writeln(&format!("writer.writeU32({});", item.position()));
+
+        match item {
+            EnumItemASTNode::Empty { .. } => (),
+            EnumItemASTNode::Tuple { values, .. } => {
+                for (i, value) in values.iter().enumerate() {
+                    let accessor = format!("value.values[{}]", i);
+                    writer.writeln(&format!("{};", generate_write(&value.type_id, &accessor)));
+                }
+            }
+            EnumItemASTNode::Struct { fields, .. } => {
+                for field in fields {
+                    let accessor = format!("value.value.{}", field.name.to_case(Case::Camel));
+                    writer.writeln(&format!("{};", generate_write(&field.type_id, &accessor)));
+                }
+            }
+        }
+
+        writer.writeln("break;");
+        writer.pop_tab();
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    write_skip_function(&mut writer, &node.id);
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "export function default{}(): {} {{",
+        node.id, node.id
+    ));
+    writer.push_tab();
+    let item = node
+        .items
+        .first()
+        .expect("enum should have at least one item");
+    generate_enum_item_value(&mut writer, item, generate_default_value);
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+fn generate_enum_item_type(item: &EnumItemASTNode) -> String {
+    match item {
+        EnumItemASTNode::Empty { id, .. } => format!("{{ kind: \"{}\" }}", id),
+        EnumItemASTNode::Tuple { id, values, .. } => format!(
+            "{{ kind: \"{}\"; values: [{}] }}",
+            id,
+            values
+                .iter()
+                .map(|value| generate_type_id(&value.type_id))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        EnumItemASTNode::Struct { id, fields, .. } => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    format!(
+                        "{}: {}",
+                        field.name.to_case(Case::Camel),
+                        generate_type_id(&field.type_id)
+                    )
+                })
+                .collect::<Vec<String>>();
+
+            if fields.is_empty() {
+                format!("{{ kind: \"{}\"; value: {{}} }}", id)
+            } else {
+                format!("{{ kind: \"{}\"; value: {{ {} }} }}", id, fields.join("; "))
+            }
+        }
+    }
+}
+
+/// Writes `return { kind: ..., ... };` for the item, taking the values of
+/// the fields from `value`. Fields of struct items are nested in `value`
+/// like tuple values are in `values`, so they can't clash with `kind`.
+fn generate_enum_item_value(
+    writer: &mut Writer,
+    item: &EnumItemASTNode,
+    value: fn(&TypeIDASTNode) -> String,
+) {
+    let kind = format!("kind: \"{}\"", item.id());
+
+    match item {
+        EnumItemASTNode::Empty { .. } => writer.writeln(&format!("return {{ {} }};", kind)),
+        EnumItemASTNode::Tuple { values, .. } => {
+            writer.writeln("return {");
+            writer.push_tab();
+            writer.writeln(&format!("{},", kind));
+            writer.writeln("values: [");
+            writer.push_tab();
+
+            for item in values {
+                writer.writeln(&format!("{},", value(&item.type_id)));
+            }
+
+            writer.pop_tab();
+            writer.writeln("],");
+            writer.pop_tab();
+            writer.writeln("};");
+        }
+        EnumItemASTNode::Struct { fields, .. } => {
+            writer.writeln("return {");
+            writer.push_tab();
+            writer.writeln(&format!("{},", kind));
+
+            if fields.is_empty() {
+                writer.writeln("value: {},");
+            } else {
+                writer.writeln("value: {");
+                writer.push_tab();
+
+                for field in fields {
+                    writer.writeln(&format!(
+                        "{}: {},",
+                        field.name.to_case(Case::Camel),
+                        value(&field.type_id)
+                    ));
+                }
+
+                writer.pop_tab();
+                writer.writeln("},");
+            }
+
+            writer.pop_tab();
+            writer.writeln("};");
+        }
+    }
+}
+
+/// Writes an object literal with a property for every field, object
+/// properties are evaluated in order, so reads happen in the buffer order.
+fn generate_object(
+    writer: &mut Writer,
+    prefix: &str,
+    fields: &[StructFieldASTNode],
+    value: impl Fn(&StructFieldASTNode) -> String,
+) {
+    if fields.is_empty() {
+        writer.writeln(&format!("{}{{}};", prefix));
+        return;
+    }
+
+    writer.writeln(&format!("{}{{", prefix));
+    writer.push_tab();
+
+    for field in fields {
+        writer.writeln(&format!(
+            "{}: {},",
+            field.name.to_case(Case::Camel),
+            value(field)
+        ));
+    }
+
+    writer.pop_tab();
+    writer.writeln("};");
+}
+
+fn write_skip_function(writer: &mut Writer, id: &str) {
+    writer.writeln(&format!(
+        "export function skip{}(reader: BytesReader, count: number): void {{",
+        id
+    ));
+    writer.push_tab();
+    writer.writeln("for (let i = 0; i < count; i++) {");
+    writer.push_tab();
+    writer.writeln(&format!("read{}(reader);", id));
+    writer.pop_tab();
+    writer.writeln("}");
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+pub fn generate_rpc(ast: &[ASTNode]) -> String {
+    let namespace = ast::find_directive_value(ast, "namespace").expect("namespace is required");
+    let namespace = match namespace {
+        ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("namespace should be a string literal"),
+        },
+    };
+
+    let scope_id = ast::find_directive_value(ast, "id").expect("id is required");
+    let scope_id = match scope_id {
+        ConstValueASTNode::Literal {
+            literal,
+            type_id: _,
+        } => match literal {
+            Literal::StringLiteral(value) => value,
+            _ => panic!("id should be a string literal"),
+        },
+    };
+
+    let class_id = format!("{}Rpc", namespace.to_case(Case::Pascal));
+    let mut writer = Writer::default();
+
+    writer.writeln(&format!("export class {} {{", class_id));
+    writer.push_tab();
+    writer.writeln(&format!("static readonly scopeId = \"{}\";", scope_id));
+    writer.new_line();
+    writer.writeln("constructor(private readonly runtime: RpcRuntime) {}");
+
+    for node in ast::find_fn_nodes(ast) {
+        writer.new_line();
+
+        if node.is_signal {
+            generate_signal_methods(&mut writer, &class_id, node);
+        } else {
+            generate_call_method(&mut writer, &class_id, node);
+        }
+    }
+
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.show().to_string()
+}
+
+fn generate_call_method(writer: &mut Writer, class_id: &str, node: &FnASTNode) {
+    let args = node
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                arg.id.to_case(Case::Camel),
+                generate_type_id(&arg.type_id)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let return_type_id = node
+        .return_type_id
+        .as_ref()
+        .map_or(String::from("void"), generate_type_id);
+
+    let (return_type_id, call) = if node.is_async {
+        (format!("Promise<{}>", return_type_id), "callAsync")
+    } else {
+        (return_type_id, "call")
+    };
+
+    let statement = if node.return_type_id.is_some() || node.is_async {
+        "return "
+    } else {
+        ""
+    };
+
+    writer.writeln(&format!(
+        "{}({}): {} {{",
+        node.id.to_case(Case::Camel),
+        args,
+        return_type_id
+    ));
+    writer.push_tab();
+    writer.writeln(&format!("{}this.runtime.{}(", statement, call));
+    writer.push_tab();
+    writer.writeln(&format!("{}.scopeId,", class_id));
+    writer.writeln(&format!("{},", node.position));
+
+    if node.args.is_empty() {
+        writer.writeln("null,");
+    } else {
+        writer.writeln("(writer) => {");
+        writer.push_tab();
+
+        for arg in &node.args {
+            let accessor = arg.id.to_case(Case::Camel);
+            writer.writeln(&format!("{};", generate_write(&arg.type_id, &accessor)));
+        }
+
+        writer.pop_tab();
+        writer.writeln("},");
+    }
+
+    match &node.return_type_id {
+        Some(type_id) => writer.writeln(&format!("(reader) => {},", generate_read(type_id))),
+        None => writer.writeln("null,"),
+    }
+
+    writer.pop_tab();
+    writer.writeln(");");
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+fn generate_signal_methods(writer: &mut Writer, class_id: &str, node: &FnASTNode) {
+    let (type_id, listener, read) = match &node.return_type_id {
+        Some(type_id) => {
+            let type_id = generate_type_id(type_id);
+            let listener = format!("(value: {}) => void", type_id);
+            let read = format!(
+                "(reader) => {}",
+                generate_read(node.return_type_id.as_ref().unwrap())
+            );
+            (type_id, listener, read)
+        }
+        None => (
+            String::from("void"),
+            String::from("() => void"),
+            String::from("() => undefined"),
+        ),
+    };
+
+    writer.writeln(&format!(
+        "on{}(listener: {}): () => void {{",
+        node.id.to_case(Case::Pascal),
+        listener
+    ));
+    writer.push_tab();
+    writer.writeln("return this.runtime.subscribe(");
+    writer.push_tab();
+    writer.writeln(&format!("{}.scopeId,", class_id));
+    writer.writeln(&format!("{},", node.position));
+    writer.writeln(&format!("{},", read));
+    writer.writeln("listener,");
+    writer.pop_tab();
+    writer.writeln(");");
+    writer.pop_tab();
+    writer.writeln("}");
+
+    writer.new_line();
+    writer.writeln(&format!(
+        "{}(): AsyncIterable<{}> {{",
+        node.id.to_case(Case::Camel),
+        type_id
+    ));
+    writer.push_tab();
+    writer.writeln("return this.runtime.stream(");
+    writer.push_tab();
+    writer.writeln(&format!("{}.scopeId,", class_id));
+    writer.writeln(&format!("{},", node.position));
+    writer.writeln(&format!("{},", read));
+    writer.pop_tab();
+    writer.writeln(");");
+    writer.pop_tab();
+    writer.writeln("}");
+}
+
+fn is_address(id: &str) -> bool {
+    id == "GroupAddress" || id == "CommandsBufferAddress"
+}
+
+pub fn generate_type_id(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { size: 8, .. } => String::from("bigint"),
+        TypeIDASTNode::Integer { .. } => String::from("number"),
+        TypeIDASTNode::Number { .. } => String::from("number"),
+        TypeIDASTNode::Bool { .. } => String::from("boolean"),
+        TypeIDASTNode::Char { .. } => String::from("string"),
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => String::from("string"),
+            id if is_address(id) => String::from("bigint"),
+            _ => id.clone(),
+        },
+        TypeIDASTNode::Generic { id, generics } => match id.as_str() {
+            "Option" => format!(
+                "{} | null",
+                generate_type_id(generics.first().expect("Optional type cannot be empty"))
+            ),
+            "Vec" => {
+                let item = generics.first().expect("Vec type cannot be empty");
+
+                match item {
+                    TypeIDASTNode::Generic { id, .. } if id == "Option" => {
+                        format!("({})[]", generate_type_id(item))
+                    }
+                    _ => format!("{}[]", generate_type_id(item)),
+                }
+            }
+            _ => format!(
+                "{}<{}>",
+                id,
+                generics
+                    .iter()
+                    .map(generate_type_id)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        },
+    }
+}
+
+fn generate_primitive_id(type_id: &TypeIDASTNode) -> Option<String> {
+    match type_id {
+        TypeIDASTNode::Integer { size, signed, .. } => {
+            Some(format!("{}{}", if *signed { "I" } else { "U" }, size * 8))
+        }
+        TypeIDASTNode::Number { size, .. } => Some(format!("F{}", size * 8)),
+        TypeIDASTNode::Bool { .. } => Some(String::from("Bool")),
+        TypeIDASTNode::Char { .. } => Some(String::from("Char")),
+        TypeIDASTNode::Other { id } if id == "String" => Some(String::from("String")),
+        TypeIDASTNode::Other { id } if is_address(id) => Some(String::from("U64")),
+        _ => None,
+    }
+}
+
+pub fn generate_read(type_id: &TypeIDASTNode) -> String {
+    if let Some(id) = generate_primitive_id(type_id) {
+        return format!("reader.read{}()", id);
+    }
+
+    match type_id {
+        TypeIDASTNode::Generic { id, generics } if id == "Vec" => format!(
+            "reader.readVec(() => {})",
+            generate_read(generics.first().expect("Vec type cannot be empty"))
+        ),
+        TypeIDASTNode::Generic { id, generics } if id == "Option" => format!(
+            "reader.readOption(() => {})",
+            generate_read(generics.first().expect("Optional type cannot be empty"))
+        ),
+        TypeIDASTNode::Generic { id, .. } | TypeIDASTNode::Other { id } => {
+            format!("read{}(reader)", id)
+        }
+        _ => unreachable!(),
+    }
+}
+
+pub fn generate_write(type_id: &TypeIDASTNode, accessor: &str) -> String {
+    if let Some(id) = generate_primitive_id(type_id) {
+        return format!("writer.write{}({})", id, accessor);
+    }
+
+    match type_id {
+        TypeIDASTNode::Generic { id, generics } if id == "Vec" => format!(
+            "writer.writeVec({}, (item) => {})",
+            accessor,
+            generate_write(generics.first().expect("Vec type cannot be empty"), "item")
+        ),
+        TypeIDASTNode::Generic { id, generics } if id == "Option" => format!(
+            "writer.writeOption({}, (value) => {})",
+            accessor,
+            generate_write(
+                generics.first().expect("Optional type cannot be empty"),
+                "value"
+            )
+        ),
+        TypeIDASTNode::Generic { id, .. } | TypeIDASTNode::Other { id } => {
+            format!("write{}(writer, {})", id, accessor)
+        }
+        _ => unreachable!(),
+    }
+}
+
+pub fn generate_default_value(type_id: &TypeIDASTNode) -> String {
+    match type_id {
+        TypeIDASTNode::Integer { size: 8, .. } => String::from("0n"),
+        TypeIDASTNode::Integer { .. } => String::from("0"),
+        TypeIDASTNode::Number { .. } => String::from("0"),
+        TypeIDASTNode::Bool { .. } => String::from("false"),
+        TypeIDASTNode::Char { .. } => String::from("\"\\0\""),
+        TypeIDASTNode::Other { id } => match id.as_str() {
+            "String" => String::from("\"\""),
+            id if is_address(id) => String::from("0n"),
+            _ => format!("default{}()", id),
+        },
+        TypeIDASTNode::Generic { id, .. } => match id.as_str() {
+            "Option" => String::from("null"),
+            "Vec" => String::from("[]"),
+            _ => format!("default{}()", id),
+        },
+    }
+}
+
+pub fn generate_const_value(node: &ConstValueASTNode, type_id: &TypeIDASTNode) -> String {
+    match node {
+        ConstValueASTNode::Literal { literal, .. } => match literal {
+            Literal::StringLiteral(value) => format!("\"{}\"", value),
+            Literal::IntLiteral(value) => match generate_type_id(type_id).as_str() {
+                "bigint" => format!("{}n", value),
+                "number" => format!("{}", value),
+                _ => panic!(
+                    "Integer literal cannot have non integer type: {:?}",
+                    type_id
+                ),
+            },
+            Literal::NumberLiteral(value) => format!("{}", value),
+            Literal::BoolLiteral(value) => format!("{}", value),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    fn parse_resource(name: &str) -> Vec<ASTNode> {
+        let src = fs::read_to_string(format!("test_resources/{}.tpb", name)).unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        parse(&mut lexer)
+    }
+
+    fn read_target(name: &str) -> String {
+        fs::read_to_string(format!("test_resources/typescript/{}.ts", name)).unwrap()
+    }
+
+    #[test]
+    fn generate_consts_test() {
+        let ast = parse_resource("consts");
+        let actual = generate_consts(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("consts").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_basic() {
+        let ast = parse_resource("struct_basic");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_basic").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_types() {
+        let ast = parse_resource("struct_types");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_types").trim());
+    }
+
+    #[test]
+    fn generate_struct_model_test_empty() {
+        let ast = parse_resource("struct_empty");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("struct_empty").trim());
+    }
+
+    #[test]
+    fn generate_enum_model_extended_test() {
+        let ast = parse_resource("enum_extended");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("enum_extended").trim());
+    }
+
+    #[test]
+    fn generate_enum_model_kind_field_test() {
+        let ast = parse_resource("enum_kind_field");
+        let actual = generate_models(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("enum_kind_field").trim());
+    }
+
+    #[test]
+    fn generate_rpc_sync_methods_test() {
+        let ast = parse_resource("rpc_sync_methods");
+        let actual = generate_rpc(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_sync_methods").trim());
+    }
+
+    #[test]
+    fn generate_rpc_async_methods_test() {
+        let ast = parse_resource("rpc_async_methods");
+        let actual = generate_rpc(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_async_methods").trim());
+    }
+
+    #[test]
+    fn generate_rpc_stream_methods_test() {
+        let ast = parse_resource("rpc_stream_methods");
+        let actual = generate_rpc(&ast);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), read_target("rpc_stream_methods").trim());
+    }
+}
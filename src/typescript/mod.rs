@@ -0,0 +1,85 @@
+use crate::{
+    ast::{self, ASTNode},
+    options::GeneratorOptions,
+    writer::reindent,
+};
+
+use self::generator::{generate_consts, generate_models, generate_rpc};
+
+pub mod generator;
+
+/// `BytesReader`, `BytesWriter` over a `DataView` and the `RpcRuntime`
+/// interface the host implements, the module has no dependencies.
+const PRIMITIVES: &str = include_str!("primitives.ts");
+
+pub fn generate(ast: &[ASTNode]) -> String {
+    generate_with_options(ast, &GeneratorOptions::default())
+}
+
+pub fn generate_with_options(ast: &[ASTNode], options: &GeneratorOptions) -> String {
+    let mut writer = options.writer();
+
+    if !options.header().is_empty() {
+        writer.writeln(options.header());
+        writer.new_line();
+    }
+
+    for import in options.imports.iter() {
+        writer.writeln(import);
+    }
+
+    if ast::contains_buffers_nodes(ast) || ast::contains_fn_nodes(ast) {
+        if !options.imports.is_empty() {
+            writer.new_line();
+        }
+
+        writer.write(PRIMITIVES);
+    }
+
+    let mut sections = vec![generate_consts(ast), generate_models(ast)];
+
+    if ast::contains_fn_nodes(ast) {
+        sections.push(generate_rpc(ast));
+    }
+
+    for section in sections.iter().filter(|section| !section.is_empty()) {
+        writer.new_line();
+        writer.write(section);
+    }
+
+    reindent(writer.show(), options.indent())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::Lexer, parser::parse};
+    use std::fs;
+
+    #[test]
+    fn generate_empty_file() {
+        let src = fs::read_to_string("test_resources/empty.tpb").unwrap();
+        let target = fs::read_to_string("test_resources/typescript/empty.ts").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let actual = generate(&ast);
+        assert_eq!(actual.trim(), target.trim());
+    }
+
+    #[test]
+    fn generate_with_custom_options() {
+        let src = fs::read_to_string("test_resources/struct_basic.tpb").unwrap();
+        let target =
+            fs::read_to_string("test_resources/typescript/struct_basic_options.ts").unwrap();
+        let mut lexer = Lexer::tokenize(&src);
+        let ast = parse(&mut lexer);
+        let options = GeneratorOptions {
+            indent: Some(2),
+            imports: vec![String::from("import { Vec2 } from \"./math\";")],
+            ..GeneratorOptions::default()
+        };
+        let actual = generate_with_options(&ast, &options);
+        println!("{}", actual);
+        assert_eq!(actual.trim(), target.trim());
+    }
+}
@@ -0,0 +1,212 @@
+const textEncoder = new TextEncoder();
+const textDecoder = new TextDecoder("utf-8", { fatal: true });
+
+/** Little-endian cursor over a `DataView`, e.g. of the wasm memory. */
+export class BytesReader {
+    constructor(
+        private readonly view: DataView,
+        public offset: number = 0,
+    ) {}
+
+    static fromBytes(bytes: Uint8Array): BytesReader {
+        return new BytesReader(new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength));
+    }
+
+    private advance(size: number): number {
+        const offset = this.offset;
+
+        if (offset + size > this.view.byteLength) {
+            throw new RangeError(`reading ${size} bytes at ${offset} is out of bounds`);
+        }
+
+        this.offset += size;
+        return offset;
+    }
+
+    readI8(): number {
+        return this.view.getInt8(this.advance(1));
+    }
+
+    readI32(): number {
+        return this.view.getInt32(this.advance(4), true);
+    }
+
+    readI64(): bigint {
+        return this.view.getBigInt64(this.advance(8), true);
+    }
+
+    readU8(): number {
+        return this.view.getUint8(this.advance(1));
+    }
+
+    readU32(): number {
+        return this.view.getUint32(this.advance(4), true);
+    }
+
+    readU64(): bigint {
+        return this.view.getBigUint64(this.advance(8), true);
+    }
+
+    readF32(): number {
+        return this.view.getFloat32(this.advance(4), true);
+    }
+
+    readF64(): number {
+        return this.view.getFloat64(this.advance(8), true);
+    }
+
+    readBool(): boolean {
+        return this.readU8() !== 0;
+    }
+
+    readChar(): string {
+        return String.fromCodePoint(this.readU32());
+    }
+
+    readString(): string {
+        const length = Number(this.readU64());
+        const offset = this.advance(length);
+        const bytes = new Uint8Array(this.view.buffer, this.view.byteOffset + offset, length);
+        return textDecoder.decode(bytes);
+    }
+
+    readVec<T>(read: () => T): T[] {
+        const length = Number(this.readU64());
+        const items: T[] = [];
+
+        for (let i = 0; i < length; i++) {
+            items.push(read());
+        }
+
+        return items;
+    }
+
+    readOption<T>(read: () => T): T | null {
+        return this.readU8() !== 0 ? read() : null;
+    }
+}
+
+/** Little-endian writer into a growing buffer, `bytes()` is the result. */
+export class BytesWriter {
+    private buffer = new ArrayBuffer(64);
+    private view = new DataView(this.buffer);
+    private length = 0;
+
+    bytes(): Uint8Array {
+        return new Uint8Array(this.buffer, 0, this.length);
+    }
+
+    private advance(size: number): number {
+        if (this.length + size > this.buffer.byteLength) {
+            let capacity = this.buffer.byteLength * 2;
+
+            while (capacity < this.length + size) {
+                capacity *= 2;
+            }
+
+            const buffer = new ArrayBuffer(capacity);
+            new Uint8Array(buffer).set(new Uint8Array(this.buffer, 0, this.length));
+            this.buffer = buffer;
+            this.view = new DataView(buffer);
+        }
+
+        const offset = this.length;
+        this.length += size;
+        return offset;
+    }
+
+    writeI8(value: number): void {
+        const offset = this.advance(1);
+        this.view.setInt8(offset, value);
+    }
+
+    writeI32(value: number): void {
+        const offset = this.advance(4);
+        this.view.setInt32(offset, value, true);
+    }
+
+    writeI64(value: bigint): void {
+        const offset = this.advance(8);
+        this.view.setBigInt64(offset, value, true);
+    }
+
+    writeU8(value: number): void {
+        const offset = this.advance(1);
+        this.view.setUint8(offset, value);
+    }
+
+    writeU32(value: number): void {
+        const offset = this.advance(4);
+        this.view.setUint32(offset, value, true);
+    }
+
+    writeU64(value: bigint): void {
+        const offset = this.advance(8);
+        this.view.setBigUint64(offset, value, true);
+    }
+
+    writeF32(value: number): void {
+        const offset = this.advance(4);
+        this.view.setFloat32(offset, value, true);
+    }
+
+    writeF64(value: number): void {
+        const offset = this.advance(8);
+        this.view.setFloat64(offset, value, true);
+    }
+
+    writeBool(value: boolean): void {
+        this.writeU8(value ? 1 : 0);
+    }
+
+    writeChar(value: string): void {
+        this.writeU32(value.codePointAt(0) ?? 0);
+    }
+
+    writeString(value: string): void {
+        const bytes = textEncoder.encode(value);
+        this.writeU64(BigInt(bytes.length));
+        const offset = this.advance(bytes.length);
+        new Uint8Array(this.buffer, offset, bytes.length).set(bytes);
+    }
+
+    writeVec<T>(items: T[], write: (item: T) => void): void {
+        this.writeU64(BigInt(items.length));
+
+        for (const item of items) {
+            write(item);
+        }
+    }
+
+    writeOption<T>(value: T | null, write: (value: T) => void): void {
+        if (value === null) {
+            this.writeU8(0);
+        } else {
+            this.writeU8(1);
+            write(value);
+        }
+    }
+}
+
+/** Calls into the Rust core, implemented by the host that loads the wasm module. */
+export interface RpcRuntime {
+    call<T = void>(
+        scopeId: string,
+        methodId: number,
+        writeArgs: ((writer: BytesWriter) => void) | null,
+        readResult: ((reader: BytesReader) => T) | null,
+    ): T;
+    callAsync<T = void>(
+        scopeId: string,
+        methodId: number,
+        writeArgs: ((writer: BytesWriter) => void) | null,
+        readResult: ((reader: BytesReader) => T) | null,
+    ): Promise<T>;
+    subscribe<T>(
+        scopeId: string,
+        methodId: number,
+        read: (reader: BytesReader) => T,
+        listener: (value: T) => void,
+    ): () => void;
+    stream<T>(scopeId: string, methodId: number, read: (reader: BytesReader) => T): AsyncIterable<T>;
+}
@@ -0,0 +1,174 @@
+//! Checks of a parsed schema that the parser itself doesn't enforce.
+
+use std::collections::BTreeMap;
+
+use crate::ast::{ASTNode, EnumItemASTNode, StructFieldASTNode};
+
+/// Top level definitions sharing a namespace can't have the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Namespace {
+    Type,
+    Method,
+    Const,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub namespace: Namespace,
+    /// Name of the top level definition the error belongs to.
+    pub id: String,
+    /// Which of the definitions named `id` in the namespace is meant,
+    /// duplicates are reported on the later ones.
+    pub occurrence: usize,
+    pub message: String,
+}
+
+/// Duplicate definitions, duplicate members and positions used twice.
+pub fn validate(ast: &[ASTNode]) -> Vec<ValidationError> {
+    let mut counts = BTreeMap::new();
+    let definitions = ast
+        .iter()
+        .filter_map(|node| {
+            let (namespace, id) = match node {
+                ASTNode::Struct(node) => (Namespace::Type, &node.id),
+                ASTNode::Enum(node) => (Namespace::Type, &node.id),
+                ASTNode::Fn(node) => (Namespace::Method, &node.id),
+                ASTNode::Const(node) => (Namespace::Const, &node.id),
+                _ => return None,
+            };
+
+            let count = counts.entry((namespace, id)).or_insert(0);
+            let error = ValidationError {
+                namespace,
+                id: id.clone(),
+                occurrence: *count,
+                message: String::new(),
+            };
+
+            *count += 1;
+            Some((node, error))
+        })
+        .collect::<Vec<_>>();
+
+    let mut errors = vec![];
+
+    for (_, error) in &definitions {
+        if error.occurrence > 0 {
+            errors.push(ValidationError {
+                message: format!("`{}` is defined more than once", error.id),
+                ..error.clone()
+            });
+        }
+    }
+
+    for (node, error) in &definitions {
+        let members = match node {
+            ASTNode::Struct(node) => fields_members(&node.fields),
+            ASTNode::Enum(node) => enum_members(&node.items),
+            _ => continue,
+        };
+
+        let mut names = BTreeMap::new();
+        let mut positions = BTreeMap::new();
+
+        for (name, position) in members {
+            if names.insert(name, ()).is_some() {
+                errors.push(ValidationError {
+                    message: format!("`{}` is defined more than once in `{}`", name, error.id),
+                    ..error.clone()
+                });
+            }
+
+            if let Some(other) = positions.insert(position, name) {
+                errors.push(ValidationError {
+                    message: format!(
+                        "position {} is used by both `{}` and `{}` in `{}`",
+                        position, other, name, error.id
+                    ),
+                    ..error.clone()
+                });
+            }
+        }
+    }
+
+    let mut positions = BTreeMap::new();
+
+    for (node, error) in &definitions {
+        if let ASTNode::Fn(node) = node {
+            if let Some(other) = positions.insert(node.position, &node.id) {
+                errors.push(ValidationError {
+                    message: format!(
+                        "position {} is used by both `{}` and `{}`",
+                        node.position, other, node.id
+                    ),
+                    ..error.clone()
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+fn fields_members(fields: &[StructFieldASTNode]) -> Vec<(&str, u32)> {
+    fields
+        .iter()
+        .map(|field| (field.name.as_str(), field.position))
+        .collect()
+}
+
+fn enum_members(items: &[EnumItemASTNode]) -> Vec<(&str, u32)> {
+    items
+        .iter()
+        .map(|item| (item.id(), item.position()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_source;
+
+    fn messages(src: &str) -> Vec<String> {
+        validate(&parse_source(src).unwrap())
+            .into_iter()
+            .map(|error| error.message)
+            .collect()
+    }
+
+    #[test]
+    fn validate_test() {
+        assert!(messages("struct Point { x: f32, y: f32 }\nfn point() -> Point;").is_empty());
+
+        assert_eq!(
+            messages("struct Point { x: f32, x: f32 }\nenum Point { A, B }\nfn Point();"),
+            vec![
+                "`Point` is defined more than once",
+                "`x` is defined more than once in `Point`",
+            ]
+        );
+
+        let mut ast = parse_source("struct Point { x: f32, y: f32 }\nfn a();\nfn b();").unwrap();
+
+        for node in ast.iter_mut() {
+            match node {
+                ASTNode::Struct(node) => node.fields[1].position = node.fields[0].position,
+                ASTNode::Fn(node) => node.position = 0,
+                _ => (),
+            }
+        }
+
+        let messages = validate(&ast)
+            .into_iter()
+            .map(|error| error.message)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            messages,
+            vec![
+                "position 0 is used by both `x` and `y` in `Point`",
+                "position 0 is used by both `a` and `b`",
+            ]
+        );
+    }
+}
@@ -63,3 +63,24 @@ impl Default for Writer {
         Self::new(4)
     }
 }
+
+/// Changes the indentation of code written with the default 4 spaces per
+/// level to `indent` spaces, for generators composing code from strings of
+/// separate writers.
+pub fn reindent(src: &str, indent: usize) -> String {
+    if indent == 4 {
+        return src.to_string();
+    }
+
+    let mut res = String::with_capacity(src.len());
+
+    for line in src.split_inclusive('\n') {
+        let code = line.trim_start_matches(' ');
+        let spaces = line.len() - code.len();
+
+        res += &" ".repeat(spaces / 4 * indent + spaces % 4);
+        res += code;
+    }
+
+    res
+}
@@ -0,0 +1,18 @@
+#define COMMANDS_DRAW_LINES 131073ULL
+#define COMMANDS_DRAW_PATH 131074ULL
+#define COMMANDS_DRAW_QUADS 131075ULL
+#define COMMANDS_DRAW_CENTERED_QUADS 131076ULL
+#define COMMANDS_DRAW_TEXTS 131077ULL
+#define COMMANDS_SET_COLOR_PIPELINE 131078ULL
+#define COMMANDS_SET_TEXTURE_PIPELINE 131079ULL
+#define COMMANDS_DRAW_CIRCLES 131080ULL
+#define COMMANDS_DRAW_HOLLOW_CIRCLES 131081ULL
+#define ADDR_SOME_VALUE "Hello World!"
+#define ADDR_GROUPS_MAIN 0ULL
+#define ADDR_GROUPS_MAIN_RENDER 1ULL
+#define ADDR_GROUPS_RPC 2ULL
+#define ADDR_GROUPS_RPC_SYNC 3ULL
+#define ADDR_GROUPS_RPC_READ 4ULL
+#define ADDR_DELTA_TIME 16.6
+#define ADDR_FLAG true
+#define ADDR_COMMANDS_BUFFERS_WIN1_MAIN_RENDER 0ULL
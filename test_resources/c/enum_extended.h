@@ -0,0 +1,344 @@
+typedef enum MyEnumKind {
+    MY_ENUM_IDLE = 1,
+    MY_ENUM_MOVE = 2,
+    MY_ENUM_UPDATE = 3,
+} MyEnumKind;
+
+typedef struct MyEnum {
+    MyEnumKind kind;
+    union {
+        struct {
+            double x;
+            double y;
+        } move;
+        struct {
+            double p1;
+            double p2;
+            tpb_bytes p4;
+        } update;
+    } value;
+} MyEnum;
+
+static inline bool my_enum_decode(const uint8_t* data, size_t len, size_t* cursor, MyEnum* out) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+    out->kind = (MyEnumKind)kind;
+
+    switch (out->kind) {
+    case MY_ENUM_IDLE:
+        return true;
+    case MY_ENUM_MOVE:
+        if (!tpb_read_f64(data, len, cursor, &out->value.move.x)) return false;
+        if (!tpb_read_f64(data, len, cursor, &out->value.move.y)) return false;
+        return true;
+    case MY_ENUM_UPDATE:
+        if (!tpb_read_f64(data, len, cursor, &out->value.update.p1)) return false;
+        if (!tpb_read_f64(data, len, cursor, &out->value.update.p2)) return false;
+        if (!tpb_read_bytes(data, len, cursor, &out->value.update.p4)) return false;
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool my_enum_encode(uint8_t* data, size_t len, size_t* cursor, const MyEnum* value) {
+    if (!tpb_write_u32(data, len, cursor, (uint32_t)value->kind)) return false;
+
+    switch (value->kind) {
+    case MY_ENUM_IDLE:
+        return true;
+    case MY_ENUM_MOVE:
+        if (!tpb_write_f64(data, len, cursor, value->value.move.x)) return false;
+        if (!tpb_write_f64(data, len, cursor, value->value.move.y)) return false;
+        return true;
+    case MY_ENUM_UPDATE:
+        if (!tpb_write_f64(data, len, cursor, value->value.update.p1)) return false;
+        if (!tpb_write_f64(data, len, cursor, value->value.update.p2)) return false;
+        if (!tpb_write_bytes(data, len, cursor, value->value.update.p4)) return false;
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool my_enum_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+
+    switch (kind) {
+    case 1:
+        return true;
+    case 2:
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        return true;
+    case 3:
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        if (!tpb_skip_bytes(data, len, cursor)) return false;
+        return true;
+    default:
+        return false;
+    }
+}
+
+typedef enum MyEnumWithoutPositionsKind {
+    MY_ENUM_WITHOUT_POSITIONS_OPTION_1 = 0,
+    MY_ENUM_WITHOUT_POSITIONS_OPTION_2 = 1,
+    MY_ENUM_WITHOUT_POSITIONS_OPTION_3 = 2,
+    MY_ENUM_WITHOUT_POSITIONS_OPTION_4 = 3,
+} MyEnumWithoutPositionsKind;
+
+typedef struct MyEnumWithoutPositions {
+    MyEnumWithoutPositionsKind kind;
+    union {
+        struct {
+            uint64_t p0;
+        } option_1;
+        struct {
+            tpb_bytes name;
+        } option_2;
+    } value;
+} MyEnumWithoutPositions;
+
+static inline bool my_enum_without_positions_decode(const uint8_t* data, size_t len, size_t* cursor, MyEnumWithoutPositions* out) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+    out->kind = (MyEnumWithoutPositionsKind)kind;
+
+    switch (out->kind) {
+    case MY_ENUM_WITHOUT_POSITIONS_OPTION_1:
+        if (!tpb_read_u64(data, len, cursor, &out->value.option_1.p0)) return false;
+        return true;
+    case MY_ENUM_WITHOUT_POSITIONS_OPTION_2:
+        if (!tpb_read_bytes(data, len, cursor, &out->value.option_2.name)) return false;
+        return true;
+    case MY_ENUM_WITHOUT_POSITIONS_OPTION_3:
+        return true;
+    case MY_ENUM_WITHOUT_POSITIONS_OPTION_4:
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool my_enum_without_positions_encode(uint8_t* data, size_t len, size_t* cursor, const MyEnumWithoutPositions* value) {
+    if (!tpb_write_u32(data, len, cursor, (uint32_t)value->kind)) return false;
+
+    switch (value->kind) {
+    case MY_ENUM_WITHOUT_POSITIONS_OPTION_1:
+        if (!tpb_write_u64(data, len, cursor, value->value.option_1.p0)) return false;
+        return true;
+    case MY_ENUM_WITHOUT_POSITIONS_OPTION_2:
+        if (!tpb_write_bytes(data, len, cursor, value->value.option_2.name)) return false;
+        return true;
+    case MY_ENUM_WITHOUT_POSITIONS_OPTION_3:
+        return true;
+    case MY_ENUM_WITHOUT_POSITIONS_OPTION_4:
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool my_enum_without_positions_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+
+    switch (kind) {
+    case 0:
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        return true;
+    case 1:
+        if (!tpb_skip_bytes(data, len, cursor)) return false;
+        return true;
+    case 2:
+        return true;
+    case 3:
+        return true;
+    default:
+        return false;
+    }
+}
+
+typedef enum MyEnumWithManyArgumentsKind {
+    MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_1 = 0,
+    MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_2 = 1,
+    MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_3 = 2,
+    MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_4 = 3,
+} MyEnumWithManyArgumentsKind;
+
+typedef struct MyEnumWithManyArguments {
+    MyEnumWithManyArgumentsKind kind;
+    union {
+        struct {
+            uint64_t p0;
+            uint64_t p1;
+            uint64_t p2;
+        } option_1;
+        struct {
+            tpb_bytes name;
+        } option_2;
+    } value;
+} MyEnumWithManyArguments;
+
+static inline bool my_enum_with_many_arguments_decode(const uint8_t* data, size_t len, size_t* cursor, MyEnumWithManyArguments* out) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+    out->kind = (MyEnumWithManyArgumentsKind)kind;
+
+    switch (out->kind) {
+    case MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_1:
+        if (!tpb_read_u64(data, len, cursor, &out->value.option_1.p0)) return false;
+        if (!tpb_read_u64(data, len, cursor, &out->value.option_1.p1)) return false;
+        if (!tpb_read_u64(data, len, cursor, &out->value.option_1.p2)) return false;
+        return true;
+    case MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_2:
+        if (!tpb_read_bytes(data, len, cursor, &out->value.option_2.name)) return false;
+        return true;
+    case MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_3:
+        return true;
+    case MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_4:
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool my_enum_with_many_arguments_encode(uint8_t* data, size_t len, size_t* cursor, const MyEnumWithManyArguments* value) {
+    if (!tpb_write_u32(data, len, cursor, (uint32_t)value->kind)) return false;
+
+    switch (value->kind) {
+    case MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_1:
+        if (!tpb_write_u64(data, len, cursor, value->value.option_1.p0)) return false;
+        if (!tpb_write_u64(data, len, cursor, value->value.option_1.p1)) return false;
+        if (!tpb_write_u64(data, len, cursor, value->value.option_1.p2)) return false;
+        return true;
+    case MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_2:
+        if (!tpb_write_bytes(data, len, cursor, value->value.option_2.name)) return false;
+        return true;
+    case MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_3:
+        return true;
+    case MY_ENUM_WITH_MANY_ARGUMENTS_OPTION_4:
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool my_enum_with_many_arguments_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+
+    switch (kind) {
+    case 0:
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        return true;
+    case 1:
+        if (!tpb_skip_bytes(data, len, cursor)) return false;
+        return true;
+    case 2:
+        return true;
+    case 3:
+        return true;
+    default:
+        return false;
+    }
+}
+
+typedef enum MyEnumWithNamedArgumentsKind {
+    MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_1 = 0,
+    MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_2 = 1,
+    MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_3 = 2,
+    MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_4 = 3,
+} MyEnumWithNamedArgumentsKind;
+
+typedef struct MyEnumWithNamedArguments {
+    MyEnumWithNamedArgumentsKind kind;
+    union {
+        struct {
+            tpb_bytes name;
+            float value;
+            tpb_bytes bytes;
+        } option_1;
+        struct {
+            uint64_t p0;
+            uint64_t p1;
+            uint64_t p2;
+        } option_2;
+    } value;
+} MyEnumWithNamedArguments;
+
+static inline bool my_enum_with_named_arguments_decode(const uint8_t* data, size_t len, size_t* cursor, MyEnumWithNamedArguments* out) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+    out->kind = (MyEnumWithNamedArgumentsKind)kind;
+
+    switch (out->kind) {
+    case MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_1:
+        if (!tpb_read_bytes(data, len, cursor, &out->value.option_1.name)) return false;
+        if (!tpb_read_f32(data, len, cursor, &out->value.option_1.value)) return false;
+        if (!tpb_read_bytes(data, len, cursor, &out->value.option_1.bytes)) return false;
+        return true;
+    case MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_2:
+        if (!tpb_read_u64(data, len, cursor, &out->value.option_2.p0)) return false;
+        if (!tpb_read_u64(data, len, cursor, &out->value.option_2.p1)) return false;
+        if (!tpb_read_u64(data, len, cursor, &out->value.option_2.p2)) return false;
+        return true;
+    case MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_3:
+        return true;
+    case MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_4:
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool my_enum_with_named_arguments_encode(uint8_t* data, size_t len, size_t* cursor, const MyEnumWithNamedArguments* value) {
+    if (!tpb_write_u32(data, len, cursor, (uint32_t)value->kind)) return false;
+
+    switch (value->kind) {
+    case MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_1:
+        if (!tpb_write_bytes(data, len, cursor, value->value.option_1.name)) return false;
+        if (!tpb_write_f32(data, len, cursor, value->value.option_1.value)) return false;
+        if (!tpb_write_bytes(data, len, cursor, value->value.option_1.bytes)) return false;
+        return true;
+    case MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_2:
+        if (!tpb_write_u64(data, len, cursor, value->value.option_2.p0)) return false;
+        if (!tpb_write_u64(data, len, cursor, value->value.option_2.p1)) return false;
+        if (!tpb_write_u64(data, len, cursor, value->value.option_2.p2)) return false;
+        return true;
+    case MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_3:
+        return true;
+    case MY_ENUM_WITH_NAMED_ARGUMENTS_OPTION_4:
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool my_enum_with_named_arguments_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+
+    switch (kind) {
+    case 0:
+        if (!tpb_skip_bytes(data, len, cursor)) return false;
+        if (!tpb_skip(data, len, cursor, 4)) return false;
+        if (!tpb_skip_bytes(data, len, cursor)) return false;
+        return true;
+    case 1:
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        if (!tpb_skip(data, len, cursor, 8)) return false;
+        return true;
+    case 2:
+        return true;
+    case 3:
+        return true;
+    default:
+        return false;
+    }
+}
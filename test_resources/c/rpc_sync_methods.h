@@ -0,0 +1,30 @@
+#define EXAMPLES_SYNC_SCOPE_ID "4de616f8-12c5-4d2c-8d48-9c5fb038991f"
+
+#define EXAMPLES_SYNC_PRINT_HELLO_WORLD_METHOD_ID 0
+#define EXAMPLES_SYNC_HELLO_WORLD_METHOD_ID 1
+#define EXAMPLES_SYNC_SAY_HELLO_METHOD_ID 2
+#define EXAMPLES_SYNC_SUM_METHOD_ID 3
+
+typedef struct ExamplesSyncSayHelloArgs {
+    tpb_bytes first_name;
+    tpb_bytes last_name;
+} ExamplesSyncSayHelloArgs;
+
+static inline bool examples_sync_say_hello_args_encode(uint8_t* data, size_t len, size_t* cursor, const ExamplesSyncSayHelloArgs* value) {
+    if (!tpb_write_bytes(data, len, cursor, value->first_name)) return false;
+    if (!tpb_write_bytes(data, len, cursor, value->last_name)) return false;
+    return true;
+}
+
+typedef struct ExamplesSyncSumArgs {
+    int32_t a;
+    float b;
+    double c;
+} ExamplesSyncSumArgs;
+
+static inline bool examples_sync_sum_args_encode(uint8_t* data, size_t len, size_t* cursor, const ExamplesSyncSumArgs* value) {
+    if (!tpb_write_i32(data, len, cursor, value->a)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->b)) return false;
+    if (!tpb_write_f64(data, len, cursor, value->c)) return false;
+    return true;
+}
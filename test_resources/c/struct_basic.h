@@ -0,0 +1,50 @@
+typedef struct ViewData {
+    float delta_time;
+    float view_width;
+    float view_height;
+    float touch_start_x;
+    float touch_start_y;
+    float last_touch_x;
+    float last_touch_y;
+    float touch_x;
+    float touch_y;
+} ViewData;
+
+static inline bool view_data_decode(const uint8_t* data, size_t len, size_t* cursor, ViewData* out) {
+    if (!tpb_read_f32(data, len, cursor, &out->delta_time)) return false;
+    if (!tpb_read_f32(data, len, cursor, &out->view_width)) return false;
+    if (!tpb_read_f32(data, len, cursor, &out->view_height)) return false;
+    if (!tpb_read_f32(data, len, cursor, &out->touch_start_x)) return false;
+    if (!tpb_read_f32(data, len, cursor, &out->touch_start_y)) return false;
+    if (!tpb_read_f32(data, len, cursor, &out->last_touch_x)) return false;
+    if (!tpb_read_f32(data, len, cursor, &out->last_touch_y)) return false;
+    if (!tpb_read_f32(data, len, cursor, &out->touch_x)) return false;
+    if (!tpb_read_f32(data, len, cursor, &out->touch_y)) return false;
+    return true;
+}
+
+static inline bool view_data_encode(uint8_t* data, size_t len, size_t* cursor, const ViewData* value) {
+    if (!tpb_write_f32(data, len, cursor, value->delta_time)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->view_width)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->view_height)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->touch_start_x)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->touch_start_y)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->last_touch_x)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->last_touch_y)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->touch_x)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->touch_y)) return false;
+    return true;
+}
+
+static inline bool view_data_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    return true;
+}
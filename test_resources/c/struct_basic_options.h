@@ -0,0 +1,221 @@
+// GENERATED, DO NOT EDIT
+
+#pragma once
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <string.h>
+#include "math.h"
+#include <assert.h>
+
+#ifndef TECH_PAWS_BUFFERS_PRIMITIVES
+#define TECH_PAWS_BUFFERS_PRIMITIVES
+
+/* Strings and byte vectors, points into the decoded buffer. */
+typedef struct tpb_bytes {
+  const uint8_t* data;
+  uint64_t len;
+} tpb_bytes;
+
+/* Other vectors, `len` items encoded in the `size` bytes at `data`. Items
+   are read one after another with the `_decode` function of the item. */
+typedef struct tpb_vec {
+  const uint8_t* data;
+  size_t size;
+  uint64_t len;
+} tpb_vec;
+
+typedef bool (*tpb_skip_fn)(const uint8_t* data, size_t len, size_t* cursor);
+
+static inline bool tpb_skip(const uint8_t* data, size_t len, size_t* cursor, size_t size) {
+  (void)data;
+  if (*cursor > len || len - *cursor < size) return false;
+  *cursor += size;
+  return true;
+}
+
+static inline bool tpb_read_le(const uint8_t* data, size_t len, size_t* cursor, size_t size, uint64_t* out) {
+  if (*cursor > len || len - *cursor < size) return false;
+  uint64_t value = 0;
+  for (size_t i = 0; i < size; i++) value |= (uint64_t)data[*cursor + i] << (8 * i);
+  *cursor += size;
+  *out = value;
+  return true;
+}
+
+static inline bool tpb_write_le(uint8_t* data, size_t len, size_t* cursor, size_t size, uint64_t value) {
+  if (*cursor > len || len - *cursor < size) return false;
+  for (size_t i = 0; i < size; i++) data[*cursor + i] = (uint8_t)(value >> (8 * i));
+  *cursor += size;
+  return true;
+}
+
+#define TPB_INTEGER(name, type, size)                                                               \
+  static inline bool tpb_read_##name(const uint8_t* data, size_t len, size_t* cursor, type* out) { \
+    uint64_t value;                                                                             \
+    if (!tpb_read_le(data, len, cursor, size, &value)) return false;                            \
+    *out = (type)value;                                                                         \
+    return true;                                                                                \
+  }                                                                                               \
+  static inline bool tpb_write_##name(uint8_t* data, size_t len, size_t* cursor, type value) {     \
+    return tpb_write_le(data, len, cursor, size, (uint64_t)value);                              \
+  }                                                                                               \
+  static inline bool tpb_skip_##name(const uint8_t* data, size_t len, size_t* cursor) {           \
+    return tpb_skip(data, len, cursor, size);                                                   \
+  }
+
+TPB_INTEGER(i8, int8_t, 1)
+TPB_INTEGER(i32, int32_t, 4)
+TPB_INTEGER(i64, int64_t, 8)
+TPB_INTEGER(u8, uint8_t, 1)
+TPB_INTEGER(u32, uint32_t, 4)
+TPB_INTEGER(u64, uint64_t, 8)
+
+#undef TPB_INTEGER
+
+static inline bool tpb_read_f32(const uint8_t* data, size_t len, size_t* cursor, float* out) {
+  uint32_t bits;
+  if (!tpb_read_u32(data, len, cursor, &bits)) return false;
+  memcpy(out, &bits, sizeof(bits));
+  return true;
+}
+
+static inline bool tpb_write_f32(uint8_t* data, size_t len, size_t* cursor, float value) {
+  uint32_t bits;
+  memcpy(&bits, &value, sizeof(bits));
+  return tpb_write_u32(data, len, cursor, bits);
+}
+
+static inline bool tpb_read_f64(const uint8_t* data, size_t len, size_t* cursor, double* out) {
+  uint64_t bits;
+  if (!tpb_read_u64(data, len, cursor, &bits)) return false;
+  memcpy(out, &bits, sizeof(bits));
+  return true;
+}
+
+static inline bool tpb_write_f64(uint8_t* data, size_t len, size_t* cursor, double value) {
+  uint64_t bits;
+  memcpy(&bits, &value, sizeof(bits));
+  return tpb_write_u64(data, len, cursor, bits);
+}
+
+static inline bool tpb_skip_f32(const uint8_t* data, size_t len, size_t* cursor) {
+  return tpb_skip(data, len, cursor, 4);
+}
+
+static inline bool tpb_skip_f64(const uint8_t* data, size_t len, size_t* cursor) {
+  return tpb_skip(data, len, cursor, 8);
+}
+
+static inline bool tpb_read_bool(const uint8_t* data, size_t len, size_t* cursor, bool* out) {
+  uint8_t value;
+  if (!tpb_read_u8(data, len, cursor, &value)) return false;
+  *out = value != 0;
+  return true;
+}
+
+static inline bool tpb_write_bool(uint8_t* data, size_t len, size_t* cursor, bool value) {
+  return tpb_write_u8(data, len, cursor, value ? 1 : 0);
+}
+
+static inline bool tpb_skip_bool(const uint8_t* data, size_t len, size_t* cursor) {
+  return tpb_skip(data, len, cursor, 1);
+}
+
+static inline bool tpb_read_bytes(const uint8_t* data, size_t len, size_t* cursor, tpb_bytes* out) {
+  uint64_t size;
+  if (!tpb_read_u64(data, len, cursor, &size)) return false;
+  if (size > len - *cursor) return false;
+  out->data = data + *cursor;
+  out->len = size;
+  *cursor += (size_t)size;
+  return true;
+}
+
+static inline bool tpb_write_bytes(uint8_t* data, size_t len, size_t* cursor, tpb_bytes value) {
+  if (!tpb_write_u64(data, len, cursor, value.len)) return false;
+  if (value.len > len - *cursor) return false;
+  if (value.len > 0) memcpy(data + *cursor, value.data, (size_t)value.len);
+  *cursor += (size_t)value.len;
+  return true;
+}
+
+static inline bool tpb_skip_bytes(const uint8_t* data, size_t len, size_t* cursor) {
+  tpb_bytes value;
+  return tpb_read_bytes(data, len, cursor, &value);
+}
+
+static inline bool tpb_read_vec(const uint8_t* data, size_t len, size_t* cursor, tpb_skip_fn skip_item, tpb_vec* out) {
+  uint64_t count;
+  if (!tpb_read_u64(data, len, cursor, &count)) return false;
+  size_t start = *cursor;
+  for (uint64_t i = 0; i < count; i++) {
+    if (!skip_item(data, len, cursor)) return false;
+  }
+  out->data = data + start;
+  out->size = *cursor - start;
+  out->len = count;
+  return true;
+}
+
+static inline bool tpb_write_vec(uint8_t* data, size_t len, size_t* cursor, tpb_vec value) {
+  if (!tpb_write_u64(data, len, cursor, value.len)) return false;
+  if (value.size > len - *cursor) return false;
+  if (value.size > 0) memcpy(data + *cursor, value.data, value.size);
+  *cursor += value.size;
+  return true;
+}
+
+#endif
+
+typedef struct ViewData {
+  float delta_time;
+  float view_width;
+  float view_height;
+  float touch_start_x;
+  float touch_start_y;
+  float last_touch_x;
+  float last_touch_y;
+  float touch_x;
+  float touch_y;
+} ViewData;
+
+static inline bool view_data_decode(const uint8_t* data, size_t len, size_t* cursor, ViewData* out) {
+  if (!tpb_read_f32(data, len, cursor, &out->delta_time)) return false;
+  if (!tpb_read_f32(data, len, cursor, &out->view_width)) return false;
+  if (!tpb_read_f32(data, len, cursor, &out->view_height)) return false;
+  if (!tpb_read_f32(data, len, cursor, &out->touch_start_x)) return false;
+  if (!tpb_read_f32(data, len, cursor, &out->touch_start_y)) return false;
+  if (!tpb_read_f32(data, len, cursor, &out->last_touch_x)) return false;
+  if (!tpb_read_f32(data, len, cursor, &out->last_touch_y)) return false;
+  if (!tpb_read_f32(data, len, cursor, &out->touch_x)) return false;
+  if (!tpb_read_f32(data, len, cursor, &out->touch_y)) return false;
+  return true;
+}
+
+static inline bool view_data_encode(uint8_t* data, size_t len, size_t* cursor, const ViewData* value) {
+  if (!tpb_write_f32(data, len, cursor, value->delta_time)) return false;
+  if (!tpb_write_f32(data, len, cursor, value->view_width)) return false;
+  if (!tpb_write_f32(data, len, cursor, value->view_height)) return false;
+  if (!tpb_write_f32(data, len, cursor, value->touch_start_x)) return false;
+  if (!tpb_write_f32(data, len, cursor, value->touch_start_y)) return false;
+  if (!tpb_write_f32(data, len, cursor, value->last_touch_x)) return false;
+  if (!tpb_write_f32(data, len, cursor, value->last_touch_y)) return false;
+  if (!tpb_write_f32(data, len, cursor, value->touch_x)) return false;
+  if (!tpb_write_f32(data, len, cursor, value->touch_y)) return false;
+  return true;
+}
+
+static inline bool view_data_skip(const uint8_t* data, size_t len, size_t* cursor) {
+  if (!tpb_skip(data, len, cursor, 4)) return false;
+  if (!tpb_skip(data, len, cursor, 4)) return false;
+  if (!tpb_skip(data, len, cursor, 4)) return false;
+  if (!tpb_skip(data, len, cursor, 4)) return false;
+  if (!tpb_skip(data, len, cursor, 4)) return false;
+  if (!tpb_skip(data, len, cursor, 4)) return false;
+  if (!tpb_skip(data, len, cursor, 4)) return false;
+  if (!tpb_skip(data, len, cursor, 4)) return false;
+  if (!tpb_skip(data, len, cursor, 4)) return false;
+  return true;
+}
@@ -0,0 +1,218 @@
+typedef struct Point {
+    float x;
+    float y;
+} Point;
+
+static inline bool point_decode(const uint8_t* data, size_t len, size_t* cursor, Point* out) {
+    if (!tpb_read_f32(data, len, cursor, &out->x)) return false;
+    if (!tpb_read_f32(data, len, cursor, &out->y)) return false;
+    return true;
+}
+
+static inline bool point_encode(uint8_t* data, size_t len, size_t* cursor, const Point* value) {
+    if (!tpb_write_f32(data, len, cursor, value->x)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->y)) return false;
+    return true;
+}
+
+static inline bool point_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    return true;
+}
+
+typedef enum ShapeKind {
+    SHAPE_DOT = 0,
+    SHAPE_POLYGON = 1,
+    SHAPE_EMPTY = 2,
+} ShapeKind;
+
+typedef struct Shape {
+    ShapeKind kind;
+    union {
+        struct {
+            Point p0;
+        } dot;
+        struct {
+            tpb_vec points;
+        } polygon;
+    } value;
+} Shape;
+
+static inline bool shape_decode(const uint8_t* data, size_t len, size_t* cursor, Shape* out) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+    out->kind = (ShapeKind)kind;
+
+    switch (out->kind) {
+    case SHAPE_DOT:
+        if (!point_decode(data, len, cursor, &out->value.dot.p0)) return false;
+        return true;
+    case SHAPE_POLYGON:
+        if (!tpb_read_vec(data, len, cursor, point_skip, &out->value.polygon.points)) return false;
+        return true;
+    case SHAPE_EMPTY:
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool shape_encode(uint8_t* data, size_t len, size_t* cursor, const Shape* value) {
+    if (!tpb_write_u32(data, len, cursor, (uint32_t)value->kind)) return false;
+
+    switch (value->kind) {
+    case SHAPE_DOT:
+        if (!point_encode(data, len, cursor, &value->value.dot.p0)) return false;
+        return true;
+    case SHAPE_POLYGON:
+        if (!tpb_write_vec(data, len, cursor, value->value.polygon.points)) return false;
+        return true;
+    case SHAPE_EMPTY:
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool shape_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+
+    switch (kind) {
+    case 0:
+        if (!point_skip(data, len, cursor)) return false;
+        return true;
+    case 1:
+        {
+            uint64_t count0;
+            if (!tpb_read_u64(data, len, cursor, &count0)) return false;
+            for (uint64_t i0 = 0; i0 < count0; i0++) {
+                if (!point_skip(data, len, cursor)) return false;
+            }
+        }
+        return true;
+    case 2:
+        return true;
+    default:
+        return false;
+    }
+}
+
+typedef struct Document {
+    uint64_t id;
+    int32_t revision;
+    double scale;
+    bool visible;
+    uint32_t separator;
+    tpb_bytes title;
+    tpb_bytes thumbnail;
+    tpb_vec tags;
+    tpb_vec weights;
+    tpb_vec shapes;
+    bool has_origin;
+    Point origin;
+    bool has_description;
+    tpb_bytes description;
+    bool has_selection;
+    tpb_vec selection;
+} Document;
+
+static inline bool document_decode(const uint8_t* data, size_t len, size_t* cursor, Document* out) {
+    if (!tpb_read_u64(data, len, cursor, &out->id)) return false;
+    if (!tpb_read_i32(data, len, cursor, &out->revision)) return false;
+    if (!tpb_read_f64(data, len, cursor, &out->scale)) return false;
+    if (!tpb_read_bool(data, len, cursor, &out->visible)) return false;
+    if (!tpb_read_u32(data, len, cursor, &out->separator)) return false;
+    if (!tpb_read_bytes(data, len, cursor, &out->title)) return false;
+    if (!tpb_read_bytes(data, len, cursor, &out->thumbnail)) return false;
+    if (!tpb_read_vec(data, len, cursor, tpb_skip_bytes, &out->tags)) return false;
+    if (!tpb_read_vec(data, len, cursor, tpb_skip_f32, &out->weights)) return false;
+    if (!tpb_read_vec(data, len, cursor, shape_skip, &out->shapes)) return false;
+    if (!tpb_read_bool(data, len, cursor, &out->has_origin)) return false;
+    if (out->has_origin && !point_decode(data, len, cursor, &out->origin)) return false;
+    if (!tpb_read_bool(data, len, cursor, &out->has_description)) return false;
+    if (out->has_description && !tpb_read_bytes(data, len, cursor, &out->description)) return false;
+    if (!tpb_read_bool(data, len, cursor, &out->has_selection)) return false;
+    if (out->has_selection && !tpb_read_vec(data, len, cursor, tpb_skip_u32, &out->selection)) return false;
+    return true;
+}
+
+static inline bool document_encode(uint8_t* data, size_t len, size_t* cursor, const Document* value) {
+    if (!tpb_write_u64(data, len, cursor, value->id)) return false;
+    if (!tpb_write_i32(data, len, cursor, value->revision)) return false;
+    if (!tpb_write_f64(data, len, cursor, value->scale)) return false;
+    if (!tpb_write_bool(data, len, cursor, value->visible)) return false;
+    if (!tpb_write_u32(data, len, cursor, value->separator)) return false;
+    if (!tpb_write_bytes(data, len, cursor, value->title)) return false;
+    if (!tpb_write_bytes(data, len, cursor, value->thumbnail)) return false;
+    if (!tpb_write_vec(data, len, cursor, value->tags)) return false;
+    if (!tpb_write_vec(data, len, cursor, value->weights)) return false;
+    if (!tpb_write_vec(data, len, cursor, value->shapes)) return false;
+    if (!tpb_write_bool(data, len, cursor, value->has_origin)) return false;
+    if (value->has_origin && !point_encode(data, len, cursor, &value->origin)) return false;
+    if (!tpb_write_bool(data, len, cursor, value->has_description)) return false;
+    if (value->has_description && !tpb_write_bytes(data, len, cursor, value->description)) return false;
+    if (!tpb_write_bool(data, len, cursor, value->has_selection)) return false;
+    if (value->has_selection && !tpb_write_vec(data, len, cursor, value->selection)) return false;
+    return true;
+}
+
+static inline bool document_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    if (!tpb_skip(data, len, cursor, 8)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 8)) return false;
+    if (!tpb_skip(data, len, cursor, 1)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip_bytes(data, len, cursor)) return false;
+    if (!tpb_skip_bytes(data, len, cursor)) return false;
+    {
+        uint64_t count0;
+        if (!tpb_read_u64(data, len, cursor, &count0)) return false;
+        for (uint64_t i0 = 0; i0 < count0; i0++) {
+            if (!tpb_skip_bytes(data, len, cursor)) return false;
+        }
+    }
+    {
+        uint64_t count0;
+        if (!tpb_read_u64(data, len, cursor, &count0)) return false;
+        for (uint64_t i0 = 0; i0 < count0; i0++) {
+            if (!tpb_skip(data, len, cursor, 4)) return false;
+        }
+    }
+    {
+        uint64_t count0;
+        if (!tpb_read_u64(data, len, cursor, &count0)) return false;
+        for (uint64_t i0 = 0; i0 < count0; i0++) {
+            if (!shape_skip(data, len, cursor)) return false;
+        }
+    }
+    {
+        bool flag0;
+        if (!tpb_read_bool(data, len, cursor, &flag0)) return false;
+        if (flag0) {
+            if (!point_skip(data, len, cursor)) return false;
+        }
+    }
+    {
+        bool flag0;
+        if (!tpb_read_bool(data, len, cursor, &flag0)) return false;
+        if (flag0) {
+            if (!tpb_skip_bytes(data, len, cursor)) return false;
+        }
+    }
+    {
+        bool flag0;
+        if (!tpb_read_bool(data, len, cursor, &flag0)) return false;
+        if (flag0) {
+            {
+                uint64_t count1;
+                if (!tpb_read_u64(data, len, cursor, &count1)) return false;
+                for (uint64_t i1 = 0; i1 < count1; i1++) {
+                    if (!tpb_skip(data, len, cursor, 4)) return false;
+                }
+            }
+        }
+    }
+    return true;
+}
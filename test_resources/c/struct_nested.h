@@ -0,0 +1,180 @@
+typedef struct Point {
+    float x;
+    float y;
+} Point;
+
+static inline bool point_decode(const uint8_t* data, size_t len, size_t* cursor, Point* out) {
+    if (!tpb_read_f32(data, len, cursor, &out->x)) return false;
+    if (!tpb_read_f32(data, len, cursor, &out->y)) return false;
+    return true;
+}
+
+static inline bool point_encode(uint8_t* data, size_t len, size_t* cursor, const Point* value) {
+    if (!tpb_write_f32(data, len, cursor, value->x)) return false;
+    if (!tpb_write_f32(data, len, cursor, value->y)) return false;
+    return true;
+}
+
+static inline bool point_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    if (!tpb_skip(data, len, cursor, 4)) return false;
+    return true;
+}
+
+typedef enum ColorKind {
+    COLOR_RGB = 0,
+    COLOR_NAMED = 1,
+    COLOR_DEFAULT = 2,
+} ColorKind;
+
+typedef struct Color {
+    ColorKind kind;
+    union {
+        struct {
+            uint8_t p0;
+            uint8_t p1;
+            uint8_t p2;
+        } rgb;
+        struct {
+            tpb_bytes name;
+        } named;
+    } value;
+} Color;
+
+static inline bool color_decode(const uint8_t* data, size_t len, size_t* cursor, Color* out) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+    out->kind = (ColorKind)kind;
+
+    switch (out->kind) {
+    case COLOR_RGB:
+        if (!tpb_read_u8(data, len, cursor, &out->value.rgb.p0)) return false;
+        if (!tpb_read_u8(data, len, cursor, &out->value.rgb.p1)) return false;
+        if (!tpb_read_u8(data, len, cursor, &out->value.rgb.p2)) return false;
+        return true;
+    case COLOR_NAMED:
+        if (!tpb_read_bytes(data, len, cursor, &out->value.named.name)) return false;
+        return true;
+    case COLOR_DEFAULT:
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool color_encode(uint8_t* data, size_t len, size_t* cursor, const Color* value) {
+    if (!tpb_write_u32(data, len, cursor, (uint32_t)value->kind)) return false;
+
+    switch (value->kind) {
+    case COLOR_RGB:
+        if (!tpb_write_u8(data, len, cursor, value->value.rgb.p0)) return false;
+        if (!tpb_write_u8(data, len, cursor, value->value.rgb.p1)) return false;
+        if (!tpb_write_u8(data, len, cursor, value->value.rgb.p2)) return false;
+        return true;
+    case COLOR_NAMED:
+        if (!tpb_write_bytes(data, len, cursor, value->value.named.name)) return false;
+        return true;
+    case COLOR_DEFAULT:
+        return true;
+    default:
+        return false;
+    }
+}
+
+static inline bool color_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    uint32_t kind;
+    if (!tpb_read_u32(data, len, cursor, &kind)) return false;
+
+    switch (kind) {
+    case 0:
+        if (!tpb_skip(data, len, cursor, 1)) return false;
+        if (!tpb_skip(data, len, cursor, 1)) return false;
+        if (!tpb_skip(data, len, cursor, 1)) return false;
+        return true;
+    case 1:
+        if (!tpb_skip_bytes(data, len, cursor)) return false;
+        return true;
+    case 2:
+        return true;
+    default:
+        return false;
+    }
+}
+
+typedef struct Line {
+    Point from;
+    Point to;
+    bool has_color;
+    Color color;
+    tpb_bytes label;
+} Line;
+
+static inline bool line_decode(const uint8_t* data, size_t len, size_t* cursor, Line* out) {
+    if (!point_decode(data, len, cursor, &out->from)) return false;
+    if (!point_decode(data, len, cursor, &out->to)) return false;
+    if (!tpb_read_bool(data, len, cursor, &out->has_color)) return false;
+    if (out->has_color && !color_decode(data, len, cursor, &out->color)) return false;
+    if (!tpb_read_bytes(data, len, cursor, &out->label)) return false;
+    return true;
+}
+
+static inline bool line_encode(uint8_t* data, size_t len, size_t* cursor, const Line* value) {
+    if (!point_encode(data, len, cursor, &value->from)) return false;
+    if (!point_encode(data, len, cursor, &value->to)) return false;
+    if (!tpb_write_bool(data, len, cursor, value->has_color)) return false;
+    if (value->has_color && !color_encode(data, len, cursor, &value->color)) return false;
+    if (!tpb_write_bytes(data, len, cursor, value->label)) return false;
+    return true;
+}
+
+static inline bool line_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    if (!point_skip(data, len, cursor)) return false;
+    if (!point_skip(data, len, cursor)) return false;
+    {
+        bool flag0;
+        if (!tpb_read_bool(data, len, cursor, &flag0)) return false;
+        if (flag0) {
+            if (!color_skip(data, len, cursor)) return false;
+        }
+    }
+    if (!tpb_skip_bytes(data, len, cursor)) return false;
+    return true;
+}
+
+typedef struct Path {
+    tpb_vec points;
+    bool has_closed;
+    bool closed;
+} Path;
+
+static inline bool path_decode(const uint8_t* data, size_t len, size_t* cursor, Path* out) {
+    if (!tpb_read_vec(data, len, cursor, point_skip, &out->points)) return false;
+    if (!tpb_read_bool(data, len, cursor, &out->has_closed)) return false;
+    if (out->has_closed && !tpb_read_bool(data, len, cursor, &out->closed)) return false;
+    return true;
+}
+
+static inline bool path_encode(uint8_t* data, size_t len, size_t* cursor, const Path* value) {
+    if (!tpb_write_vec(data, len, cursor, value->points)) return false;
+    if (!tpb_write_bool(data, len, cursor, value->has_closed)) return false;
+    if (value->has_closed && !tpb_write_bool(data, len, cursor, value->closed)) return false;
+    return true;
+}
+
+static inline bool path_skip(const uint8_t* data, size_t len, size_t* cursor) {
+    {
+        uint64_t count0;
+        if (!tpb_read_u64(data, len, cursor, &count0)) return false;
+        for (uint64_t i0 = 0; i0 < count0; i0++) {
+            if (!point_skip(data, len, cursor)) return false;
+        }
+    }
+    {
+        bool flag0;
+        if (!tpb_read_bool(data, len, cursor, &flag0)) return false;
+        if (flag0) {
+            if (!tpb_skip(data, len, cursor, 1)) return false;
+        }
+    }
+    return true;
+}
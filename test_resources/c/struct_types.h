@@ -0,0 +1 @@
+/* BasicTypesModel has no C representation */
@@ -0,0 +1,34 @@
+public static class Commands
+{
+    public const ulong DrawLines = 131073;
+    public const ulong DrawPath = 131074;
+    public const ulong DrawQuads = 131075;
+    public const ulong DrawCenteredQuads = 131076;
+    public const ulong DrawTexts = 131077;
+    public const ulong SetColorPipeline = 131078;
+    public const ulong SetTexturePipeline = 131079;
+    public const ulong DrawCircles = 131080;
+    public const ulong DrawHollowCircles = 131081;
+}
+
+public static class Addr
+{
+    public const string SomeValue = "Hello World!";
+
+    public static class Groups
+    {
+        public static readonly GroupAddress Main = new GroupAddress(0);
+        public static readonly GroupAddress MainRender = new GroupAddress(1);
+        public static readonly GroupAddress Rpc = new GroupAddress(2);
+        public static readonly GroupAddress RpcSync = new GroupAddress(3);
+        public static readonly GroupAddress RpcRead = new GroupAddress(4);
+    }
+
+    public const double DeltaTime = 16.6;
+    public const bool Flag = true;
+
+    public static class CommandsBuffers
+    {
+        public static readonly CommandsBufferAddress Win1MainRender = new CommandsBufferAddress(0);
+    }
+}
@@ -0,0 +1,314 @@
+public abstract record MyEnum
+{
+    private MyEnum() {}
+
+    public sealed record Idle() : MyEnum;
+    public sealed record Move(
+        double X,
+        double Y
+    ) : MyEnum;
+    public sealed record Update(
+        double P1,
+        double P2,
+        string P4
+    ) : MyEnum;
+
+    public static MyEnum CreateBuffersDefault()
+    {
+        return new Idle();
+    }
+
+    public static MyEnum ReadFromBuffers(BinaryReader reader)
+    {
+        var kind = reader.ReadUInt32();
+
+        switch (kind)
+        {
+            case 1:
+                return new Idle();
+            case 2:
+                return new Move(
+                    reader.ReadDouble(),
+                    reader.ReadDouble()
+                );
+            case 3:
+                return new Update(
+                    reader.ReadDouble(),
+                    reader.ReadDouble(),
+                    reader.ReadBuffersString()
+                );
+            default:
+                throw new InvalidDataException($"Invalid MyEnum kind: {kind}");
+        }
+    }
+
+    public static void SkipInBuffers(BinaryReader reader, ulong count)
+    {
+        for (ulong i = 0; i < count; i++)
+        {
+            ReadFromBuffers(reader);
+        }
+    }
+
+    public void WriteToBuffers(BinaryWriter writer)
+    {
+        switch (this)
+        {
+            case Idle:
+                writer.Write(1u);
+                break;
+            case Move model:
+                writer.Write(2u);
+                writer.Write(model.X);
+                writer.Write(model.Y);
+                break;
+            case Update model:
+                writer.Write(3u);
+                writer.Write(model.P1);
+                writer.Write(model.P2);
+                writer.WriteBuffersString(model.P4);
+                break;
+        }
+    }
+}
+
+public abstract record MyEnumWithoutPositions
+{
+    private MyEnumWithoutPositions() {}
+
+    public sealed record Option1(
+        ulong P0
+    ) : MyEnumWithoutPositions;
+    public sealed record Option2(
+        string Name
+    ) : MyEnumWithoutPositions;
+    public sealed record Option3() : MyEnumWithoutPositions;
+    public sealed record Option4() : MyEnumWithoutPositions;
+
+    public static MyEnumWithoutPositions CreateBuffersDefault()
+    {
+        return new Option1(
+            0
+        );
+    }
+
+    public static MyEnumWithoutPositions ReadFromBuffers(BinaryReader reader)
+    {
+        var kind = reader.ReadUInt32();
+
+        switch (kind)
+        {
+            case 0:
+                return new Option1(
+                    reader.ReadUInt64()
+                );
+            case 1:
+                return new Option2(
+                    reader.ReadBuffersString()
+                );
+            case 2:
+                return new Option3();
+            case 3:
+                return new Option4();
+            default:
+                throw new InvalidDataException($"Invalid MyEnumWithoutPositions kind: {kind}");
+        }
+    }
+
+    public static void SkipInBuffers(BinaryReader reader, ulong count)
+    {
+        for (ulong i = 0; i < count; i++)
+        {
+            ReadFromBuffers(reader);
+        }
+    }
+
+    public void WriteToBuffers(BinaryWriter writer)
+    {
+        switch (this)
+        {
+            case Option1 model:
+                writer.Write(0u);
+                writer.Write(model.P0);
+                break;
+            case Option2 model:
+                writer.Write(1u);
+                writer.WriteBuffersString(model.Name);
+                break;
+            case Option3:
+                writer.Write(2u);
+                break;
+            case Option4:
+                writer.Write(3u);
+                break;
+        }
+    }
+}
+
+public abstract record MyEnumWithManyArguments
+{
+    private MyEnumWithManyArguments() {}
+
+    public sealed record Option1(
+        ulong P0,
+        ulong P1,
+        ulong P2
+    ) : MyEnumWithManyArguments;
+    public sealed record Option2(
+        string Name
+    ) : MyEnumWithManyArguments;
+    public sealed record Option3() : MyEnumWithManyArguments;
+    public sealed record Option4() : MyEnumWithManyArguments;
+
+    public static MyEnumWithManyArguments CreateBuffersDefault()
+    {
+        return new Option1(
+            0,
+            0,
+            0
+        );
+    }
+
+    public static MyEnumWithManyArguments ReadFromBuffers(BinaryReader reader)
+    {
+        var kind = reader.ReadUInt32();
+
+        switch (kind)
+        {
+            case 0:
+                return new Option1(
+                    reader.ReadUInt64(),
+                    reader.ReadUInt64(),
+                    reader.ReadUInt64()
+                );
+            case 1:
+                return new Option2(
+                    reader.ReadBuffersString()
+                );
+            case 2:
+                return new Option3();
+            case 3:
+                return new Option4();
+            default:
+                throw new InvalidDataException($"Invalid MyEnumWithManyArguments kind: {kind}");
+        }
+    }
+
+    public static void SkipInBuffers(BinaryReader reader, ulong count)
+    {
+        for (ulong i = 0; i < count; i++)
+        {
+            ReadFromBuffers(reader);
+        }
+    }
+
+    public void WriteToBuffers(BinaryWriter writer)
+    {
+        switch (this)
+        {
+            case Option1 model:
+                writer.Write(0u);
+                writer.Write(model.P0);
+                writer.Write(model.P1);
+                writer.Write(model.P2);
+                break;
+            case Option2 model:
+                writer.Write(1u);
+                writer.WriteBuffersString(model.Name);
+                break;
+            case Option3:
+                writer.Write(2u);
+                break;
+            case Option4:
+                writer.Write(3u);
+                break;
+        }
+    }
+}
+
+public abstract record MyEnumWithNamedArguments
+{
+    private MyEnumWithNamedArguments() {}
+
+    public sealed record Option1(
+        string Name,
+        float Value,
+        List<byte> Bytes
+    ) : MyEnumWithNamedArguments;
+    public sealed record Option2(
+        ulong P0,
+        ulong P1,
+        ulong P2
+    ) : MyEnumWithNamedArguments;
+    public sealed record Option3() : MyEnumWithNamedArguments;
+    public sealed record Option4() : MyEnumWithNamedArguments;
+
+    public static MyEnumWithNamedArguments CreateBuffersDefault()
+    {
+        return new Option1(
+            "",
+            0,
+            new List<byte>()
+        );
+    }
+
+    public static MyEnumWithNamedArguments ReadFromBuffers(BinaryReader reader)
+    {
+        var kind = reader.ReadUInt32();
+
+        switch (kind)
+        {
+            case 0:
+                return new Option1(
+                    reader.ReadBuffersString(),
+                    reader.ReadSingle(),
+                    reader.ReadList(() => reader.ReadByte())
+                );
+            case 1:
+                return new Option2(
+                    reader.ReadUInt64(),
+                    reader.ReadUInt64(),
+                    reader.ReadUInt64()
+                );
+            case 2:
+                return new Option3();
+            case 3:
+                return new Option4();
+            default:
+                throw new InvalidDataException($"Invalid MyEnumWithNamedArguments kind: {kind}");
+        }
+    }
+
+    public static void SkipInBuffers(BinaryReader reader, ulong count)
+    {
+        for (ulong i = 0; i < count; i++)
+        {
+            ReadFromBuffers(reader);
+        }
+    }
+
+    public void WriteToBuffers(BinaryWriter writer)
+    {
+        switch (this)
+        {
+            case Option1 model:
+                writer.Write(0u);
+                writer.WriteBuffersString(model.Name);
+                writer.Write(model.Value);
+                writer.WriteList(model.Bytes, item => writer.Write(item));
+                break;
+            case Option2 model:
+                writer.Write(1u);
+                writer.Write(model.P0);
+                writer.Write(model.P1);
+                writer.Write(model.P2);
+                break;
+            case Option3:
+                writer.Write(2u);
+                break;
+            case Option4:
+                writer.Write(3u);
+                break;
+        }
+    }
+}
@@ -0,0 +1,137 @@
+public sealed record Circle(
+    float Radius
+)
+{
+    public static Circle CreateBuffersDefault()
+    {
+        return new Circle(
+            0
+        );
+    }
+
+    public static Circle ReadFromBuffers(BinaryReader reader)
+    {
+        return new Circle(
+            reader.ReadSingle()
+        );
+    }
+
+    public static void SkipInBuffers(BinaryReader reader, ulong count)
+    {
+        for (ulong i = 0; i < count; i++)
+        {
+            ReadFromBuffers(reader);
+        }
+    }
+
+    public void WriteToBuffers(BinaryWriter writer)
+    {
+        writer.Write(Radius);
+    }
+}
+
+public sealed record Square(
+    float Size
+)
+{
+    public static Square CreateBuffersDefault()
+    {
+        return new Square(
+            0
+        );
+    }
+
+    public static Square ReadFromBuffers(BinaryReader reader)
+    {
+        return new Square(
+            reader.ReadSingle()
+        );
+    }
+
+    public static void SkipInBuffers(BinaryReader reader, ulong count)
+    {
+        for (ulong i = 0; i < count; i++)
+        {
+            ReadFromBuffers(reader);
+        }
+    }
+
+    public void WriteToBuffers(BinaryWriter writer)
+    {
+        writer.Write(Size);
+    }
+}
+
+public abstract record Shape
+{
+    private Shape() {}
+
+    public sealed record Circle(
+        global::Circle P0
+    ) : Shape;
+    public sealed record Square(
+        global::Square SquareValue
+    ) : Shape;
+    public sealed record Group(
+        List<global::Shape> Shapes
+    ) : Shape;
+
+    public static Shape CreateBuffersDefault()
+    {
+        return new Circle(
+            global::Circle.CreateBuffersDefault()
+        );
+    }
+
+    public static Shape ReadFromBuffers(BinaryReader reader)
+    {
+        var kind = reader.ReadUInt32();
+
+        switch (kind)
+        {
+            case 0:
+                return new Circle(
+                    global::Circle.ReadFromBuffers(reader)
+                );
+            case 1:
+                return new Square(
+                    global::Square.ReadFromBuffers(reader)
+                );
+            case 2:
+                return new Group(
+                    reader.ReadList(() => global::Shape.ReadFromBuffers(reader))
+                );
+            default:
+                throw new InvalidDataException($"Invalid Shape kind: {kind}");
+        }
+    }
+
+    public static void SkipInBuffers(BinaryReader reader, ulong count)
+    {
+        for (ulong i = 0; i < count; i++)
+        {
+            ReadFromBuffers(reader);
+        }
+    }
+
+    public void WriteToBuffers(BinaryWriter writer)
+    {
+        switch (this)
+        {
+            case Circle model:
+                writer.Write(0u);
+                model.P0.WriteToBuffers(writer);
+                break;
+            case Square model:
+                writer.Write(1u);
+                model.SquareValue.WriteToBuffers(writer);
+                break;
+            case Group model:
+                writer.Write(2u);
+                writer.WriteList(model.Shapes, item => item.WriteToBuffers(writer));
+                break;
+        }
+    }
+}
+
+
@@ -0,0 +1,22 @@
+public sealed class ExamplesStreamRpc
+{
+    private const string ScopeId = "723ca727-6a66-43a7-bfcc-b8ad94eac9be";
+
+    private readonly ITechPawsBuffersRuntime runtime;
+
+    public event Action<int>? Counter;
+    public event Action<string>? Theme;
+    public event Action? Trigger;
+
+    public ExamplesStreamRpc(ITechPawsBuffersRuntime runtime)
+    {
+        this.runtime = runtime;
+    }
+
+    public void ConsumeSignals()
+    {
+        runtime.ConsumeSignal(ScopeId, 0, reader => Counter?.Invoke(reader.ReadInt32()));
+        runtime.ConsumeSignal(ScopeId, 1, reader => Theme?.Invoke(reader.ReadBuffersString()));
+        runtime.ConsumeSignal(ScopeId, 2, reader => Trigger?.Invoke());
+    }
+}
@@ -0,0 +1,59 @@
+public sealed class ExamplesSyncRpc
+{
+    private const string ScopeId = "4de616f8-12c5-4d2c-8d48-9c5fb038991f";
+
+    private readonly ITechPawsBuffersRuntime runtime;
+
+    public ExamplesSyncRpc(ITechPawsBuffersRuntime runtime)
+    {
+        this.runtime = runtime;
+    }
+
+    public void PrintHelloWorld()
+    {
+        runtime.Call(
+            ScopeId,
+            0,
+            null
+        );
+    }
+
+    public string HelloWorld()
+    {
+        return runtime.Call(
+            ScopeId,
+            1,
+            null,
+            reader => reader.ReadBuffersString()
+        );
+    }
+
+    public string SayHello(string firstName, string lastName)
+    {
+        return runtime.Call(
+            ScopeId,
+            2,
+            writer =>
+            {
+                writer.WriteBuffersString(firstName);
+                writer.WriteBuffersString(lastName);
+            },
+            reader => reader.ReadBuffersString()
+        );
+    }
+
+    public double Sum(int a, float b, double c)
+    {
+        return runtime.Call(
+            ScopeId,
+            3,
+            writer =>
+            {
+                writer.Write(a);
+                writer.Write(b);
+                writer.Write(c);
+            },
+            reader => reader.ReadDouble()
+        );
+    }
+}
@@ -0,0 +1,63 @@
+public sealed record ViewData(
+    float DeltaTime,
+    float ViewWidth,
+    float ViewHeight,
+    float TouchStartX,
+    float TouchStartY,
+    float LastTouchX,
+    float LastTouchY,
+    float TouchX,
+    float TouchY
+)
+{
+    public static ViewData CreateBuffersDefault()
+    {
+        return new ViewData(
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0
+        );
+    }
+
+    public static ViewData ReadFromBuffers(BinaryReader reader)
+    {
+        return new ViewData(
+            reader.ReadSingle(),
+            reader.ReadSingle(),
+            reader.ReadSingle(),
+            reader.ReadSingle(),
+            reader.ReadSingle(),
+            reader.ReadSingle(),
+            reader.ReadSingle(),
+            reader.ReadSingle(),
+            reader.ReadSingle()
+        );
+    }
+
+    public static void SkipInBuffers(BinaryReader reader, ulong count)
+    {
+        for (ulong i = 0; i < count; i++)
+        {
+            ReadFromBuffers(reader);
+        }
+    }
+
+    public void WriteToBuffers(BinaryWriter writer)
+    {
+        writer.Write(DeltaTime);
+        writer.Write(ViewWidth);
+        writer.Write(ViewHeight);
+        writer.Write(TouchStartX);
+        writer.Write(TouchStartY);
+        writer.Write(LastTouchX);
+        writer.Write(LastTouchY);
+        writer.Write(TouchX);
+        writer.Write(TouchY);
+    }
+}
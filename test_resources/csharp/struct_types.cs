@@ -0,0 +1,83 @@
+public sealed record BasicTypesModel(
+    byte Byte,
+    int SomeInteger,
+    long SomeLong,
+    uint SomeUnsigedInteger,
+    uint SomeUnsigedLong,
+    float SomeFloatNumber,
+    double SomeDoubleNumber,
+    bool SomeBool,
+    string SomeString,
+    List<string> Vector,
+    LinearTable<float, Test> Generic,
+    MyModel Custom,
+    string? OptionalString,
+    float? OptionalF32
+)
+{
+    public static BasicTypesModel CreateBuffersDefault()
+    {
+        return new BasicTypesModel(
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            false,
+            "",
+            new List<string>(),
+            LinearTable<float, Test>.CreateBuffersDefault(),
+            MyModel.CreateBuffersDefault(),
+            null,
+            null
+        );
+    }
+
+    public static BasicTypesModel ReadFromBuffers(BinaryReader reader)
+    {
+        return new BasicTypesModel(
+            reader.ReadByte(),
+            reader.ReadInt32(),
+            reader.ReadInt64(),
+            reader.ReadUInt32(),
+            reader.ReadUInt32(),
+            reader.ReadSingle(),
+            reader.ReadDouble(),
+            reader.ReadBoolean(),
+            reader.ReadBuffersString(),
+            reader.ReadList(() => reader.ReadBuffersString()),
+            LinearTable<float, Test>.ReadFromBuffers(reader),
+            MyModel.ReadFromBuffers(reader),
+            reader.ReadByte() != 0 ? (string?)reader.ReadBuffersString() : null,
+            reader.ReadByte() != 0 ? (float?)reader.ReadSingle() : null
+        );
+    }
+
+    public static void SkipInBuffers(BinaryReader reader, ulong count)
+    {
+        for (ulong i = 0; i < count; i++)
+        {
+            ReadFromBuffers(reader);
+        }
+    }
+
+    public void WriteToBuffers(BinaryWriter writer)
+    {
+        writer.Write(Byte);
+        writer.Write(SomeInteger);
+        writer.Write(SomeLong);
+        writer.Write(SomeUnsigedInteger);
+        writer.Write(SomeUnsigedLong);
+        writer.Write(SomeFloatNumber);
+        writer.Write(SomeDoubleNumber);
+        writer.Write(SomeBool);
+        writer.WriteBuffersString(SomeString);
+        writer.WriteList(Vector, item => writer.WriteBuffersString(item));
+        Generic.WriteToBuffers(writer);
+        Custom.WriteToBuffers(writer);
+        writer.WriteOption(OptionalString, value => writer.WriteBuffersString(value));
+        writer.WriteOption(OptionalF32, value => writer.Write(value));
+    }
+}
@@ -0,0 +1,26 @@
+const (
+	CommandsDrawLines          uint64 = 131073
+	CommandsDrawPath           uint64 = 131074
+	CommandsDrawQuads          uint64 = 131075
+	CommandsDrawCenteredQuads  uint64 = 131076
+	CommandsDrawTexts          uint64 = 131077
+	CommandsSetColorPipeline   uint64 = 131078
+	CommandsSetTexturePipeline uint64 = 131079
+	CommandsDrawCircles        uint64 = 131080
+	CommandsDrawHollowCircles  uint64 = 131081
+)
+
+const (
+	AddrSomeValue string = "Hello World!"
+
+	AddrGroupsMain       uint64 = 0
+	AddrGroupsMainRender uint64 = 1
+	AddrGroupsRpc        uint64 = 2
+	AddrGroupsRpcSync    uint64 = 3
+	AddrGroupsRpcRead    uint64 = 4
+
+	AddrDeltaTime float64 = 16.6
+	AddrFlag      bool    = true
+
+	AddrCommandsBuffersWin1MainRender uint64 = 0
+)
@@ -0,0 +1,378 @@
+type MyEnum interface {
+	isMyEnum()
+	WriteToBuffers(buf []byte) []byte
+}
+
+type MyEnumIdle struct{}
+
+type MyEnumMove struct {
+	X float64
+	Y float64
+}
+
+type MyEnumUpdate struct {
+	P1 float64
+	P2 float64
+	P4 string
+}
+
+func DefaultMyEnum() MyEnum {
+	return MyEnumIdle{}
+}
+
+func ReadMyEnum(r io.Reader) (MyEnum, error) {
+	kind, err := readU32(r)
+	if err != nil {
+		return nil, err
+	}
+
+	switch kind {
+	case 1:
+		return MyEnumIdle{}, nil
+	case 2:
+		var v MyEnumMove
+		if v.X, err = readF64(r); err != nil {
+			return nil, err
+		}
+		if v.Y, err = readF64(r); err != nil {
+			return nil, err
+		}
+		return v, nil
+	case 3:
+		var v MyEnumUpdate
+		if v.P1, err = readF64(r); err != nil {
+			return nil, err
+		}
+		if v.P2, err = readF64(r); err != nil {
+			return nil, err
+		}
+		if v.P4, err = readString(r); err != nil {
+			return nil, err
+		}
+		return v, nil
+	default:
+		return nil, invalidKind("MyEnum", kind)
+	}
+}
+
+func SkipMyEnum(r io.Reader, count uint64) error {
+	for i := uint64(0); i < count; i++ {
+		if _, err := ReadMyEnum(r); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (MyEnumIdle) isMyEnum() {}
+
+func (MyEnumIdle) WriteToBuffers(buf []byte) []byte {
+	return appendU32(buf, 1)
+}
+
+func (MyEnumMove) isMyEnum() {}
+
+func (v MyEnumMove) WriteToBuffers(buf []byte) []byte {
+	buf = appendU32(buf, 2)
+	buf = appendF64(buf, v.X)
+	buf = appendF64(buf, v.Y)
+	return buf
+}
+
+func (MyEnumUpdate) isMyEnum() {}
+
+func (v MyEnumUpdate) WriteToBuffers(buf []byte) []byte {
+	buf = appendU32(buf, 3)
+	buf = appendF64(buf, v.P1)
+	buf = appendF64(buf, v.P2)
+	buf = appendString(buf, v.P4)
+	return buf
+}
+
+type MyEnumWithoutPositions interface {
+	isMyEnumWithoutPositions()
+	WriteToBuffers(buf []byte) []byte
+}
+
+type MyEnumWithoutPositionsOption1 struct {
+	P0 uint64
+}
+
+type MyEnumWithoutPositionsOption2 struct {
+	Name string
+}
+
+type MyEnumWithoutPositionsOption3 struct{}
+
+type MyEnumWithoutPositionsOption4 struct{}
+
+func DefaultMyEnumWithoutPositions() MyEnumWithoutPositions {
+	return MyEnumWithoutPositionsOption1{}
+}
+
+func ReadMyEnumWithoutPositions(r io.Reader) (MyEnumWithoutPositions, error) {
+	kind, err := readU32(r)
+	if err != nil {
+		return nil, err
+	}
+
+	switch kind {
+	case 0:
+		var v MyEnumWithoutPositionsOption1
+		if v.P0, err = readU64(r); err != nil {
+			return nil, err
+		}
+		return v, nil
+	case 1:
+		var v MyEnumWithoutPositionsOption2
+		if v.Name, err = readString(r); err != nil {
+			return nil, err
+		}
+		return v, nil
+	case 2:
+		return MyEnumWithoutPositionsOption3{}, nil
+	case 3:
+		return MyEnumWithoutPositionsOption4{}, nil
+	default:
+		return nil, invalidKind("MyEnumWithoutPositions", kind)
+	}
+}
+
+func SkipMyEnumWithoutPositions(r io.Reader, count uint64) error {
+	for i := uint64(0); i < count; i++ {
+		if _, err := ReadMyEnumWithoutPositions(r); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (MyEnumWithoutPositionsOption1) isMyEnumWithoutPositions() {}
+
+func (v MyEnumWithoutPositionsOption1) WriteToBuffers(buf []byte) []byte {
+	buf = appendU32(buf, 0)
+	buf = appendU64(buf, v.P0)
+	return buf
+}
+
+func (MyEnumWithoutPositionsOption2) isMyEnumWithoutPositions() {}
+
+func (v MyEnumWithoutPositionsOption2) WriteToBuffers(buf []byte) []byte {
+	buf = appendU32(buf, 1)
+	buf = appendString(buf, v.Name)
+	return buf
+}
+
+func (MyEnumWithoutPositionsOption3) isMyEnumWithoutPositions() {}
+
+func (MyEnumWithoutPositionsOption3) WriteToBuffers(buf []byte) []byte {
+	return appendU32(buf, 2)
+}
+
+func (MyEnumWithoutPositionsOption4) isMyEnumWithoutPositions() {}
+
+func (MyEnumWithoutPositionsOption4) WriteToBuffers(buf []byte) []byte {
+	return appendU32(buf, 3)
+}
+
+type MyEnumWithManyArguments interface {
+	isMyEnumWithManyArguments()
+	WriteToBuffers(buf []byte) []byte
+}
+
+type MyEnumWithManyArgumentsOption1 struct {
+	P0 uint64
+	P1 uint64
+	P2 uint64
+}
+
+type MyEnumWithManyArgumentsOption2 struct {
+	Name string
+}
+
+type MyEnumWithManyArgumentsOption3 struct{}
+
+type MyEnumWithManyArgumentsOption4 struct{}
+
+func DefaultMyEnumWithManyArguments() MyEnumWithManyArguments {
+	return MyEnumWithManyArgumentsOption1{}
+}
+
+func ReadMyEnumWithManyArguments(r io.Reader) (MyEnumWithManyArguments, error) {
+	kind, err := readU32(r)
+	if err != nil {
+		return nil, err
+	}
+
+	switch kind {
+	case 0:
+		var v MyEnumWithManyArgumentsOption1
+		if v.P0, err = readU64(r); err != nil {
+			return nil, err
+		}
+		if v.P1, err = readU64(r); err != nil {
+			return nil, err
+		}
+		if v.P2, err = readU64(r); err != nil {
+			return nil, err
+		}
+		return v, nil
+	case 1:
+		var v MyEnumWithManyArgumentsOption2
+		if v.Name, err = readString(r); err != nil {
+			return nil, err
+		}
+		return v, nil
+	case 2:
+		return MyEnumWithManyArgumentsOption3{}, nil
+	case 3:
+		return MyEnumWithManyArgumentsOption4{}, nil
+	default:
+		return nil, invalidKind("MyEnumWithManyArguments", kind)
+	}
+}
+
+func SkipMyEnumWithManyArguments(r io.Reader, count uint64) error {
+	for i := uint64(0); i < count; i++ {
+		if _, err := ReadMyEnumWithManyArguments(r); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (MyEnumWithManyArgumentsOption1) isMyEnumWithManyArguments() {}
+
+func (v MyEnumWithManyArgumentsOption1) WriteToBuffers(buf []byte) []byte {
+	buf = appendU32(buf, 0)
+	buf = appendU64(buf, v.P0)
+	buf = appendU64(buf, v.P1)
+	buf = appendU64(buf, v.P2)
+	return buf
+}
+
+func (MyEnumWithManyArgumentsOption2) isMyEnumWithManyArguments() {}
+
+func (v MyEnumWithManyArgumentsOption2) WriteToBuffers(buf []byte) []byte {
+	buf = appendU32(buf, 1)
+	buf = appendString(buf, v.Name)
+	return buf
+}
+
+func (MyEnumWithManyArgumentsOption3) isMyEnumWithManyArguments() {}
+
+func (MyEnumWithManyArgumentsOption3) WriteToBuffers(buf []byte) []byte {
+	return appendU32(buf, 2)
+}
+
+func (MyEnumWithManyArgumentsOption4) isMyEnumWithManyArguments() {}
+
+func (MyEnumWithManyArgumentsOption4) WriteToBuffers(buf []byte) []byte {
+	return appendU32(buf, 3)
+}
+
+type MyEnumWithNamedArguments interface {
+	isMyEnumWithNamedArguments()
+	WriteToBuffers(buf []byte) []byte
+}
+
+type MyEnumWithNamedArgumentsOption1 struct {
+	Name  string
+	Value float32
+	Bytes []uint8
+}
+
+type MyEnumWithNamedArgumentsOption2 struct {
+	P0 uint64
+	P1 uint64
+	P2 uint64
+}
+
+type MyEnumWithNamedArgumentsOption3 struct{}
+
+type MyEnumWithNamedArgumentsOption4 struct{}
+
+func DefaultMyEnumWithNamedArguments() MyEnumWithNamedArguments {
+	return MyEnumWithNamedArgumentsOption1{}
+}
+
+func ReadMyEnumWithNamedArguments(r io.Reader) (MyEnumWithNamedArguments, error) {
+	kind, err := readU32(r)
+	if err != nil {
+		return nil, err
+	}
+
+	switch kind {
+	case 0:
+		var v MyEnumWithNamedArgumentsOption1
+		if v.Name, err = readString(r); err != nil {
+			return nil, err
+		}
+		if v.Value, err = readF32(r); err != nil {
+			return nil, err
+		}
+		if v.Bytes, err = readVec(r, readU8); err != nil {
+			return nil, err
+		}
+		return v, nil
+	case 1:
+		var v MyEnumWithNamedArgumentsOption2
+		if v.P0, err = readU64(r); err != nil {
+			return nil, err
+		}
+		if v.P1, err = readU64(r); err != nil {
+			return nil, err
+		}
+		if v.P2, err = readU64(r); err != nil {
+			return nil, err
+		}
+		return v, nil
+	case 2:
+		return MyEnumWithNamedArgumentsOption3{}, nil
+	case 3:
+		return MyEnumWithNamedArgumentsOption4{}, nil
+	default:
+		return nil, invalidKind("MyEnumWithNamedArguments", kind)
+	}
+}
+
+func SkipMyEnumWithNamedArguments(r io.Reader, count uint64) error {
+	for i := uint64(0); i < count; i++ {
+		if _, err := ReadMyEnumWithNamedArguments(r); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (MyEnumWithNamedArgumentsOption1) isMyEnumWithNamedArguments() {}
+
+func (v MyEnumWithNamedArgumentsOption1) WriteToBuffers(buf []byte) []byte {
+	buf = appendU32(buf, 0)
+	buf = appendString(buf, v.Name)
+	buf = appendF32(buf, v.Value)
+	buf = appendVec(buf, v.Bytes, appendU8)
+	return buf
+}
+
+func (MyEnumWithNamedArgumentsOption2) isMyEnumWithNamedArguments() {}
+
+func (v MyEnumWithNamedArgumentsOption2) WriteToBuffers(buf []byte) []byte {
+	buf = appendU32(buf, 1)
+	buf = appendU64(buf, v.P0)
+	buf = appendU64(buf, v.P1)
+	buf = appendU64(buf, v.P2)
+	return buf
+}
+
+func (MyEnumWithNamedArgumentsOption3) isMyEnumWithNamedArguments() {}
+
+func (MyEnumWithNamedArgumentsOption3) WriteToBuffers(buf []byte) []byte {
+	return appendU32(buf, 2)
+}
+
+func (MyEnumWithNamedArgumentsOption4) isMyEnumWithNamedArguments() {}
+
+func (MyEnumWithNamedArgumentsOption4) WriteToBuffers(buf []byte) []byte {
+	return appendU32(buf, 3)
+}
@@ -0,0 +1,29 @@
+type ExamplesStreamRPC struct {
+	runtime Runtime
+}
+
+func NewExamplesStreamRPC(runtime Runtime) *ExamplesStreamRPC {
+	return &ExamplesStreamRPC{runtime: runtime}
+}
+
+// ScopeID is the id of the schema the methods are called in.
+func (rpc *ExamplesStreamRPC) ScopeID() string {
+	return "723ca727-6a66-43a7-bfcc-b8ad94eac9be"
+}
+
+func (rpc *ExamplesStreamRPC) ConsumeCounter() (int32, bool, error) {
+	res, ok := rpc.runtime.Consume(rpc.ScopeID(), 0)
+	return consumeResult(res, ok, readI32)
+}
+
+func (rpc *ExamplesStreamRPC) ConsumeTheme() (string, bool, error) {
+	res, ok := rpc.runtime.Consume(rpc.ScopeID(), 1)
+	return consumeResult(res, ok, readString)
+}
+
+func (rpc *ExamplesStreamRPC) ConsumeTrigger() bool {
+	_, ok := rpc.runtime.Consume(rpc.ScopeID(), 2)
+	return ok
+}
+
+
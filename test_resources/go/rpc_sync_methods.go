@@ -0,0 +1,43 @@
+type ExamplesSyncRPC struct {
+	runtime Runtime
+}
+
+func NewExamplesSyncRPC(runtime Runtime) *ExamplesSyncRPC {
+	return &ExamplesSyncRPC{runtime: runtime}
+}
+
+// ScopeID is the id of the schema the methods are called in.
+func (rpc *ExamplesSyncRPC) ScopeID() string {
+	return "4de616f8-12c5-4d2c-8d48-9c5fb038991f"
+}
+
+func (rpc *ExamplesSyncRPC) PrintHelloWorld() error {
+	_, err := rpc.runtime.Call(rpc.ScopeID(), 0, nil)
+	return err
+}
+
+func (rpc *ExamplesSyncRPC) HelloWorld() (string, error) {
+	res, err := rpc.runtime.Call(rpc.ScopeID(), 1, nil)
+	return readResult(res, err, readString)
+}
+
+func (rpc *ExamplesSyncRPC) SayHello(firstName string, lastName string) (string, error) {
+	var buf []byte
+	buf = appendString(buf, firstName)
+	buf = appendString(buf, lastName)
+
+	res, err := rpc.runtime.Call(rpc.ScopeID(), 2, buf)
+	return readResult(res, err, readString)
+}
+
+func (rpc *ExamplesSyncRPC) Sum(a int32, b float32, c float64) (float64, error) {
+	var buf []byte
+	buf = appendI32(buf, a)
+	buf = appendF32(buf, b)
+	buf = appendF64(buf, c)
+
+	res, err := rpc.runtime.Call(rpc.ScopeID(), 3, buf)
+	return readResult(res, err, readF64)
+}
+
+
@@ -0,0 +1,75 @@
+type ViewData struct {
+	DeltaTime   float32
+	ViewWidth   float32
+	ViewHeight  float32
+	TouchStartX float32
+	TouchStartY float32
+	LastTouchX  float32
+	LastTouchY  float32
+	TouchX      float32
+	TouchY      float32
+}
+
+func DefaultViewData() ViewData {
+	return ViewData{}
+}
+
+func ReadViewData(r io.Reader) (ViewData, error) {
+	var m ViewData
+	err := m.ReadFromBuffers(r)
+	return m, err
+}
+
+func SkipViewData(r io.Reader, count uint64) error {
+	for i := uint64(0); i < count; i++ {
+		if _, err := ReadViewData(r); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (m *ViewData) ReadFromBuffers(r io.Reader) error {
+	var err error
+	if m.DeltaTime, err = readF32(r); err != nil {
+		return err
+	}
+	if m.ViewWidth, err = readF32(r); err != nil {
+		return err
+	}
+	if m.ViewHeight, err = readF32(r); err != nil {
+		return err
+	}
+	if m.TouchStartX, err = readF32(r); err != nil {
+		return err
+	}
+	if m.TouchStartY, err = readF32(r); err != nil {
+		return err
+	}
+	if m.LastTouchX, err = readF32(r); err != nil {
+		return err
+	}
+	if m.LastTouchY, err = readF32(r); err != nil {
+		return err
+	}
+	if m.TouchX, err = readF32(r); err != nil {
+		return err
+	}
+	if m.TouchY, err = readF32(r); err != nil {
+		return err
+	}
+	return nil
+}
+
+func (m ViewData) WriteToBuffers(buf []byte) []byte {
+	buf = appendF32(buf, m.DeltaTime)
+	buf = appendF32(buf, m.ViewWidth)
+	buf = appendF32(buf, m.ViewHeight)
+	buf = appendF32(buf, m.TouchStartX)
+	buf = appendF32(buf, m.TouchStartY)
+	buf = appendF32(buf, m.LastTouchX)
+	buf = appendF32(buf, m.LastTouchY)
+	buf = appendF32(buf, m.TouchX)
+	buf = appendF32(buf, m.TouchY)
+	return buf
+}
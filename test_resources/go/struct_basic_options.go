@@ -0,0 +1,295 @@
+// Copyright (c) Tech Paws
+
+package snapshots
+
+import (
+	"bytes"
+	"context"
+	"fmt"
+	"io"
+	"math"
+)
+
+// Runtime is the transport the RPC clients call into, e.g. a cgo binding of
+// the Rust core.
+type Runtime interface {
+	Call(scopeID string, methodID uint64, args []byte) ([]byte, error)
+	CallContext(ctx context.Context, scopeID string, methodID uint64, args []byte) ([]byte, error)
+	// Consume takes the last value emitted by the signal, ok is false if
+	// there is none.
+	Consume(scopeID string, methodID uint64) (res []byte, ok bool)
+}
+
+func readFull(r io.Reader, size int) ([]byte, error) {
+	buf := make([]byte, size)
+	_, err := io.ReadFull(r, buf)
+	return buf, err
+}
+
+func readU8(r io.Reader) (uint8, error) {
+	buf, err := readFull(r, 1)
+	if err != nil {
+		return 0, err
+	}
+	return buf[0], nil
+}
+
+func readU32(r io.Reader) (uint32, error) {
+	buf, err := readFull(r, 4)
+	if err != nil {
+		return 0, err
+	}
+	return uint32(buf[0]) | uint32(buf[1])<<8 | uint32(buf[2])<<16 | uint32(buf[3])<<24, nil
+}
+
+func readU64(r io.Reader) (uint64, error) {
+	lo, err := readU32(r)
+	if err != nil {
+		return 0, err
+	}
+	hi, err := readU32(r)
+	if err != nil {
+		return 0, err
+	}
+	return uint64(lo) | uint64(hi)<<32, nil
+}
+
+func readI8(r io.Reader) (int8, error) {
+	value, err := readU8(r)
+	return int8(value), err
+}
+
+func readI32(r io.Reader) (int32, error) {
+	value, err := readU32(r)
+	return int32(value), err
+}
+
+func readI64(r io.Reader) (int64, error) {
+	value, err := readU64(r)
+	return int64(value), err
+}
+
+func readF32(r io.Reader) (float32, error) {
+	value, err := readU32(r)
+	return math.Float32frombits(value), err
+}
+
+func readF64(r io.Reader) (float64, error) {
+	value, err := readU64(r)
+	return math.Float64frombits(value), err
+}
+
+func readBool(r io.Reader) (bool, error) {
+	value, err := readU8(r)
+	return value != 0, err
+}
+
+func readChar(r io.Reader) (rune, error) {
+	value, err := readU32(r)
+	return rune(value), err
+}
+
+func readString(r io.Reader) (string, error) {
+	size, err := readU64(r)
+	if err != nil {
+		return "", err
+	}
+	if size > math.MaxInt64 {
+		return "", io.ErrUnexpectedEOF
+	}
+	var buf bytes.Buffer
+	if _, err := io.CopyN(&buf, r, int64(size)); err != nil {
+		if err == io.EOF {
+			err = io.ErrUnexpectedEOF
+		}
+		return "", err
+	}
+	return buf.String(), nil
+}
+
+func readVec[T any](r io.Reader, read func(io.Reader) (T, error)) ([]T, error) {
+	count, err := readU64(r)
+	if err != nil {
+		return nil, err
+	}
+	var items []T
+	for i := uint64(0); i < count; i++ {
+		item, err := read(r)
+		if err != nil {
+			return nil, err
+		}
+		items = append(items, item)
+	}
+	return items, nil
+}
+
+func readOption[T any](r io.Reader, read func(io.Reader) (T, error)) (*T, error) {
+	flag, err := readU8(r)
+	if err != nil || flag == 0 {
+		return nil, err
+	}
+	value, err := read(r)
+	if err != nil {
+		return nil, err
+	}
+	return &value, nil
+}
+
+func readResult[T any](res []byte, err error, read func(io.Reader) (T, error)) (T, error) {
+	if err != nil {
+		var zero T
+		return zero, err
+	}
+	return read(bytes.NewReader(res))
+}
+
+func consumeResult[T any](res []byte, ok bool, read func(io.Reader) (T, error)) (T, bool, error) {
+	if !ok {
+		var zero T
+		return zero, false, nil
+	}
+	value, err := read(bytes.NewReader(res))
+	return value, err == nil, err
+}
+
+func invalidKind(id string, kind uint32) error {
+	return fmt.Errorf("invalid %s kind: %d", id, kind)
+}
+
+func appendU8(buf []byte, value uint8) []byte {
+	return append(buf, value)
+}
+
+func appendU32(buf []byte, value uint32) []byte {
+	return append(buf, byte(value), byte(value>>8), byte(value>>16), byte(value>>24))
+}
+
+func appendU64(buf []byte, value uint64) []byte {
+	return appendU32(appendU32(buf, uint32(value)), uint32(value>>32))
+}
+
+func appendI8(buf []byte, value int8) []byte {
+	return appendU8(buf, uint8(value))
+}
+
+func appendI32(buf []byte, value int32) []byte {
+	return appendU32(buf, uint32(value))
+}
+
+func appendI64(buf []byte, value int64) []byte {
+	return appendU64(buf, uint64(value))
+}
+
+func appendF32(buf []byte, value float32) []byte {
+	return appendU32(buf, math.Float32bits(value))
+}
+
+func appendF64(buf []byte, value float64) []byte {
+	return appendU64(buf, math.Float64bits(value))
+}
+
+func appendBool(buf []byte, value bool) []byte {
+	if value {
+		return appendU8(buf, 1)
+	}
+	return appendU8(buf, 0)
+}
+
+func appendChar(buf []byte, value rune) []byte {
+	return appendU32(buf, uint32(value))
+}
+
+func appendString(buf []byte, value string) []byte {
+	return append(appendU64(buf, uint64(len(value))), value...)
+}
+
+func appendVec[T any](buf []byte, items []T, write func([]byte, T) []byte) []byte {
+	buf = appendU64(buf, uint64(len(items)))
+	for _, item := range items {
+		buf = write(buf, item)
+	}
+	return buf
+}
+
+func appendOption[T any](buf []byte, value *T, write func([]byte, T) []byte) []byte {
+	if value == nil {
+		return appendU8(buf, 0)
+	}
+	return write(appendU8(buf, 1), *value)
+}
+
+type ViewData struct {
+	DeltaTime   float32
+	ViewWidth   float32
+	ViewHeight  float32
+	TouchStartX float32
+	TouchStartY float32
+	LastTouchX  float32
+	LastTouchY  float32
+	TouchX      float32
+	TouchY      float32
+}
+
+func DefaultViewData() ViewData {
+	return ViewData{}
+}
+
+func ReadViewData(r io.Reader) (ViewData, error) {
+	var m ViewData
+	err := m.ReadFromBuffers(r)
+	return m, err
+}
+
+func SkipViewData(r io.Reader, count uint64) error {
+	for i := uint64(0); i < count; i++ {
+		if _, err := ReadViewData(r); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (m *ViewData) ReadFromBuffers(r io.Reader) error {
+	var err error
+	if m.DeltaTime, err = readF32(r); err != nil {
+		return err
+	}
+	if m.ViewWidth, err = readF32(r); err != nil {
+		return err
+	}
+	if m.ViewHeight, err = readF32(r); err != nil {
+		return err
+	}
+	if m.TouchStartX, err = readF32(r); err != nil {
+		return err
+	}
+	if m.TouchStartY, err = readF32(r); err != nil {
+		return err
+	}
+	if m.LastTouchX, err = readF32(r); err != nil {
+		return err
+	}
+	if m.LastTouchY, err = readF32(r); err != nil {
+		return err
+	}
+	if m.TouchX, err = readF32(r); err != nil {
+		return err
+	}
+	if m.TouchY, err = readF32(r); err != nil {
+		return err
+	}
+	return nil
+}
+
+func (m ViewData) WriteToBuffers(buf []byte) []byte {
+	buf = appendF32(buf, m.DeltaTime)
+	buf = appendF32(buf, m.ViewWidth)
+	buf = appendF32(buf, m.ViewHeight)
+	buf = appendF32(buf, m.TouchStartX)
+	buf = appendF32(buf, m.TouchStartY)
+	buf = appendF32(buf, m.LastTouchX)
+	buf = appendF32(buf, m.LastTouchY)
+	buf = appendF32(buf, m.TouchX)
+	buf = appendF32(buf, m.TouchY)
+	return buf
+}
@@ -0,0 +1,103 @@
+type BasicTypesModel struct {
+	Byte               uint8
+	SomeInteger        int32
+	SomeLong           int64
+	SomeUnsigedInteger uint32
+	SomeUnsigedLong    uint32
+	SomeFloatNumber    float32
+	SomeDoubleNumber   float64
+	SomeBool           bool
+	SomeString         string
+	Vector             []string
+	Generic            LinearTable[float32, Test]
+	Custom             MyModel
+	OptionalString     *string
+	OptionalF32        *float32
+}
+
+func DefaultBasicTypesModel() BasicTypesModel {
+	return BasicTypesModel{
+		Generic: DefaultLinearTable[float32, Test](),
+		Custom:  DefaultMyModel(),
+	}
+}
+
+func ReadBasicTypesModel(r io.Reader) (BasicTypesModel, error) {
+	var m BasicTypesModel
+	err := m.ReadFromBuffers(r)
+	return m, err
+}
+
+func SkipBasicTypesModel(r io.Reader, count uint64) error {
+	for i := uint64(0); i < count; i++ {
+		if _, err := ReadBasicTypesModel(r); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func (m *BasicTypesModel) ReadFromBuffers(r io.Reader) error {
+	var err error
+	if m.Byte, err = readU8(r); err != nil {
+		return err
+	}
+	if m.SomeInteger, err = readI32(r); err != nil {
+		return err
+	}
+	if m.SomeLong, err = readI64(r); err != nil {
+		return err
+	}
+	if m.SomeUnsigedInteger, err = readU32(r); err != nil {
+		return err
+	}
+	if m.SomeUnsigedLong, err = readU32(r); err != nil {
+		return err
+	}
+	if m.SomeFloatNumber, err = readF32(r); err != nil {
+		return err
+	}
+	if m.SomeDoubleNumber, err = readF64(r); err != nil {
+		return err
+	}
+	if m.SomeBool, err = readBool(r); err != nil {
+		return err
+	}
+	if m.SomeString, err = readString(r); err != nil {
+		return err
+	}
+	if m.Vector, err = readVec(r, readString); err != nil {
+		return err
+	}
+	if m.Generic, err = ReadLinearTable[float32, Test](r); err != nil {
+		return err
+	}
+	if m.Custom, err = ReadMyModel(r); err != nil {
+		return err
+	}
+	if m.OptionalString, err = readOption(r, readString); err != nil {
+		return err
+	}
+	if m.OptionalF32, err = readOption(r, readF32); err != nil {
+		return err
+	}
+	return nil
+}
+
+func (m BasicTypesModel) WriteToBuffers(buf []byte) []byte {
+	buf = appendU8(buf, m.Byte)
+	buf = appendI32(buf, m.SomeInteger)
+	buf = appendI64(buf, m.SomeLong)
+	buf = appendU32(buf, m.SomeUnsigedInteger)
+	buf = appendU32(buf, m.SomeUnsigedLong)
+	buf = appendF32(buf, m.SomeFloatNumber)
+	buf = appendF64(buf, m.SomeDoubleNumber)
+	buf = appendBool(buf, m.SomeBool)
+	buf = appendString(buf, m.SomeString)
+	buf = appendVec(buf, m.Vector, appendString)
+	buf = m.Generic.WriteToBuffers(buf)
+	buf = m.Custom.WriteToBuffers(buf)
+	buf = appendOption(buf, m.OptionalString, appendString)
+	buf = appendOption(buf, m.OptionalF32, appendF32)
+	return buf
+}
@@ -0,0 +1,164 @@
+// GENERATED, DO NOT EDIT
+
+import org.junit.Assert.assertArrayEquals
+import org.junit.Test
+
+class TestVectorsTest {
+    @Test
+    fun pointDefault() {
+        val data = bytesOf(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00)
+        val expected = Point(0.0f, 0.0f)
+        val value = Point.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun pointMin() {
+        val data = bytesOf(0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF)
+        val expected = Point(-3.4028235e38f, -3.4028235e38f)
+        val value = Point.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun pointMax() {
+        val data = bytesOf(0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F)
+        val expected = Point(3.4028235e38f, 3.4028235e38f)
+        val value = Point.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun pointRandom0() {
+        val data = bytesOf(0xA2, 0x64, 0xC4, 0xC6, 0xD0, 0x97, 0x65, 0x46)
+        val expected = Point(-25138.316f, 14693.953f)
+        val value = Point.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun labelsDefault() {
+        val data = bytesOf(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00)
+        val expected = Labels(0uL, 0, false, "", listOf(), null)
+        val value = Labels.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun labelsMin() {
+        val data = bytesOf(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00)
+        val expected = Labels(0uL, -128, false, "", listOf(), null)
+        val value = Labels.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun labelsMax() {
+        val data = bytesOf(0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, 0x01, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x9C, 0x6E, 0xC3, 0xAF, 0x63, 0xC3, 0xB8, 0x64, 0xC3, 0xA9, 0x20, 0xE2, 0x9C, 0x93, 0x20, 0x22, 0x71, 0x75, 0x6F, 0x74, 0x65, 0x64, 0x22, 0x20, 0x5C, 0x20, 0x0A, 0xE4, 0xB8, 0xAD, 0xE6, 0x96, 0x87, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0x01, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x9C, 0x6E, 0xC3, 0xAF, 0x63, 0xC3, 0xB8, 0x64, 0xC3, 0xA9, 0x20, 0xE2, 0x9C, 0x93, 0x20, 0x22, 0x71, 0x75, 0x6F, 0x74, 0x65, 0x64, 0x22, 0x20, 0x5C, 0x20, 0x0A, 0xE4, 0xB8, 0xAD, 0xE6, 0x96, 0x87)
+        val expected = Labels(18446744073709551615uL, 127, true, "\u00DCn\u00EFc\u00F8d\u00E9 \u2713 \"quoted\" \\ \n\u4E2D\u6587", listOf(Point(3.4028235e38f, 3.4028235e38f), Point(3.4028235e38f, 3.4028235e38f)), "\u00DCn\u00EFc\u00F8d\u00E9 \u2713 \"quoted\" \\ \n\u4E2D\u6587")
+        val value = Labels.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun labelsRandom0() {
+        val data = bytesOf(0xAF, 0xCD, 0x1D, 0x7B, 0x39, 0xA8, 0x20, 0xE2, 0xF4, 0x01, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x31, 0x5F, 0x5C, 0x61, 0xE2, 0x9C, 0x93, 0x5F, 0x62, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xEC, 0x6D, 0x6F, 0x46, 0xA2, 0xAD, 0x8A, 0xC6, 0x32, 0x9A, 0x98, 0x46, 0xAA, 0xFE, 0xC4, 0xC6, 0x01, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x59, 0xE2, 0x9C, 0x93, 0x62, 0xE4, 0xB8, 0xAD)
+        val expected = Labels(16294208416658607535uL, -12, true, "1_\\a\u2713_b", listOf(Point(15323.48f, -17750.816f), Point(19533.098f, -25215.332f)), "\"Y\u2713b\u4E2D")
+        val value = Labels.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun commandDefault() {
+        val data = bytesOf(0x00, 0x00, 0x00, 0x00)
+        val expected = CommandIdle
+        val value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun commandIdle() {
+        val data = bytesOf(0x00, 0x00, 0x00, 0x00)
+        val expected = CommandIdle
+        val value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun commandMoveMin() {
+        val data = bytesOf(0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF)
+        val expected = CommandMove(Int.MIN_VALUE, Point(-3.4028235e38f, -3.4028235e38f))
+        val value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun commandMoveMax() {
+        val data = bytesOf(0x01, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F)
+        val expected = CommandMove(2147483647, Point(3.4028235e38f, 3.4028235e38f))
+        val value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun commandRenameMin() {
+        val data = bytesOf(0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00)
+        val expected = CommandRename("")
+        val value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun commandRenameMax() {
+        val data = bytesOf(0x02, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x9C, 0x6E, 0xC3, 0xAF, 0x63, 0xC3, 0xB8, 0x64, 0xC3, 0xA9, 0x20, 0xE2, 0x9C, 0x93, 0x20, 0x22, 0x71, 0x75, 0x6F, 0x74, 0x65, 0x64, 0x22, 0x20, 0x5C, 0x20, 0x0A, 0xE4, 0xB8, 0xAD, 0xE6, 0x96, 0x87)
+        val expected = CommandRename("\u00DCn\u00EFc\u00F8d\u00E9 \u2713 \"quoted\" \\ \n\u4E2D\u6587")
+        val value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    @Test
+    fun commandRandom0() {
+        val data = bytesOf(0x01, 0x00, 0x00, 0x00, 0xF4, 0x65, 0xB9, 0xA1, 0x62, 0x75, 0xED, 0xC6, 0x50, 0xF8, 0x4D, 0xC6)
+        val expected = CommandMove(-1581685260, Point(-30394.691f, -13182.078f))
+        val value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        assertArrayEquals(data, writeVector(expected))
+        assertArrayEquals(data, writeVector(value))
+    }
+
+    private fun bytesOf(vararg values: Int): ByteArray = ByteArray(values.size) { values[it].toByte() }
+
+    private fun writeVector(value: TechPawsBuffersModel): ByteArray {
+        val bytesWriter = TechPawsBuffersBytesWriter()
+        value.writeToBuffers(bytesWriter)
+        return bytesWriter.toByteArray()
+    }
+}
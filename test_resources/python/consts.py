@@ -0,0 +1,19 @@
+COMMANDS_DRAW_LINES: Final[int] = 131073
+COMMANDS_DRAW_PATH: Final[int] = 131074
+COMMANDS_DRAW_QUADS: Final[int] = 131075
+COMMANDS_DRAW_CENTERED_QUADS: Final[int] = 131076
+COMMANDS_DRAW_TEXTS: Final[int] = 131077
+COMMANDS_SET_COLOR_PIPELINE: Final[int] = 131078
+COMMANDS_SET_TEXTURE_PIPELINE: Final[int] = 131079
+COMMANDS_DRAW_CIRCLES: Final[int] = 131080
+COMMANDS_DRAW_HOLLOW_CIRCLES: Final[int] = 131081
+
+ADDR_SOME_VALUE: Final[str] = "Hello World!"
+ADDR_GROUPS_MAIN: Final[int] = 0
+ADDR_GROUPS_MAIN_RENDER: Final[int] = 1
+ADDR_GROUPS_RPC: Final[int] = 2
+ADDR_GROUPS_RPC_SYNC: Final[int] = 3
+ADDR_GROUPS_RPC_READ: Final[int] = 4
+ADDR_DELTA_TIME: Final[float] = 16.6
+ADDR_FLAG: Final[bool] = True
+ADDR_COMMANDS_BUFFERS_WIN1_MAIN_RENDER: Final[int] = 0
@@ -0,0 +1,279 @@
+class MyEnum:
+    @staticmethod
+    def create_buffers_default() -> MyEnum:
+        return MyEnumIdle()
+
+    @staticmethod
+    def read_from_buffers(reader: BytesReader) -> MyEnum:
+        kind = reader.read_u32()
+
+        if kind == 1:
+            return MyEnumIdle()
+        if kind == 2:
+            return MyEnumMove(
+                x=reader.read_f64(),
+                y=reader.read_f64(),
+            )
+        if kind == 3:
+            return MyEnumUpdate(
+                p1=reader.read_f64(),
+                p2=reader.read_f64(),
+                p4=reader.read_string(),
+            )
+
+        raise ValueError("Invalid MyEnum kind: %d" % kind)
+
+    @staticmethod
+    def skip_in_buffers(reader: BytesReader, count: int) -> None:
+        for _ in range(count):
+            MyEnum.read_from_buffers(reader)
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        raise NotImplementedError
+
+
+@dataclass
+class MyEnumIdle(MyEnum):
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(1)
+
+
+@dataclass
+class MyEnumMove(MyEnum):
+    x: float
+    y: float
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(2)
+        writer.write_f64(self.x)
+        writer.write_f64(self.y)
+
+
+@dataclass
+class MyEnumUpdate(MyEnum):
+    p1: float
+    p2: float
+    p4: str
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(3)
+        writer.write_f64(self.p1)
+        writer.write_f64(self.p2)
+        writer.write_string(self.p4)
+
+
+class MyEnumWithoutPositions:
+    @staticmethod
+    def create_buffers_default() -> MyEnumWithoutPositions:
+        return MyEnumWithoutPositionsOption1(
+            p0=0,
+        )
+
+    @staticmethod
+    def read_from_buffers(reader: BytesReader) -> MyEnumWithoutPositions:
+        kind = reader.read_u32()
+
+        if kind == 0:
+            return MyEnumWithoutPositionsOption1(
+                p0=reader.read_u64(),
+            )
+        if kind == 1:
+            return MyEnumWithoutPositionsOption2(
+                name=reader.read_string(),
+            )
+        if kind == 2:
+            return MyEnumWithoutPositionsOption3()
+        if kind == 3:
+            return MyEnumWithoutPositionsOption4()
+
+        raise ValueError("Invalid MyEnumWithoutPositions kind: %d" % kind)
+
+    @staticmethod
+    def skip_in_buffers(reader: BytesReader, count: int) -> None:
+        for _ in range(count):
+            MyEnumWithoutPositions.read_from_buffers(reader)
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        raise NotImplementedError
+
+
+@dataclass
+class MyEnumWithoutPositionsOption1(MyEnumWithoutPositions):
+    p0: int
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(0)
+        writer.write_u64(self.p0)
+
+
+@dataclass
+class MyEnumWithoutPositionsOption2(MyEnumWithoutPositions):
+    name: str
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(1)
+        writer.write_string(self.name)
+
+
+@dataclass
+class MyEnumWithoutPositionsOption3(MyEnumWithoutPositions):
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(2)
+
+
+@dataclass
+class MyEnumWithoutPositionsOption4(MyEnumWithoutPositions):
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(3)
+
+
+class MyEnumWithManyArguments:
+    @staticmethod
+    def create_buffers_default() -> MyEnumWithManyArguments:
+        return MyEnumWithManyArgumentsOption1(
+            p0=0,
+            p1=0,
+            p2=0,
+        )
+
+    @staticmethod
+    def read_from_buffers(reader: BytesReader) -> MyEnumWithManyArguments:
+        kind = reader.read_u32()
+
+        if kind == 0:
+            return MyEnumWithManyArgumentsOption1(
+                p0=reader.read_u64(),
+                p1=reader.read_u64(),
+                p2=reader.read_u64(),
+            )
+        if kind == 1:
+            return MyEnumWithManyArgumentsOption2(
+                name=reader.read_string(),
+            )
+        if kind == 2:
+            return MyEnumWithManyArgumentsOption3()
+        if kind == 3:
+            return MyEnumWithManyArgumentsOption4()
+
+        raise ValueError("Invalid MyEnumWithManyArguments kind: %d" % kind)
+
+    @staticmethod
+    def skip_in_buffers(reader: BytesReader, count: int) -> None:
+        for _ in range(count):
+            MyEnumWithManyArguments.read_from_buffers(reader)
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        raise NotImplementedError
+
+
+@dataclass
+class MyEnumWithManyArgumentsOption1(MyEnumWithManyArguments):
+    p0: int
+    p1: int
+    p2: int
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(0)
+        writer.write_u64(self.p0)
+        writer.write_u64(self.p1)
+        writer.write_u64(self.p2)
+
+
+@dataclass
+class MyEnumWithManyArgumentsOption2(MyEnumWithManyArguments):
+    name: str
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(1)
+        writer.write_string(self.name)
+
+
+@dataclass
+class MyEnumWithManyArgumentsOption3(MyEnumWithManyArguments):
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(2)
+
+
+@dataclass
+class MyEnumWithManyArgumentsOption4(MyEnumWithManyArguments):
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(3)
+
+
+class MyEnumWithNamedArguments:
+    @staticmethod
+    def create_buffers_default() -> MyEnumWithNamedArguments:
+        return MyEnumWithNamedArgumentsOption1(
+            name="",
+            value=0.0,
+            bytes=[],
+        )
+
+    @staticmethod
+    def read_from_buffers(reader: BytesReader) -> MyEnumWithNamedArguments:
+        kind = reader.read_u32()
+
+        if kind == 0:
+            return MyEnumWithNamedArgumentsOption1(
+                name=reader.read_string(),
+                value=reader.read_f32(),
+                bytes=reader.read_vec(lambda: reader.read_u8()),
+            )
+        if kind == 1:
+            return MyEnumWithNamedArgumentsOption2(
+                p0=reader.read_u64(),
+                p1=reader.read_u64(),
+                p2=reader.read_u64(),
+            )
+        if kind == 2:
+            return MyEnumWithNamedArgumentsOption3()
+        if kind == 3:
+            return MyEnumWithNamedArgumentsOption4()
+
+        raise ValueError("Invalid MyEnumWithNamedArguments kind: %d" % kind)
+
+    @staticmethod
+    def skip_in_buffers(reader: BytesReader, count: int) -> None:
+        for _ in range(count):
+            MyEnumWithNamedArguments.read_from_buffers(reader)
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        raise NotImplementedError
+
+
+@dataclass
+class MyEnumWithNamedArgumentsOption1(MyEnumWithNamedArguments):
+    name: str
+    value: float
+    bytes: List[int]
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(0)
+        writer.write_string(self.name)
+        writer.write_f32(self.value)
+        writer.write_vec(self.bytes, lambda item: writer.write_u8(item))
+
+
+@dataclass
+class MyEnumWithNamedArgumentsOption2(MyEnumWithNamedArguments):
+    p0: int
+    p1: int
+    p2: int
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(1)
+        writer.write_u64(self.p0)
+        writer.write_u64(self.p1)
+        writer.write_u64(self.p2)
+
+
+@dataclass
+class MyEnumWithNamedArgumentsOption3(MyEnumWithNamedArguments):
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(2)
+
+
+@dataclass
+class MyEnumWithNamedArgumentsOption4(MyEnumWithNamedArguments):
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u32(3)
@@ -0,0 +1,26 @@
+class ExamplesStreamRpc:
+    SCOPE_ID: Final[str] = "723ca727-6a66-43a7-bfcc-b8ad94eac9be"
+
+    def __init__(self, runtime: RpcRuntime) -> None:
+        self.runtime = runtime
+
+    def consume_counter(self) -> Optional[int]:
+        data = self.runtime.consume(ExamplesStreamRpc.SCOPE_ID, 0)
+
+        if data is None:
+            return None
+
+        reader = BytesReader(data)
+        return reader.read_i32()
+
+    def consume_theme(self) -> Optional[str]:
+        data = self.runtime.consume(ExamplesStreamRpc.SCOPE_ID, 1)
+
+        if data is None:
+            return None
+
+        reader = BytesReader(data)
+        return reader.read_string()
+
+    def consume_trigger(self) -> bool:
+        return self.runtime.consume(ExamplesStreamRpc.SCOPE_ID, 2) is not None
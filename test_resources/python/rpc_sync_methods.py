@@ -0,0 +1,27 @@
+class ExamplesSyncRpc:
+    SCOPE_ID: Final[str] = "4de616f8-12c5-4d2c-8d48-9c5fb038991f"
+
+    def __init__(self, runtime: RpcRuntime) -> None:
+        self.runtime = runtime
+
+    def print_hello_world(self) -> None:
+        self.runtime.call(ExamplesSyncRpc.SCOPE_ID, 0, b"")
+
+    def hello_world(self) -> str:
+        reader = BytesReader(self.runtime.call(ExamplesSyncRpc.SCOPE_ID, 1, b""))
+        return reader.read_string()
+
+    def say_hello(self, first_name: str, last_name: str) -> str:
+        writer = BytesWriter()
+        writer.write_string(first_name)
+        writer.write_string(last_name)
+        reader = BytesReader(self.runtime.call(ExamplesSyncRpc.SCOPE_ID, 2, writer.to_bytes()))
+        return reader.read_string()
+
+    def sum(self, a: int, b: float, c: float) -> float:
+        writer = BytesWriter()
+        writer.write_i32(a)
+        writer.write_f32(b)
+        writer.write_f64(c)
+        reader = BytesReader(self.runtime.call(ExamplesSyncRpc.SCOPE_ID, 3, writer.to_bytes()))
+        return reader.read_f64()
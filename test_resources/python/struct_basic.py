@@ -0,0 +1,55 @@
+@dataclass
+class ViewData:
+    delta_time: float
+    view_width: float
+    view_height: float
+    touch_start_x: float
+    touch_start_y: float
+    last_touch_x: float
+    last_touch_y: float
+    touch_x: float
+    touch_y: float
+
+    @staticmethod
+    def create_buffers_default() -> ViewData:
+        return ViewData(
+            delta_time=0.0,
+            view_width=0.0,
+            view_height=0.0,
+            touch_start_x=0.0,
+            touch_start_y=0.0,
+            last_touch_x=0.0,
+            last_touch_y=0.0,
+            touch_x=0.0,
+            touch_y=0.0,
+        )
+
+    @staticmethod
+    def read_from_buffers(reader: BytesReader) -> ViewData:
+        return ViewData(
+            delta_time=reader.read_f32(),
+            view_width=reader.read_f32(),
+            view_height=reader.read_f32(),
+            touch_start_x=reader.read_f32(),
+            touch_start_y=reader.read_f32(),
+            last_touch_x=reader.read_f32(),
+            last_touch_y=reader.read_f32(),
+            touch_x=reader.read_f32(),
+            touch_y=reader.read_f32(),
+        )
+
+    @staticmethod
+    def skip_in_buffers(reader: BytesReader, count: int) -> None:
+        for _ in range(count):
+            ViewData.read_from_buffers(reader)
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_f32(self.delta_time)
+        writer.write_f32(self.view_width)
+        writer.write_f32(self.view_height)
+        writer.write_f32(self.touch_start_x)
+        writer.write_f32(self.touch_start_y)
+        writer.write_f32(self.last_touch_x)
+        writer.write_f32(self.last_touch_y)
+        writer.write_f32(self.touch_x)
+        writer.write_f32(self.touch_y)
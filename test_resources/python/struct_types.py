@@ -0,0 +1,75 @@
+@dataclass
+class BasicTypesModel:
+    byte: int
+    some_integer: int
+    some_long: int
+    some_unsiged_integer: int
+    some_unsiged_long: int
+    some_float_number: float
+    some_double_number: float
+    some_bool: bool
+    some_string: str
+    vector: List[str]
+    generic: LinearTable
+    custom: MyModel
+    optional_string: Optional[str]
+    optional_f32: Optional[float]
+
+    @staticmethod
+    def create_buffers_default() -> BasicTypesModel:
+        return BasicTypesModel(
+            byte=0,
+            some_integer=0,
+            some_long=0,
+            some_unsiged_integer=0,
+            some_unsiged_long=0,
+            some_float_number=0.0,
+            some_double_number=0.0,
+            some_bool=False,
+            some_string="",
+            vector=[],
+            generic=LinearTable.create_buffers_default(),
+            custom=MyModel.create_buffers_default(),
+            optional_string=None,
+            optional_f32=None,
+        )
+
+    @staticmethod
+    def read_from_buffers(reader: BytesReader) -> BasicTypesModel:
+        return BasicTypesModel(
+            byte=reader.read_u8(),
+            some_integer=reader.read_i32(),
+            some_long=reader.read_i64(),
+            some_unsiged_integer=reader.read_u32(),
+            some_unsiged_long=reader.read_u32(),
+            some_float_number=reader.read_f32(),
+            some_double_number=reader.read_f64(),
+            some_bool=reader.read_bool(),
+            some_string=reader.read_string(),
+            vector=reader.read_vec(lambda: reader.read_string()),
+            generic=LinearTable.read_from_buffers(reader),
+            custom=MyModel.read_from_buffers(reader),
+            optional_string=reader.read_option(lambda: reader.read_string()),
+            optional_f32=reader.read_option(lambda: reader.read_f32()),
+        )
+
+    @staticmethod
+    def skip_in_buffers(reader: BytesReader, count: int) -> None:
+        for _ in range(count):
+            BasicTypesModel.read_from_buffers(reader)
+
+    def write_to_buffers(self, writer: BytesWriter) -> None:
+        writer.write_u8(self.byte)
+        writer.write_i32(self.some_integer)
+        writer.write_i64(self.some_long)
+        writer.write_u32(self.some_unsiged_integer)
+        writer.write_u32(self.some_unsiged_long)
+        writer.write_f32(self.some_float_number)
+        writer.write_f64(self.some_double_number)
+        writer.write_bool(self.some_bool)
+        writer.write_string(self.some_string)
+        writer.write_vec(self.vector, lambda item: writer.write_string(item))
+        self.generic.write_to_buffers(writer)
+        self.custom.write_to_buffers(writer)
+        writer.write_option(self.optional_string, lambda value: writer.write_string(value))
+        writer.write_option(self.optional_f32, lambda value: writer.write_f32(value))
@@ -0,0 +1,443 @@
+// GENERATED, DO NOT EDIT
+
+#![allow(warnings)]
+#![allow(clippy)]
+#![allow(unknown_lints)]
+
+use tech_paws_buffers::memory::{BytesReader, BytesWriter, TechPawsBuffersModel};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Empty;
+
+impl Default for Empty {
+    fn default() -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Default for Point {
+    fn default() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shape {
+    pub name: String,
+    pub points: Vec<Point>,
+    pub label: Option<String>,
+    pub flags: Vec<bool>,
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self {
+            name: String::default(),
+            points: Vec::<Point>::default(),
+            label: Option::<String>::default(),
+            flags: Vec::<bool>::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    pub table: LinearTable<f32, Point>,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self {
+            table: LinearTable::<f32, Point>::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Idle,
+    Move {
+        to: Point,
+        speed: i64,
+    },
+    Rename(
+        String,
+        char,
+    ),
+    Nested(
+        Vec<Command>,
+    ),
+}
+
+impl Default for Command {
+    fn default() -> Self {
+        Self::Idle
+    }
+}
+
+impl TechPawsBuffersModel for Empty {
+    fn read_from_buffers(_: &mut BytesReader) -> Self {
+        Empty
+    }
+
+    fn write_to_buffers(&self, _: &mut BytesWriter) {}
+
+    fn skip_in_buffers(_: &mut BytesReader, _: u64) {}
+}
+
+impl TechPawsBuffersModel for Point {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        Self {
+            x: bytes_reader.read_f32(),
+            y: bytes_reader.read_f32(),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        bytes_writer.write_f32(self.x);
+        bytes_writer.write_f32(self.y);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            bytes_reader.read_f32();
+            bytes_reader.read_f32();
+        }
+    }
+}
+
+impl TechPawsBuffersModel for Shape {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        Self {
+            name: String::read_from_buffers(bytes_reader),
+            points: Vec::<Point>::read_from_buffers(bytes_reader),
+            label: Option::<String>::read_from_buffers(bytes_reader),
+            flags: Vec::<bool>::read_from_buffers(bytes_reader),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        self.name.write_to_buffers(bytes_writer);
+        self.points.write_to_buffers(bytes_writer);
+        self.label.write_to_buffers(bytes_writer);
+        self.flags.write_to_buffers(bytes_writer);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            String::read_from_buffers(bytes_reader);
+            Vec::<Point>::read_from_buffers(bytes_reader);
+            Option::<String>::read_from_buffers(bytes_reader);
+            Vec::<bool>::read_from_buffers(bytes_reader);
+        }
+    }
+}
+
+impl TechPawsBuffersModel for Table {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        Self {
+            table: LinearTable::<f32, Point>::read_from_buffers(bytes_reader),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        self.table.write_to_buffers(bytes_writer);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            LinearTable::<f32, Point>::read_from_buffers(bytes_reader);
+        }
+    }
+}
+
+impl TechPawsBuffersModel for Command {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        let value = bytes_reader.read_u32();
+
+        match value {
+            0 => return Command::Idle,
+            3 => return Command::Move {
+                to: Point::read_from_buffers(bytes_reader),
+                speed: bytes_reader.read_i64(),
+            },
+            2 => return Command::Rename(
+                String::read_from_buffers(bytes_reader),
+                bytes_reader.read_char(),
+            ),
+            4 => return Command::Nested(
+                Vec::<Command>::read_from_buffers(bytes_reader),
+            ),
+            _ => panic!("Unsupported enum value: {}", value),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        match self {
+            Command::Idle => {
+                bytes_writer.write_u32(0);
+            },
+            Command::Move {
+                to,
+                speed,
+            } => {
+                bytes_writer.write_u32(3);
+                to.write_to_buffers(bytes_writer);
+                bytes_writer.write_i64(*speed);
+            },
+            Command::Rename(
+                v0,
+                v1,
+            ) => {
+                bytes_writer.write_u32(2);
+                v0.write_to_buffers(bytes_writer);
+                bytes_writer.write_char(*v1);
+            },
+            Command::Nested(
+                v0,
+            ) => {
+                bytes_writer.write_u32(4);
+                v0.write_to_buffers(bytes_writer);
+            },
+        }
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            let value = bytes_reader.read_u32();
+
+            match value {
+                0 => (),
+                3 => {
+                    Point::read_from_buffers(bytes_reader);
+                    bytes_reader.read_i64();
+                },
+                2 => {
+                    String::read_from_buffers(bytes_reader);
+                    bytes_reader.read_char();
+                },
+                4 => {
+                    Vec::<Command>::read_from_buffers(bytes_reader);
+                },
+                _ => panic!("Unsupported enum value: {}", value),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod buffers_tests {
+    use super::*;
+
+    const ROUND_TRIP_ITERATIONS: usize = 64;
+    const MAX_DEPTH: usize = 3;
+    const END_MARKER: u32 = 0xC0FFEE;
+
+    struct Rng(u64);
+
+    impl Rng {
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^ (z >> 31)
+        }
+
+        fn next_below(&mut self, bound: u64) -> u64 {
+            self.next_u64() % bound
+        }
+    }
+
+    trait Arbitrary {
+        fn arbitrary(rng: &mut Rng, depth: usize) -> Self;
+    }
+
+    impl Arbitrary for u8 {
+        fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+            rng.next_u64() as u8
+        }
+    }
+
+    impl Arbitrary for i8 {
+        fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+            rng.next_u64() as i8
+        }
+    }
+
+    impl Arbitrary for u32 {
+        fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+            rng.next_u64() as u32
+        }
+    }
+
+    impl Arbitrary for i32 {
+        fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+            rng.next_u64() as i32
+        }
+    }
+
+    impl Arbitrary for u64 {
+        fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+            rng.next_u64()
+        }
+    }
+
+    impl Arbitrary for i64 {
+        fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+            rng.next_u64() as i64
+        }
+    }
+
+    impl Arbitrary for f32 {
+        fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+            (rng.next_below(1 << 24) as f32 - 8388608.0) / 256.0
+        }
+    }
+
+    impl Arbitrary for f64 {
+        fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+            (rng.next_below(1 << 53) as f64 - 4503599627370496.0) / 1048576.0
+        }
+    }
+
+    impl Arbitrary for bool {
+        fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+            rng.next_u64() & 1 == 1
+        }
+    }
+
+    impl Arbitrary for char {
+        fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+            char::from_u32(rng.next_below(0xD800) as u32).unwrap()
+        }
+    }
+
+    impl Arbitrary for String {
+        fn arbitrary(rng: &mut Rng, depth: usize) -> Self {
+            (0..rng.next_below(8)).map(|_| char::arbitrary(rng, depth)).collect()
+        }
+    }
+
+    impl<T: Arbitrary> Arbitrary for Vec<T> {
+        fn arbitrary(rng: &mut Rng, depth: usize) -> Self {
+            if depth >= MAX_DEPTH {
+                return vec![];
+            }
+
+            (0..rng.next_below(4)).map(|_| T::arbitrary(rng, depth + 1)).collect()
+        }
+    }
+
+    impl<T: Arbitrary> Arbitrary for Option<T> {
+        fn arbitrary(rng: &mut Rng, depth: usize) -> Self {
+            if depth >= MAX_DEPTH || rng.next_u64() & 1 == 0 {
+                None
+            } else {
+                Some(T::arbitrary(rng, depth + 1))
+            }
+        }
+    }
+
+    impl Arbitrary for Empty {
+        fn arbitrary(_: &mut Rng, _: usize) -> Self {
+            Self
+        }
+    }
+
+    impl Arbitrary for Point {
+        fn arbitrary(rng: &mut Rng, depth: usize) -> Self {
+            Self {
+                x: Arbitrary::arbitrary(rng, depth + 1),
+                y: Arbitrary::arbitrary(rng, depth + 1),
+            }
+        }
+    }
+
+    impl Arbitrary for Shape {
+        fn arbitrary(rng: &mut Rng, depth: usize) -> Self {
+            Self {
+                name: Arbitrary::arbitrary(rng, depth + 1),
+                points: Arbitrary::arbitrary(rng, depth + 1),
+                label: Arbitrary::arbitrary(rng, depth + 1),
+                flags: Arbitrary::arbitrary(rng, depth + 1),
+            }
+        }
+    }
+
+    impl Arbitrary for Command {
+        fn arbitrary(rng: &mut Rng, depth: usize) -> Self {
+            match rng.next_below(4) {
+                0 => Self::Idle,
+                1 => Self::Move {
+                    to: Arbitrary::arbitrary(rng, depth + 1),
+                    speed: Arbitrary::arbitrary(rng, depth + 1),
+                },
+                2 => Self::Rename(
+                    Arbitrary::arbitrary(rng, depth + 1),
+                    Arbitrary::arbitrary(rng, depth + 1),
+                ),
+                _ => Self::Nested(
+                    Arbitrary::arbitrary(rng, depth + 1),
+                ),
+            }
+        }
+    }
+
+    fn check_round_trip<T: Arbitrary + TechPawsBuffersModel + PartialEq + std::fmt::Debug>() {
+        let mut rng = Rng(0);
+
+        for _ in 0..ROUND_TRIP_ITERATIONS {
+            let first = T::arbitrary(&mut rng, 0);
+            let second = T::arbitrary(&mut rng, 0);
+
+            let mut bytes_writer = BytesWriter::default();
+            first.write_to_buffers(&mut bytes_writer);
+            second.write_to_buffers(&mut bytes_writer);
+            bytes_writer.write_u32(END_MARKER);
+            let data = bytes_writer.buffer;
+
+            let mut bytes_reader = BytesReader::new(&data);
+            assert_eq!(T::read_from_buffers(&mut bytes_reader), first);
+            assert_eq!(T::read_from_buffers(&mut bytes_reader), second);
+            assert_eq!(bytes_reader.read_u32(), END_MARKER, "read_from_buffers stopped at a wrong offset");
+
+            let mut bytes_reader = BytesReader::new(&data);
+            T::skip_in_buffers(&mut bytes_reader, 1);
+            assert_eq!(T::read_from_buffers(&mut bytes_reader), second, "skip_in_buffers stopped at a wrong offset");
+
+            let mut bytes_reader = BytesReader::new(&data);
+            T::skip_in_buffers(&mut bytes_reader, 2);
+            assert_eq!(bytes_reader.read_u32(), END_MARKER, "skip_in_buffers stopped at a wrong offset");
+        }
+    }
+
+    #[test]
+    fn empty_round_trip() {
+        check_round_trip::<Empty>();
+    }
+
+    #[test]
+    fn point_round_trip() {
+        check_round_trip::<Point>();
+    }
+
+    #[test]
+    fn shape_round_trip() {
+        check_round_trip::<Shape>();
+    }
+
+    #[test]
+    fn command_round_trip() {
+        check_round_trip::<Command>();
+    }
+}
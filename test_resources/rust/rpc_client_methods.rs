@@ -0,0 +1,224 @@
+pub fn register_rpc(runtime: &mut TechPawsBuffersRuntime) {
+    let scope_id = TechPawsScopeId(uuid!("4de616f8-12c5-4d2c-8d48-9c5fb038991f"));
+    runtime.memory.add_scope(scope_id);
+    runtime.register_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(0),
+            handler: print_hello_world_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Zero,
+    );
+    runtime.register_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(1),
+            handler: say_hello_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Medium,
+    );
+    runtime.register_signal_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(2),
+            handler: counter_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Medium,
+    );
+    runtime.register_signal_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(3),
+            handler: trigger_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Zero,
+    );
+}
+
+pub fn print_hello_world_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    print_hello_world();
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct __say_hello_rpc_args__ {
+    pub first_name: String,
+    pub last_name: String,
+}
+
+impl TechPawsBuffersModel for __say_hello_rpc_args__ {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        Self {
+            first_name: String::read_from_buffers(bytes_reader),
+            last_name: String::read_from_buffers(bytes_reader),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        self.first_name.write_to_buffers(bytes_writer);
+        self.last_name.write_to_buffers(bytes_writer);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            String::read_from_buffers(bytes_reader);
+            String::read_from_buffers(bytes_reader);
+        }
+    }
+}
+
+pub fn say_hello_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    let args = memory.get_scope_mut(scope_id).rpc_buffer_read(
+        rpc_method_address,
+        TechPawsRuntimeRpcMethodBuffer::Server,
+        |bytes_reader| __say_hello_rpc_args__::read_from_buffers(bytes_reader),
+    );
+
+    let result = say_hello(
+        args.first_name,
+        args.last_name,
+    );
+
+    memory.get_scope_mut(scope_id).rpc_buffer_write(
+        rpc_method_address,
+        TechPawsRuntimeRpcMethodBuffer::Client,
+        |bytes_writer| {
+            result.write_to_buffers(bytes_writer);
+        },
+    );
+}
+
+pub fn counter_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    let result = counter();
+
+    if let TechPawsSignalRpcResult::Data(result) = result {
+        memory.get_scope_mut(scope_id).rpc_buffer_write(
+            rpc_method_address,
+            TechPawsRuntimeRpcMethodBuffer::Client,
+            |bytes_writer| {
+                bytes_writer.write_u8(0xFF);
+                bytes_writer.write_i32(result);
+            },
+        );
+    }
+}
+
+pub fn trigger_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    let result = trigger();
+
+    if result.has_new_data() {
+        memory.get_scope_mut(scope_id).rpc_buffer_write(
+            rpc_method_address,
+            TechPawsRuntimeRpcMethodBuffer::Client,
+            |bytes_writer| {
+                bytes_writer.write_u8(0xFF);
+            },
+        );
+    }
+}
+
+pub struct ExamplesClientRpc;
+
+impl ExamplesClientRpc {
+    pub const SCOPE_ID: TechPawsScopeId = TechPawsScopeId(uuid!("4de616f8-12c5-4d2c-8d48-9c5fb038991f"));
+
+    pub fn print_hello_world(runtime: &mut TechPawsBuffersRuntime) {
+        print_hello_world_rpc_handler(
+            Self::SCOPE_ID,
+            &mut runtime.memory,
+            RpcMethodAddress(0),
+        );
+    }
+
+    pub fn say_hello(
+        runtime: &mut TechPawsBuffersRuntime,
+        first_name: String,
+        last_name: String,
+    ) -> String {
+        let args = __say_hello_rpc_args__ {
+            first_name,
+            last_name,
+        };
+
+        runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_write(
+            RpcMethodAddress(1),
+            TechPawsRuntimeRpcMethodBuffer::Server,
+            |bytes_writer| {
+                args.write_to_buffers(bytes_writer);
+            },
+        );
+
+        say_hello_rpc_handler(
+            Self::SCOPE_ID,
+            &mut runtime.memory,
+            RpcMethodAddress(1),
+        );
+
+        runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_read(
+            RpcMethodAddress(1),
+            TechPawsRuntimeRpcMethodBuffer::Client,
+            |bytes_reader| String::read_from_buffers(bytes_reader),
+        )
+    }
+
+    pub fn consume_counter(runtime: &mut TechPawsBuffersRuntime) -> Option<i32> {
+        let result = runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_read(
+            RpcMethodAddress(2),
+            TechPawsRuntimeRpcMethodBuffer::Client,
+            |bytes_reader| {
+                if bytes_reader.read_u8() != 0xFF {
+                    return None;
+                }
+
+                Some(bytes_reader.read_i32())
+            },
+        );
+
+        if result.is_some() {
+            runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_write(
+                RpcMethodAddress(2),
+                TechPawsRuntimeRpcMethodBuffer::Client,
+                |bytes_writer| {
+                    bytes_writer.write_u8(0x00);
+                },
+            );
+        }
+
+        result
+    }
+
+    pub fn consume_trigger(runtime: &mut TechPawsBuffersRuntime) -> bool {
+        let result = runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_read(
+            RpcMethodAddress(3),
+            TechPawsRuntimeRpcMethodBuffer::Client,
+            |bytes_reader| bytes_reader.read_u8() == 0xFF,
+        );
+
+        if result {
+            runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_write(
+                RpcMethodAddress(3),
+                TechPawsRuntimeRpcMethodBuffer::Client,
+                |bytes_writer| {
+                    bytes_writer.write_u8(0x00);
+                },
+            );
+        }
+
+        result
+    }
+}
@@ -0,0 +1,126 @@
+pub fn register_rpc(runtime: &mut TechPawsBuffersRuntime) {
+    let scope_id = TechPawsScopeId(uuid!("0f4c3d2e-5a7b-4c1d-9e8f-6a5b4c3d2e1f"));
+    runtime.memory.add_scope(scope_id);
+    runtime.register_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(0),
+            handler: add_points_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Large,
+    );
+    runtime.register_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(1),
+            handler: polygon_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Large,
+    );
+    runtime.register_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(2),
+            handler: description_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Large,
+    );
+    runtime.register_signal_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(3),
+            handler: points_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Large,
+    );
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct __add_points_rpc_args__ {
+    pub points: Vec<Point>,
+}
+
+impl TechPawsBuffersModel for __add_points_rpc_args__ {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        Self {
+            points: Vec::<Point>::read_from_buffers(bytes_reader),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        self.points.write_to_buffers(bytes_writer);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            Vec::<Point>::read_from_buffers(bytes_reader);
+        }
+    }
+}
+
+pub fn add_points_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    let args = memory.get_scope_mut(scope_id).rpc_buffer_read(
+        rpc_method_address,
+        TechPawsRuntimeRpcMethodBuffer::Server,
+        |bytes_reader| __add_points_rpc_args__::read_from_buffers(bytes_reader),
+    );
+
+    add_points(
+        args.points,
+    );
+}
+
+pub fn polygon_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    let result = polygon();
+
+    memory.get_scope_mut(scope_id).rpc_buffer_write(
+        rpc_method_address,
+        TechPawsRuntimeRpcMethodBuffer::Client,
+        |bytes_writer| {
+            result.write_to_buffers(bytes_writer);
+        },
+    );
+}
+
+pub fn description_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    let result = description();
+
+    memory.get_scope_mut(scope_id).rpc_buffer_write(
+        rpc_method_address,
+        TechPawsRuntimeRpcMethodBuffer::Client,
+        |bytes_writer| {
+            result.write_to_buffers(bytes_writer);
+        },
+    );
+}
+
+pub fn points_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    let result = points();
+
+    if let TechPawsSignalRpcResult::Data(result) = result {
+        memory.get_scope_mut(scope_id).rpc_buffer_write(
+            rpc_method_address,
+            TechPawsRuntimeRpcMethodBuffer::Client,
+            |bytes_writer| {
+                bytes_writer.write_u8(0xFF);
+                result.write_to_buffers(bytes_writer);
+            },
+        );
+    }
+}
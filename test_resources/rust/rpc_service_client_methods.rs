@@ -0,0 +1,218 @@
+pub trait ExamplesService: Send + Sync {
+    fn print_hello_world(&self);
+
+    fn say_hello(&self, first_name: String, last_name: String) -> String;
+
+    fn counter(&self) -> TechPawsSignalRpcResult<i32>;
+}
+
+/// Services passed to `register_rpc`, keyed by the address of the runtime
+/// memory, so handlers can stay plain functions and every runtime calls
+/// its own service.
+static SERVICES: std::sync::Mutex<Vec<(usize, std::sync::Arc<dyn ExamplesService>)>> =
+    std::sync::Mutex::new(Vec::new());
+
+fn registered_service(memory: &TechPawsRuntimeMemory) -> std::sync::Arc<dyn ExamplesService> {
+    let key = memory as *const TechPawsRuntimeMemory as usize;
+    let services = SERVICES.lock().unwrap();
+
+    services
+        .iter()
+        .find(|(service_key, _)| *service_key == key)
+        .map(|(_, service)| service.clone())
+        .expect("ExamplesService is not registered with this runtime")
+}
+
+pub fn register_rpc(
+    runtime: &mut TechPawsBuffersRuntime,
+    service: std::sync::Arc<dyn ExamplesService>,
+) {
+    let key = &runtime.memory as *const TechPawsRuntimeMemory as usize;
+    let mut services = SERVICES.lock().unwrap();
+    services.retain(|(service_key, _)| *service_key != key);
+    services.push((key, service));
+    drop(services);
+
+    let scope_id = TechPawsScopeId(uuid!("4de616f8-12c5-4d2c-8d48-9c5fb038991f"));
+    runtime.memory.add_scope(scope_id);
+    runtime.register_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(0),
+            handler: print_hello_world_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Zero,
+    );
+    runtime.register_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(1),
+            handler: say_hello_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Medium,
+    );
+    runtime.register_signal_rpc_method(
+        TechPawsRpcMethod {
+            scope_id,
+            rpc_method_address: RpcMethodAddress(2),
+            handler: counter_rpc_handler,
+        },
+        TechPawsRuntimeRpcMethodPayloadSize::Medium,
+    );
+}
+
+pub fn print_hello_world_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    let service = registered_service(memory);
+
+    service.print_hello_world();
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct __say_hello_rpc_args__ {
+    pub first_name: String,
+    pub last_name: String,
+}
+
+impl TechPawsBuffersModel for __say_hello_rpc_args__ {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        Self {
+            first_name: String::read_from_buffers(bytes_reader),
+            last_name: String::read_from_buffers(bytes_reader),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        self.first_name.write_to_buffers(bytes_writer);
+        self.last_name.write_to_buffers(bytes_writer);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            String::read_from_buffers(bytes_reader);
+            String::read_from_buffers(bytes_reader);
+        }
+    }
+}
+
+pub fn say_hello_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    let service = registered_service(memory);
+
+    let args = memory.get_scope_mut(scope_id).rpc_buffer_read(
+        rpc_method_address,
+        TechPawsRuntimeRpcMethodBuffer::Server,
+        |bytes_reader| __say_hello_rpc_args__::read_from_buffers(bytes_reader),
+    );
+
+    let result = service.say_hello(
+        args.first_name,
+        args.last_name,
+    );
+
+    memory.get_scope_mut(scope_id).rpc_buffer_write(
+        rpc_method_address,
+        TechPawsRuntimeRpcMethodBuffer::Client,
+        |bytes_writer| {
+            result.write_to_buffers(bytes_writer);
+        },
+    );
+}
+
+pub fn counter_rpc_handler(
+    scope_id: TechPawsScopeId,
+    memory: &mut TechPawsRuntimeMemory,
+    rpc_method_address: RpcMethodAddress,
+) {
+    let service = registered_service(memory);
+
+    let result = service.counter();
+
+    if let TechPawsSignalRpcResult::Data(result) = result {
+        memory.get_scope_mut(scope_id).rpc_buffer_write(
+            rpc_method_address,
+            TechPawsRuntimeRpcMethodBuffer::Client,
+            |bytes_writer| {
+                bytes_writer.write_u8(0xFF);
+                bytes_writer.write_i32(result);
+            },
+        );
+    }
+}
+
+pub struct ExamplesRpc;
+
+impl ExamplesRpc {
+    pub const SCOPE_ID: TechPawsScopeId = TechPawsScopeId(uuid!("4de616f8-12c5-4d2c-8d48-9c5fb038991f"));
+
+    pub fn print_hello_world(runtime: &mut TechPawsBuffersRuntime) {
+        print_hello_world_rpc_handler(
+            Self::SCOPE_ID,
+            &mut runtime.memory,
+            RpcMethodAddress(0),
+        );
+    }
+
+    pub fn say_hello(
+        runtime: &mut TechPawsBuffersRuntime,
+        first_name: String,
+        last_name: String,
+    ) -> String {
+        let args = __say_hello_rpc_args__ {
+            first_name,
+            last_name,
+        };
+
+        runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_write(
+            RpcMethodAddress(1),
+            TechPawsRuntimeRpcMethodBuffer::Server,
+            |bytes_writer| {
+                args.write_to_buffers(bytes_writer);
+            },
+        );
+
+        say_hello_rpc_handler(
+            Self::SCOPE_ID,
+            &mut runtime.memory,
+            RpcMethodAddress(1),
+        );
+
+        runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_read(
+            RpcMethodAddress(1),
+            TechPawsRuntimeRpcMethodBuffer::Client,
+            |bytes_reader| String::read_from_buffers(bytes_reader),
+        )
+    }
+
+    pub fn consume_counter(runtime: &mut TechPawsBuffersRuntime) -> Option<i32> {
+        let result = runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_read(
+            RpcMethodAddress(2),
+            TechPawsRuntimeRpcMethodBuffer::Client,
+            |bytes_reader| {
+                if bytes_reader.read_u8() != 0xFF {
+                    return None;
+                }
+
+                Some(bytes_reader.read_i32())
+            },
+        );
+
+        if result.is_some() {
+            runtime.memory.get_scope_mut(Self::SCOPE_ID).rpc_buffer_write(
+                RpcMethodAddress(2),
+                TechPawsRuntimeRpcMethodBuffer::Client,
+                |bytes_writer| {
+                    bytes_writer.write_u8(0x00);
+                },
+            );
+        }
+
+        result
+    }
+}
@@ -0,0 +1,53 @@
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[non_exhaustive]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+    pub is_visible: bool,
+}
+
+impl Default for Point {
+    fn default() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            is_visible: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub struct Empty;
+
+impl Default for Empty {
+    fn default() -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "value", rename_all_fields = "camelCase")]
+#[allow(clippy::derive_partial_eq_without_eq)]
+pub enum Command {
+    Idle,
+    Move(
+        Point,
+    ),
+    Resize(
+        u32,
+        u32,
+    ),
+    SetTitle {
+        title: String,
+        is_modal: bool,
+    },
+}
+
+impl Default for Command {
+    fn default() -> Self {
+        Self::Idle
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (c) Tech Paws
+// GENERATED, DO NOT EDIT
+
+pub mod models {
+  use tech_paws_buffers::memory::{BytesReader, BytesWriter, TechPawsBuffersModel};
+  use std::fmt;
+
+  #[derive(Debug, Clone, PartialEq, Eq)]
+  pub struct ViewData {
+    pub delta_time: f32,
+    pub view_width: f32,
+    pub view_height: f32,
+    pub touch_start_x: f32,
+    pub touch_start_y: f32,
+    pub last_touch_x: f32,
+    pub last_touch_y: f32,
+    pub touch_x: f32,
+    pub touch_y: f32,
+  }
+
+  impl Default for ViewData {
+    fn default() -> Self {
+      Self {
+        delta_time: 0.0,
+        view_width: 0.0,
+        view_height: 0.0,
+        touch_start_x: 0.0,
+        touch_start_y: 0.0,
+        last_touch_x: 0.0,
+        last_touch_y: 0.0,
+        touch_x: 0.0,
+        touch_y: 0.0,
+      }
+    }
+  }
+
+  impl TechPawsBuffersModel for ViewData {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+      Self {
+        delta_time: bytes_reader.read_f32(),
+        view_width: bytes_reader.read_f32(),
+        view_height: bytes_reader.read_f32(),
+        touch_start_x: bytes_reader.read_f32(),
+        touch_start_y: bytes_reader.read_f32(),
+        last_touch_x: bytes_reader.read_f32(),
+        last_touch_y: bytes_reader.read_f32(),
+        touch_x: bytes_reader.read_f32(),
+        touch_y: bytes_reader.read_f32(),
+      }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+      bytes_writer.write_f32(self.delta_time);
+      bytes_writer.write_f32(self.view_width);
+      bytes_writer.write_f32(self.view_height);
+      bytes_writer.write_f32(self.touch_start_x);
+      bytes_writer.write_f32(self.touch_start_y);
+      bytes_writer.write_f32(self.last_touch_x);
+      bytes_writer.write_f32(self.last_touch_y);
+      bytes_writer.write_f32(self.touch_x);
+      bytes_writer.write_f32(self.touch_y);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+      for _ in 0..count {
+        bytes_reader.read_f32();
+        bytes_reader.read_f32();
+        bytes_reader.read_f32();
+        bytes_reader.read_f32();
+        bytes_reader.read_f32();
+        bytes_reader.read_f32();
+        bytes_reader.read_f32();
+        bytes_reader.read_f32();
+        bytes_reader.read_f32();
+      }
+    }
+  }
+}
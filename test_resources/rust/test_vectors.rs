@@ -0,0 +1,165 @@
+// GENERATED, DO NOT EDIT
+
+use super::*;
+use tech_paws_buffers::memory::{BytesReader, BytesWriter, TechPawsBuffersModel};
+
+fn read_vector<T: TechPawsBuffersModel>(data: &[u8]) -> T {
+    let mut bytes_reader = BytesReader::new(data);
+    T::read_from_buffers(&mut bytes_reader)
+}
+
+fn write_vector<T: TechPawsBuffersModel>(value: &T) -> Vec<u8> {
+    let mut bytes_writer = BytesWriter::default();
+    value.write_to_buffers(&mut bytes_writer);
+    bytes_writer.buffer
+}
+
+#[test]
+fn point_default() {
+    let data: &[u8] = &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    let expected = Point { x: 0.0, y: 0.0 };
+    let value = read_vector::<Point>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn point_min() {
+    let data: &[u8] = &[0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF];
+    let expected = Point { x: -3.4028235e38, y: -3.4028235e38 };
+    let value = read_vector::<Point>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn point_max() {
+    let data: &[u8] = &[0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F];
+    let expected = Point { x: 3.4028235e38, y: 3.4028235e38 };
+    let value = read_vector::<Point>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn point_random_0() {
+    let data: &[u8] = &[0xA2, 0x64, 0xC4, 0xC6, 0xD0, 0x97, 0x65, 0x46];
+    let expected = Point { x: -25138.316, y: 14693.953 };
+    let value = read_vector::<Point>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn labels_default() {
+    let data: &[u8] = &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    let expected = Labels { id: 0, delta: 0, visible: false, title: String::from(""), points: vec![], note: None };
+    let value = read_vector::<Labels>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn labels_min() {
+    let data: &[u8] = &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    let expected = Labels { id: 0, delta: -128, visible: false, title: String::from(""), points: vec![], note: None };
+    let value = read_vector::<Labels>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn labels_max() {
+    let data: &[u8] = &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, 0x01, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x9C, 0x6E, 0xC3, 0xAF, 0x63, 0xC3, 0xB8, 0x64, 0xC3, 0xA9, 0x20, 0xE2, 0x9C, 0x93, 0x20, 0x22, 0x71, 0x75, 0x6F, 0x74, 0x65, 0x64, 0x22, 0x20, 0x5C, 0x20, 0x0A, 0xE4, 0xB8, 0xAD, 0xE6, 0x96, 0x87, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0x01, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x9C, 0x6E, 0xC3, 0xAF, 0x63, 0xC3, 0xB8, 0x64, 0xC3, 0xA9, 0x20, 0xE2, 0x9C, 0x93, 0x20, 0x22, 0x71, 0x75, 0x6F, 0x74, 0x65, 0x64, 0x22, 0x20, 0x5C, 0x20, 0x0A, 0xE4, 0xB8, 0xAD, 0xE6, 0x96, 0x87];
+    let expected = Labels { id: 18446744073709551615, delta: 127, visible: true, title: String::from("Ünïcødé ✓ \"quoted\" \\ \n中文"), points: vec![Point { x: 3.4028235e38, y: 3.4028235e38 }, Point { x: 3.4028235e38, y: 3.4028235e38 }], note: Some(String::from("Ünïcødé ✓ \"quoted\" \\ \n中文")) };
+    let value = read_vector::<Labels>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn labels_random_0() {
+    let data: &[u8] = &[0xAF, 0xCD, 0x1D, 0x7B, 0x39, 0xA8, 0x20, 0xE2, 0xF4, 0x01, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x31, 0x5F, 0x5C, 0x61, 0xE2, 0x9C, 0x93, 0x5F, 0x62, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xEC, 0x6D, 0x6F, 0x46, 0xA2, 0xAD, 0x8A, 0xC6, 0x32, 0x9A, 0x98, 0x46, 0xAA, 0xFE, 0xC4, 0xC6, 0x01, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x59, 0xE2, 0x9C, 0x93, 0x62, 0xE4, 0xB8, 0xAD];
+    let expected = Labels { id: 16294208416658607535, delta: -12, visible: true, title: String::from("1_\\a✓_b"), points: vec![Point { x: 15323.48, y: -17750.816 }, Point { x: 19533.098, y: -25215.332 }], note: Some(String::from("\"Y✓b中")) };
+    let value = read_vector::<Labels>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn command_default() {
+    let data: &[u8] = &[0x00, 0x00, 0x00, 0x00];
+    let expected = Command::Idle;
+    let value = read_vector::<Command>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn command_idle() {
+    let data: &[u8] = &[0x00, 0x00, 0x00, 0x00];
+    let expected = Command::Idle;
+    let value = read_vector::<Command>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn command_move_min() {
+    let data: &[u8] = &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF];
+    let expected = Command::Move { speed: -2147483648, to: Point { x: -3.4028235e38, y: -3.4028235e38 } };
+    let value = read_vector::<Command>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn command_move_max() {
+    let data: &[u8] = &[0x01, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F];
+    let expected = Command::Move { speed: 2147483647, to: Point { x: 3.4028235e38, y: 3.4028235e38 } };
+    let value = read_vector::<Command>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn command_rename_min() {
+    let data: &[u8] = &[0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    let expected = Command::Rename(String::from(""));
+    let value = read_vector::<Command>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn command_rename_max() {
+    let data: &[u8] = &[0x02, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x9C, 0x6E, 0xC3, 0xAF, 0x63, 0xC3, 0xB8, 0x64, 0xC3, 0xA9, 0x20, 0xE2, 0x9C, 0x93, 0x20, 0x22, 0x71, 0x75, 0x6F, 0x74, 0x65, 0x64, 0x22, 0x20, 0x5C, 0x20, 0x0A, 0xE4, 0xB8, 0xAD, 0xE6, 0x96, 0x87];
+    let expected = Command::Rename(String::from("Ünïcødé ✓ \"quoted\" \\ \n中文"));
+    let value = read_vector::<Command>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
+
+#[test]
+fn command_random_0() {
+    let data: &[u8] = &[0x01, 0x00, 0x00, 0x00, 0xF4, 0x65, 0xB9, 0xA1, 0x62, 0x75, 0xED, 0xC6, 0x50, 0xF8, 0x4D, 0xC6];
+    let expected = Command::Move { speed: -1581685260, to: Point { x: -30394.691, y: -13182.078 } };
+    let value = read_vector::<Command>(data);
+
+    assert_eq!(value, expected);
+    assert_eq!(write_vector(&value), data);
+}
@@ -0,0 +1,507 @@
+// GENERATED, DO NOT EDIT
+
+#![allow(warnings)]
+#![allow(clippy)]
+#![allow(unknown_lints)]
+
+use tech_paws_buffers::memory::{BytesReader, BytesWriter, TechPawsBuffersModel};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Empty;
+
+impl Default for Empty {
+    fn default() -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Default for Point {
+    fn default() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shape {
+    pub name: String,
+    pub points: Vec<Point>,
+    pub label: Option<String>,
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self {
+            name: String::default(),
+            points: Vec::<Point>::default(),
+            label: Option::<String>::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    pub table: LinearTable<f32, Point>,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self {
+            table: LinearTable::<f32, Point>::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Idle,
+    Move {
+        to: Point,
+        visible: bool,
+    },
+    Rename(
+        String,
+        char,
+    ),
+}
+
+impl Default for Command {
+    fn default() -> Self {
+        Self::Idle
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Leaf(
+        u32,
+    ),
+    Group(
+        Vec<Node>,
+    ),
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Self::Leaf(
+            0,
+        )
+    }
+}
+
+impl TechPawsBuffersModel for Empty {
+    fn read_from_buffers(_: &mut BytesReader) -> Self {
+        Empty
+    }
+
+    fn write_to_buffers(&self, _: &mut BytesWriter) {}
+
+    fn skip_in_buffers(_: &mut BytesReader, _: u64) {}
+}
+
+impl TechPawsBuffersModel for Point {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        Self {
+            x: bytes_reader.read_f32(),
+            y: bytes_reader.read_f32(),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        bytes_writer.write_f32(self.x);
+        bytes_writer.write_f32(self.y);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            bytes_reader.read_f32();
+            bytes_reader.read_f32();
+        }
+    }
+}
+
+impl TechPawsBuffersModel for Shape {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        Self {
+            name: String::read_from_buffers(bytes_reader),
+            points: Vec::<Point>::read_from_buffers(bytes_reader),
+            label: Option::<String>::read_from_buffers(bytes_reader),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        self.name.write_to_buffers(bytes_writer);
+        self.points.write_to_buffers(bytes_writer);
+        self.label.write_to_buffers(bytes_writer);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            String::read_from_buffers(bytes_reader);
+            Vec::<Point>::read_from_buffers(bytes_reader);
+            Option::<String>::read_from_buffers(bytes_reader);
+        }
+    }
+}
+
+impl TechPawsBuffersModel for Table {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        Self {
+            table: LinearTable::<f32, Point>::read_from_buffers(bytes_reader),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        self.table.write_to_buffers(bytes_writer);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            LinearTable::<f32, Point>::read_from_buffers(bytes_reader);
+        }
+    }
+}
+
+impl TechPawsBuffersModel for Command {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        let value = bytes_reader.read_u32();
+
+        match value {
+            1 => return Command::Idle,
+            2 => return Command::Move {
+                to: Point::read_from_buffers(bytes_reader),
+                visible: bytes_reader.read_bool(),
+            },
+            3 => return Command::Rename(
+                String::read_from_buffers(bytes_reader),
+                bytes_reader.read_char(),
+            ),
+            _ => panic!("Unsupported enum value: {}", value),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        match self {
+            Command::Idle => {
+                bytes_writer.write_u32(1);
+            },
+            Command::Move {
+                to,
+                visible,
+            } => {
+                bytes_writer.write_u32(2);
+                to.write_to_buffers(bytes_writer);
+                bytes_writer.write_bool(*visible);
+            },
+            Command::Rename(
+                v0,
+                v1,
+            ) => {
+                bytes_writer.write_u32(3);
+                v0.write_to_buffers(bytes_writer);
+                bytes_writer.write_char(*v1);
+            },
+        }
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            let value = bytes_reader.read_u32();
+
+            match value {
+                1 => (),
+                2 => {
+                    Point::read_from_buffers(bytes_reader);
+                    bytes_reader.read_bool();
+                },
+                3 => {
+                    String::read_from_buffers(bytes_reader);
+                    bytes_reader.read_char();
+                },
+                _ => panic!("Unsupported enum value: {}", value),
+            }
+        }
+    }
+}
+
+impl TechPawsBuffersModel for Node {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        let value = bytes_reader.read_u32();
+
+        match value {
+            0 => return Node::Leaf(
+                bytes_reader.read_u32(),
+            ),
+            1 => return Node::Group(
+                Vec::<Node>::read_from_buffers(bytes_reader),
+            ),
+            _ => panic!("Unsupported enum value: {}", value),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        match self {
+            Node::Leaf(
+                v0,
+            ) => {
+                bytes_writer.write_u32(0);
+                bytes_writer.write_u32(*v0);
+            },
+            Node::Group(
+                v0,
+            ) => {
+                bytes_writer.write_u32(1);
+                v0.write_to_buffers(bytes_writer);
+            },
+        }
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            let value = bytes_reader.read_u32();
+
+            match value {
+                0 => {
+                    bytes_reader.read_u32();
+                },
+                1 => {
+                    Vec::<Node>::read_from_buffers(bytes_reader);
+                },
+                _ => panic!("Unsupported enum value: {}", value),
+            }
+        }
+    }
+}
+
+pub mod try_read {
+    pub const MAX_COLLECTION_LEN: u64 = 4096;
+    pub const MAX_DEPTH: u64 = 32;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum DecodeError {
+        UnexpectedEnd { offset: usize, size: usize },
+        CollectionTooLong { offset: usize, len: u64 },
+        TooDeep { offset: usize },
+        UnknownEnumValue { offset: usize, type_id: &'static str, value: u32 },
+        InvalidBool { offset: usize, value: u8 },
+        InvalidOption { offset: usize, value: u8 },
+        InvalidChar { offset: usize, value: u32 },
+        InvalidUtf8 { offset: usize },
+    }
+
+    pub struct CheckedBytesReader<'a> {
+        data: &'a [u8],
+        offset: usize,
+        depth: u64,
+    }
+
+    impl<'a> CheckedBytesReader<'a> {
+        pub fn new(data: &'a [u8]) -> Self {
+            Self { data, offset: 0, depth: 0 }
+        }
+
+        pub fn offset(&self) -> usize {
+            self.offset
+        }
+
+        pub fn remaining(&self) -> usize {
+            self.data.len() - self.offset
+        }
+
+        pub fn read_bytes(&mut self, size: usize) -> Result<&'a [u8], DecodeError> {
+            if size > self.remaining() {
+                return Err(DecodeError::UnexpectedEnd { offset: self.offset, size });
+            }
+
+            let bytes = &self.data[self.offset..self.offset + size];
+            self.offset += size;
+            Ok(bytes)
+        }
+
+        pub fn read_len(&mut self) -> Result<usize, DecodeError> {
+            let offset = self.offset;
+            let len = u64::try_read_from_buffers(self)?;
+
+            if len > MAX_COLLECTION_LEN {
+                return Err(DecodeError::CollectionTooLong { offset, len });
+            }
+
+            Ok(len as usize)
+        }
+
+        pub fn nested<T>(
+            &mut self,
+            read: impl FnOnce(&mut Self) -> Result<T, DecodeError>,
+        ) -> Result<T, DecodeError> {
+            if self.depth >= MAX_DEPTH {
+                return Err(DecodeError::TooDeep { offset: self.offset });
+            }
+
+            self.depth += 1;
+            let res = read(self);
+            self.depth -= 1;
+            res
+        }
+    }
+
+    pub trait TryReadFromBuffers: Sized {
+        fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError>;
+    }
+
+    macro_rules! impl_try_read_from_buffers {
+        ($($type_id:ty),*) => {
+            $(
+                impl TryReadFromBuffers for $type_id {
+                    fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+                        let bytes = bytes_reader.read_bytes(std::mem::size_of::<$type_id>())?;
+                        Ok(<$type_id>::from_le_bytes(bytes.try_into().unwrap()))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_try_read_from_buffers!(u8, i8, u32, i32, u64, i64, f32, f64);
+
+    impl TryReadFromBuffers for bool {
+        fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+            let offset = bytes_reader.offset();
+
+            match u8::try_read_from_buffers(bytes_reader)? {
+                0 => Ok(false),
+                1 => Ok(true),
+                value => Err(DecodeError::InvalidBool { offset, value }),
+            }
+        }
+    }
+
+    impl TryReadFromBuffers for char {
+        fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+            let offset = bytes_reader.offset();
+            let value = u32::try_read_from_buffers(bytes_reader)?;
+
+            char::from_u32(value).ok_or(DecodeError::InvalidChar { offset, value })
+        }
+    }
+
+    impl TryReadFromBuffers for String {
+        fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+            let len = bytes_reader.read_len()?;
+            let offset = bytes_reader.offset();
+            let bytes = bytes_reader.read_bytes(len)?;
+
+            String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::InvalidUtf8 { offset })
+        }
+    }
+
+    impl<T: TryReadFromBuffers> TryReadFromBuffers for Vec<T> {
+        fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+            let len = bytes_reader.read_len()?;
+            let mut items = Vec::with_capacity(len.min(bytes_reader.remaining()));
+
+            for _ in 0..len {
+                items.push(T::try_read_from_buffers(bytes_reader)?);
+            }
+
+            Ok(items)
+        }
+    }
+
+    impl<T: TryReadFromBuffers> TryReadFromBuffers for Option<T> {
+        fn try_read_from_buffers(bytes_reader: &mut CheckedBytesReader) -> Result<Self, DecodeError> {
+            let offset = bytes_reader.offset();
+
+            match u8::try_read_from_buffers(bytes_reader)? {
+                0 => Ok(None),
+                1 => Ok(Some(T::try_read_from_buffers(bytes_reader)?)),
+                value => Err(DecodeError::InvalidOption { offset, value }),
+            }
+        }
+    }
+}
+
+impl try_read::TryReadFromBuffers for Empty {
+    fn try_read_from_buffers(_: &mut try_read::CheckedBytesReader) -> Result<Self, try_read::DecodeError> {
+        Ok(Self)
+    }
+}
+
+impl try_read::TryReadFromBuffers for Point {
+    fn try_read_from_buffers(bytes_reader: &mut try_read::CheckedBytesReader) -> Result<Self, try_read::DecodeError> {
+        bytes_reader.nested(|bytes_reader| {
+            Ok(Self {
+                x: try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+                y: try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+            })
+        })
+    }
+}
+
+impl try_read::TryReadFromBuffers for Shape {
+    fn try_read_from_buffers(bytes_reader: &mut try_read::CheckedBytesReader) -> Result<Self, try_read::DecodeError> {
+        bytes_reader.nested(|bytes_reader| {
+            Ok(Self {
+                name: try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+                points: try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+                label: try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+            })
+        })
+    }
+}
+
+impl try_read::TryReadFromBuffers for Command {
+    fn try_read_from_buffers(bytes_reader: &mut try_read::CheckedBytesReader) -> Result<Self, try_read::DecodeError> {
+        bytes_reader.nested(|bytes_reader| {
+            let offset = bytes_reader.offset();
+            let value: u32 = try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?;
+
+            match value {
+                1 => Ok(Self::Idle),
+                2 => Ok(Self::Move {
+                    to: try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+                    visible: try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+                }),
+                3 => Ok(Self::Rename(
+                    try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+                    try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+                )),
+                _ => Err(try_read::DecodeError::UnknownEnumValue {
+                    offset,
+                    type_id: "Command",
+                    value,
+                }),
+            }
+        })
+    }
+}
+
+impl try_read::TryReadFromBuffers for Node {
+    fn try_read_from_buffers(bytes_reader: &mut try_read::CheckedBytesReader) -> Result<Self, try_read::DecodeError> {
+        bytes_reader.nested(|bytes_reader| {
+            let offset = bytes_reader.offset();
+            let value: u32 = try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?;
+
+            match value {
+                0 => Ok(Self::Leaf(
+                    try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+                )),
+                1 => Ok(Self::Group(
+                    try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+                )),
+                _ => Err(try_read::DecodeError::UnknownEnumValue {
+                    offset,
+                    type_id: "Node",
+                    value,
+                }),
+            }
+        })
+    }
+}
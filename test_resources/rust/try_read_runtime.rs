@@ -0,0 +1,126 @@
+// GENERATED, DO NOT EDIT
+
+#![allow(warnings)]
+#![allow(clippy)]
+#![allow(unknown_lints)]
+
+use tech_paws_buffers::memory::{BytesReader, BytesWriter, TechPawsBuffersModel};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodeError {
+    pub message: String,
+}
+
+impl Default for DecodeError {
+    fn default() -> Self {
+        Self {
+            message: String::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    Done,
+    Failed(
+        DecodeError,
+    ),
+}
+
+impl Default for Message {
+    fn default() -> Self {
+        Self::Done
+    }
+}
+
+impl TechPawsBuffersModel for DecodeError {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        Self {
+            message: String::read_from_buffers(bytes_reader),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        self.message.write_to_buffers(bytes_writer);
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            String::read_from_buffers(bytes_reader);
+        }
+    }
+}
+
+impl TechPawsBuffersModel for Message {
+    fn read_from_buffers(bytes_reader: &mut BytesReader) -> Self {
+        let value = bytes_reader.read_u32();
+
+        match value {
+            0 => return Message::Done,
+            1 => return Message::Failed(
+                DecodeError::read_from_buffers(bytes_reader),
+            ),
+            _ => panic!("Unsupported enum value: {}", value),
+        }
+    }
+
+    fn write_to_buffers(&self, bytes_writer: &mut BytesWriter) {
+        match self {
+            Message::Done => {
+                bytes_writer.write_u32(0);
+            },
+            Message::Failed(
+                v0,
+            ) => {
+                bytes_writer.write_u32(1);
+                v0.write_to_buffers(bytes_writer);
+            },
+        }
+    }
+
+    fn skip_in_buffers(bytes_reader: &mut BytesReader, count: u64) {
+        for _ in 0..count {
+            let value = bytes_reader.read_u32();
+
+            match value {
+                0 => (),
+                1 => {
+                    DecodeError::read_from_buffers(bytes_reader);
+                },
+                _ => panic!("Unsupported enum value: {}", value),
+            }
+        }
+    }
+}
+
+
+impl crate::try_read::TryReadFromBuffers for DecodeError {
+    fn try_read_from_buffers(bytes_reader: &mut crate::try_read::CheckedBytesReader) -> Result<Self, crate::try_read::DecodeError> {
+        bytes_reader.nested(|bytes_reader| {
+            Ok(Self {
+                message: crate::try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+            })
+        })
+    }
+}
+
+impl crate::try_read::TryReadFromBuffers for Message {
+    fn try_read_from_buffers(bytes_reader: &mut crate::try_read::CheckedBytesReader) -> Result<Self, crate::try_read::DecodeError> {
+        bytes_reader.nested(|bytes_reader| {
+            let offset = bytes_reader.offset();
+            let value: u32 = crate::try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?;
+
+            match value {
+                0 => Ok(Self::Done),
+                1 => Ok(Self::Failed(
+                    crate::try_read::TryReadFromBuffers::try_read_from_buffers(bytes_reader)?,
+                )),
+                _ => Err(crate::try_read::DecodeError::UnknownEnumValue {
+                    offset,
+                    type_id: "Message",
+                    value,
+                }),
+            }
+        })
+    }
+}
@@ -1,4 +1,3 @@
 // GENERATED, DO NOT EDIT
 
 import Foundation
-import Combine
@@ -0,0 +1,87 @@
+// GENERATED, DO NOT EDIT
+
+import Foundation
+import Models
+
+public struct ViewData: TechPawsBuffersModel {
+  public let deltaTime: Float
+  public let viewWidth: Float
+  public let viewHeight: Float
+  public let touchStartX: Float
+  public let touchStartY: Float
+  public let lastTouchX: Float
+  public let lastTouchY: Float
+  public let touchX: Float
+  public let touchY: Float
+
+  public static func createBuffersDefault() -> Self {
+    return ViewData(
+      deltaTime: 0,
+      viewWidth: 0,
+      viewHeight: 0,
+      touchStartX: 0,
+      touchStartY: 0,
+      lastTouchX: 0,
+      lastTouchY: 0,
+      touchX: 0,
+      touchY: 0
+    )
+  }
+
+  public static func readFromBuffers(
+    _ bytesReader: TechPawsBuffersBytesReader
+  ) -> Self {
+    let deltaTime = bytesReader.readFloat()
+    let viewWidth = bytesReader.readFloat()
+    let viewHeight = bytesReader.readFloat()
+    let touchStartX = bytesReader.readFloat()
+    let touchStartY = bytesReader.readFloat()
+    let lastTouchX = bytesReader.readFloat()
+    let lastTouchY = bytesReader.readFloat()
+    let touchX = bytesReader.readFloat()
+    let touchY = bytesReader.readFloat()
+
+    return ViewData(
+      deltaTime: deltaTime,
+      viewWidth: viewWidth,
+      viewHeight: viewHeight,
+      touchStartX: touchStartX,
+      touchStartY: touchStartY,
+      lastTouchX: lastTouchX,
+      lastTouchY: lastTouchY,
+      touchX: touchX,
+      touchY: touchY
+    )
+  }
+
+  public static func skipInBuffers(
+    _ bytesReader: TechPawsBuffersBytesReader,
+    _ count: UInt64
+  ) {
+    for _ in 1...count {
+      let _ = bytesReader.readFloat()
+      let _ = bytesReader.readFloat()
+      let _ = bytesReader.readFloat()
+      let _ = bytesReader.readFloat()
+      let _ = bytesReader.readFloat()
+      let _ = bytesReader.readFloat()
+      let _ = bytesReader.readFloat()
+      let _ = bytesReader.readFloat()
+      let _ = bytesReader.readFloat()
+    }
+  }
+
+  public func writeToBuffers(
+    _ bytesWriter: TechPawsBuffersBytesWriter
+  ) {
+    bytesWriter.writeFloat(deltaTime)
+    bytesWriter.writeFloat(viewWidth)
+    bytesWriter.writeFloat(viewHeight)
+    bytesWriter.writeFloat(touchStartX)
+    bytesWriter.writeFloat(touchStartY)
+    bytesWriter.writeFloat(lastTouchX)
+    bytesWriter.writeFloat(lastTouchY)
+    bytesWriter.writeFloat(touchX)
+    bytesWriter.writeFloat(touchY)
+  }
+}
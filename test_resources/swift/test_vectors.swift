@@ -0,0 +1,146 @@
+// GENERATED, DO NOT EDIT
+
+import XCTest
+
+final class TestVectorsTests: XCTestCase {
+    func testPointDefault() {
+        let data: [UInt8] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        let expected = Point(x: 0.0, y: 0.0)
+        let value = Point.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testPointMin() {
+        let data: [UInt8] = [0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF]
+        let expected = Point(x: -3.4028235e38, y: -3.4028235e38)
+        let value = Point.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testPointMax() {
+        let data: [UInt8] = [0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F]
+        let expected = Point(x: 3.4028235e38, y: 3.4028235e38)
+        let value = Point.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testPointRandom0() {
+        let data: [UInt8] = [0xA2, 0x64, 0xC4, 0xC6, 0xD0, 0x97, 0x65, 0x46]
+        let expected = Point(x: -25138.316, y: 14693.953)
+        let value = Point.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testLabelsDefault() {
+        let data: [UInt8] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        let expected = Labels(id: 0, delta: 0, visible: false, title: "", points: [], note: nil)
+        let value = Labels.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testLabelsMin() {
+        let data: [UInt8] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        let expected = Labels(id: 0, delta: -128, visible: false, title: "", points: [], note: nil)
+        let value = Labels.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testLabelsMax() {
+        let data: [UInt8] = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, 0x01, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x9C, 0x6E, 0xC3, 0xAF, 0x63, 0xC3, 0xB8, 0x64, 0xC3, 0xA9, 0x20, 0xE2, 0x9C, 0x93, 0x20, 0x22, 0x71, 0x75, 0x6F, 0x74, 0x65, 0x64, 0x22, 0x20, 0x5C, 0x20, 0x0A, 0xE4, 0xB8, 0xAD, 0xE6, 0x96, 0x87, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0x01, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x9C, 0x6E, 0xC3, 0xAF, 0x63, 0xC3, 0xB8, 0x64, 0xC3, 0xA9, 0x20, 0xE2, 0x9C, 0x93, 0x20, 0x22, 0x71, 0x75, 0x6F, 0x74, 0x65, 0x64, 0x22, 0x20, 0x5C, 0x20, 0x0A, 0xE4, 0xB8, 0xAD, 0xE6, 0x96, 0x87]
+        let expected = Labels(id: 18446744073709551615, delta: 127, visible: true, title: "\u{DC}n\u{EF}c\u{F8}d\u{E9} \u{2713} \"quoted\" \\ \n\u{4E2D}\u{6587}", points: [Point(x: 3.4028235e38, y: 3.4028235e38), Point(x: 3.4028235e38, y: 3.4028235e38)], note: "\u{DC}n\u{EF}c\u{F8}d\u{E9} \u{2713} \"quoted\" \\ \n\u{4E2D}\u{6587}")
+        let value = Labels.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testLabelsRandom0() {
+        let data: [UInt8] = [0xAF, 0xCD, 0x1D, 0x7B, 0x39, 0xA8, 0x20, 0xE2, 0xF4, 0x01, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x31, 0x5F, 0x5C, 0x61, 0xE2, 0x9C, 0x93, 0x5F, 0x62, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xEC, 0x6D, 0x6F, 0x46, 0xA2, 0xAD, 0x8A, 0xC6, 0x32, 0x9A, 0x98, 0x46, 0xAA, 0xFE, 0xC4, 0xC6, 0x01, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x59, 0xE2, 0x9C, 0x93, 0x62, 0xE4, 0xB8, 0xAD]
+        let expected = Labels(id: 16294208416658607535, delta: -12, visible: true, title: "1_\\a\u{2713}_b", points: [Point(x: 15323.48, y: -17750.816), Point(x: 19533.098, y: -25215.332)], note: "\"Y\u{2713}b\u{4E2D}")
+        let value = Labels.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testCommandDefault() {
+        let data: [UInt8] = [0x00, 0x00, 0x00, 0x00]
+        let expected = Command.idle
+        let value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testCommandIdle() {
+        let data: [UInt8] = [0x00, 0x00, 0x00, 0x00]
+        let expected = Command.idle
+        let value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testCommandMoveMin() {
+        let data: [UInt8] = [0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF]
+        let expected = Command.move(-2147483648, Point(x: -3.4028235e38, y: -3.4028235e38))
+        let value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testCommandMoveMax() {
+        let data: [UInt8] = [0x01, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F]
+        let expected = Command.move(2147483647, Point(x: 3.4028235e38, y: 3.4028235e38))
+        let value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testCommandRenameMin() {
+        let data: [UInt8] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        let expected = Command.rename("")
+        let value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testCommandRenameMax() {
+        let data: [UInt8] = [0x02, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x9C, 0x6E, 0xC3, 0xAF, 0x63, 0xC3, 0xB8, 0x64, 0xC3, 0xA9, 0x20, 0xE2, 0x9C, 0x93, 0x20, 0x22, 0x71, 0x75, 0x6F, 0x74, 0x65, 0x64, 0x22, 0x20, 0x5C, 0x20, 0x0A, 0xE4, 0xB8, 0xAD, 0xE6, 0x96, 0x87]
+        let expected = Command.rename("\u{DC}n\u{EF}c\u{F8}d\u{E9} \u{2713} \"quoted\" \\ \n\u{4E2D}\u{6587}")
+        let value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    func testCommandRandom0() {
+        let data: [UInt8] = [0x01, 0x00, 0x00, 0x00, 0xF4, 0x65, 0xB9, 0xA1, 0x62, 0x75, 0xED, 0xC6, 0x50, 0xF8, 0x4D, 0xC6]
+        let expected = Command.move(-1581685260, Point(x: -30394.691, y: -13182.078))
+        let value = Command.readFromBuffers(TechPawsBuffersBytesReader(data))
+
+        XCTAssertEqual(writeVector(expected), data)
+        XCTAssertEqual(writeVector(value), data)
+    }
+
+    private func writeVector<T: TechPawsBuffersModel>(_ value: T) -> [UInt8] {
+        let bytesWriter = TechPawsBuffersBytesWriter()
+        value.writeToBuffers(bytesWriter)
+        return bytesWriter.bytes
+    }
+}
@@ -0,0 +1,27 @@
+export const Commands = {
+    drawLines: 131073n,
+    drawPath: 131074n,
+    drawQuads: 131075n,
+    drawCenteredQuads: 131076n,
+    drawTexts: 131077n,
+    setColorPipeline: 131078n,
+    setTexturePipeline: 131079n,
+    drawCircles: 131080n,
+    drawHollowCircles: 131081n,
+} as const;
+
+export const Addr = {
+    someValue: "Hello World!",
+    Groups: {
+        main: 0n,
+        mainRender: 1n,
+        rpc: 2n,
+        rpcSync: 3n,
+        rpcRead: 4n,
+    },
+    deltaTime: 16.6,
+    flag: true,
+    CommandsBuffers: {
+        win1MainRender: 0n,
+    },
+} as const;
@@ -0,0 +1,2 @@
+// GENERATED, DO NOT EDIT
+
@@ -0,0 +1,280 @@
+export type MyEnum =
+    | { kind: "Idle" }
+    | { kind: "Move"; value: { x: number; y: number } }
+    | { kind: "Update"; values: [number, number, string] };
+
+export function readMyEnum(reader: BytesReader): MyEnum {
+    const kind = reader.readU32();
+
+    switch (kind) {
+        case 1:
+            return { kind: "Idle" };
+        case 2:
+            return {
+                kind: "Move",
+                value: {
+                    x: reader.readF64(),
+                    y: reader.readF64(),
+                },
+            };
+        case 3:
+            return {
+                kind: "Update",
+                values: [
+                    reader.readF64(),
+                    reader.readF64(),
+                    reader.readString(),
+                ],
+            };
+        default:
+            throw new Error(`Invalid MyEnum kind: ${kind}`);
+    }
+}
+
+export function writeMyEnum(writer: BytesWriter, value: MyEnum): void {
+    switch (value.kind) {
+        case "Idle":
+            writer.writeU32(1);
+            break;
+        case "Move":
+            writer.writeU32(2);
+            writer.writeF64(value.value.x);
+            writer.writeF64(value.value.y);
+            break;
+        case "Update":
+            writer.writeU32(3);
+            writer.writeF64(value.values[0]);
+            writer.writeF64(value.values[1]);
+            writer.writeString(value.values[2]);
+            break;
+    }
+}
+
+export function skipMyEnum(reader: BytesReader, count: number): void {
+    for (let i = 0; i < count; i++) {
+        readMyEnum(reader);
+    }
+}
+
+export function defaultMyEnum(): MyEnum {
+    return { kind: "Idle" };
+}
+
+export type MyEnumWithoutPositions =
+    | { kind: "Option1"; values: [bigint] }
+    | { kind: "Option2"; value: { name: string } }
+    | { kind: "Option3" }
+    | { kind: "Option4" };
+
+export function readMyEnumWithoutPositions(reader: BytesReader): MyEnumWithoutPositions {
+    const kind = reader.readU32();
+
+    switch (kind) {
+        case 0:
+            return {
+                kind: "Option1",
+                values: [
+                    reader.readU64(),
+                ],
+            };
+        case 1:
+            return {
+                kind: "Option2",
+                value: {
+                    name: reader.readString(),
+                },
+            };
+        case 2:
+            return { kind: "Option3" };
+        case 3:
+            return { kind: "Option4" };
+        default:
+            throw new Error(`Invalid MyEnumWithoutPositions kind: ${kind}`);
+    }
+}
+
+export function writeMyEnumWithoutPositions(writer: BytesWriter, value: MyEnumWithoutPositions): void {
+    switch (value.kind) {
+        case "Option1":
+            writer.writeU32(0);
+            writer.writeU64(value.values[0]);
+            break;
+        case "Option2":
+            writer.writeU32(1);
+            writer.writeString(value.value.name);
+            break;
+        case "Option3":
+            writer.writeU32(2);
+            break;
+        case "Option4":
+            writer.writeU32(3);
+            break;
+    }
+}
+
+export function skipMyEnumWithoutPositions(reader: BytesReader, count: number): void {
+    for (let i = 0; i < count; i++) {
+        readMyEnumWithoutPositions(reader);
+    }
+}
+
+export function defaultMyEnumWithoutPositions(): MyEnumWithoutPositions {
+    return {
+        kind: "Option1",
+        values: [
+            0n,
+        ],
+    };
+}
+
+export type MyEnumWithManyArguments =
+    | { kind: "Option1"; values: [bigint, bigint, bigint] }
+    | { kind: "Option2"; value: { name: string } }
+    | { kind: "Option3" }
+    | { kind: "Option4" };
+
+export function readMyEnumWithManyArguments(reader: BytesReader): MyEnumWithManyArguments {
+    const kind = reader.readU32();
+
+    switch (kind) {
+        case 0:
+            return {
+                kind: "Option1",
+                values: [
+                    reader.readU64(),
+                    reader.readU64(),
+                    reader.readU64(),
+                ],
+            };
+        case 1:
+            return {
+                kind: "Option2",
+                value: {
+                    name: reader.readString(),
+                },
+            };
+        case 2:
+            return { kind: "Option3" };
+        case 3:
+            return { kind: "Option4" };
+        default:
+            throw new Error(`Invalid MyEnumWithManyArguments kind: ${kind}`);
+    }
+}
+
+export function writeMyEnumWithManyArguments(writer: BytesWriter, value: MyEnumWithManyArguments): void {
+    switch (value.kind) {
+        case "Option1":
+            writer.writeU32(0);
+            writer.writeU64(value.values[0]);
+            writer.writeU64(value.values[1]);
+            writer.writeU64(value.values[2]);
+            break;
+        case "Option2":
+            writer.writeU32(1);
+            writer.writeString(value.value.name);
+            break;
+        case "Option3":
+            writer.writeU32(2);
+            break;
+        case "Option4":
+            writer.writeU32(3);
+            break;
+    }
+}
+
+export function skipMyEnumWithManyArguments(reader: BytesReader, count: number): void {
+    for (let i = 0; i < count; i++) {
+        readMyEnumWithManyArguments(reader);
+    }
+}
+
+export function defaultMyEnumWithManyArguments(): MyEnumWithManyArguments {
+    return {
+        kind: "Option1",
+        values: [
+            0n,
+            0n,
+            0n,
+        ],
+    };
+}
+
+export type MyEnumWithNamedArguments =
+    | { kind: "Option1"; value: { name: string; value: number; bytes: number[] } }
+    | { kind: "Option2"; values: [bigint, bigint, bigint] }
+    | { kind: "Option3" }
+    | { kind: "Option4" };
+
+export function readMyEnumWithNamedArguments(reader: BytesReader): MyEnumWithNamedArguments {
+    const kind = reader.readU32();
+
+    switch (kind) {
+        case 0:
+            return {
+                kind: "Option1",
+                value: {
+                    name: reader.readString(),
+                    value: reader.readF32(),
+                    bytes: reader.readVec(() => reader.readU8()),
+                },
+            };
+        case 1:
+            return {
+                kind: "Option2",
+                values: [
+                    reader.readU64(),
+                    reader.readU64(),
+                    reader.readU64(),
+                ],
+            };
+        case 2:
+            return { kind: "Option3" };
+        case 3:
+            return { kind: "Option4" };
+        default:
+            throw new Error(`Invalid MyEnumWithNamedArguments kind: ${kind}`);
+    }
+}
+
+export function writeMyEnumWithNamedArguments(writer: BytesWriter, value: MyEnumWithNamedArguments): void {
+    switch (value.kind) {
+        case "Option1":
+            writer.writeU32(0);
+            writer.writeString(value.value.name);
+            writer.writeF32(value.value.value);
+            writer.writeVec(value.value.bytes, (item) => writer.writeU8(item));
+            break;
+        case "Option2":
+            writer.writeU32(1);
+            writer.writeU64(value.values[0]);
+            writer.writeU64(value.values[1]);
+            writer.writeU64(value.values[2]);
+            break;
+        case "Option3":
+            writer.writeU32(2);
+            break;
+        case "Option4":
+            writer.writeU32(3);
+            break;
+    }
+}
+
+export function skipMyEnumWithNamedArguments(reader: BytesReader, count: number): void {
+    for (let i = 0; i < count; i++) {
+        readMyEnumWithNamedArguments(reader);
+    }
+}
+
+export function defaultMyEnumWithNamedArguments(): MyEnumWithNamedArguments {
+    return {
+        kind: "Option1",
+        value: {
+            name: "",
+            value: 0,
+            bytes: [],
+        },
+    };
+}
+
+
@@ -0,0 +1,61 @@
+export type Input =
+    | { kind: "Key"; value: { kind: number; code: number } }
+    | { kind: "Click"; values: [number, number] };
+
+export function readInput(reader: BytesReader): Input {
+    const kind = reader.readU32();
+
+    switch (kind) {
+        case 0:
+            return {
+                kind: "Key",
+                value: {
+                    kind: reader.readU32(),
+                    code: reader.readU32(),
+                },
+            };
+        case 1:
+            return {
+                kind: "Click",
+                values: [
+                    reader.readF32(),
+                    reader.readF32(),
+                ],
+            };
+        default:
+            throw new Error(`Invalid Input kind: ${kind}`);
+    }
+}
+
+export function writeInput(writer: BytesWriter, value: Input): void {
+    switch (value.kind) {
+        case "Key":
+            writer.writeU32(0);
+            writer.writeU32(value.value.kind);
+            writer.writeU32(value.value.code);
+            break;
+        case "Click":
+            writer.writeU32(1);
+            writer.writeF32(value.values[0]);
+            writer.writeF32(value.values[1]);
+            break;
+    }
+}
+
+export function skipInput(reader: BytesReader, count: number): void {
+    for (let i = 0; i < count; i++) {
+        readInput(reader);
+    }
+}
+
+export function defaultInput(): Input {
+    return {
+        kind: "Key",
+        value: {
+            kind: 0,
+            code: 0,
+        },
+    };
+}
+
+
@@ -0,0 +1,47 @@
+export class ExamplesAsyncRpc {
+    static readonly scopeId = "106c2228-ff3b-45c5-8a55-db9c0537f275";
+
+    constructor(private readonly runtime: RpcRuntime) {}
+
+    printHelloWorld(): Promise<void> {
+        return this.runtime.callAsync(
+            ExamplesAsyncRpc.scopeId,
+            0,
+            null,
+            null,
+        );
+    }
+
+    helloWorld(): Promise<string> {
+        return this.runtime.callAsync(
+            ExamplesAsyncRpc.scopeId,
+            1,
+            null,
+            (reader) => reader.readString(),
+        );
+    }
+
+    sayHello(name: string): Promise<string> {
+        return this.runtime.callAsync(
+            ExamplesAsyncRpc.scopeId,
+            2,
+            (writer) => {
+                writer.writeString(name);
+            },
+            (reader) => reader.readString(),
+        );
+    }
+
+    sum(a: number, b: number, c: number): Promise<number> {
+        return this.runtime.callAsync(
+            ExamplesAsyncRpc.scopeId,
+            3,
+            (writer) => {
+                writer.writeI32(a);
+                writer.writeF32(b);
+                writer.writeF64(c);
+            },
+            (reader) => reader.readF64(),
+        );
+    }
+}
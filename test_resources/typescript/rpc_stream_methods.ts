@@ -0,0 +1,56 @@
+export class ExamplesStreamRpc {
+    static readonly scopeId = "723ca727-6a66-43a7-bfcc-b8ad94eac9be";
+
+    constructor(private readonly runtime: RpcRuntime) {}
+
+    onCounter(listener: (value: number) => void): () => void {
+        return this.runtime.subscribe(
+            ExamplesStreamRpc.scopeId,
+            0,
+            (reader) => reader.readI32(),
+            listener,
+        );
+    }
+
+    counter(): AsyncIterable<number> {
+        return this.runtime.stream(
+            ExamplesStreamRpc.scopeId,
+            0,
+            (reader) => reader.readI32(),
+        );
+    }
+
+    onTheme(listener: (value: string) => void): () => void {
+        return this.runtime.subscribe(
+            ExamplesStreamRpc.scopeId,
+            1,
+            (reader) => reader.readString(),
+            listener,
+        );
+    }
+
+    theme(): AsyncIterable<string> {
+        return this.runtime.stream(
+            ExamplesStreamRpc.scopeId,
+            1,
+            (reader) => reader.readString(),
+        );
+    }
+
+    onTrigger(listener: () => void): () => void {
+        return this.runtime.subscribe(
+            ExamplesStreamRpc.scopeId,
+            2,
+            () => undefined,
+            listener,
+        );
+    }
+
+    trigger(): AsyncIterable<void> {
+        return this.runtime.stream(
+            ExamplesStreamRpc.scopeId,
+            2,
+            () => undefined,
+        );
+    }
+}
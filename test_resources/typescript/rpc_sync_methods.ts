@@ -0,0 +1,48 @@
+export class ExamplesSyncRpc {
+    static readonly scopeId = "4de616f8-12c5-4d2c-8d48-9c5fb038991f";
+
+    constructor(private readonly runtime: RpcRuntime) {}
+
+    printHelloWorld(): void {
+        this.runtime.call(
+            ExamplesSyncRpc.scopeId,
+            0,
+            null,
+            null,
+        );
+    }
+
+    helloWorld(): string {
+        return this.runtime.call(
+            ExamplesSyncRpc.scopeId,
+            1,
+            null,
+            (reader) => reader.readString(),
+        );
+    }
+
+    sayHello(firstName: string, lastName: string): string {
+        return this.runtime.call(
+            ExamplesSyncRpc.scopeId,
+            2,
+            (writer) => {
+                writer.writeString(firstName);
+                writer.writeString(lastName);
+            },
+            (reader) => reader.readString(),
+        );
+    }
+
+    sum(a: number, b: number, c: number): number {
+        return this.runtime.call(
+            ExamplesSyncRpc.scopeId,
+            3,
+            (writer) => {
+                writer.writeI32(a);
+                writer.writeF32(b);
+                writer.writeF64(c);
+            },
+            (reader) => reader.readF64(),
+        );
+    }
+}
@@ -0,0 +1,57 @@
+export interface ViewData {
+    deltaTime: number;
+    viewWidth: number;
+    viewHeight: number;
+    touchStartX: number;
+    touchStartY: number;
+    lastTouchX: number;
+    lastTouchY: number;
+    touchX: number;
+    touchY: number;
+}
+
+export function readViewData(reader: BytesReader): ViewData {
+    return {
+        deltaTime: reader.readF32(),
+        viewWidth: reader.readF32(),
+        viewHeight: reader.readF32(),
+        touchStartX: reader.readF32(),
+        touchStartY: reader.readF32(),
+        lastTouchX: reader.readF32(),
+        lastTouchY: reader.readF32(),
+        touchX: reader.readF32(),
+        touchY: reader.readF32(),
+    };
+}
+
+export function writeViewData(writer: BytesWriter, value: ViewData): void {
+    writer.writeF32(value.deltaTime);
+    writer.writeF32(value.viewWidth);
+    writer.writeF32(value.viewHeight);
+    writer.writeF32(value.touchStartX);
+    writer.writeF32(value.touchStartY);
+    writer.writeF32(value.lastTouchX);
+    writer.writeF32(value.lastTouchY);
+    writer.writeF32(value.touchX);
+    writer.writeF32(value.touchY);
+}
+
+export function skipViewData(reader: BytesReader, count: number): void {
+    for (let i = 0; i < count; i++) {
+        readViewData(reader);
+    }
+}
+
+export function defaultViewData(): ViewData {
+    return {
+        deltaTime: 0,
+        viewWidth: 0,
+        viewHeight: 0,
+        touchStartX: 0,
+        touchStartY: 0,
+        lastTouchX: 0,
+        lastTouchY: 0,
+        touchX: 0,
+        touchY: 0,
+    };
+}
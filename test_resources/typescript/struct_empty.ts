@@ -0,0 +1,18 @@
+export interface Empty {}
+
+export function readEmpty(reader: BytesReader): Empty {
+    return {};
+}
+
+export function writeEmpty(writer: BytesWriter, value: Empty): void {
+}
+
+export function skipEmpty(reader: BytesReader, count: number): void {
+    for (let i = 0; i < count; i++) {
+        readEmpty(reader);
+    }
+}
+
+export function defaultEmpty(): Empty {
+    return {};
+}
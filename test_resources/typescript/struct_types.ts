@@ -0,0 +1,77 @@
+export interface BasicTypesModel {
+    byte: number;
+    someInteger: number;
+    someLong: bigint;
+    someUnsigedInteger: number;
+    someUnsigedLong: number;
+    someFloatNumber: number;
+    someDoubleNumber: number;
+    someBool: boolean;
+    someString: string;
+    vector: string[];
+    generic: LinearTable<number, Test>;
+    custom: MyModel;
+    optionalString: string | null;
+    optionalF32: number | null;
+}
+
+export function readBasicTypesModel(reader: BytesReader): BasicTypesModel {
+    return {
+        byte: reader.readU8(),
+        someInteger: reader.readI32(),
+        someLong: reader.readI64(),
+        someUnsigedInteger: reader.readU32(),
+        someUnsigedLong: reader.readU32(),
+        someFloatNumber: reader.readF32(),
+        someDoubleNumber: reader.readF64(),
+        someBool: reader.readBool(),
+        someString: reader.readString(),
+        vector: reader.readVec(() => reader.readString()),
+        generic: readLinearTable(reader),
+        custom: readMyModel(reader),
+        optionalString: reader.readOption(() => reader.readString()),
+        optionalF32: reader.readOption(() => reader.readF32()),
+    };
+}
+
+export function writeBasicTypesModel(writer: BytesWriter, value: BasicTypesModel): void {
+    writer.writeU8(value.byte);
+    writer.writeI32(value.someInteger);
+    writer.writeI64(value.someLong);
+    writer.writeU32(value.someUnsigedInteger);
+    writer.writeU32(value.someUnsigedLong);
+    writer.writeF32(value.someFloatNumber);
+    writer.writeF64(value.someDoubleNumber);
+    writer.writeBool(value.someBool);
+    writer.writeString(value.someString);
+    writer.writeVec(value.vector, (item) => writer.writeString(item));
+    writeLinearTable(writer, value.generic);
+    writeMyModel(writer, value.custom);
+    writer.writeOption(value.optionalString, (value) => writer.writeString(value));
+    writer.writeOption(value.optionalF32, (value) => writer.writeF32(value));
+}
+
+export function skipBasicTypesModel(reader: BytesReader, count: number): void {
+    for (let i = 0; i < count; i++) {
+        readBasicTypesModel(reader);
+    }
+}
+
+export function defaultBasicTypesModel(): BasicTypesModel {
+    return {
+        byte: 0,
+        someInteger: 0,
+        someLong: 0n,
+        someUnsigedInteger: 0,
+        someUnsigedLong: 0,
+        someFloatNumber: 0,
+        someDoubleNumber: 0,
+        someBool: false,
+        someString: "",
+        vector: [],
+        generic: defaultLinearTable(),
+        custom: defaultMyModel(),
+        optionalString: null,
+        optionalF32: null,
+    };
+}